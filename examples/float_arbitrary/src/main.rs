@@ -1,4 +1,4 @@
-use arbitrary::Arbitrary;
+use arbitrary::{Arbitrary, Unstructured};
 use nutype::nutype;
 
 #[nutype(
@@ -40,6 +40,18 @@ struct GreaterAndLessOrEqualF32(f32);
 #[nutype(derive(Debug, Arbitrary), validate(greater = -1.0, less_or_equal = -0.5))]
 struct GreaterOrEqualAndLessOrEqualF64(f64);
 
+#[nutype(derive(Debug, Arbitrary), validate(latitude))]
+struct Latitude(f64);
+
+#[nutype(derive(Debug, Arbitrary), validate(longitude, greater = 0.0))]
+struct EasternLongitude(f32);
+
+#[nutype(derive(Debug, Arbitrary), validate(percentage))]
+struct Percentage(f32);
+
+#[nutype(derive(Debug, Arbitrary), validate(ratio, less = 0.5))]
+struct LowerHalfRatio(f64);
+
 fn main() {
     arbtest::builder().run(|u| {
         let _num = UnrestrictedFloatNumber::arbitrary(u)?.into_inner();
@@ -111,4 +123,43 @@ fn main() {
         assert!((-1.0..=-0.5).contains(&value));
         Ok(())
     });
+
+    arbtest::builder().run(|u| {
+        let value: f64 = Latitude::arbitrary(u)?.into_inner();
+        assert!((-90.0..=90.0).contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value: f32 = EasternLongitude::arbitrary(u)?.into_inner();
+        assert!(value > 0.0 && value <= 180.0);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value: f32 = Percentage::arbitrary(u)?.into_inner();
+        assert!((0.0..=100.0).contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value: f64 = LowerHalfRatio::arbitrary(u)?.into_inner();
+        assert!((0.0..0.5).contains(&value));
+        Ok(())
+    });
+
+    // Shrunk inputs converge to the boundaries: an empty input gives the lower one and
+    // a single odd byte gives the upper one.
+    let at_boundary =
+        |data: &[u8]| GreaterOrEqualAndLessF32::arbitrary(&mut Unstructured::new(data));
+    assert_eq!(at_boundary(&[]).unwrap().into_inner(), -10.0);
+    let value = at_boundary(&[1]).unwrap().into_inner();
+    assert!(value < 10.0 && value > 9.99);
+
+    let at_boundary = |data: &[u8]| GreaterAndLessF32::arbitrary(&mut Unstructured::new(data));
+    let value = at_boundary(&[]).unwrap().into_inner();
+    assert!(value > -10.0 && value < -9.99);
+    // Both boundaries are exclusive, so neither of them is ever produced
+    let value = at_boundary(&[1]).unwrap().into_inner();
+    assert!(value < 10.0 && value > 9.99);
 }
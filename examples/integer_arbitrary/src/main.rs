@@ -1,4 +1,4 @@
-use arbitrary::Arbitrary;
+use arbitrary::{Arbitrary, Unstructured};
 use nutype::nutype;
 
 // Inclusive boundaries. 1 and 6 are included, so the value can only be 1, 2, 3, 4, 5 or 6.
@@ -33,6 +33,67 @@ struct LessThan2(u128);
 #[nutype(validate(less = 2), derive(Arbitrary, Debug))]
 struct LessOrEqual2(u128);
 
+// A signed 3-bit value is in range -4..=3, and it's additionally limited to -2..=3.
+#[nutype(
+    validate(fits_bits = 3, greater_or_equal = -2),
+    derive(Arbitrary, Debug)
+)]
+struct ThreeBitsFromMinusTwo(i16);
+
+#[nutype(
+    validate(aligned_to = 8, greater_or_equal = -20, less = 20),
+    derive(Arbitrary, Debug)
+)]
+struct AlignedTo8(i8);
+
+#[nutype(validate(odd, greater_or_equal = -5, less_or_equal = 5), derive(Arbitrary, Debug))]
+struct SmallOdd(i32);
+
+#[nutype(validate(even), derive(Arbitrary, Debug))]
+struct EvenByte(u8);
+
+#[nutype(
+    validate(power_of_two, greater_or_equal = 3, less_or_equal = 100),
+    derive(Arbitrary, Debug)
+)]
+struct BufferSize(u32);
+
+// Zero is excluded from -3..=3, and the other values are multiples of 3, so only -3 and 3 are left.
+#[nutype(
+    validate(nonzero, aligned_to = 3, greater_or_equal = -3, less_or_equal = 3),
+    derive(Arbitrary, Debug)
+)]
+struct NonzeroStep(i64);
+
+// The values are picked from the list, 10 is excluded by the upper bound.
+#[nutype(validate(one_of = [1, 2, 5, 10], less = 10), derive(Arbitrary, Debug))]
+struct Coin(u8);
+
+// Only 1 and 4 are left from 0..=4 after excluding 0, 2 and 3.
+#[nutype(
+    validate(not_in = [0, 2, 3], greater_or_equal = 0, less_or_equal = 4),
+    derive(Arbitrary, Debug)
+)]
+struct SparseSlot(u8);
+
+// The excluded powers of two are skipped, so only 8 and 64 are left.
+#[nutype(
+    validate(power_of_two, not_in = [16, 32], greater_or_equal = 8, less_or_equal = 64),
+    derive(Arbitrary, Debug)
+)]
+struct SparseBlockSize(u32);
+
+// The percentage is additionally limited to 50..=100.
+#[nutype(validate(percentage, greater_or_equal = 50), derive(Arbitrary, Debug))]
+struct UpperHalfPercentage(u32);
+
+// The boundaries are given by the const generic parameters.
+#[nutype(
+    validate(greater_or_equal = MIN, less_or_equal = MAX),
+    derive(Arbitrary, Debug)
+)]
+struct Bounded<const MIN: i16, const MAX: i16>(i16);
+
 fn main() {
     arbtest::builder().run(|u| {
         let tax_class = GermanTaxClass::arbitrary(u)?.into_inner();
@@ -70,4 +131,84 @@ fn main() {
         assert!(value == 0 || value == 1 || value == 2);
         Ok(())
     });
+
+    arbtest::builder().run(|u| {
+        let value = ThreeBitsFromMinusTwo::arbitrary(u)?.into_inner();
+        assert!((-2..=3).contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = AlignedTo8::arbitrary(u)?.into_inner();
+        assert!([-16, -8, 0, 8, 16].contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = SmallOdd::arbitrary(u)?.into_inner();
+        assert!([-5, -3, -1, 1, 3, 5].contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = EvenByte::arbitrary(u)?.into_inner();
+        assert_eq!(value % 2, 0);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = BufferSize::arbitrary(u)?.into_inner();
+        assert!([4, 8, 16, 32, 64].contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = NonzeroStep::arbitrary(u)?.into_inner();
+        assert!(value == -3 || value == 3);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = Coin::arbitrary(u)?.into_inner();
+        assert!([1, 2, 5].contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = SparseSlot::arbitrary(u)?.into_inner();
+        assert!(value == 1 || value == 4);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = SparseBlockSize::arbitrary(u)?.into_inner();
+        assert!(value == 8 || value == 64);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = UpperHalfPercentage::arbitrary(u)?.into_inner();
+        assert!((50..=100).contains(&value));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let value = Bounded::<-3, 3>::arbitrary(u)?.into_inner();
+        assert!((-3..=3).contains(&value));
+        Ok(())
+    });
+
+    // Shrunk inputs converge to the boundaries: an empty input gives the lower one and
+    // a single odd byte gives the upper one.
+    let at_boundary = |data: &[u8]| GermanTaxClass::arbitrary(&mut Unstructured::new(data));
+    assert_eq!(at_boundary(&[]).unwrap().into_inner(), 1);
+    assert_eq!(at_boundary(&[1]).unwrap().into_inner(), 6);
+
+    let at_boundary = |data: &[u8]| AlignedTo8::arbitrary(&mut Unstructured::new(data));
+    assert_eq!(at_boundary(&[]).unwrap().into_inner(), -16);
+    assert_eq!(at_boundary(&[1]).unwrap().into_inner(), 16);
+
+    // One byte picks the boundary, the rest is the offset from it.
+    assert_eq!(GermanTaxClass::size_hint(0), (17, Some(17)));
+    assert_eq!(GreaterThan125::size_hint(0), (2, Some(2)));
 }
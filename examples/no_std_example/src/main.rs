@@ -15,6 +15,7 @@ fn panic(_panic: &PanicInfo<'_>) -> ! {
 #[nutype(
     validate(greater_or_equal = 1, less_or_equal = 6),
     sanitize(with = |x| x),
+    from_float = round,
     derive(
         Debug,
         Clone,
@@ -72,3 +73,13 @@ pub struct Point {
     Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, AsRef, Into, From, Deref, Borrow, Hash
 ))]
 pub struct Location(Point);
+
+// Heapless collections
+#[nutype(
+    validate(len_min = 3, len_max = 16),
+    derive(Debug, Clone, PartialEq, Eq, AsRef, Deref)
+)]
+pub struct DeviceName(heapless::String<16>);
+
+#[nutype(validate(not_empty), derive(Debug, Clone, PartialEq, AsRef))]
+pub struct Readings(heapless::Vec<i16, 8>);
@@ -85,30 +85,70 @@
 //! * String
 //! * Integer (`u8`, `u16`,`u32`, `u64`, `u128`, `i8`, `i16`, `i32`, `i64`, `i128`, `usize`, `isize`)
 //! * Float (`f32`, `f64`)
+//! * Range (`Range<T>`, `RangeInclusive<T>`)
+//! * Tuple (e.g. `(f64, f64)`)
 //! * Any other arbitrary type
 //!
 //! ## String
 //!
-//! At the moment the string inner type supports only `String` (owned) type.
+//! Besides `String`, the string inner type can be `Box<str>`, `Arc<str>` or `Cow<'static, str>`.
+//! Sanitizers and validators work the same way, `Deref` and `Borrow` target `str` for these containers.
 //!
 //! ### String sanitizers
 //!
-//! | Sanitizer   | Description                                                                         | Example                                         |
-//! |-------------|-------------------------------------------------------------------------------------|-------------------------------------------------|
-//! | `trim`      | Removes leading and trailing whitespaces                                            | `trim`                                          |
-//! | `lowercase` | Converts the string to lowercase                                                    | `lowercase`                                     |
-//! | `uppercase` | Converts the string to uppercase                                                    | `uppercase`                                     |
-//! | `with`      | Custom sanitizer. A function or closure that receives `String` and returns `String` | `with = \|mut s: String\| { s.truncate(5); s }` |
+//! | Sanitizer              | Description                                                                         | Example                                         |
+//! |------------------------|-------------------------------------------------------------------------------------|-------------------------------------------------|
+//! | `trim`                 | Removes leading and trailing whitespaces                                            | `trim`                                          |
+//! | `lowercase`            | Converts the string to lowercase                                                    | `lowercase`                                     |
+//! | `uppercase`            | Converts the string to uppercase                                                    | `uppercase`                                     |
+//! | `normalize_newlines`   | Converts CRLF (`\r\n`) and CR (`\r`) line endings to LF (`\n`)                      | `normalize_newlines`                            |
+//! | `collapse_whitespace`  | Replaces every run of whitespaces (including newlines) with a single space          | `collapse_whitespace`                           |
+//! | `remove_control_chars` | Removes control characters (including newlines and tabs)                            | `remove_control_chars`                          |
+//! | `truncate`             | Cuts the string to at most the given number of chars (or another `len_unit`)        | `truncate = 64`                                 |
+//! | `nfc`                  | Unicode normalization form C. Requires `unicode_normalization` feature.             | `nfc`                                           |
+//! | `nfkc`                 | Unicode normalization form KC. Requires `unicode_normalization` feature.            | `nfkc`                                          |
+//! | `with`                 | Custom sanitizer. A function or closure that receives `String` and returns `String` | `with = \|mut s: String\| { s.truncate(5); s }` |
 //!
 //! ### String validators
 //!
-//! | Validator      | Description                                                                     | Error variant        | Example                                      |
-//! |----------------|---------------------------------------------------------------------------------|----------------------|----------------------------------------------|
-//! | `len_char_min` | Min length of the string (in chars, not bytes)                                  | `LenCharMinViolated` | `len_char_min = 5`                           |
-//! | `len_char_max` | Max length of the string (in chars, not bytes)                                  | `LenCharMaxViolated` | `len_char_max = 255`                         |
-//! | `not_empty`    | Rejects an empty string                                                         | `NotEmptyViolated`   | `not_empty`                                  |
-//! | `regex`        | Validates format with a regex. Requires `regex` feature.                        | `RegexViolated`      | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
-//! | `predicate`    | Custom validator. A function or closure that receives `&str` and returns `bool` | `PredicateViolated`  | `predicate = \|s: &str\| s.contains('@')`    |
+//! | Validator          | Description                                                                             | Error variant            | Example                                      |
+//! |--------------------|-----------------------------------------------------------------------------------------|--------------------------|----------------------------------------------|
+//! | `len_char_min`     | Min length of the string (in chars, unless another `len_unit` is set)                   | `LenCharMinViolated`     | `len_char_min = 5`                           |
+//! | `len_char_max`     | Max length of the string (in chars, unless another `len_unit` is set)                   | `LenCharMaxViolated`     | `len_char_max = 255`                         |
+//! | `len_byte_min`     | Min length of the string in bytes (UTF-8)                                               | `LenByteMinViolated`     | `len_byte_min = 5`                           |
+//! | `len_byte_max`     | Max length of the string in bytes (UTF-8), e.g. to fit a database column                | `LenByteMaxViolated`     | `len_byte_max = 255`                         |
+//! | `len_grapheme_min` | Min length of the string in grapheme clusters. Requires `unicode_segmentation` feature. | `LenGraphemeMinViolated` | `len_grapheme_min = 3`                       |
+//! | `len_grapheme_max` | Max length of the string in grapheme clusters. Requires `unicode_segmentation` feature. | `LenGraphemeMaxViolated` | `len_grapheme_max = 32`                      |
+//! | `not_empty`        | Rejects an empty string                                                                 | `NotEmptyViolated`       | `not_empty`                                  |
+//! | `regex`            | Validates format with a regex. Requires `regex` feature.                                | `RegexViolated`          | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
+//! | `numeric`          | A decimal number like `-12.50` (no exponent), optionally within inclusive `min`/`max`   | `NumericViolated`        | `numeric(min = 0, max = 9999)`               |
+//! | `ascii`            | The string must contain only ASCII characters                                           | `AsciiViolated`          | `ascii`                                      |
+//! | `alphanumeric`     | Every character must be alphanumeric (`char::is_alphanumeric`, Unicode aware)           | `AlphanumericViolated`   | `alphanumeric`                               |
+//! | `alphabetic`       | Every character must be alphabetic (`char::is_alphabetic`, Unicode aware)               | `AlphabeticViolated`     | `alphabetic`                                 |
+//! | `email`            | Email address (`local@domain.tld`), a pragmatic subset of RFC 5322                      | `EmailViolated`          | `email`                                      |
+//! | `url`              | Valid URL, optionally with one of the given schemes. Requires `url` feature.            | `UrlViolated`            | `url` or `url(schemes = ["https"])`          |
+//! | `starts_with`      | The string must start with the given prefix                                             | `StartsWithViolated`     | `starts_with = "sk_"`                        |
+//! | `ends_with`        | The string must end with the given suffix                                               | `EndsWithViolated`       | `ends_with = ".pdf"`                         |
+//! | `contains`         | The string must contain the given substring                                             | `ContainsViolated`       | `contains = "@"`                             |
+//! | `predicate`        | Custom validator. A function or closure that receives `&str` and returns `bool`         | `PredicateViolated`      | `predicate = \|s: &str\| s.contains('@')`    |
+//!
+//! #### Length unit
+//!
+//! `len_char_min`, `len_char_max` and `truncate` count chars by default.
+//! Set `len_unit = bytes` or `len_unit = graphemes` to count UTF-8 bytes or grapheme clusters instead
+//! (the latter requires `unicode_segmentation` feature).
+//! `truncate` always cuts on a boundary of the chosen unit, so the result stays valid UTF-8:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     len_unit = bytes,
+//!     sanitize(truncate = 255),
+//!     validate(not_empty, len_char_max = 255),
+//! )]
+//! pub struct Title(String);
+//! ```
 //!
 //! #### Regex validation
 //!
@@ -176,19 +216,52 @@
 //!
 //! ### Integer validators
 //!
-//! | Validator           | Description           | Error variant             | Example                              |
-//! | ------------------- | --------------------- | ------------------------- | ------------------------------------ |
-//! | `less`              | Exclusive upper bound | `LessViolated`            | `less = 100`                         |
-//! | `less_or_equal`     | Inclusive upper bound | `LessOrEqualViolated`     | `less_or_equal = 99`                 |
-//! | `greater`           | Exclusive lower bound | `GreaterViolated`         | `greater = 17`                       |
-//! | `greater_or_equal`  | Inclusive lower bound | `GreaterOrEqualViolated`  | `greater_or_equal = 18`              |
-//! | `predicate`         | Custom predicate      | `PredicateViolated`       | `predicate = \|num\| num % 2 == 0`   |
+//! | Validator          | Description             | Error variant            | Example                            |
+//! | ------------------ | ----------------------- | ------------------------ | ---------------------------------- |
+//! | `less`             | Exclusive upper bound   | `LessViolated`           | `less = 100`                       |
+//! | `less_or_equal`    | Inclusive upper bound   | `LessOrEqualViolated`    | `less_or_equal = 99`               |
+//! | `greater`          | Exclusive lower bound   | `GreaterViolated`        | `greater = 17`                     |
+//! | `greater_or_equal` | Inclusive lower bound   | `GreaterOrEqualViolated` | `greater_or_equal = 18`            |
+//! | `fits_bits`        | Fits in N bits          | `FitsBitsViolated`       | `fits_bits = 12`                   |
+//! | `aligned_to`       | Multiple of N           | `AlignedToViolated`      | `aligned_to = 4096`                |
+//! | `multiple_of`      | Multiple of N           | `MultipleOfViolated`     | `multiple_of = 5`                  |
+//! | `percentage`       | From 0 to 100           | `PercentageViolated`     | `percentage`                       |
+//! | `even`             | Divisible by 2          | `EvenViolated`           | `even`                             |
+//! | `odd`              | Not divisible by 2      | `OddViolated`            | `odd`                              |
+//! | `power_of_two`     | Power of two (unsigned) | `PowerOfTwoViolated`     | `power_of_two`                     |
+//! | `nonzero`          | Not zero                | `NonzeroViolated`        | `nonzero`                          |
+//! | `one_of`           | One of the given values | `OneOfViolated`          | `one_of = [1, 2, 5, 10]`           |
+//! | `not_in`           | Not one of given values | `NotInViolated`          | `not_in = [0, 255]`                |
+//! | `predicate`        | Custom predicate        | `PredicateViolated`      | `predicate = \|num\| num % 2 == 0` |
+//!
+//! `multiple_of` does the same check as `aligned_to` and is meant for quantities in fixed increments (cents, lot sizes, page sizes). It can also be spelled `divisible_by`.
+//!
+//! Types with `power_of_two` validator also get `log2()` method, which returns `n` of the value `2^n`.
+//!
+//! Types with both lower and upper bounds also get `all()`, which iterates over every valid value in ascending order, and `count()`.
+//! If the type is validated by the bounds alone, `count()` is computed from the bounds, otherwise it walks over the values between them.
+//! Types with validation also get `range(from, to)`, which iterates over the valid values from `from` to `to` (inclusive).
+//! Types validated by the bounds alone also get `saturating_add()` and `saturating_sub()`, which saturate at the bounds of the validators rather than at the bounds of the inner type.
+//! They also get `::new_or_clamp()` constructor, which clamps an out-of-range value to the nearest bound instead of returning an error (e.g. for the input of a UI slider).
+//!
+//! With `nonzero` validator the value is stored as `NonZero*` (e.g. `NonZeroU32`), so `Option<T>` has the same size as `T`.
+//!
+//! Validators can be combined with `any`, `all` and `not`, e.g. `validate(any(multiple_of = 3, multiple_of = 5))`.
+//! Failure of `any(..)` and `not(..)` is reported as `AnyViolated` and `NotViolated`.
+//! `all(..)` on the top level is the same as listing the validators, so the error names the one that failed.
 //!
 //! ### Integer derivable traits
 //!
 //! The following traits can be derived for an integer-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `TryFrom<&str>`, `Hash`, `Borrow`, `Display`, `Default`, `LowerHex`, `UpperHex`, `Binary`, `Octal`,
+//! `Sum`, `Product`, `Add`, `Sub`, `Mul`, `Neg`, `AddAssign`, `SubAssign`, `MulAssign`,
+//! `Serialize`, `Deserialize`, `ToPrimitive`, `FromPrimitive`, `Bounded`, `Zero`, `One`.
+//!
+//! `Sum` and `Product` are implemented only for types without validation. With validation `::try_sum()` and `::try_product()`
+//! are generated instead, which fold the inner values and validate the result once at the end.
+//!
+//! Integer-based types can be used as map keys (e.g. `HashMap<UserId, User>`): formats like JSON encode the keys as strings, and `Deserialize` parses them back. As values, strings are rejected. Flattened maps (`#[serde(flatten)]`) need `serde(as_string)`, since they buffer the keys as strings.
 //!
 //!
 //! ## Float
@@ -197,9 +270,13 @@
 //!
 //! ### Float sanitizers
 //!
-//! | Sanitizer | Description       | Example                                |
-//! |-----------|-------------------|----------------------------------------|
-//! | `with`    | Custom sanitizer. | `with = \|val\| val.clamp(0.0, 100.0)` |
+//! | Sanitizer | Description                                                        | Example                                |
+//! |-----------|--------------------------------------------------------------------|----------------------------------------|
+//! | `with`    | Custom sanitizer.                                                  | `with = \|val\| val.clamp(0.0, 100.0)` |
+//! | `clamp`   | Clamp the value into the inclusive range.                          | `clamp(min = 0.0, max = 100.0)`        |
+//! | `round`   | Round to the given number of decimal places (requires `std`).      | `round = 2`                            |
+//! | `floor`   | Round down to the given number of decimal places (requires `std`). | `floor = 2`                            |
+//! | `ceil`    | Round up to the given number of decimal places (requires `std`).   | `ceil = 2`                             |
 //!
 //! ### Float validators
 //!
@@ -210,16 +287,21 @@
 //! | `greater`          | Exclusive lower bound            | `GreaterViolated`        | `greater = 0.0`                     |
 //! | `greater_or_equal` | Inclusive lower bound            | `GreaterOrEqualViolated` | `greater_or_equal = 0.0`            |
 //! | `finite`           | Check against NaN and infinity   | `FiniteViolated`         | `finite`                            |
+//! | `latitude`         | Finite, from -90.0 to 90.0       | `LatitudeViolated`       | `latitude`                          |
+//! | `longitude`        | Finite, from -180.0 to 180.0     | `LongitudeViolated`      | `longitude`                         |
+//! | `percentage`       | Finite, from 0.0 to 100.0        | `PercentageViolated`     | `percentage`                        |
+//! | `ratio`            | Finite, from 0.0 to 1.0          | `RatioViolated`          | `ratio`                             |
 //! | `predicate`        | Custom predicate                 | `PredicateViolated`      | `predicate = \|val\| val != 50.0`   |
 //!
 //! ### Float derivable traits
 //!
 //! The following traits can be derived for a float-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `TryFrom<&str>`, `Hash`, `Borrow`, `Display`, `Default`, `Sum`, `Product`, `Add`, `Sub`, `Mul`, `Neg`,
+//! `AddAssign`, `SubAssign`, `MulAssign`, `Serialize`, `Deserialize`, `ToPrimitive`, `FromPrimitive`.
 //!
-//! It's also possible to derive `Eq` and `Ord` if the validation rules guarantee that `NaN` is excluded.
-//! This can be done by applying `finite` validation. For example:
+//! `Eq`, `Ord` and `Hash` can be derived only if the validation rules guarantee that `NaN` is excluded.
+//! This can be done by applying `finite` validation or any of the boundaries (`greater`, `less`, etc.). For example:
 //!
 //! ```rust
 //! use nutype::nutype;
@@ -231,6 +313,97 @@
 //! struct Size(f64);
 //! ```
 //!
+//! ## Range
+//!
+//! The range inner types are: `Range<T>` and `RangeInclusive<T>` (e.g. `Range<u64>` or `std::ops::RangeInclusive<f64>`).
+//!
+//! ### Range sanitizers
+//!
+//! | Sanitizer | Description       | Example                                |
+//! |-----------|-------------------|----------------------------------------|
+//! | `with`    | Custom sanitizer. | `with = \|r\| r.start..r.end.min(100)` |
+//!
+//! ### Range validators
+//!
+//! | Validator                | Description                  | Error variant                 | Example                         |
+//! | ------------------------ | ---------------------------- | ----------------------------- | ------------------------------- |
+//! | `non_empty`              | Contains at least one value  | `NonEmptyViolated`            | `non_empty`                     |
+//! | `start_greater_or_equal` | Inclusive lower bound        | `StartGreaterOrEqualViolated` | `start_greater_or_equal = 0`    |
+//! | `end_less_or_equal`      | Inclusive upper bound        | `EndLessOrEqualViolated`      | `end_less_or_equal = 86_400`    |
+//! | `predicate`              | Custom predicate             | `PredicateViolated`           | `predicate = \|r\| r.end % 2 == 0` |
+//!
+//! ### Range derivable traits
+//!
+//! The following traits can be derived for a range-based type:
+//! `Debug`, `Clone`, `PartialEq`, `Eq`, `Hash`, `AsRef`, `Deref`, `Into`, `From`, `TryFrom`, `Borrow`,
+//! `Default`, `Serialize`, `Deserialize`, `Arbitrary`.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(non_empty, end_less_or_equal = 86_400),
+//!     derive(Debug, PartialEq, Deref),
+//! )]
+//! pub struct TimeWindow(std::ops::Range<u64>);
+//!
+//! assert_eq!(
+//!     TimeWindow::new(60..60),
+//!     Err(TimeWindowError::NonEmptyViolated)
+//! );
+//!
+//! let window = TimeWindow::new(0..3_600).unwrap();
+//! assert!(window.contains(&1_800));
+//! ```
+//!
+//! ## Tuple
+//!
+//! Tuples (e.g. `(f64, f64)`) can have sanitizers and validators per component.
+//! Rules for a component are grouped after its position (`0 => ...`), groups are separated with `;`.
+//! Rules without a position apply to the whole tuple.
+//!
+//! ### Tuple sanitizers
+//!
+//! | Sanitizer | Description       | Example                                     |
+//! |-----------|-------------------|---------------------------------------------|
+//! | `with`    | Custom sanitizer. | `0 => with = \|lat\| lat.clamp(-90.0, 90.0)` |
+//!
+//! ### Tuple validators
+//!
+//! | Validator          | Description                    | Error variant                        | Example                          |
+//! | ------------------ | ------------------------------ | ------------------------------------ | -------------------------------- |
+//! | `less`             | Exclusive upper bound          | `Component{N}LessViolated`           | `0 => less = 100`                |
+//! | `less_or_equal`    | Inclusive upper bound          | `Component{N}LessOrEqualViolated`    | `0 => less_or_equal = 90.0`      |
+//! | `greater`          | Exclusive lower bound          | `Component{N}GreaterViolated`        | `1 => greater = 0`               |
+//! | `greater_or_equal` | Inclusive lower bound          | `Component{N}GreaterOrEqualViolated` | `1 => greater_or_equal = -180.0` |
+//! | `finite`           | Check against NaN and infinity | `Component{N}FiniteViolated`         | `0 => finite`                    |
+//! | `predicate`        | Custom predicate               | `PredicateViolated`                  | `predicate = \|&(a, b)\| a < b`   |
+//!
+//! ### Tuple derivable traits
+//!
+//! The following traits can be derived for a tuple-based type:
+//! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `AsRef`, `Deref`, `Into`,
+//! `From`, `TryFrom`, `Borrow`, `Default`, `Serialize`, `Deserialize`, `Arbitrary`.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(
+//!         0 => greater_or_equal = -90.0, less_or_equal = 90.0;
+//!         1 => greater_or_equal = -180.0, less_or_equal = 180.0
+//!     ),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Coordinate((f64, f64));
+//!
+//! assert_eq!(
+//!     Coordinate::new((91.0, 0.0)),
+//!     Err(CoordinateError::Component0LessOrEqualViolated)
+//! );
+//! assert!(Coordinate::new((50.45, 30.52)).is_ok());
+//! ```
+//!
 //! ## Other inner types
 //!
 //! For any other type it is possible to define custom sanitizers with `with` and custom
@@ -281,6 +454,62 @@
 //!
 //! ```
 //!
+//! ### Length validators
+//!
+//! Types that have `len()` and `is_empty()` methods (e.g. `Vec<T>`, `heapless::Vec<T, N>`, `heapless::String<N>`)
+//! can be validated by their length:
+//!
+//! | Validator   | Description                  | Error variant      | Example       |
+//! | ----------- | ---------------------------- | ------------------ | ------------- |
+//! | `len_min`   | Min length (as by `len()`)   | `LenMinViolated`   | `len_min = 1` |
+//! | `len_max`   | Max length (as by `len()`)   | `LenMaxViolated`   | `len_max = 8` |
+//! | `not_empty` | Rejects an empty value       | `NotEmptyViolated` | `not_empty`   |
+//!
+//! This makes it possible to use validated newtypes in `no_std` environments without `alloc`:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(len_min = 3, len_max = 16),
+//!     derive(Debug, Clone, PartialEq, Deref),
+//! )]
+//! pub struct DeviceName(heapless::String<16>);
+//! ```
+//!
+//! Note, that for strings `len()` returns the length in bytes.
+//!
+//! The same applies to `SmallVec` from [`smallvec`](https://crates.io/crates/smallvec) and `ArrayVec`/`ArrayString` from [`arrayvec`](https://crates.io/crates/arrayvec),
+//! when small validated collections are needed without heap allocation.
+//!
+//! ### Build collections from iterators
+//!
+//! Collection based types (e.g. `Vec<T>`, `BTreeSet<T>`) can derive `FromIterator` if there is no validation, or `TryFromIterator` which generates `::try_from_iter()` constructor:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(with = |mut ids: Vec<u32>| { ids.sort(); ids }),
+//!     derive(Debug, FromIterator, AsRef),
+//! )]
+//! pub struct SortedIds(Vec<u32>);
+//!
+//! #[nutype(
+//!     validate(not_empty),
+//!     derive(Debug, TryFromIterator),
+//! )]
+//! pub struct Tags(std::collections::BTreeSet<String>);
+//!
+//! let ids: SortedIds = [3, 1, 2].into_iter().collect();
+//! assert_eq!(ids.as_ref(), &[1, 2, 3]);
+//!
+//! assert_eq!(
+//!     Tags::try_from_iter(Vec::<String>::new()).unwrap_err(),
+//!     TagsError::NotEmptyViolated,
+//! );
+//! ```
+//!
 //! ## Custom sanitizers
 //!
 //! You can set custom sanitizers using the `with` option.
@@ -345,21 +574,749 @@
 //! pub struct Name(String);
 //! ```
 //!
-//! ### Derive `Eq` and `Ord` on float types
+//! The default value goes through the sanitizers and validators the same way as with `::new()`.
+//! For integer and float types without sanitizers a default value given as a number literal is checked against the boundaries at compile time,
+//! so `validate(greater_or_equal = 18), default = 17` does not compile. Other default values are checked by a generated test.
+//!
+//! Types with validation also get `::new_or_default()` constructor, which falls back to the default value if the validation fails.
+//! It's handy for loading configuration, where an invalid value should not abort the startup:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 1024),
+//!     default = 8080,
+//! )]
+//! pub struct Port(u16);
+//!
+//! assert_eq!(Port::new_or_default(3000).into_inner(), 3000);
+//! assert_eq!(Port::new_or_default(80).into_inner(), 8080);
+//! ```
+//!
+//! ### Derive `Eq`, `Ord` and `Hash` on float types
 //!
-//! With nutype it's possible to derive `Eq` and `Ord` if there is `finite` validation set.
-//! The `finite` validation ensures that the valid value excludes `NaN`.
+//! With nutype it's possible to derive `Eq`, `Ord` and `Hash` if there is `finite` validation or a boundary set.
+//! The `finite` validation and the boundaries ensure that the valid value excludes `NaN`.
+//! `Hash` treats `0.0` and `-0.0` as the same value, to be consistent with `Eq`.
 //!
 //! ```
 //! use nutype::nutype;
 //!
 //! #[nutype(
 //!     validate(finite),
-//!     derive(PartialEq, Eq, PartialOrd, Ord),
+//!     derive(PartialEq, Eq, PartialOrd, Ord, Hash),
 //! )]
 //! pub struct Weight(f64);
 //! ```
 //!
+//! ### Parse integer and float types from strings
+//!
+//! Integer and float based types get `::parse()` constructor, which parses a string into the inner type
+//! and then runs sanitizers and validators. Both steps are covered by a single error type:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(greater_or_equal = 1024))]
+//! pub struct Port(u16);
+//!
+//! assert_eq!(Port::parse("8080").unwrap().into_inner(), 8080);
+//! assert!(matches!(Port::parse("http"), Err(PortParseError::Parse(_))));
+//! assert!(matches!(
+//!     Port::parse("80"),
+//!     Err(PortParseError::Validate(PortError::GreaterOrEqualViolated))
+//! ));
+//! ```
+//!
+//! Derive `FromStr` or `TryFrom<&str>` to use the same parsing through the standard traits, e.g. with `str::parse()`
+//! or when reading query strings and environment variables:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(greater_or_equal = 1024), derive(Debug, FromStr, TryFrom<&str>))]
+//! pub struct Port(u16);
+//!
+//! let port: Port = "8080".parse().unwrap();
+//! assert_eq!(port.into_inner(), 8080);
+//! assert!(matches!(Port::try_from("80"), Err(PortParseError::Validate(_))));
+//! ```
+//!
+//! The wrapped parse or validation error is also available through `Error::source()`, so error reporters can show the whole chain.
+//!
+//! Integer based types additionally get `::parse_radix()`, which parses a string in the given base:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(less_or_equal = 511))]
+//! pub struct Permissions(u16);
+//!
+//! assert_eq!(Permissions::parse_radix("755", 8).unwrap().into_inner(), 493);
+//! ```
+//!
+//! ### Parametrize boundaries with const generics
+//!
+//! Boundaries can refer to const generic parameters, so a single definition can serve many types:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = MIN, less_or_equal = MAX),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Bounded<const MIN: i64, const MAX: i64>(i64);
+//!
+//! type Dice = Bounded<1, 6>;
+//!
+//! assert_eq!(Dice::new(6).unwrap().into_inner(), 6);
+//! assert_eq!(Dice::new(7), Err(BoundedError::LessOrEqualViolated));
+//! ```
+//!
+//! The error type is shared by all the instantiations, so its message shows the parameter name (e.g. `MAX`) instead of the value.
+//!
+//! ### Tag IDs with a phantom type parameter
+//!
+//! A type parameter can be used as a tag in a `PhantomData` field, so `Id<User>` and `Id<Order>` are distinct types that share the same validation:
+//!
+//! ```
+//! use nutype::nutype;
+//! #[nutype(
+//!     validate(greater = 0),
+//!     derive(Debug, Clone, Copy, PartialEq, Eq, Hash),
+//! )]
+//! pub struct Id<T>(u64, std::marker::PhantomData<T>);
+//!
+//! struct User;
+//! struct Order;
+//!
+//! let user_id: Id<User> = Id::new(1).unwrap();
+//! let order_id: Id<Order> = Id::new(1).unwrap();
+//! ```
+//!
+//! The tag types do not need to implement any traits.
+//! The inner type can not depend on the type parameter.
+//!
+//! ### Convert between newtypes with the same inner type
+//!
+//! `convertible_with = OtherType` generates `TryFrom` in both directions, so the value is revalidated against the rules of the target type:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 100),
+//!     derive(Debug, PartialEq, TryFrom),
+//! )]
+//! pub struct Percentage(u8);
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 1, less_or_equal = 10),
+//!     derive(Debug, PartialEq, TryFrom),
+//!     convertible_with = Percentage,
+//! )]
+//! pub struct Score(u8);
+//!
+//! # fn main() {
+//! let score = Score::try_from(Percentage::new(7).unwrap()).unwrap();
+//! assert_eq!(
+//!     Score::try_from(Percentage::new(50).unwrap()),
+//!     Err(ScoreError::LessOrEqualViolated),
+//! );
+//!
+//! let percentage: Percentage = score.try_into().unwrap();
+//! # }
+//! ```
+//!
+//! The other type must implement `TryFrom` for the inner type (e.g. with `derive(TryFrom)` or `derive(From)`).
+//! Declare `convertible_with` only on one of the two types, otherwise the implementations conflict.
+//! The attribute can be repeated to convert with several types.
+//!
+//! ### Compare with the inner type
+//!
+//! Derive `PartialEq<Inner>` and `PartialOrd<Inner>` to compare a value with the inner type directly, in both directions:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 150),
+//!     derive(Debug, PartialEq<Inner>, PartialOrd<Inner>),
+//! )]
+//! pub struct Age(u8);
+//!
+//! let age = Age::new(18).unwrap();
+//! assert!(age == 18);
+//! assert!(18 == age);
+//! assert!(age >= 18);
+//! assert!(21 > age);
+//! ```
+//!
+//! ### Check a value without constructing the type
+//!
+//! Sanitizers and validators are exposed as associated functions, which is handy to pre-check user input (e.g. live validation of a form field):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim, lowercase),
+//!     validate(not_empty, len_char_max = 20),
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::sanitize("  FooBar "), "foobar");
+//! assert_eq!(Username::validate(""), Err(UsernameError::NotEmptyViolated));
+//! assert!(Username::is_valid("foobar"));
+//! ```
+//!
+//! `::validate()` and `::is_valid()` do not sanitize the value.
+//! `::sanitize()` is generated for types without validation as well.
+//!
+//! ### Get the value back when validation fails
+//!
+//! `::new()` consumes the value, so on failure it is lost.
+//! `::new_or_return()` returns the (sanitized) value along with the error instead, which saves a clone of heap-allocated inner types:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(len_char_max = 5), derive(Debug))]
+//! pub struct Code(String);
+//!
+//! let (value, err) = Code::new_or_return("abcdef").unwrap_err();
+//! assert_eq!(value, "abcdef");
+//! assert_eq!(err, CodeError::LenCharMaxViolated);
+//! ```
+//!
+//! ### Borrowed companion type for String based types
+//!
+//! `borrowed = UsernameRef` generates an unsized type that wraps `str`, so validated values can be passed around without copying, the same way `&str` relates to `String`:
+//!
+//! ```
+//! use nutype::nutype;
+//! use std::collections::HashMap;
+//!
+//! #[nutype(
+//!     validate(not_empty, len_char_max = 20),
+//!     derive(Debug, PartialEq, Eq, Hash),
+//!     borrowed = UsernameRef,
+//! )]
+//! pub struct Username(String);
+//!
+//! let username: &UsernameRef = UsernameRef::from_str("alice").unwrap();
+//! assert_eq!(username.as_str(), "alice");
+//!
+//! let mut scores: HashMap<Username, u32> = HashMap::new();
+//! scores.insert(username.to_owned(), 10);
+//! assert_eq!(scores.get(username), Some(&10));
+//! ```
+//!
+//! The owned type implements `Borrow` of the borrowed type, and the borrowed type implements `ToOwned` (requires `std` feature).
+//! A borrowed value can not be sanitized, so `borrowed` can not be combined with sanitizers.
+//!
+//! ### Compare secrets in constant time
+//!
+//! Mark a type holding a secret (e.g. an API token) as `sensitive` to implement `PartialEq` with constant-time comparison, so the comparison does not leak the secret through timing side-channels.
+//! It requires `subtle` feature and works with String based types and types that implement `AsRef<[u8]>` (e.g. `Vec<u8>`):
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sensitive,
+//!     validate(len_char_min = 32),
+//!     derive(Debug, PartialEq, Eq),
+//! )]
+//! pub struct ApiToken(String);
+//! ```
+//!
+//! ### Keep secrets out of logs
+//!
+//! Set `debug = redacted` to make the derived `Debug` print only the type name instead of the inner value,
+//! so tokens and personal data don't leak into logs and panic messages:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(debug = redacted, derive(Debug))]
+//! pub struct ApiKey(String);
+//!
+//! let key = ApiKey::new("secret");
+//! assert_eq!(format!("{key:?}"), "ApiKey(***)");
+//! ```
+//!
+//! ### Named associated constants
+//!
+//! Frequently used values can be defined with `consts(...)` as associated constants of the type, so they don't need `::new().unwrap()` at runtime.
+//! The constants bypass the sanitizers, so they have to be given already sanitized.
+//! Constants given as number literals are checked against the numeric validators at compile time, the other ones are checked by the test generated along with the type.
+//! It's not supported by String based types, since `String` can not be constructed in a const context.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 150),
+//!     consts(ZERO = 0, RETIREMENT = 65),
+//!     derive(Debug, Clone, Copy, PartialEq),
+//! )]
+//! pub struct Age(u8);
+//!
+//! assert_eq!(Age::RETIREMENT.into_inner(), 65);
+//! assert_eq!(Age::ZERO, Age::new(0).unwrap());
+//! assert_eq!(Age::MAX.into_inner(), 150);
+//! ```
+//!
+//! Integer types that are validated only by the bounds (`greater`, `greater_or_equal`, `less`, `less_or_equal`) also get `MIN` and/or `MAX` constants, e.g. `Age::MAX` above is `150`.
+//! They are not generated if the name is already used within `consts(...)`.
+//!
+//! ### Construct values in const context
+//!
+//! Integer and float types get `const fn new()` (as well as `::sanitize()`, `::validate()` and `::is_valid()`), if the sanitizers and validators can be evaluated at compile time:
+//! * integer types validated only by the bounds (`greater`, `greater_or_equal`, `less`, `less_or_equal`) and `nonzero`;
+//! * float types sanitized only by `clamp` and validated only by the bounds, given as number literals.
+//!
+//! Types without sanitizers and validators get it too. With `on_error = ` or `tracing` feature the constructors are not `const`, since the error can not be reported at compile time, but `::sanitize()`, `::validate()` and `::is_valid()` still are.
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 18, less_or_equal = 150),
+//!     derive(Debug, Clone, Copy),
+//! )]
+//! pub struct Age(u8);
+//!
+//! const ADULT: Age = match Age::new(18) {
+//!     Ok(age) => age,
+//!     Err(_) => panic!("invalid age"),
+//! };
+//!
+//! assert_eq!(ADULT.into_inner(), 18);
+//! ```
+//!
+//! Validated integer types with `const fn validate()` also get `::const_new::<VALUE>()`, which checks the value at compile time (it does not report the error with `on_error = ` or `tracing`).
+//! An invalid value fails the build rather than returning an error, so there is nothing to unwrap:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(less_or_equal = 150), derive(Debug))]
+//! pub struct Age(u8);
+//!
+//! const RETIREMENT: Age = Age::const_new::<65>();
+//!
+//! // Does not compile:
+//! // const AGE: Age = Age::const_new::<200>();
+//!
+//! assert_eq!(RETIREMENT.into_inner(), 65);
+//! ```
+//!
+//! ### Report validation failures
+//!
+//! Set `on_error = ` to a function or closure to have it called on every failed construction (`::new()` and everything based on it, e.g. `TryFrom` or `Deserialize`) with the type name and the validation error.
+//! This allows to collect metrics on bad input without wrapping every constructor. `::validate()` and `::is_valid()` don't invoke the callback.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! fn record_validation_failure<E: std::fmt::Display>(type_name: &'static str, error: &E) {
+//!     eprintln!("Invalid {type_name}: {error}");
+//! }
+//!
+//! #[nutype(
+//!     validate(len_char_max = 20),
+//!     on_error = record_validation_failure,
+//! )]
+//! pub struct Username(String);
+//! # fn main() {}
+//! ```
+//!
+//! ### Error type visibility and placement
+//!
+//! By default the validation error type is re-exported next to the newtype with the same visibility.
+//! Use `error(vis = ..., module = ...)` to change it: `vis` sets the visibility of the error type independently from the newtype, and `module` places it into a submodule as `Error` (e.g. `age::Error`) instead of `AgeError`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 18),
+//!     error(module = age),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Age(u8);
+//!
+//! fn check_age(value: u8) -> Result<Age, age::Error> {
+//!     Age::new(value)
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ### Construct only through traits
+//!
+//! Set `no_new` flag to keep `::new()` (and `::new_or_return()`) private, so the type can be constructed only with the derived traits, like `TryFrom`, `FromStr` or `Deserialize`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     no_new,
+//!     validate(not_empty),
+//!     derive(Debug, TryFrom, AsRef),
+//! )]
+//! pub struct Username(String);
+//!
+//! let username = Username::try_from("ada").unwrap();
+//! assert_eq!(username.as_ref(), "ada");
+//! ```
+//!
+//! ### Rename the constructor
+//!
+//! Set `try_new` flag on a type with validation to name its constructor `::try_new()` instead of `::new()`, so the call sites show that the construction can fail.
+//! `::new()` stays private then. The flag is rejected for types without validation, since their `::new()` can not fail.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     try_new,
+//!     validate(not_empty),
+//!     derive(Debug, AsRef),
+//! )]
+//! pub struct Username(String);
+//!
+//! let username = Username::try_new("ada").unwrap();
+//! assert_eq!(username.as_ref(), "ada");
+//! ```
+//!
+//! Set `constructor = ` to give the constructor any other name, e.g. to keep the conventions of an existing codebase when hand-written newtypes are replaced with nutype:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     constructor = from_raw,
+//!     sanitize(uppercase),
+//!     derive(Debug, AsRef),
+//! )]
+//! pub struct Ticker(String);
+//!
+//! assert_eq!(Ticker::from_raw("aapl").as_ref(), "AAPL");
+//! ```
+//!
+//! Integer and float types can not name the constructor `parse`, since they already have `::parse()` to parse a string.
+//!
+//! ### Construct many values at once
+//!
+//! Types with validation get `::new_all()`, which takes any iterator of raw values (e.g. a CSV column or a JSON array) and returns either all the values or the index of the first invalid one along with the error (requires `std` feature):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(less_or_equal = 100), derive(Debug, PartialEq))]
+//! pub struct Score(u8);
+//!
+//! let scores = Score::new_all([10, 80, 100]).unwrap();
+//! assert_eq!(scores.len(), 3);
+//!
+//! assert_eq!(
+//!     Score::new_all([10, 200, 250]).unwrap_err(),
+//!     (1, ScoreError::LessOrEqualViolated),
+//! );
+//! ```
+//!
+//! ### Borrow the inner value
+//!
+//! Every type gets `as_inner()`, which returns a reference to the inner value without deriving `AsRef` or consuming the value with `into_inner()`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(not_empty))]
+//! pub struct Username(String);
+//!
+//! let username = Username::new("bob").unwrap();
+//! let len = username.as_inner().len();
+//! assert_eq!(len, 3);
+//! assert_eq!(username.into_inner(), "bob");
+//! ```
+//!
+//! ### Transform the inner value
+//!
+//! Types without validation get `map()`, and types with validation get `try_map()`, which transform the inner value with the given closure.
+//! The result is sanitized and validated the same way as with `::new()`, so there is no need to unwrap and reconstruct the value by hand:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(len_char_max = 10),
+//!     derive(Debug, AsRef),
+//! )]
+//! pub struct Tag(String);
+//!
+//! let tag = Tag::new("rust").unwrap();
+//! let tag = tag.try_map(|s| format!("{s}-lang ")).unwrap();
+//! assert_eq!(tag.as_ref(), "rust-lang");
+//! assert!(tag.try_map(|s| s.repeat(2)).is_err());
+//! ```
+//!
+//! ### Mutate the inner value in place
+//!
+//! Set `mut_guard = ` on String based types and other inner types (e.g. collections) to generate `::as_mut_guard()`.
+//! It returns a guard, which dereferences mutably to the inner value, so it can be edited in place.
+//! When the guard is dropped, the value is sanitized and validated again. If it does not pass the validation, the original value is restored (so the inner type must implement `Clone`) and with `mut_guard = panic` the guard panics after that:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(with = |mut ids: Vec<u32>| { ids.sort(); ids }),
+//!     validate(len_max = 3),
+//!     mut_guard = restore,
+//!     derive(Debug, AsRef),
+//! )]
+//! pub struct TopIds(Vec<u32>);
+//!
+//! let mut ids = TopIds::new(vec![7]).unwrap();
+//! ids.as_mut_guard().extend([3, 5]);
+//! assert_eq!(ids.as_ref(), &[3, 5, 7]);
+//!
+//! // Too many ids, so the previous value is restored
+//! ids.as_mut_guard().push(1);
+//! assert_eq!(ids.as_ref(), &[3, 5, 7]);
+//! ```
+//!
+//! With either policy the type also gets `modify()`, which applies a closure to the inner value in place and returns the validation error, if the previous value had to be restored (it never panics).
+//! The previous value is restored as well, if the closure panics.
+//! Like the guard, it clones the previous value on every call to be able to restore it, so the inner type must implement `Clone`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(len_max = 3),
+//!     mut_guard = restore,
+//!     derive(Debug, AsRef),
+//! )]
+//! pub struct TopIds(Vec<u32>);
+//!
+//! let mut ids = TopIds::new(vec![7, 3]).unwrap();
+//! assert!(ids.modify(|ids| ids.push(5)).is_ok());
+//! assert_eq!(
+//!     ids.modify(|ids| ids.push(1)),
+//!     Err(TopIdsError::LenMaxViolated),
+//! );
+//! assert_eq!(ids.as_ref(), &[7, 3, 5]);
+//! ```
+//!
+//! ### Serialize as a string
+//!
+//! With `serde` feature, set `serde(as_string)` to make the derived `Serialize` and `Deserialize` use the textual form of the value, regardless of the inner type.
+//! The value is serialized with `Display` of the inner type. Deserialization expects a string, parses it with `FromStr` of the inner type and then runs sanitizers and validators as usual.
+//! It's handy for IDs, versions and other values whose canonical wire form is a string (e.g. 64-bit IDs, which JavaScript can not represent as numbers):
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater = 0),
+//!     serde(as_string),
+//!     derive(Debug, Serialize, Deserialize),
+//! )]
+//! pub struct AccountId(u64);
+//!
+//! let id = AccountId::new(9007199254740993).unwrap();
+//! assert_eq!(serde_json::to_string(&id).unwrap(), r#""9007199254740993""#);
+//! assert!(serde_json::from_str::<AccountId>(r#""0""#).is_err());
+//! ```
+//!
+//! ### Validate slices without copying
+//!
+//! Set `new_slice` on integer and float based types to generate `::new_slice()`, which validates every element of `&[Inner]` and returns it as `&[Type]` without copying, so large numeric buffers can be validated cheaply.
+//! The type gets `#[repr(transparent)]` to make the reinterpretation sound. The error `{TypeName}SliceError` tells the index of the first invalid element.
+//! Since the elements are not sanitized, `new_slice` can not be combined with sanitizers:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     new_slice,
+//!     validate(less_or_equal = 4095),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Sample(u16);
+//!
+//! let raw_samples: Vec<u16> = vec![0, 17, 4095];
+//! let samples: &[Sample] = Sample::new_slice(&raw_samples).unwrap();
+//! assert_eq!(samples[1], Sample::new(17).unwrap());
+//!
+//! let err = Sample::new_slice(&[1, 4096]).unwrap_err();
+//! assert_eq!(err.index, 1);
+//! assert_eq!(err.error, SampleError::LessOrEqualViolated);
+//! ```
+//!
+//! ### Keep SQL constraints in sync
+//!
+//! Set `sql_check` on integer, float and String based types to generate `::sql_check(column)`, which renders the validators as a condition of SQL `CHECK` constraint for the given column.
+//! This way a database schema (e.g. a migration generated by a build script) can be kept in lock-step with the Rust invariants.
+//! String lengths are checked with `CHAR_LENGTH`. Validators that can not be expressed in SQL (`predicate`, `regex`, `numeric`) cause a compile error:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sql_check,
+//!     validate(greater_or_equal = 18, less = 150),
+//! )]
+//! pub struct Age(u8);
+//!
+//! #[nutype(
+//!     sql_check,
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 64),
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Age::sql_check("age"), "age >= 18 AND age < 150");
+//! assert_eq!(
+//!     Username::sql_check("username"),
+//!     "CHAR_LENGTH(username) > 0 AND CHAR_LENGTH(username) <= 64",
+//! );
+//! ```
+//!
+//! ### Share validation with the frontend
+//!
+//! Set `zod` on integer, float and String based types to generate `::zod_schema()`, which renders the sanitizers and validators as a [Zod](https://zod.dev) schema.
+//! Integer based types always get the range of the inner type (e.g. `.gte(0).lte(255)` for `u8`) unless the validators narrow it down already.
+//! Note that Zod measures string lengths in UTF-16 code units, while `len_char_min` and `len_char_max` count characters.
+//! Rules that can not be expressed in Zod (`predicate`, `numeric`, `normalize_newlines`, `with`) cause a compile error:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     zod,
+//!     validate(greater_or_equal = 18, less = 150),
+//! )]
+//! pub struct Age(u8);
+//!
+//! #[nutype(
+//!     zod,
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 64),
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Age::zod_schema(), "z.number().int().gte(18).lt(150)");
+//! assert_eq!(Username::zod_schema(), "z.string().trim().min(1).max(64)");
+//! ```
+//!
+//! ### Convert floats into integer types
+//!
+//! Use `from_float = ` on integer based types to implement `TryFrom<f64>` with the given rounding mode: `floor`, `ceil`, `round` (half-way cases away from zero) or `exact` (values with a fractional part are rejected).
+//! The rounded value goes through the sanitizers and validators, and the errors are returned as `{TypeName}FromFloatError`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     from_float = round,
+//!     validate(less_or_equal = 100),
+//!     derive(Debug),
+//! )]
+//! pub struct Humidity(u8);
+//!
+//! assert_eq!(Humidity::try_from(42.7).unwrap().into_inner(), 43);
+//! assert_eq!(
+//!     Humidity::try_from(100.9).unwrap_err(),
+//!     HumidityFromFloatError::Validate(HumidityError::LessOrEqualViolated),
+//! );
+//! assert_eq!(
+//!     Humidity::try_from(f64::NAN).unwrap_err(),
+//!     HumidityFromFloatError::NotFinite,
+//! );
+//! ```
+//!
+//! ### Checked arithmetic
+//!
+//! Set `checked_arithmetic` on integer and float based types to generate `checked_add()`, `checked_sub()` and `checked_mul()`.
+//! The operation is performed on the inner values and the result goes through the sanitizers and validators, so there is no need to unwrap and reconstruct the value by hand.
+//! The errors are returned as `{TypeName}ArithmeticError`, which has `Overflow` variant (integer types only) and `Validate` variant (if there is validation):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     checked_arithmetic,
+//!     validate(less_or_equal = 100),
+//!     derive(Debug, Clone, Copy),
+//! )]
+//! pub struct Percent(u8);
+//!
+//! let half = Percent::new(50).unwrap();
+//! assert_eq!(half.checked_add(half).unwrap().into_inner(), 100);
+//! assert_eq!(
+//!     half.checked_mul(half).unwrap_err(),
+//!     PercentArithmeticError::Overflow,
+//! );
+//! assert_eq!(
+//!     half.checked_add(Percent::new(60).unwrap()).unwrap_err(),
+//!     PercentArithmeticError::Validate(PercentError::LessOrEqualViolated),
+//! );
+//! ```
+//!
+//! ### Arithmetic operators
+//!
+//! `Add`, `Sub`, `Mul` and `Neg` can be derived for integer and float based types (`Neg` for signed integers only). The operation is performed on the inner values and the result goes through `::new()`.
+//! Types with validation must set `arithmetic = ` to define what happens with an invalid result:
+//! * `arithmetic = panic` - panic, including on integer overflow.
+//! * `arithmetic = saturate` - clamp the result to the boundaries. Allowed only if the type is validated by the boundaries alone (`greater_or_equal` and `less_or_equal` for floats).
+//! * `arithmetic = wrapping` - wrap the result around the range defined by the boundaries, e.g. `22 + 5` gives `3` for an hour validated with `less_or_equal = 23`. Integers only, allowed only if the type is validated by the boundaries alone.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 100),
+//!     arithmetic = saturate,
+//!     derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul),
+//! )]
+//! pub struct Percent(u8);
+//!
+//! let half = Percent::new(50).unwrap();
+//! assert_eq!((half + half).into_inner(), 100);
+//! assert_eq!((half * half).into_inner(), 100);
+//! assert_eq!((half - Percent::new(60).unwrap()).into_inner(), 0);
+//! ```
+//!
+//! Use `checked_arithmetic` instead to get a `Result`.
+//!
+//! `Add<Inner>`, `Sub<Inner>` and `Mul<Inner>` take the inner type as the right-hand side operand and follow the same `arithmetic = ` policy:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 150),
+//!     arithmetic = saturate,
+//!     derive(Debug, Clone, Copy, PartialEq, Add<Inner>),
+//! )]
+//! pub struct Age(u8);
+//!
+//! let age = Age::new(30).unwrap();
+//! assert_eq!((age + 1).into_inner(), 31);
+//! assert_eq!((age + 200).into_inner(), 150);
+//! ```
+//!
+//! `AddAssign`, `SubAssign` and `MulAssign` can be derived only for types without validation, since the construction cannot fail.
+//! The result goes through the sanitizers, and `arithmetic = ` defines what happens on integer overflow (e.g. `arithmetic = saturate` for `saturating_add()` or `arithmetic = wrapping` for `wrapping_add()`).
+//!
 //! ## How to break the constraints?
 //!
 //! It's discouraged, but it's possible to bypass the constraints by enabling `new_unchecked` crate feature and marking a type with `new_unchecked`:
@@ -383,12 +1340,20 @@
 //!
 //! ## Feature flags
 //!
-//! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+//! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html). For integer and bounded float types the values are generated as an offset from the declared boundaries, so inputs shrunk by a fuzzer converge to the boundaries rather than to zero.
+//! * `kani` - generates [Kani](https://model-checking.github.io/kani/) proof harnesses for integer and float based types with validation, which verify that every value constructed with `::new()` satisfies the validators and that sanitizers and validators never panic. The harnesses are compiled only under `cfg(kani)`, so run them with `cargo kani --features nutype/kani`.
 //! * `new_unchecked` - enables generation of unsafe `::new_unchecked()` function.
+//! * `num_traits` - allows to derive `ToPrimitive` and `FromPrimitive` traits of [`num-traits`](https://crates.io/crates/num-traits) crate for integer and float based types. `FromPrimitive` returns `None` if the value does not pass the validation. Integer based types can also derive `Bounded` (if validated by the bounds only), `Zero` and `One` (the latter two panic if the value does not pass the validation and require `Add` and `Mul` to be implemented by hand). Note: your crate also has to explicitly have `num-traits` within dependencies.
+//! * `ordered_float` - allows to derive `NotNan` and `OrderedFloat` for float based types, which generates conversions into `NotNan<T>` and `OrderedFloat<T>` of [`ordered-float`](https://crates.io/crates/ordered-float) crate and `TryFrom` back. It requires validation that excludes `NaN` (e.g. `finite`). Note: your crate also has to explicitly have `ordered-float` within dependencies.
 //! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` and `lazy_static` within dependencies.
-//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits.
 //! * `schemars08` - allows to derive [`JsonSchema`](https://docs.rs/schemars/0.8.12/schemars/trait.JsonSchema.html) trait of [schemars](https://crates.io/crates/schemars) crate. Note that at the moment validation rules are not respected.
+//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits. Types that derive `Display` and `FromStr` also work with [`serde_with`](https://crates.io/crates/serde_with) adapters like `DisplayFromStr`, including as map keys (e.g. `HashMap<DisplayFromStr, _>`).
 //! * `std` - enabled by default. Use `default-features = false` to disable.
+//! * `subtle` - allows to mark types as `sensitive`, so `PartialEq` compares them in constant time using [`subtle`](https://crates.io/crates/subtle) crate. Note: your crate also has to explicitly have `subtle` within dependencies.
+//! * `tracing` - emits a debug event with [`tracing`](https://crates.io/crates/tracing) when validation fails on construction. The event has `type_name` and `violation` fields, so production input issues can be diagnosed without logging at every call site. With the feature `::new()` of integer and float types is not `const` (see "Construct values in const context"), `::const_new()` remains available.
+//! * `unicode_normalization` - allows to use `nfc` and `nfkc` sanitizers on string-based types, which bring the string into a canonical Unicode form, so visually identical input is stored and compared the same way. Note: your crate also has to explicitly have `unicode-normalization` within dependencies.
+//! * `unicode_segmentation` - allows to use `len_grapheme_min` and `len_grapheme_max` validators on string-based types, which count grapheme clusters instead of chars. Note: your crate also has to explicitly have `unicode-segmentation` within dependencies.
+//! * `url` - allows to use `url` validator on string-based types, which parses the value with [`url`](https://crates.io/crates/url) crate. Note: your crate also has to explicitly have `url` within dependencies.
 //!
 //! ## Support Ukrainian military forces 🇺🇦
 //!
@@ -411,6 +1376,16 @@
 
 pub use nutype_macros::nutype;
 
+// The generated code refers to `::nutype::__private`, which has to resolve within this crate too.
+extern crate self as nutype;
+
+// Dependencies of the generated code, so the crates that use nutype don't need to declare them.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
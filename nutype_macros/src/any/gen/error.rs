@@ -1,18 +1,24 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::Generics;
 
 use crate::{
     any::models::AnyValidator,
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
+        gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
         models::{ErrorTypeName, TypeName},
     },
 };
 
-pub fn gen_validation_error_type(type_name: &TypeName, validators: &[AnyValidator]) -> TokenStream {
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    generics: &Generics,
+    validators: &[AnyValidator],
+) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
     let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -28,6 +34,15 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[AnyValidator])
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
+            AnyValidator::LenMin(_) => {
+                quote!(LenMinViolated,)
+            }
+            AnyValidator::LenMax(_) => {
+                quote!(LenMaxViolated,)
+            }
+            AnyValidator::NotEmpty => {
+                quote!(NotEmptyViolated,)
+            }
             AnyValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
@@ -44,10 +59,26 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[AnyValidator])
 
 fn gen_impl_display_trait(
     type_name: &TypeName,
+    generics: &Generics,
     error_type_name: &ErrorTypeName,
     validators: &[AnyValidator],
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
+        AnyValidator::LenMin(len_min) => {
+            let len_min = gen_error_message_value(len_min, generics);
+            quote! {
+                 #error_type_name::LenMinViolated => write!(f, "{} is too short. The value length must be at least {:#?}.", stringify!(#type_name), #len_min)
+            }
+        }
+        AnyValidator::LenMax(len_max) => {
+            let len_max = gen_error_message_value(len_max, generics);
+            quote! {
+                 #error_type_name::LenMaxViolated => write!(f, "{} is too long. The value length must be at most {:#?}.", stringify!(#type_name), #len_max)
+            }
+        }
+        AnyValidator::NotEmpty => quote! {
+             #error_type_name::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
+        },
         AnyValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
@@ -6,13 +6,14 @@ use std::collections::HashSet;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse_quote;
+use syn::Generics;
 
 use crate::common::{
     gen::{
         error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
         traits::GeneratedTraits, GenerateNewtype,
     },
-    models::{ErrorTypeName, Guard, TypeName, TypedCustomFunction},
+    models::{ArithmeticPolicy, ErrorTypeName, Guard, TypeName, TypedCustomFunction},
 };
 
 use self::error::gen_validation_error_type;
@@ -70,6 +71,27 @@ impl GenerateNewtype for AnyNewtype {
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
+                AnyValidator::LenMin(min_len) => {
+                    quote!(
+                        if val.len() < #min_len {
+                            return Err(#error_name::LenMinViolated);
+                        }
+                    )
+                }
+                AnyValidator::LenMax(max_len) => {
+                    quote!(
+                        if val.len() > #max_len {
+                            return Err(#error_name::LenMaxViolated);
+                        }
+                    )
+                }
+                AnyValidator::NotEmpty => {
+                    quote!(
+                        if val.is_empty() {
+                            return Err(#error_name::NotEmptyViolated);
+                        }
+                    )
+                }
                 AnyValidator::Predicate(predicate) => {
                     let inner_type_ref: syn::Type = parse_quote!(
                         &'a #inner_type
@@ -88,6 +110,8 @@ impl GenerateNewtype for AnyNewtype {
             .collect();
 
         quote!(
+            // The inner type is not known, so e.g. `&Vec<T>` can not be replaced with a slice.
+            #[allow(clippy::ptr_arg)]
             fn validate<'a>(val: &'a #inner_type) -> ::core::result::Result<(), #error_name> {
                 #validations
                 Ok(())
@@ -97,21 +121,26 @@ impl GenerateNewtype for AnyNewtype {
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        generics: &Generics,
         validators: &[Self::Validator],
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, generics, validators)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        _maybe_arithmetic_policy: Option<ArithmeticPolicy>,
         guard: &AnyGuard,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
             inner_type,
             maybe_error_type_name,
             traits,
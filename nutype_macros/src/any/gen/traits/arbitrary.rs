@@ -1,5 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::Generics;
 
 use crate::{
     any::models::{AnyGuard, AnyInnerType},
@@ -8,9 +9,11 @@ use crate::{
 
 pub fn gen_impl_trait_arbitrary(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &AnyInnerType,
     guard: &AnyGuard,
 ) -> Result<TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     // It's not possible to generate implementation of `Arbitrary` trait, because we don't know nor
     // type nor validation rules.
     if guard.has_validation() {
@@ -23,10 +26,10 @@ pub fn gen_impl_trait_arbitrary(
     // Generate implementation of `Arbitrary` trait, assuming that inner type implements Arbitrary
     // too.
     Ok(quote!(
-        impl ::arbitrary::Arbitrary<'_> for #type_name {
+        impl #impl_generics ::arbitrary::Arbitrary<'_> for #type_name #ty_generics #where_clause {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
                 let inner_value: #inner_type = u.arbitrary()?;
-                Ok(#type_name::new(inner_value))
+                Ok(Self::new(inner_value))
             }
         }
 
@@ -3,17 +3,24 @@ pub mod arbitrary;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use std::collections::HashSet;
+use syn::Generics;
 
 use crate::{
     any::models::AnyDeriveTrait,
     any::models::{AnyGuard, AnyInnerType},
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
+                gen_impl_trait_from_iterator, gen_impl_trait_from_str, gen_impl_trait_into,
+                gen_impl_trait_partial_eq_inner, gen_impl_trait_partial_ord_inner,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_try_from, gen_impl_trait_try_from_iterator,
+                split_into_generatable_traits, GeneratableTrait, GeneratableTraits,
+                GeneratedTraits,
+            },
+            InnerStorage,
         },
         models::{ErrorTypeName, TypeName},
     },
@@ -31,6 +38,12 @@ impl From<AnyDeriveTrait> for AnyGeneratableTrait {
             AnyDeriveTrait::PartialEq => {
                 AnyGeneratableTrait::Transparent(AnyTransparentTrait::PartialEq)
             }
+            AnyDeriveTrait::PartialEqInner => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::PartialEqInner)
+            }
+            AnyDeriveTrait::PartialOrdInner => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::PartialOrdInner)
+            }
             AnyDeriveTrait::Eq => AnyGeneratableTrait::Transparent(AnyTransparentTrait::Eq),
             AnyDeriveTrait::PartialOrd => {
                 AnyGeneratableTrait::Transparent(AnyTransparentTrait::PartialOrd)
@@ -54,6 +67,12 @@ impl From<AnyDeriveTrait> for AnyGeneratableTrait {
             AnyDeriveTrait::ArbitraryArbitrary => {
                 AnyGeneratableTrait::Irregular(AnyIrregularTrait::ArbitraryArbitrary)
             }
+            AnyDeriveTrait::FromIterator => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::FromIterator)
+            }
+            AnyDeriveTrait::TryFromIterator => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::TryFromIterator)
+            }
         }
     }
 }
@@ -91,6 +110,8 @@ impl ToTokens for AnyTransparentTrait {
 /// an implementation for it.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 enum AnyIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
     AsRef,
     From,
     Into,
@@ -100,6 +121,8 @@ enum AnyIrregularTrait {
     FromStr,
     TryFrom,
     Default,
+    FromIterator,
+    TryFromIterator,
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
@@ -107,6 +130,7 @@ enum AnyIrregularTrait {
 
 pub fn gen_traits(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &AnyInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<AnyDeriveTrait>,
@@ -126,6 +150,7 @@ pub fn gen_traits(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
         inner_type,
         maybe_error_type_name,
         irregular_traits,
@@ -141,6 +166,7 @@ pub fn gen_traits(
 
 fn gen_implemented_traits(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &AnyInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     impl_traits: Vec<AnyIrregularTrait>,
@@ -150,23 +176,25 @@ fn gen_implemented_traits(
     impl_traits
         .iter()
         .map(|t| match t {
-            AnyIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            AnyIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            AnyIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type.clone())),
-            AnyIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
-            AnyIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
-            AnyIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
+            AnyIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            AnyIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
+            AnyIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type.clone())),
+            AnyIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics)),
+            AnyIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            AnyIrregularTrait::PartialEqInner => Ok(gen_impl_trait_partial_eq_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            AnyIrregularTrait::PartialOrdInner => Ok(gen_impl_trait_partial_ord_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            AnyIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
             AnyIrregularTrait::FromStr => Ok(
-                gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_from_str(type_name, generics, inner_type, maybe_error_type_name.as_ref())
             ),
             AnyIrregularTrait::TryFrom => Ok(
-                gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_try_from(type_name, generics, inner_type, maybe_error_type_name.as_ref())
             ),
             AnyIrregularTrait::Default => Ok(
                 match maybe_default_value {
                     Some(ref default_value) => {
                         let has_validation = maybe_error_type_name.is_some();
-                        gen_impl_trait_default(type_name, default_value, has_validation)
+                        gen_impl_trait_default(type_name, generics, default_value, has_validation)
                     }
                     None => {
                         panic!(
@@ -175,13 +203,19 @@ fn gen_implemented_traits(
                     }
                 }
             ),
+            AnyIrregularTrait::FromIterator => Ok(
+                gen_impl_trait_from_iterator(type_name, generics, inner_type)
+            ),
+            AnyIrregularTrait::TryFromIterator => Ok(
+                gen_impl_trait_try_from_iterator(type_name, generics, inner_type, maybe_error_type_name.as_ref())
+            ),
             AnyIrregularTrait::SerdeSerialize => Ok(
-                gen_impl_trait_serde_serialize(type_name)
+                gen_impl_trait_serde_serialize(type_name, generics)
             ),
             AnyIrregularTrait::SerdeDeserialize => Ok(
-                gen_impl_trait_serde_deserialize(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_serde_deserialize(type_name, generics, inner_type, maybe_error_type_name.as_ref())
             ),
-            AnyIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard),
+            AnyIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, generics, inner_type, guard),
         })
         .collect()
 }
@@ -4,7 +4,7 @@ use quote::ToTokens;
 use std::fmt::Debug;
 use syn::Field;
 
-use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait};
+use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait, ValueOrExpr};
 
 /// Sanitizer for "any" type.
 #[derive(Debug, Kinded)]
@@ -19,6 +19,9 @@ pub type SpannedAnySanitizer = SpannedItem<AnySanitizer>;
 #[derive(Debug, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum AnyValidator {
+    LenMin(ValueOrExpr<usize>),
+    LenMax(ValueOrExpr<usize>),
+    NotEmpty,
     Predicate(CustomFunction),
 }
 
@@ -31,6 +34,8 @@ pub enum AnyDeriveTrait {
     Clone,
     Copy,
     PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
     Eq,
     PartialOrd,
     Ord,
@@ -44,6 +49,8 @@ pub enum AnyDeriveTrait {
     TryFrom,
     Default,
     Hash,
+    FromIterator,
+    TryFromIterator,
 
     // External crates
     SerdeSerialize,
@@ -55,6 +62,14 @@ impl TypeTrait for AnyDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &AnyDeriveTrait::FromStr
     }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &AnyDeriveTrait::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &AnyDeriveTrait::SerdeDeserialize
+    }
 }
 
 pub type AnyRawGuard = RawGuard<SpannedAnySanitizer, SpannedAnyValidator>;
@@ -1,6 +1,13 @@
 use crate::common::{
-    models::{Attributes, CustomFunction, SpannedDeriveTrait},
-    parse::{parse_sanitizer_kind, parse_validator_kind, ParseableAttributes},
+    models::{
+        Attributes, CheckedArithmetic, CustomFunction, NewSlice, SpannedDeriveTrait, SqlCheck,
+        ZodSchema,
+    },
+    parse::{
+        parse_number_or_expr, parse_sanitizer_kind, parse_validator_kind, reject_arithmetic,
+        reject_borrowed, reject_checked_arithmetic, reject_from_float, reject_len_unit,
+        reject_new_slice, reject_sql_check, reject_zod_schema, ParseableAttributes,
+    },
 };
 use proc_macro2::TokenStream;
 use syn::{
@@ -25,9 +32,35 @@ pub fn parse_attributes(
         sanitizers,
         validators,
         new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
         default,
+        convertible_with,
+        borrowed,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
         derive_traits,
     } = attrs;
+    reject_from_float(from_float)?;
+    reject_new_slice(new_slice)?;
+    reject_checked_arithmetic(checked_arithmetic)?;
+    reject_arithmetic(arithmetic)?;
+    reject_sql_check(sql_check)?;
+    reject_zod_schema(zod_schema)?;
+    reject_borrowed(borrowed)?;
+    reject_len_unit(len_unit)?;
     let raw_guard = AnyRawGuard {
         sanitizers,
         validators,
@@ -35,8 +68,25 @@ pub fn parse_attributes(
     let guard = validate_any_guard(raw_guard)?;
     Ok(Attributes {
         new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice: NewSlice::Off,
+        checked_arithmetic: CheckedArithmetic::Off,
+        sql_check: SqlCheck::Off,
+        zod_schema: ZodSchema::Off,
+        sensitive,
         guard,
         default,
+        convertible_with,
+        maybe_borrowed_type_name: None,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: None,
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: mut_guard.map(|mut_guard| mut_guard.item),
+        maybe_serde_mode: serde.map(|serde| serde.item),
+        maybe_arithmetic_policy: None,
+        maybe_debug_mode: debug.map(|debug| debug.item),
         derive_traits,
     })
 }
@@ -61,9 +111,29 @@ impl Parse for SpannedAnySanitizer {
 
 impl Parse for SpannedAnyValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_validator_kind(input)?;
 
         match kind {
+            AnyValidatorKind::LenMin => {
+                let _: Token![=] = input.parse()?;
+                let (min_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedAnyValidator {
+                    item: AnyValidator::LenMin(min_len),
+                    span,
+                })
+            }
+            AnyValidatorKind::LenMax => {
+                let _: Token![=] = input.parse()?;
+                let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedAnyValidator {
+                    item: AnyValidator::LenMax(max_len),
+                    span,
+                })
+            }
+            AnyValidatorKind::NotEmpty => Ok(SpannedAnyValidator {
+                item: AnyValidator::NotEmpty,
+                span: ident.span(),
+            }),
             AnyValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let span = input.span();
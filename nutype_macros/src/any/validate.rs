@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use proc_macro2::Span;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
+    models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
     validate::validate_duplicates,
 };
 
@@ -38,6 +38,29 @@ fn validate_validators(
         format!("Duplicated validators `{kind}`.\nOh, maybe it's a time to take a break?")
     })?;
 
+    // len_max VS len_min
+    //
+    let maybe_len_min = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            AnyValidator::LenMin(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    let maybe_len_max = validators
+        .iter()
+        .flat_map(|v| match v.item {
+            AnyValidator::LenMax(ValueOrExpr::Value(len)) => Some((v.span, len)),
+            _ => None,
+        })
+        .next();
+    if let (Some((_, len_min)), Some((len_max_span, len_max))) = (maybe_len_min, maybe_len_max) {
+        if len_min > len_max {
+            let msg = "`len_min` cannot be greater than `len_max`.\nDon't you find this obvious?";
+            return Err(syn::Error::new(len_max_span, msg));
+        }
+    }
+
     let validators: Vec<AnyValidator> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
@@ -71,7 +94,7 @@ pub fn validate_any_derive_traits(
 
 fn to_any_derive_trait(
     tr: DeriveTrait,
-    _has_validation: bool,
+    has_validation: bool,
     span: Span,
 ) -> Result<AnyDeriveTrait, syn::Error> {
     match tr {
@@ -79,6 +102,8 @@ fn to_any_derive_trait(
         DeriveTrait::Clone => Ok(AnyDeriveTrait::Clone),
         DeriveTrait::Copy => Ok(AnyDeriveTrait::Copy),
         DeriveTrait::PartialEq => Ok(AnyDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(AnyDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(AnyDeriveTrait::PartialOrdInner),
         DeriveTrait::Eq => Ok(AnyDeriveTrait::Eq),
         DeriveTrait::Ord => Ok(AnyDeriveTrait::Ord),
         DeriveTrait::PartialOrd => Ok(AnyDeriveTrait::PartialOrd),
@@ -95,7 +120,42 @@ fn to_any_derive_trait(
         DeriveTrait::SerdeDeserialize => Ok(AnyDeriveTrait::SerdeDeserialize),
         DeriveTrait::Hash => Ok(AnyDeriveTrait::Hash),
         DeriveTrait::ArbitraryArbitrary => Ok(AnyDeriveTrait::ArbitraryArbitrary),
-        DeriveTrait::SchemarsJsonSchema => {
+        DeriveTrait::FromIterator => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `FromIterator` trait, because there is validation defined. Use `TryFromIterator` instead.",
+                ))
+            } else {
+                Ok(AnyDeriveTrait::FromIterator)
+            }
+        }
+        DeriveTrait::TryFromIterator => Ok(AnyDeriveTrait::TryFromIterator),
+        DeriveTrait::SchemarsJsonSchema
+        | DeriveTrait::NumTraitsToPrimitive
+        | DeriveTrait::NumTraitsFromPrimitive
+        | DeriveTrait::NumTraitsBounded
+        | DeriveTrait::NumTraitsZero
+        | DeriveTrait::NumTraitsOne
+        | DeriveTrait::OrderedFloatNotNan
+        | DeriveTrait::OrderedFloatOrderedFloat
+        | DeriveTrait::LowerHex
+        | DeriveTrait::UpperHex
+        | DeriveTrait::Binary
+        | DeriveTrait::Octal
+        | DeriveTrait::Sum
+        | DeriveTrait::Product
+        | DeriveTrait::Add
+        | DeriveTrait::Sub
+        | DeriveTrait::Mul
+        | DeriveTrait::AddInner
+        | DeriveTrait::SubInner
+        | DeriveTrait::MulInner
+        | DeriveTrait::Neg
+        | DeriveTrait::AddAssign
+        | DeriveTrait::SubAssign
+        | DeriveTrait::MulAssign
+        | DeriveTrait::TryFromStr => {
             let msg =
                 format!("Deriving of trait `{tr:?}` is not (yet) supported for an arbitrary type");
             Err(syn::Error::new(span, msg))
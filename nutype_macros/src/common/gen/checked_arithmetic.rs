@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
+
+use crate::common::{
+    gen::{error::gen_impl_error_trait, InnerStorage},
+    models::{ArithmeticErrorTypeName, CheckedArithmetic, ErrorTypeName, TypeName},
+};
+
+pub fn gen_arithmetic_error_type_name(type_name: &TypeName) -> ArithmeticErrorTypeName {
+    let ident = format_ident!("{type_name}ArithmeticError");
+    ArithmeticErrorTypeName::new(ident)
+}
+
+/// Generate `checked_add()`, `checked_sub()` and `checked_mul()`, which perform the operation on
+/// the inner values and pass the result through `::new()`, together with the error they return.
+/// Only integer operations can overflow, float operations are plain `+`, `-` and `*`.
+#[allow(clippy::too_many_arguments)]
+pub fn gen_checked_arithmetic(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    checked_arithmetic: CheckedArithmetic,
+    can_overflow: bool,
+) -> TokenStream {
+    if let CheckedArithmetic::Off = checked_arithmetic {
+        return quote!();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arithmetic_error_type_name = gen_arithmetic_error_type_name(type_name);
+    let def_error = gen_def_arithmetic_error(
+        type_name,
+        maybe_error_type_name,
+        &arithmetic_error_type_name,
+        can_overflow,
+    );
+
+    let lhs = storage.gen_load(quote!(self.0));
+    let rhs = storage.gen_load(quote!(rhs.0));
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote!(Self::new(raw_value).map_err(#arithmetic_error_type_name::Validate))
+    } else {
+        quote!(Ok(Self::new(raw_value)))
+    };
+
+    let methods = [
+        (quote!(checked_add), quote!(+), "Adds"),
+        (quote!(checked_sub), quote!(-), "Subtracts"),
+        (quote!(checked_mul), quote!(*), "Multiplies by"),
+    ]
+    .into_iter()
+    .map(|(method, operator, verb)| {
+        let doc = format!(
+            " {verb} `rhs` and passes the result through the sanitizers and validators."
+        );
+        let raw_value = if can_overflow {
+            quote!(#lhs.#method(#rhs).ok_or(#arithmetic_error_type_name::Overflow)?)
+        } else {
+            quote!(#lhs #operator #rhs)
+        };
+        quote!(
+            #[doc = #doc]
+            pub fn #method(self, rhs: Self) -> ::core::result::Result<Self, #arithmetic_error_type_name> {
+                let raw_value: #inner_type = #raw_value;
+                #raw_value_to_result
+            }
+        )
+    });
+
+    quote! {
+        #def_error
+
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+fn gen_def_arithmetic_error(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    arithmetic_error_type_name: &ArithmeticErrorTypeName,
+    can_overflow: bool,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+
+    let (overflow_variant, overflow_display) = if can_overflow {
+        (
+            quote!(Overflow,),
+            quote!(#arithmetic_error_type_name::Overflow => write!(f, "Arithmetic operation on {} overflowed", #type_name_str),),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+    let (validate_variant, validate_display) = match maybe_error_type_name {
+        Some(error_type_name) => (
+            quote!(Validate(#error_type_name),),
+            quote!(#arithmetic_error_type_name::Validate(err) => write!(f, "Arithmetic operation on {} produced an invalid value: {}", #type_name_str, err),),
+        ),
+        None => (quote!(), quote!()),
+    };
+    let impl_error_trait = gen_impl_error_trait(arithmetic_error_type_name);
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum #arithmetic_error_type_name {
+            #overflow_variant
+            #validate_variant
+        }
+
+        impl ::core::fmt::Display for #arithmetic_error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #overflow_display
+                    #validate_display
+                }
+            }
+        }
+
+        #impl_error_trait
+    }
+}
@@ -1,18 +1,27 @@
 use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
 
-use crate::common::models::{ErrorTypeName, TypeName};
+use crate::{
+    common::models::{ErrorTypeName, FromFloatErrorTypeName, TypeName},
+    utils::tokens::contains_ident,
+};
 
 pub fn gen_error_type_name(type_name: &TypeName) -> ErrorTypeName {
     let ident = format_ident!("{type_name}Error");
     ErrorTypeName::new(ident)
 }
 
+pub fn gen_from_float_error_type_name(type_name: &TypeName) -> FromFloatErrorTypeName {
+    let ident = format_ident!("{type_name}FromFloatError");
+    FromFloatErrorTypeName::new(ident)
+}
+
 // NOTE: There is no `::core::error::Error` yet in stable Rust.
 // So for `no_std` we just don't implement `Error` trait.
 #[allow(unused_variables)]
-pub fn gen_impl_error_trait(error_type_name: &ErrorTypeName) -> TokenStream {
+pub fn gen_impl_error_trait(error_type_name: impl ToTokens) -> TokenStream {
     cfg_if! {
         if #[cfg(feature = "std")] {
             quote! {
@@ -27,3 +36,33 @@ pub fn gen_impl_error_trait(error_type_name: &ErrorTypeName) -> TokenStream {
         }
     }
 }
+
+/// With `tracing` feature every failed construction emits a debug event with the type name and
+/// the violated rule. It makes the constructors non-const.
+#[allow(unused_variables)]
+pub fn gen_trace_validation_error(type_name: &str) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "tracing")] {
+            quote! {
+                ::nutype::__private::tracing::debug!(type_name = #type_name, violation = ?err, "Validation failed");
+            }
+        } else {
+            quote!{}
+        }
+    }
+}
+
+/// Error types are not generic, so a value that refers to a generic parameter of the type
+/// (e.g. `less_or_equal = MAX`) can not be evaluated in the error message.
+/// Such a value is shown the way it's written instead.
+pub fn gen_error_message_value(value: impl ToTokens, generics: &Generics) -> TokenStream {
+    let tokens = value.to_token_stream();
+    let refers_to_generic_params = generics
+        .const_params()
+        .any(|param| contains_ident(tokens.clone(), &param.ident));
+    if refers_to_generic_params {
+        quote!(::core::format_args!("{}", stringify!(#tokens)))
+    } else {
+        tokens
+    }
+}
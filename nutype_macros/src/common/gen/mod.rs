@@ -1,26 +1,46 @@
+pub mod checked_arithmetic;
 pub mod error;
+pub mod mut_guard;
+pub mod new_slice;
 pub mod new_unchecked;
+pub mod parse;
 pub mod parse_error;
+pub mod sql_check;
 pub mod tests;
 pub mod traits;
+pub mod zod;
 
+use cfg_if::cfg_if;
 use std::{collections::HashSet, hash::Hash};
 
 use self::traits::GeneratedTraits;
 
 use super::models::{
-    ErrorTypeName, GenerateParams, Guard, NewUnchecked, ParseErrorTypeName, TypeName, TypeTrait,
+    ArithmeticErrorTypeName, ArithmeticPolicy, AssociatedConst, BorrowedTypeName,
+    CheckedArithmetic, ConstructorName, CustomFunction, ErrorPlacement, ErrorTypeName, FromFloat,
+    FromFloatErrorTypeName, GenerateParams, Guard, MutGuardTypeName, NewSlice, NewUnchecked, NoNew,
+    ParseErrorTypeName, SliceErrorTypeName, SqlCheck, TypeName, TypeTrait, ZodSchema,
 };
 use crate::common::{
     gen::{
-        error::gen_error_type_name, new_unchecked::gen_new_unchecked,
+        checked_arithmetic::{gen_arithmetic_error_type_name, gen_checked_arithmetic},
+        error::{gen_error_type_name, gen_from_float_error_type_name, gen_trace_validation_error},
+        mut_guard::{gen_mut_guard, gen_mut_guard_type_name},
+        new_slice::{gen_new_slice, gen_slice_error_type_name},
+        new_unchecked::gen_new_unchecked,
+        parse::{gen_parse_constructor, gen_parse_radix_constructor},
         parse_error::gen_parse_error_name,
+        tests::gen_test_should_have_valid_consts,
+        traits::{
+            gen_constant_time_eq, gen_impl_convertible_with, gen_redacted_debug,
+            gen_serde_as_string, gen_unbound_transparent_traits,
+        },
     },
     models::ModuleName,
 };
 use proc_macro2::{Punct, Spacing, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
-use syn::Visibility;
+use syn::{Generics, Visibility};
 
 /// Inject an inner type into a closure, so compiler does not complain if the token stream matchers
 /// the expected closure pattern.
@@ -97,12 +117,19 @@ pub fn gen_module_name_for_type(type_name: &TypeName) -> ModuleName {
     ModuleName::new(ident)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_reimports(
     vis: Visibility,
     type_name: &TypeName,
     module_name: &ModuleName,
     maybe_error_type_name: Option<&ErrorTypeName>,
+    maybe_error_placement: Option<&ErrorPlacement>,
     maybe_parse_error_type_name: Option<&ParseErrorTypeName>,
+    maybe_borrowed_type_name: Option<&BorrowedTypeName>,
+    maybe_from_float_error_type_name: Option<&FromFloatErrorTypeName>,
+    maybe_mut_guard_type_name: Option<&MutGuardTypeName>,
+    maybe_slice_error_type_name: Option<&SliceErrorTypeName>,
+    maybe_arithmetic_error_type_name: Option<&ArithmeticErrorTypeName>,
 ) -> TokenStream {
     let reimport_main_type = quote! {
         #vis use #module_name::#type_name;
@@ -111,9 +138,19 @@ pub fn gen_reimports(
     let reimport_error_type_if_needed = match maybe_error_type_name {
         None => quote!(),
         Some(ref error_type_name) => {
-            quote! (
-                #vis use #module_name::#error_type_name;
-            )
+            let error_vis = maybe_error_placement
+                .and_then(|placement| placement.maybe_vis.as_ref())
+                .unwrap_or(&vis);
+            match maybe_error_placement.and_then(|placement| placement.maybe_module.as_ref()) {
+                Some(error_module) => quote! (
+                    #error_vis mod #error_module {
+                        pub use super::#module_name::#error_type_name as Error;
+                    }
+                ),
+                None => quote! (
+                    #error_vis use #module_name::#error_type_name;
+                ),
+            }
         }
     };
 
@@ -126,35 +163,353 @@ pub fn gen_reimports(
         }
     };
 
+    let reimport_borrowed_type_if_needed = match maybe_borrowed_type_name {
+        None => quote!(),
+        Some(borrowed_type_name) => {
+            quote! (
+                #vis use #module_name::#borrowed_type_name;
+            )
+        }
+    };
+
+    let reimport_from_float_error_type_if_needed = match maybe_from_float_error_type_name {
+        None => quote!(),
+        Some(from_float_error_type_name) => {
+            quote! (
+                #vis use #module_name::#from_float_error_type_name;
+            )
+        }
+    };
+
+    let reimport_mut_guard_type_if_needed = match maybe_mut_guard_type_name {
+        None => quote!(),
+        Some(mut_guard_type_name) => {
+            quote! (
+                #vis use #module_name::#mut_guard_type_name;
+            )
+        }
+    };
+
+    let reimport_slice_error_type_if_needed = match maybe_slice_error_type_name {
+        None => quote!(),
+        Some(slice_error_type_name) => {
+            quote! (
+                #vis use #module_name::#slice_error_type_name;
+            )
+        }
+    };
+
+    let reimport_arithmetic_error_type_if_needed = match maybe_arithmetic_error_type_name {
+        None => quote!(),
+        Some(arithmetic_error_type_name) => {
+            quote! (
+                #vis use #module_name::#arithmetic_error_type_name;
+            )
+        }
+    };
+
     quote! {
         #reimport_main_type
         #reimport_error_type_if_needed
         #reimport_parse_error_type_if_needed
+        #reimport_borrowed_type_if_needed
+        #reimport_from_float_error_type_if_needed
+        #reimport_mut_guard_type_if_needed
+        #reimport_slice_error_type_if_needed
+        #reimport_arithmetic_error_type_if_needed
+    }
+}
+
+/// With `no_new` flag `::new()` stays private to the generated module, where it's still used by
+/// the trait implementations.
+fn gen_new_visibility(no_new: NoNew) -> TokenStream {
+    match no_new {
+        NoNew::Off => quote!(pub),
+        NoNew::On => quote!(#[allow(dead_code)]),
+    }
+}
+
+/// If the constructor is renamed (e.g. to `try_new`), `::new()` stays private the same way as
+/// with `no_new` flag, and the constructor with the given name calls it.
+fn gen_renamed_constructor(
+    maybe_constructor_name: Option<&ConstructorName>,
+    no_new: NoNew,
+    constness: Option<&TokenStream>,
+    input_type: &TokenStream,
+    output_type: TokenStream,
+) -> (TokenStream, TokenStream) {
+    let constructor_vis = gen_new_visibility(no_new);
+    match maybe_constructor_name {
+        None => (constructor_vis, quote!()),
+        Some(constructor_name) => (
+            gen_new_visibility(NoNew::On),
+            quote!(
+                #[inline]
+                #constructor_vis #constness fn #constructor_name(raw_value: #input_type) -> #output_type {
+                    Self::new(raw_value)
+                }
+            ),
+        ),
     }
 }
 
-pub fn gen_impl_into_inner(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+/// How the inner value is kept within the generated struct.
+pub enum InnerStorage {
+    /// The inner value is stored as it is.
+    Plain,
+    /// The inner value is stored as the given `NonZero*` type, so `Option<Type>` has the same
+    /// size as `Type`. It's used only when zero is rejected by the validators.
+    NonZero(TokenStream),
+}
+
+impl InnerStorage {
+    fn gen_field_type(&self, inner_type: impl ToTokens) -> TokenStream {
+        match self {
+            Self::Plain => quote!(#inner_type),
+            Self::NonZero(storage_type) => quote!(#storage_type),
+        }
+    }
+
+    /// Converts the inner value into the stored one.
+    /// The value is expected to be validated already, so the panic is reachable only through
+    /// `::new_unchecked()` or invalid constants.
+    pub fn gen_store(&self, value: TokenStream) -> TokenStream {
+        match self {
+            Self::Plain => value,
+            Self::NonZero(storage_type) => quote!(
+                match #storage_type::new(#value) {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => panic!("nutype: zero can not be stored in a nonzero type"),
+                }
+            ),
+        }
+    }
+
+    /// Converts the stored value back into the inner one.
+    pub fn gen_load(&self, value: TokenStream) -> TokenStream {
+        match self {
+            Self::Plain => value,
+            Self::NonZero(_) => quote!(#value.get()),
+        }
+    }
+
+    /// Reference to the stored value as a reference to the inner type.
+    pub fn gen_load_ref(&self, value: TokenStream, inner_type: impl ToTokens) -> TokenStream {
+        match self {
+            Self::Plain => quote!(&#value),
+            // SAFETY: `NonZero*` types are guaranteed to have the same layout as the corresponding
+            // integer types, and the shared reference does not allow to write zero into it.
+            Self::NonZero(storage_type) => quote!(
+                unsafe { &*(&#value as *const #storage_type as *const #inner_type) }
+            ),
+        }
+    }
+}
+
+/// Generate associated constants requested with `consts(...)`.
+/// The values bypass `::new()`, so they are checked by the generated test.
+pub fn gen_impl_consts(
+    type_name: &TypeName,
+    generics: &Generics,
+    consts: &[AssociatedConst],
+    storage: &InnerStorage,
+) -> TokenStream {
+    if consts.is_empty() {
+        return quote!();
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    let definitions = consts.iter().map(|AssociatedConst { name, value }| {
+        let stored_value = storage.gen_store(quote!(#value));
+        quote!(
+            pub const #name: Self = Self(#stored_value #phantom_value);
+        )
+    });
+
     quote! {
-        impl #type_name {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            #(#definitions)*
+        }
+    }
+}
+
+/// `::new_or_default()` for types with validation and `default = `.
+/// The default value is expected to be valid, which is checked by the generated test.
+pub fn gen_new_or_default(
+    type_name: &TypeName,
+    generics: &Generics,
+    input_type: TokenStream,
+    default_value: &syn::Expr,
+    no_new: NoNew,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructor_vis = gen_new_visibility(no_new);
+    let tp = type_name.to_string();
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Same as `::new()`, but falls back to the default value if the validation fails.
+            #constructor_vis fn new_or_default(raw_value: #input_type) -> Self {
+                Self::new(raw_value).unwrap_or_else(|_| {
+                    Self::new(#default_value).unwrap_or_else(|err| {
+                        let tp = #tp;
+                        panic!("\nDefault value for type `{tp}` is invalid.\nERROR: {err:?}\n");
+                    })
+                })
+            }
+        }
+    }
+}
+
+/// `::new_all()` for types with validation: constructs a value from every raw value and fails
+/// on the first invalid one, reporting its index.
+#[allow(unused_variables)]
+pub fn gen_new_all(
+    type_name: &TypeName,
+    generics: &Generics,
+    input_type: TokenStream,
+    error_type_name: &ErrorTypeName,
+    no_new: NoNew,
+) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let constructor_vis = gen_new_visibility(no_new);
+            quote! {
+                impl #impl_generics #type_name #ty_generics #where_clause {
+                    /// Same as `::new()`, but for many values at once.
+                    /// On failure returns the index of the first invalid value along with the error.
+                    #constructor_vis fn new_all(
+                        raw_values: impl IntoIterator<Item = #input_type>,
+                    ) -> ::core::result::Result<::std::vec::Vec<Self>, (usize, #error_type_name)> {
+                        raw_values
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, raw_value)| Self::new(raw_value).map_err(|err| (index, err)))
+                            .collect()
+                    }
+                }
+            }
+        } else {
+            quote!{}
+        }
+    }
+}
+
+pub fn gen_impl_into_inner(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    storage: &InnerStorage,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_value = storage.gen_load(quote!(self.0));
+    let inner_ref = storage.gen_load_ref(quote!(self.0), &inner_type);
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
             #[inline]
             pub fn into_inner(self) -> #inner_type {
-                self.0
+                #inner_value
+            }
+
+            #[inline]
+            pub fn as_inner(&self) -> &#inner_type {
+                #inner_ref
             }
         }
     }
 }
 
+/// `map()` for types without validation and `try_map()` for types with validation.
+/// The result of the closure goes through `::new()`, so it's sanitized and validated again.
+pub fn gen_impl_map(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    no_new: NoNew,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructor_vis = gen_new_visibility(no_new);
+    let map = match maybe_error_type_name {
+        None => quote!(
+            /// Transforms the inner value with the given function. The result is sanitized.
+            #constructor_vis fn map(self, f: impl FnOnce(#inner_type) -> #inner_type) -> Self {
+                Self::new(f(self.into_inner()))
+            }
+        ),
+        Some(error_type_name) => quote!(
+            /// Transforms the inner value with the given function.
+            /// The result is sanitized and validated, as if it was passed to `::new()`.
+            #constructor_vis fn try_map(
+                self,
+                f: impl FnOnce(#inner_type) -> #inner_type,
+            ) -> ::core::result::Result<Self, #error_type_name> {
+                Self::new(f(self.into_inner()))
+            }
+        ),
+    };
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            #map
+        }
+    }
+}
+
+/// Functions nested into methods can not use the generic parameters of the outer item, so they
+/// have to declare the same parameters on their own.
+fn add_generics_to_nested_fn(fn_tokens: TokenStream, generics: &Generics) -> TokenStream {
+    if generics.params.is_empty() {
+        return fn_tokens;
+    }
+    let mut item_fn: syn::ItemFn =
+        syn::parse2(fn_tokens).expect("nutype: generated nested function must be a valid item");
+    let mut fn_generics = generics.clone();
+    // Defaults for generic parameters are not allowed on functions.
+    for param in fn_generics.params.iter_mut() {
+        if let syn::GenericParam::Const(const_param) = param {
+            const_param.eq_token = None;
+            const_param.default = None;
+        }
+    }
+    // Keep own parameters of the function (e.g. lifetimes) in front.
+    let own_params = std::mem::take(&mut item_fn.sig.generics.params);
+    fn_generics.params = own_params.into_iter().chain(fn_generics.params).collect();
+    item_fn.sig.generics = fn_generics;
+    item_fn.into_token_stream()
+}
+
+/// Turn a generated function into `const fn`, if the constructor is expected to be `const`.
+fn make_fn_const_if(fn_tokens: TokenStream, is_const: bool) -> TokenStream {
+    if !is_const {
+        return fn_tokens;
+    }
+    let mut item_fn: syn::ItemFn =
+        syn::parse2(fn_tokens).expect("nutype: generated function must be a valid item");
+    item_fn.sig.constness = Some(Default::default());
+    item_fn.into_token_stream()
+}
+
+/// If the type is tagged with a type parameter, the `PhantomData` field has to be filled
+/// when the value is constructed.
+pub fn gen_phantom_value(generics: &Generics) -> Option<TokenStream> {
+    generics
+        .type_params()
+        .next()
+        .map(|_| quote!(, ::core::marker::PhantomData))
+}
+
 pub trait GenerateNewtype {
     type Sanitizer;
     type Validator;
     type InnerType: ToTokens;
-    type TypedTrait: Hash + TypeTrait;
+    type TypedTrait: Hash + Eq + TypeTrait;
 
     /// If the type has dedicated parse error. This error is used within `FromStr` trait.
     /// For most of the types it's different from the regular validation error, because parsing
     /// happens in 2 stages:
     /// * &str -> inner type (parsing)
     /// * inner type -> nutype (validation)
+    ///
     /// But for the String based types there is no first stage, so the parse error is the same as
     /// validation error.
     const HAS_DEDICATED_PARSE_ERROR: bool = true;
@@ -162,6 +517,24 @@ pub trait GenerateNewtype {
     /// If it's true, then `::new()` function receives `impl Into<T>` instead of `T`.
     const NEW_CONVERT_INTO_INNER_TYPE: bool = false;
 
+    /// If it's true, then `::parse()` constructor is generated, which parses `&str` into the
+    /// inner type and then sanitizes and validates it.
+    /// The parse error type is generated along with it.
+    const HAS_PARSE_CONSTRUCTOR: bool = false;
+
+    /// Whether arithmetic operations on the inner type can overflow (integers), so
+    /// `checked_add()` and friends need `Overflow` error.
+    const HAS_ARITHMETIC_OVERFLOW: bool = false;
+
+    /// If it's true, then the inner type can be the type of a const generic parameter, so types
+    /// with `const fn new()` also get `::const_new::<VALUE>()`, which checks the value at compile
+    /// time.
+    const HAS_CONST_PARAM_INNER_TYPE: bool = false;
+
+    /// If it's true, then `::parse_radix()` constructor is generated (for integer types).
+    /// It requires `HAS_PARSE_CONSTRUCTOR` to be true, because it reuses the parse error.
+    const HAS_PARSE_RADIX_CONSTRUCTOR: bool = false;
+
     fn gen_fn_sanitize(inner_type: &Self::InnerType, sanitizers: &[Self::Sanitizer])
         -> TokenStream;
 
@@ -173,28 +546,159 @@ pub trait GenerateNewtype {
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        generics: &Generics,
         validators: &[Self::Validator],
     ) -> TokenStream;
 
+    /// Generate the borrowed companion type requested with `borrowed = `.
+    /// Only String based types support it, for the other types the attribute is rejected
+    /// when it's parsed.
+    fn gen_borrowed_type(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _borrowed_type_name: &BorrowedTypeName,
+        _maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> Result<TokenStream, syn::Error> {
+        Ok(quote!())
+    }
+
+    /// Generate `TryFrom<f64>` requested with `from_float = `.
+    /// Only integer based types support it, for the other types the attribute is rejected
+    /// when it's parsed.
+    fn gen_impl_try_from_float(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _from_float: FromFloat,
+        _maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `::sql_check()` requested with `sql_check`.
+    /// Only integer, float and String based types support it, for the other types the attribute
+    /// is rejected when it's parsed.
+    fn gen_sql_check(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _validators: &[Self::Validator],
+    ) -> Result<TokenStream, syn::Error> {
+        Ok(quote!())
+    }
+
+    /// Generate `::zod_schema()` requested with `zod`.
+    /// Only integer, float and String based types support it, for the other types the attribute
+    /// is rejected when it's parsed.
+    fn gen_zod_schema(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> Result<TokenStream, syn::Error> {
+        Ok(quote!())
+    }
+
+    /// Generate methods that are sound only thanks to the validators, e.g. `log2()` for an
+    /// integer with `power_of_two` validator.
+    fn gen_validator_methods(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _validators: &[Self::Validator],
+        _storage: &InnerStorage,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// How the inner value is stored within the generated struct.
+    fn gen_inner_storage(
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> InnerStorage {
+        InnerStorage::Plain
+    }
+
+    /// If it's true, then `::new()` and the functions it relies on are generated as `const fn`,
+    /// so the type can be constructed in `const` and `static` contexts.
+    /// It's possible only if the sanitizers and the validators can be evaluated at compile time.
+    fn is_new_const(_guard: &Guard<Self::Sanitizer, Self::Validator>) -> bool {
+        false
+    }
+
+    /// Type of the argument of the generated `::validate()` and `::is_valid()` functions.
+    fn gen_validate_input_type(inner_type: &Self::InnerType) -> TokenStream {
+        quote!(&#inner_type)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_arithmetic_policy: Option<ArithmeticPolicy>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
     ) -> Result<GeneratedTraits, syn::Error>;
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_new_with_validation(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
         validators: &[Self::Validator],
+        maybe_on_error: Option<&CustomFunction>,
+        no_new: NoNew,
+        maybe_constructor_name: Option<&ConstructorName>,
+        is_const: bool,
+        storage: &InnerStorage,
     ) -> TokenStream {
-        let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
-        let validation_error = Self::gen_validation_error_type(type_name, validators);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let constructor_vis = gen_new_visibility(no_new);
+        let turbofish = ty_generics.as_turbofish();
+        let validation_error = Self::gen_validation_error_type(type_name, generics, validators);
         let error_type_name = gen_error_type_name(type_name);
-        let validate = Self::gen_fn_validate(inner_type, type_name, validators);
+        let phantom_value = gen_phantom_value(generics);
+        let validate_input_type = Self::gen_validate_input_type(inner_type);
+        let stored_value = storage.gen_store(quote!(sanitized_value));
+        let type_name_str = type_name.to_string();
+        let call_on_error = maybe_on_error.map(|on_error| {
+            quote!(
+                (#on_error)(#type_name_str, &err);
+            )
+        });
+        let trace_error = gen_trace_validation_error(&type_name_str);
+        // Reporting the error (the callback or the `tracing` event) can not be evaluated at
+        // compile time, so the constructors are `const` only without it. `::sanitize()`,
+        // `::validate()` and `::const_new()` don't report the error, so they remain `const`.
+        let is_new_const = is_const && call_on_error.is_none() && trace_error.is_empty();
+        let new_constness = is_new_const.then(|| quote!(const));
+        let constness = is_const.then(|| quote!(const));
+        let sanitize = add_generics_to_nested_fn(
+            make_fn_const_if(Self::gen_fn_sanitize(inner_type, sanitizers), is_const),
+            generics,
+        );
+        let validate = add_generics_to_nested_fn(
+            make_fn_const_if(
+                Self::gen_fn_validate(inner_type, type_name, validators),
+                is_const,
+            ),
+            generics,
+        );
+        let report_error = if trace_error.is_empty() && call_on_error.is_none() {
+            quote!()
+        } else {
+            quote!(
+                .map_err(|err| {
+                    #trace_error
+                    #call_on_error
+                    err
+                })
+            )
+        };
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -205,32 +709,113 @@ pub trait GenerateNewtype {
             (quote!(#inner_type), quote!())
         };
 
+        let (new_vis, impl_renamed_constructor) = gen_renamed_constructor(
+            maybe_constructor_name,
+            no_new,
+            new_constness.as_ref(),
+            &input_type,
+            quote!(::core::result::Result<Self, #error_type_name>),
+        );
+
+        let impl_const_new = if is_const && Self::HAS_CONST_PARAM_INNER_TYPE {
+            let panic_msg = format!("{type_name}::const_new() got an invalid value");
+            quote!(
+                /// Same as `::new()`, but the value is checked at compile time, so an invalid
+                /// value fails the compilation instead of returning an error.
+                /// `on_error` callback and `tracing` event are not involved.
+                #constructor_vis const fn const_new<const VALUE: #inner_type>() -> Self {
+                    const {
+                        let sanitized_value: #inner_type = Self::sanitize(VALUE);
+                        match Self::validate(&sanitized_value) {
+                            Ok(()) => #type_name(#stored_value #phantom_value),
+                            Err(_) => panic!(#panic_msg),
+                        }
+                    }
+                }
+            )
+        } else {
+            quote!()
+        };
+
         quote!(
             #validation_error
 
-            impl #type_name {
-                pub fn new(raw_value: #input_type) -> ::core::result::Result<Self, #error_type_name> {
-                    // Keep sanitize() and validate() within new() so they do not overlap with outer
+            impl #impl_generics #type_name #ty_generics #where_clause {
+                #new_vis #new_constness fn new(raw_value: #input_type) -> ::core::result::Result<Self, #error_type_name> {
+                    let sanitized_value: #inner_type = Self::sanitize(raw_value);
+                    // `?` can not be used within `const fn`.
+                    match Self::validate(&sanitized_value) #report_error {
+                        Ok(()) => Ok(#type_name(#stored_value #phantom_value)),
+                        Err(err) => Err(err),
+                    }
+                }
+
+                #impl_renamed_constructor
+
+                #impl_const_new
+
+                /// Same as `::new()`, but gives the value back if the validation fails,
+                /// so it does not have to be cloned in advance (e.g. a `String` or a `Vec`).
+                /// The returned value is already sanitized.
+                #constructor_vis #new_constness fn new_or_return(
+                    raw_value: #input_type,
+                ) -> ::core::result::Result<Self, (#inner_type, #error_type_name)> {
+                    let sanitized_value: #inner_type = Self::sanitize(raw_value);
+                    match Self::validate(&sanitized_value) #report_error {
+                        Ok(()) => Ok(#type_name(#stored_value #phantom_value)),
+                        Err(err) => Err((sanitized_value, err)),
+                    }
+                }
+
+                /// Applies the sanitizers to the value without constructing the type.
+                pub #constness fn sanitize(raw_value: #input_type) -> #inner_type {
+                    // Keep the nested function within sanitize() so it does not overlap with outer
                     // scope imported with `use super::*`.
                     #sanitize
-                    #validate
 
                     #convert_raw_value_if_necessary
 
-                    let sanitized_value: #inner_type = sanitize(raw_value);
-                    validate(&sanitized_value)?;
-                    Ok(#type_name(sanitized_value))
+                    sanitize #turbofish (raw_value)
+                }
+
+                /// Checks the value against the validators without constructing the type.
+                /// The value is not sanitized.
+                pub #constness fn validate(value: #validate_input_type) -> ::core::result::Result<(), #error_type_name> {
+                    #validate
+
+                    validate #turbofish (value)
+                }
+
+                /// Returns `true` if the value passes the validators.
+                /// The value is not sanitized.
+                #[inline]
+                pub #constness fn is_valid(value: #validate_input_type) -> bool {
+                    Self::validate(value).is_ok()
                 }
             }
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_new_without_validation(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
+        no_new: NoNew,
+        maybe_constructor_name: Option<&ConstructorName>,
+        is_const: bool,
+        storage: &InnerStorage,
     ) -> TokenStream {
-        let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+        let constness = is_const.then(|| quote!(const));
+        let sanitize = add_generics_to_nested_fn(
+            make_fn_const_if(Self::gen_fn_sanitize(inner_type, sanitizers), is_const),
+            generics,
+        );
+        let phantom_value = gen_phantom_value(generics);
+        let stored_value = storage.gen_store(quote!(Self::sanitize(raw_value)));
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -241,41 +826,115 @@ pub trait GenerateNewtype {
             (quote!(#inner_type), quote!())
         };
 
+        let (new_vis, impl_renamed_constructor) = gen_renamed_constructor(
+            maybe_constructor_name,
+            no_new,
+            constness.as_ref(),
+            &input_type,
+            quote!(Self),
+        );
+
         quote!(
-            impl #type_name {
-                pub fn new(raw_value: #input_type) -> Self {
+            impl #impl_generics #type_name #ty_generics #where_clause {
+                #new_vis #constness fn new(raw_value: #input_type) -> Self {
+                    Self(#stored_value #phantom_value)
+                }
+
+                #impl_renamed_constructor
+
+                /// Applies the sanitizers to the value without constructing the type.
+                pub #constness fn sanitize(raw_value: #input_type) -> #inner_type {
                     #sanitize
 
                     #convert_raw_value_if_necessary
 
-                    Self(sanitize(raw_value))
+                    sanitize #turbofish (raw_value)
                 }
             }
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_implementation(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         new_unchecked: NewUnchecked,
+        no_new: NoNew,
+        maybe_constructor_name: Option<&ConstructorName>,
+        maybe_on_error: Option<&CustomFunction>,
+        storage: &InnerStorage,
     ) -> TokenStream {
+        let is_const = Self::is_new_const(guard);
         let impl_new = match guard {
-            Guard::WithoutValidation { sanitizers } => {
-                Self::gen_new_without_validation(type_name, inner_type, sanitizers)
-            }
+            Guard::WithoutValidation { sanitizers } => Self::gen_new_without_validation(
+                type_name,
+                generics,
+                inner_type,
+                sanitizers,
+                no_new,
+                maybe_constructor_name,
+                is_const,
+                storage,
+            ),
             Guard::WithValidation {
                 sanitizers,
                 validators,
-            } => Self::gen_new_with_validation(type_name, inner_type, sanitizers, validators),
+            } => Self::gen_new_with_validation(
+                type_name,
+                generics,
+                inner_type,
+                sanitizers,
+                validators,
+                maybe_on_error,
+                no_new,
+                maybe_constructor_name,
+                is_const,
+                storage,
+            ),
+        };
+        let impl_into_inner = gen_impl_into_inner(type_name, generics, inner_type, storage);
+        let impl_new_unchecked =
+            gen_new_unchecked(type_name, generics, inner_type, new_unchecked, storage);
+        let maybe_error_type_name = guard
+            .has_validation()
+            .then(|| gen_error_type_name(type_name));
+        let impl_map = gen_impl_map(
+            type_name,
+            generics,
+            inner_type,
+            maybe_error_type_name.as_ref(),
+            no_new,
+        );
+        let impl_parse = if Self::HAS_PARSE_CONSTRUCTOR {
+            gen_parse_constructor(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )
+        } else {
+            quote!()
+        };
+        let impl_parse_radix = if Self::HAS_PARSE_RADIX_CONSTRUCTOR {
+            gen_parse_radix_constructor(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )
+        } else {
+            quote!()
         };
-        let impl_into_inner = gen_impl_into_inner(type_name, inner_type);
-        let impl_new_unchecked = gen_new_unchecked(type_name, inner_type, new_unchecked);
 
         quote! {
             #impl_new
             #impl_into_inner
             #impl_new_unchecked
+            #impl_map
+            #impl_parse
+            #impl_parse_radix
         }
     }
 
@@ -292,15 +951,44 @@ pub trait GenerateNewtype {
             traits,
             vis,
             type_name,
+            generics,
+            maybe_phantom_type,
             guard,
             new_unchecked,
+            no_new,
+            maybe_constructor_name,
+            new_slice,
+            checked_arithmetic,
+            sql_check,
+            zod_schema,
+            sensitive,
             maybe_default_value,
+            convertible_with,
+            maybe_borrowed_type_name,
+            consts,
+            maybe_on_error,
+            maybe_from_float,
+            maybe_error_placement,
+            maybe_mut_guard,
+            maybe_serde_mode,
+            maybe_arithmetic_policy,
+            maybe_debug_mode,
             inner_type,
         } = params;
 
         let module_name = gen_module_name_for_type(&type_name);
-        let implementation =
-            Self::gen_implementation(&type_name, &inner_type, &guard, new_unchecked);
+        let storage = Self::gen_inner_storage(&inner_type, &guard);
+        let implementation = Self::gen_implementation(
+            &type_name,
+            &generics,
+            &inner_type,
+            &guard,
+            new_unchecked,
+            no_new,
+            maybe_constructor_name.as_ref(),
+            maybe_on_error.as_ref(),
+            &storage,
+        );
 
         let maybe_error_type_name: Option<ErrorTypeName> = match guard {
             Guard::WithoutValidation { .. } => None,
@@ -308,26 +996,173 @@ pub trait GenerateNewtype {
         };
 
         let has_from_str_trait = traits.iter().any(|t| t.is_from_str());
-        let maybe_parse_error_type_name = if has_from_str_trait && Self::HAS_DEDICATED_PARSE_ERROR {
+        let has_parse_error = has_from_str_trait || Self::HAS_PARSE_CONSTRUCTOR;
+        let maybe_parse_error_type_name = if has_parse_error && Self::HAS_DEDICATED_PARSE_ERROR {
             Some(gen_parse_error_name(&type_name))
         } else {
             None
         };
 
-        let tests = Self::gen_tests(
-            &type_name,
-            &inner_type,
-            &maybe_default_value,
-            &guard,
-            &traits,
-        );
+        // The generated tests would need concrete values for the generic parameters, which only
+        // the user of the type knows.
+        let tests = if generics.params.is_empty() {
+            let type_tests = Self::gen_tests(
+                &type_name,
+                &inner_type,
+                &maybe_default_value,
+                &guard,
+                &traits,
+            );
+            let test_valid_consts =
+                gen_test_should_have_valid_consts(&type_name, &consts, guard.has_validation());
+            quote! {
+                #type_tests
+                #test_valid_consts
+            }
+        } else {
+            quote!()
+        };
 
         let reimports = gen_reimports(
             vis,
             &type_name,
             &module_name,
             maybe_error_type_name.as_ref(),
+            maybe_error_placement.as_ref(),
             maybe_parse_error_type_name.as_ref(),
+            maybe_borrowed_type_name.as_ref(),
+            maybe_from_float
+                .map(|_| gen_from_float_error_type_name(&type_name))
+                .as_ref(),
+            maybe_mut_guard
+                .map(|_| gen_mut_guard_type_name(&type_name))
+                .as_ref(),
+            match (new_slice, &maybe_error_type_name) {
+                (NewSlice::On, Some(_)) => Some(gen_slice_error_type_name(&type_name)),
+                _ => None,
+            }
+            .as_ref(),
+            match checked_arithmetic {
+                CheckedArithmetic::On => Some(gen_arithmetic_error_type_name(&type_name)),
+                CheckedArithmetic::Off => None,
+            }
+            .as_ref(),
+        );
+
+        let borrowed_type = match maybe_borrowed_type_name {
+            Some(ref borrowed_type_name) => Self::gen_borrowed_type(
+                &type_name,
+                &generics,
+                borrowed_type_name,
+                maybe_error_type_name.as_ref(),
+            )?,
+            None => quote!(),
+        };
+
+        let impl_consts = gen_impl_consts(&type_name, &generics, &consts, &storage);
+
+        let input_type = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            quote!(impl Into<#inner_type>)
+        } else {
+            quote!(#inner_type)
+        };
+
+        let impl_new_or_default = match (&maybe_default_value, guard.has_validation()) {
+            (Some(default_value), true) => gen_new_or_default(
+                &type_name,
+                &generics,
+                input_type.clone(),
+                default_value,
+                no_new,
+            ),
+            _ => quote!(),
+        };
+
+        let impl_new_all = match maybe_error_type_name {
+            Some(ref error_type_name) => {
+                gen_new_all(&type_name, &generics, input_type, error_type_name, no_new)
+            }
+            None => quote!(),
+        };
+
+        let mut_guard = match maybe_mut_guard {
+            Some(policy) => gen_mut_guard(
+                &type_name,
+                &generics,
+                &inner_type,
+                policy,
+                guard.has_validation(),
+            ),
+            None => quote!(),
+        };
+
+        let impl_new_slice = gen_new_slice(
+            &type_name,
+            &generics,
+            &inner_type,
+            maybe_error_type_name.as_ref(),
+            new_slice,
+        );
+
+        let impl_sql_check = match (sql_check, guard.validators()) {
+            (SqlCheck::On, Some(validators)) => {
+                Self::gen_sql_check(&type_name, &generics, &inner_type, validators)?
+            }
+            _ => quote!(),
+        };
+
+        let impl_zod_schema = match zod_schema {
+            ZodSchema::On => Self::gen_zod_schema(&type_name, &generics, &inner_type, &guard)?,
+            ZodSchema::Off => quote!(),
+        };
+
+        let impl_validator_methods = match guard.validators() {
+            Some(validators) => Self::gen_validator_methods(
+                &type_name,
+                &generics,
+                &inner_type,
+                validators,
+                &storage,
+            ),
+            None => quote!(),
+        };
+
+        let impl_try_from_float = match maybe_from_float {
+            Some(from_float) => Self::gen_impl_try_from_float(
+                &type_name,
+                &generics,
+                &inner_type,
+                from_float,
+                maybe_error_type_name.as_ref(),
+            ),
+            None => quote!(),
+        };
+
+        let impl_checked_arithmetic = gen_checked_arithmetic(
+            &type_name,
+            &generics,
+            &inner_type,
+            maybe_error_type_name.as_ref(),
+            &storage,
+            checked_arithmetic,
+            Self::HAS_ARITHMETIC_OVERFLOW,
+        );
+
+        let impl_convertible_with = gen_impl_convertible_with(
+            &type_name,
+            &generics,
+            &inner_type,
+            maybe_error_type_name.as_ref(),
+            &convertible_with,
+        );
+
+        let (traits, impl_serde_as_string) = gen_serde_as_string(
+            &type_name,
+            &generics,
+            &inner_type,
+            maybe_error_type_name.as_ref(),
+            traits,
+            maybe_serde_mode,
         );
 
         let GeneratedTraits {
@@ -335,12 +1170,32 @@ pub trait GenerateNewtype {
             implement_traits,
         } = Self::gen_traits(
             &type_name,
+            &generics,
             &inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            maybe_arithmetic_policy,
             &guard,
         )?;
+        let (derive_transparent_traits, constant_time_eq) =
+            gen_constant_time_eq(&type_name, &generics, sensitive, derive_transparent_traits)?;
+        let (derive_transparent_traits, redacted_debug) = gen_redacted_debug(
+            &type_name,
+            &generics,
+            maybe_debug_mode,
+            derive_transparent_traits,
+        )?;
+        let (derive_transparent_traits, unbound_transparent_traits) =
+            gen_unbound_transparent_traits(&type_name, &generics, derive_transparent_traits)?;
+
+        let where_clause = &generics.where_clause;
+        let phantom_field = maybe_phantom_type.map(|phantom_type| quote!(, #phantom_type));
+        let field_type = storage.gen_field_type(&inner_type);
+        let repr_transparent = match new_slice {
+            NewSlice::Off => quote!(),
+            NewSlice::On => quote!(#[repr(transparent)]),
+        };
 
         Ok(quote!(
             #[doc(hidden)]
@@ -349,10 +1204,27 @@ pub trait GenerateNewtype {
 
                 #(#doc_attrs)*
                 #derive_transparent_traits
-                pub struct #type_name(#inner_type);
+                #repr_transparent
+                pub struct #type_name #generics (#field_type #phantom_field) #where_clause;
 
                 #implementation
+                #impl_consts
+                #impl_new_or_default
+                #impl_new_all
                 #implement_traits
+                #impl_serde_as_string
+                #unbound_transparent_traits
+                #constant_time_eq
+                #redacted_debug
+                #impl_convertible_with
+                #impl_new_slice
+                #impl_sql_check
+                #impl_zod_schema
+                #impl_validator_methods
+                #impl_try_from_float
+                #impl_checked_arithmetic
+                #borrowed_type
+                #mut_guard
 
                 #tests
             }
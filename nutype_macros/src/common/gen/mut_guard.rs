@@ -0,0 +1,216 @@
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Generics};
+
+use crate::common::{
+    gen::error::gen_error_type_name,
+    models::{MutGuardPolicy, MutGuardTypeName, TypeName},
+};
+
+pub fn gen_mut_guard_type_name(type_name: &TypeName) -> MutGuardTypeName {
+    let ident = format_ident!("{type_name}MutGuard");
+    MutGuardTypeName::new(ident)
+}
+
+/// Generate `::as_mut_guard()` together with the guard it returns.
+/// The guard gives mutable access to the inner value, and when it's dropped the value is
+/// sanitized and validated again. If the validation fails, the original value is restored
+/// (the inner type must implement `Clone`) and with `MutGuardPolicy::Panic` the guard panics.
+/// The type also gets `modify()`, which reports the failed validation as an error regardless of
+/// the policy.
+pub fn gen_mut_guard(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    policy: MutGuardPolicy,
+    has_validation: bool,
+) -> TokenStream {
+    let mut_guard_type_name = gen_mut_guard_type_name(type_name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut guard_generics = generics.clone();
+    guard_generics.params.insert(0, parse_quote!('__nutype_a));
+    let (guard_impl_generics, guard_ty_generics, _) = guard_generics.split_for_impl();
+
+    let type_with_generics = quote!(<#type_name #ty_generics>);
+    let type_name_str = type_name.to_string();
+
+    let if_has_validation = |check: TokenStream| {
+        if has_validation {
+            check
+        } else {
+            quote!()
+        }
+    };
+    let validate = match policy {
+        // The original value is already in place
+        MutGuardPolicy::Restore => if_has_validation(quote!(
+            if #type_with_generics::validate(&sanitized).is_err() {
+                return;
+            }
+        )),
+        // The original value is restored before panicking, so the type never holds an invalid value
+        // even if the panic is caught.
+        MutGuardPolicy::Panic => if_has_validation(quote!(
+            if let Err(err) = #type_with_generics::validate(&sanitized) {
+                panic!("{} does not pass the validation after mutation: {}", #type_name_str, err);
+            }
+        )),
+    };
+
+    let restore_if_panicking = gen_restore_if_panicking();
+
+    let impl_modify = gen_modify(type_name, generics, &inner_type, has_validation);
+
+    quote! {
+        #impl_modify
+
+        /// Gives mutable access to the inner value of the newtype.
+        /// The value is sanitized and validated again when the guard is dropped.
+        pub struct #mut_guard_type_name #guard_generics #where_clause {
+            value: &'__nutype_a mut #type_name #ty_generics,
+            original: Option<#inner_type>,
+        }
+
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Returns a guard, which dereferences mutably to the inner value.
+            /// The sanitizers and validators are applied again, when the guard is dropped.
+            pub fn as_mut_guard<'__nutype_a>(&'__nutype_a mut self) -> #mut_guard_type_name #guard_ty_generics {
+                #mut_guard_type_name {
+                    original: Some(::core::clone::Clone::clone(&self.0)),
+                    value: self,
+                }
+            }
+        }
+
+        impl #guard_impl_generics ::core::ops::Deref for #mut_guard_type_name #guard_ty_generics #where_clause {
+            type Target = #inner_type;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.value.0
+            }
+        }
+
+        impl #guard_impl_generics ::core::ops::DerefMut for #mut_guard_type_name #guard_ty_generics #where_clause {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.value.0
+            }
+        }
+
+        impl #guard_impl_generics ::core::ops::Drop for #mut_guard_type_name #guard_ty_generics #where_clause {
+            fn drop(&mut self) {
+                let original = self.original.take().expect("nutype: the guard is dropped only once");
+                #restore_if_panicking
+                let edited = ::core::mem::replace(&mut self.value.0, original);
+                let sanitized = #type_with_generics::sanitize(edited);
+                #validate
+                self.value.0 = sanitized;
+            }
+        }
+    }
+}
+
+/// Running sanitizers and validators while unwinding risks a panic within a panic, which aborts
+/// the process. So in that case the original value is restored without any checks.
+fn gen_restore_if_panicking() -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            quote!(
+                if ::std::thread::panicking() {
+                    self.value.0 = original;
+                    return;
+                }
+            )
+        } else {
+            quote!()
+        }
+    }
+}
+
+/// `modify()` mutates the value in place, so the previous value has to be cloned beforehand to
+/// be able to restore it. The `Clone` bound is spelled out, so the requirement is visible in
+/// the docs of the generated method.
+fn gen_modify(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    has_validation: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let apply = gen_apply_restoring_on_unwind();
+    let modify = if has_validation {
+        let error_type_name = gen_error_type_name(type_name);
+        quote!(
+            /// Applies the function to the inner value in place, then sanitizes and validates it
+            /// again. If the validation fails or the function panics, the previous value is
+            /// restored and the error is returned. The previous value is cloned on every call to
+            /// make it possible.
+            pub fn modify(
+                &mut self,
+                f: impl FnOnce(&mut #inner_type),
+            ) -> ::core::result::Result<(), #error_type_name>
+            where
+                #inner_type: ::core::clone::Clone,
+            {
+                let edited = #apply;
+                let sanitized = Self::sanitize(edited);
+                Self::validate(&sanitized)?;
+                self.0 = sanitized;
+                Ok(())
+            }
+        )
+    } else {
+        quote!(
+            /// Applies the function to the inner value in place, then sanitizes it again.
+            /// If the function panics, the previous value is restored. The previous value is
+            /// cloned on every call to make it possible.
+            pub fn modify(&mut self, f: impl FnOnce(&mut #inner_type))
+            where
+                #inner_type: ::core::clone::Clone,
+            {
+                let edited = #apply;
+                self.0 = Self::sanitize(edited);
+            }
+        )
+    };
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            #modify
+        }
+    )
+}
+
+/// Applies `f` to `self.0` and evaluates to the edited value, leaving the previous value in
+/// `self.0`. If `f` panics, the previous value is put back while unwinding, so the type never
+/// holds a value, which has not been sanitized and validated (unlike the guard, this works
+/// without `std`).
+fn gen_apply_restoring_on_unwind() -> TokenStream {
+    quote!({
+        struct RestoreOnUnwind<'a, T> {
+            value: &'a mut T,
+            original: ::core::option::Option<T>,
+        }
+
+        impl<T> ::core::ops::Drop for RestoreOnUnwind<'_, T> {
+            fn drop(&mut self) {
+                if let ::core::option::Option::Some(original) = self.original.take() {
+                    *self.value = original;
+                }
+            }
+        }
+
+        let mut restore = RestoreOnUnwind {
+            original: ::core::option::Option::Some(::core::clone::Clone::clone(&self.0)),
+            value: &mut self.0,
+        };
+        f(&mut *restore.value);
+        let original = restore
+            .original
+            .take()
+            .expect("nutype: the original value is taken only once");
+        ::core::mem::replace(&mut *restore.value, original)
+    })
+}
@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
+
+use crate::common::{
+    gen::error::gen_impl_error_trait,
+    models::{ErrorTypeName, NewSlice, SliceErrorTypeName, TypeName},
+};
+
+pub fn gen_slice_error_type_name(type_name: &TypeName) -> SliceErrorTypeName {
+    let ident = format_ident!("{type_name}SliceError");
+    SliceErrorTypeName::new(ident)
+}
+
+/// Generate `::new_slice()`, which validates every element of `&[Inner]` and then reinterprets
+/// it as `&[Type]` without copying. The type itself is marked with `#[repr(transparent)]`
+/// by the caller, which is what makes the reinterpretation sound.
+pub fn gen_new_slice(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    new_slice: NewSlice,
+) -> TokenStream {
+    if let NewSlice::Off = new_slice {
+        return quote!();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // SAFETY: the type is `#[repr(transparent)]` over the inner type or its `NonZero*`
+    // counterpart, which has the same layout (the phantom field, if any, is zero-sized), so
+    // `[Inner]` and `[Type]` have the same layout. Zero is rejected by the validation then.
+    let reinterpret = quote!(
+        unsafe { &*(raw_values as *const [#inner_type] as *const [Self]) }
+    );
+
+    match maybe_error_type_name {
+        None => quote!(
+            impl #impl_generics #type_name #ty_generics #where_clause {
+                /// Reinterprets a slice of the inner values as a slice of the newtype without
+                /// copying.
+                pub fn new_slice(raw_values: &[#inner_type]) -> &[Self] {
+                    #reinterpret
+                }
+            }
+        ),
+        Some(error_type_name) => {
+            let slice_error_type_name = gen_slice_error_type_name(type_name);
+            let impl_error_trait = gen_impl_error_trait(&slice_error_type_name);
+
+            quote!(
+                /// Error of `::new_slice()`: the first element that does not pass the validation.
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                pub struct #slice_error_type_name {
+                    /// Index of the invalid element within the slice.
+                    pub index: usize,
+                    /// Why the element is invalid.
+                    pub error: #error_type_name,
+                }
+
+                impl ::core::fmt::Display for #slice_error_type_name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "Element at index {} is invalid: {}", self.index, self.error)
+                    }
+                }
+
+                #impl_error_trait
+
+                impl #impl_generics #type_name #ty_generics #where_clause {
+                    /// Validates every element of the slice and reinterprets it as a slice of
+                    /// the newtype without copying.
+                    pub fn new_slice(
+                        raw_values: &[#inner_type],
+                    ) -> ::core::result::Result<&[Self], #slice_error_type_name> {
+                        for (index, raw_value) in raw_values.iter().enumerate() {
+                            Self::validate(raw_value)
+                                .map_err(|error| #slice_error_type_name { index, error })?;
+                        }
+                        Ok(#reinterpret)
+                    }
+                }
+            )
+        }
+    }
+}
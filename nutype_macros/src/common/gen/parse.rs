@@ -0,0 +1,80 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::{ErrorTypeName, TypeName};
+
+use super::parse_error::{gen_def_parse_error, gen_parse_error_name};
+
+/// Generate `::parse()` constructor for non-string types (e.g. integers or floats) together with
+/// the parse error it returns.
+/// The string is parsed into the inner type first, and then the value goes through the regular
+/// sanitization and validation.
+pub fn gen_parse_constructor(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_error_type_name = gen_parse_error_name(type_name);
+    let def_parse_error = gen_def_parse_error(
+        &inner_type,
+        type_name,
+        maybe_error_type_name,
+        &parse_error_type_name,
+        true,
+    );
+
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote!(Self::new(raw_value).map_err(#parse_error_type_name::Validate))
+    } else {
+        quote!(Ok(Self::new(raw_value)))
+    };
+
+    quote! {
+        #def_parse_error
+
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Parses a string into the inner type and then sanitizes and validates it.
+            pub fn parse(raw_string: &str) -> ::core::result::Result<Self, #parse_error_type_name> {
+                let raw_value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate `::parse_radix()` constructor for integer types.
+/// It reuses the parse error generated by [gen_parse_constructor], since `from_str_radix()`
+/// returns the same error as `FromStr` of the integer types.
+pub fn gen_parse_radix_constructor(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_error_type_name = gen_parse_error_name(type_name);
+
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote!(Self::new(raw_value).map_err(#parse_error_type_name::Validate))
+    } else {
+        quote!(Ok(Self::new(raw_value)))
+    };
+
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Parses a string in the given base (e.g. `16` for hex) into the inner type and then
+            /// sanitizes and validates it.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `radix` is not in the range from 2 to 36.
+            pub fn parse_radix(raw_string: &str, radix: u32) -> ::core::result::Result<Self, #parse_error_type_name> {
+                let raw_value = <#inner_type>::from_str_radix(raw_string, radix).map_err(#parse_error_type_name::Parse)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 
-use crate::common::models::{ErrorTypeName, InnerType, ParseErrorTypeName, TypeName};
+use crate::common::models::{ErrorTypeName, ParseErrorTypeName, TypeName};
 
 /// Generate a name for the error which is used for FromStr trait implementation.
 pub fn gen_parse_error_name(type_name: &TypeName) -> ParseErrorTypeName {
@@ -12,13 +12,17 @@ pub fn gen_parse_error_name(type_name: &TypeName) -> ParseErrorTypeName {
 
 /// Generate an error which is used for FromStr trait implementation of non-string types (e.g.
 /// floats or integers)
+///
+/// `is_inner_parse_error_std` tells whether the parse error of the inner type is known to implement
+/// `std::error::Error`, so it can be exposed as the source of the error.
+#[allow(unused_variables)]
 pub fn gen_def_parse_error(
-    inner_type: impl Into<InnerType>,
+    inner_type: impl ToTokens,
     type_name: &TypeName,
     maybe_error_type_name: Option<&ErrorTypeName>,
     parse_error_type_name: &ParseErrorTypeName,
+    is_inner_parse_error_std: bool,
 ) -> TokenStream {
-    let inner_type: InnerType = inner_type.into();
     let type_name_str = type_name.to_string();
 
     let definition = if let Some(error_type_name) = maybe_error_type_name {
@@ -58,10 +62,23 @@ pub fn gen_def_parse_error(
 
     cfg_if! {
         if #[cfg(feature = "std")] {
+            let parse_source = if is_inner_parse_error_std {
+                quote!(#parse_error_type_name::Parse(err) => Some(err),)
+            } else {
+                quote!(#parse_error_type_name::Parse(_) => None,)
+            };
+            let validate_source = if maybe_error_type_name.is_some() {
+                quote!(#parse_error_type_name::Validate(err) => Some(err),)
+            } else {
+                quote!()
+            };
             let impl_std_error = quote! {
                 impl ::std::error::Error for #parse_error_type_name {
                     fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
-                        None
+                        match self {
+                            #parse_source
+                            #validate_source
+                        }
                     }
                 }
             };
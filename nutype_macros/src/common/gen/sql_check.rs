@@ -0,0 +1,59 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::TypeName;
+
+/// Generate `::sql_check()`, which renders the validators as a condition of SQL `CHECK`
+/// constraint for the given column.
+/// Every item of `conditions` must evaluate to a `String` using `column: &str`.
+pub fn gen_impl_sql_check(
+    type_name: &TypeName,
+    generics: &Generics,
+    conditions: Vec<TokenStream>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Returns the condition of SQL `CHECK` constraint that corresponds to the validators,
+            /// e.g. `age >= 18 AND age <= 150` for column `age`.
+            pub fn sql_check(column: &str) -> ::std::string::String {
+                let conditions: &[::std::string::String] = &[#(#conditions),*];
+                conditions.join(" AND ")
+            }
+        }
+    )
+}
+
+/// Condition that compares the column with a value of the inner type, e.g. `age >= 18`.
+/// The value is rendered with `Debug`, so floats always look like floats (e.g. `1.0` or
+/// `3.4028235e38`).
+pub fn gen_sql_comparison(
+    op: &str,
+    value: impl ToTokens,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    quote!({
+        let value: #inner_type = #value;
+        ::std::format!("{} {} {:?}", column, #op, value)
+    })
+}
+
+/// Condition that checks that the column is within the inclusive range, e.g.
+/// `percent BETWEEN 0 AND 100`.
+pub fn gen_sql_between(
+    min: impl ToTokens,
+    max: impl ToTokens,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    quote!({
+        let min: #inner_type = #min;
+        let max: #inner_type = #max;
+        ::std::format!("{} BETWEEN {:?} AND {:?}", column, min, max)
+    })
+}
+
+pub fn unsupported_sql_validator(validator_name: &str) -> syn::Error {
+    let msg = format!("`sql_check` can not express `{validator_name}` validator in SQL.");
+    syn::Error::new(Span::call_site(), msg)
+}
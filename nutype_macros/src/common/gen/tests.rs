@@ -1,7 +1,8 @@
+use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::common::models::{NumericBound, TypeName};
+use crate::common::models::{AssociatedConst, NumericBound, TypeName};
 
 pub fn gen_test_should_have_consistent_lower_and_upper_boundaries<Validator>(
     type_name: &TypeName,
@@ -55,10 +56,85 @@ Note: the test is generated automatically by #[nutype] macro
     Some(quote!(
         #[test]
         fn should_have_valid_default_value() {
-            let default_inner_value = #type_name::default().into_inner();
-            // Typically `::default()` would already panic, but in case if the panic is removed by
-            // some reason we still want the test to fail.
-            #type_name::new(default_inner_value).expect(#msg);
+            // The default value is used by `Default` and `::new_or_default()`, and `Default`
+            // may be not derived, so the value is checked directly.
+            #type_name::new(#default_value).expect(#msg);
         }
     ))
 }
+
+pub fn gen_test_should_have_valid_consts(
+    type_name: &TypeName,
+    consts: &[AssociatedConst],
+    has_validation: bool,
+) -> Option<TokenStream> {
+    if !has_validation || consts.is_empty() {
+        return None;
+    }
+
+    let checks = consts.iter().map(|AssociatedConst { name, value }| {
+        let msg = format!(
+            "
+Type `{type_name}` has invalid constant `{name} = {value}`
+Note: the test is generated automatically by #[nutype] macro
+",
+            value = value.to_token_stream()
+        );
+        quote!(
+            #type_name::validate(&#type_name::#name.into_inner()).expect(#msg);
+        )
+    });
+
+    Some(quote!(
+        #[test]
+        fn should_have_valid_consts() {
+            #(#checks)*
+        }
+    ))
+}
+
+/// Generate a Kani proof harness, which checks that every value produced by `::new()` satisfies
+/// the validators (and that sanitizers and validators never panic).
+/// The harness is compiled only by `cargo kani`, so it's not a part of regular builds.
+#[allow(unused_variables)]
+pub fn gen_kani_proof_should_satisfy_validators(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    has_validation: bool,
+) -> Option<TokenStream> {
+    cfg_if! {
+        if #[cfg(feature = "kani")] {
+            if !has_validation {
+                return None;
+            }
+
+            let msg = format!(
+                "
+Type `{type_name}` was constructed with a value that violates its validators
+Note: the proof harness is generated automatically by #[nutype] macro
+"
+            );
+
+            // `cfg(kani)` is set only by `cargo kani`, so it's unknown to the regular builds
+            // of the user's crate.
+            Some(quote!(
+                #[allow(unexpected_cfgs)]
+                mod kani_proofs {
+                    #[cfg(kani)]
+                    use super::*;
+
+                    #[cfg(kani)]
+                    #[kani::proof]
+                    fn should_satisfy_validators() {
+                        let raw_value: #inner_type = ::kani::any();
+                        if let Ok(value) = #type_name::new(raw_value) {
+                            assert!(#type_name::is_valid(&value.into_inner()), #msg);
+                        }
+                    }
+                }
+            ))
+        } else {
+            None
+        }
+    }
+}
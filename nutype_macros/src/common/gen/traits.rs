@@ -1,11 +1,17 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, hash::Hash};
 
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse::Parser, parse_quote, punctuated::Punctuated, Generics};
 
-use crate::common::models::{ErrorTypeName, InnerType, TypeName};
+use crate::common::models::{
+    DebugMode, ErrorTypeName, InnerType, Sensitive, SerdeMode, TypeName, TypeTrait,
+};
 
-use super::parse_error::{gen_def_parse_error, gen_parse_error_name};
+use super::{
+    parse_error::{gen_def_parse_error, gen_parse_error_name},
+    InnerStorage,
+};
 
 /// Generated implementation of traits.
 pub struct GeneratedTraits {
@@ -51,7 +57,12 @@ where
     }
 }
 
-pub fn gen_impl_trait_into(type_name: &TypeName, inner_type: impl Into<InnerType>) -> TokenStream {
+pub fn gen_impl_trait_into(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl Into<InnerType>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let inner_type: InnerType = inner_type.into();
 
     // NOTE: We're getting blank implementation of
@@ -59,18 +70,23 @@ pub fn gen_impl_trait_into(type_name: &TypeName, inner_type: impl Into<InnerType
     // by implementing
     //     From<Type> for Inner
     quote! {
-        impl ::core::convert::From<#type_name> for #inner_type {
+        impl #impl_generics ::core::convert::From<#type_name #ty_generics> for #inner_type #where_clause {
             #[inline]
-            fn from(value: #type_name) -> Self {
+            fn from(value: #type_name #ty_generics) -> Self {
                 value.into_inner()
             }
         }
     }
 }
 
-pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_as_ref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::AsRef<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::AsRef<#inner_type> for #type_name #ty_generics #where_clause {
             #[inline]
             fn as_ref(&self) -> &#inner_type {
                 &self.0
@@ -79,9 +95,14 @@ pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) ->
     }
 }
 
-pub fn gen_impl_trait_deref(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_deref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::ops::Deref for #type_name {
+        impl #impl_generics ::core::ops::Deref for #type_name #ty_generics #where_clause {
             type Target = #inner_type;
 
             #[inline]
@@ -92,14 +113,17 @@ pub fn gen_impl_trait_deref(type_name: &TypeName, inner_type: impl ToTokens) ->
     }
 }
 
-pub fn gen_impl_trait_display(type_name: &TypeName) -> TokenStream {
+pub fn gen_impl_trait_display(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::fmt::Display for #type_name {
+        impl #impl_generics ::core::fmt::Display for #type_name #ty_generics #where_clause {
             #[inline]
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 // A tiny wrapper function with trait boundary that improves error reporting.
                 // It makes it clear for the end-user that the inner type has to implement Display
                 // in order to derive display for the newtype.
+                // The formatter is passed through, so flags like width, fill, alignment and
+                // precision are honored by the inner type.
                 #[inline]
                 fn display<T: ::core::fmt::Display>(f: &mut ::core::fmt::Formatter<'_>, val: &T) -> ::core::fmt::Result {
                     use ::core::fmt::Display;
@@ -111,9 +135,70 @@ pub fn gen_impl_trait_display(type_name: &TypeName) -> TokenStream {
     }
 }
 
-pub fn gen_impl_trait_borrow(type_name: &TypeName, borrowed_type: impl ToTokens) -> TokenStream {
+/// Implement `PartialEq<Inner>` for the newtype and the reverse `PartialEq<Newtype>` for the inner
+/// type, so the values can be compared without `into_inner()`.
+pub fn gen_impl_trait_partial_eq_inner(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    storage: &InnerStorage,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let value = storage.gen_load(quote!(self.0));
+    let other_value = storage.gen_load(quote!(other.0));
+    quote! {
+        impl #impl_generics ::core::cmp::PartialEq<#inner_type> for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn eq(&self, other: &#inner_type) -> bool {
+                #value == *other
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq<#type_name #ty_generics> for #inner_type #where_clause {
+            #[inline]
+            fn eq(&self, other: &#type_name #ty_generics) -> bool {
+                *self == #other_value
+            }
+        }
+    }
+}
+
+/// Implement `PartialOrd<Inner>` for the newtype and the reverse `PartialOrd<Newtype>` for the
+/// inner type. It relies on `PartialEq<Inner>`, which is required by `PartialOrd<Inner>`.
+pub fn gen_impl_trait_partial_ord_inner(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    storage: &InnerStorage,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let value = storage.gen_load(quote!(self.0));
+    let other_value = storage.gen_load(quote!(other.0));
+    quote! {
+        impl #impl_generics ::core::cmp::PartialOrd<#inner_type> for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn partial_cmp(&self, other: &#inner_type) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&#value, other)
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialOrd<#type_name #ty_generics> for #inner_type #where_clause {
+            #[inline]
+            fn partial_cmp(&self, other: &#type_name #ty_generics) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(self, &#other_value)
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_borrow(
+    type_name: &TypeName,
+    generics: &Generics,
+    borrowed_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::borrow::Borrow<#borrowed_type> for #type_name {
+        impl #impl_generics ::core::borrow::Borrow<#borrowed_type> for #type_name #ty_generics #where_clause {
             #[inline]
             fn borrow(&self) -> &#borrowed_type {
                 &self.0
@@ -122,9 +207,14 @@ pub fn gen_impl_trait_borrow(type_name: &TypeName, borrowed_type: impl ToTokens)
     }
 }
 
-pub fn gen_impl_trait_from(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_from(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::From<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::From<#inner_type> for #type_name #ty_generics #where_clause {
             #[inline]
             fn from(raw_value: #inner_type) -> Self {
                 Self::new(raw_value)
@@ -135,19 +225,21 @@ pub fn gen_impl_trait_from(type_name: &TypeName, inner_type: impl ToTokens) -> T
 
 pub fn gen_impl_trait_try_from(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl ToTokens,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     match maybe_error_type_name {
         Some(error_type_name) => {
             // The case when there are validation
             //
             quote! {
-                impl ::core::convert::TryFrom<#inner_type> for #type_name {
+                impl #impl_generics ::core::convert::TryFrom<#inner_type> for #type_name #ty_generics #where_clause {
                     type Error = #error_type_name;
 
                     #[inline]
-                    fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
+                    fn try_from(raw_value: #inner_type) -> Result<Self, Self::Error> {
                         Self::new(raw_value)
                     }
                 }
@@ -157,11 +249,13 @@ pub fn gen_impl_trait_try_from(
             // The case when there are no validation
             //
             quote! {
-                impl ::core::convert::TryFrom<#inner_type> for #type_name {
+                // Deriving `TryFrom` on a type without validation is explicitly requested by the user.
+                #[allow(clippy::infallible_try_from)]
+                impl #impl_generics ::core::convert::TryFrom<#inner_type> for #type_name #ty_generics #where_clause {
                     type Error = ::core::convert::Infallible;
 
                     #[inline]
-                    fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
+                    fn try_from(raw_value: #inner_type) -> Result<Self, Self::Error> {
                         Ok(Self::new(raw_value))
                     }
                 }
@@ -170,12 +264,414 @@ pub fn gen_impl_trait_try_from(
     }
 }
 
+/// Primitive types which `ToPrimitive` and `FromPrimitive` of `num_traits` have conversions for.
+pub const PRIMITIVE_TYPES: [&str; 14] = [
+    "isize", "i8", "i16", "i32", "i64", "i128", "usize", "u8", "u16", "u32", "u64", "u128", "f32",
+    "f64",
+];
+
+/// All the methods are forwarded to the inner type, so e.g. `to_f64()` of a float based type
+/// does not lose precision by going through the default `to_i64()`.
+pub fn gen_impl_trait_num_traits_to_primitive(
+    type_name: &TypeName,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let methods = PRIMITIVE_TYPES.iter().map(|primitive| {
+        let primitive = format_ident!("{primitive}");
+        let method = format_ident!("to_{primitive}");
+        quote! {
+            #[inline]
+            fn #method(&self) -> Option<#primitive> {
+                ::num_traits::ToPrimitive::#method(&self.0)
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::num_traits::ToPrimitive for #type_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+/// `FromPrimitive` returns `None` if the value can not be represented by the inner type or
+/// does not pass the validation.
+pub fn gen_impl_trait_num_traits_from_primitive(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_value_to_option = if maybe_error_type_name.is_some() {
+        quote!(.and_then(|inner_value| Self::new(inner_value).ok()))
+    } else {
+        quote!(.map(Self::new))
+    };
+    let methods = PRIMITIVE_TYPES.iter().map(|primitive| {
+        let primitive = format_ident!("{primitive}");
+        let method = format_ident!("from_{primitive}");
+        quote! {
+            #[inline]
+            fn #method(n: #primitive) -> Option<Self> {
+                <#inner_type as ::num_traits::FromPrimitive>::#method(n) #inner_value_to_option
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::num_traits::FromPrimitive for #type_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+/// `FromIterator` accepts the items of the inner collection (e.g. `T` of `Vec<T>`), the collected
+/// value goes through the sanitizers.
+pub fn gen_impl_trait_from_iterator(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let item_type = quote!(<#inner_type as ::core::iter::IntoIterator>::Item);
+    quote! {
+        impl #impl_generics ::core::iter::FromIterator<#item_type> for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #item_type>>(iter: I) -> Self {
+                Self::new(iter.into_iter().collect::<#inner_type>())
+            }
+        }
+    }
+}
+
+/// There is no fallible counterpart of `FromIterator` in the standard library, so
+/// `TryFromIterator` generates `::try_from_iter()` constructor instead.
+pub fn gen_impl_trait_try_from_iterator(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let item_type = quote!(<#inner_type as ::core::iter::IntoIterator>::Item);
+    let (error_type, construct) = match maybe_error_type_name {
+        Some(error_type_name) => (
+            quote!(#error_type_name),
+            quote!(Self::new(iter.into_iter().collect::<#inner_type>())),
+        ),
+        None => (
+            quote!(::core::convert::Infallible),
+            quote!(Ok(Self::new(iter.into_iter().collect::<#inner_type>()))),
+        ),
+    };
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Collects the items into the inner collection and constructs the value from it.
+            pub fn try_from_iter<I: ::core::iter::IntoIterator<Item = #item_type>>(
+                iter: I,
+            ) -> ::core::result::Result<Self, #error_type> {
+                #construct
+            }
+        }
+    }
+}
+
+/// `Sum` and `Product` fold the inner values. The result of a type with validation may be
+/// invalid, so instead of the traits `::try_sum()` / `::try_product()` are generated, which validate
+/// the result once at the end.
+pub fn gen_impl_trait_sum_or_product(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    fold: Fold,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (fold_trait, method, try_method) = match fold {
+        Fold::Sum => (quote!(::core::iter::Sum), quote!(sum), quote!(try_sum)),
+        Fold::Product => (
+            quote!(::core::iter::Product),
+            quote!(product),
+            quote!(try_product),
+        ),
+    };
+    let inner_value = storage.gen_load(quote!(value.0));
+    match maybe_error_type_name {
+        Some(error_type_name) => {
+            let doc =
+                format!(" Calculates the {method} of the inner values and validates the result.");
+            quote! {
+                impl #impl_generics #type_name #ty_generics #where_clause {
+                    #[doc = #doc]
+                    pub fn #try_method<I: ::core::iter::IntoIterator<Item = Self>>(
+                        iter: I,
+                    ) -> ::core::result::Result<Self, #error_type_name> {
+                        let raw_value: #inner_type = iter.into_iter().map(|value| #inner_value).#method();
+                        Self::new(raw_value)
+                    }
+                }
+            }
+        }
+        None => {
+            let mut generics_with_lifetime = generics.clone();
+            generics_with_lifetime
+                .params
+                .insert(0, parse_quote!('__nutype_a));
+            let (ref_impl_generics, _, _) = generics_with_lifetime.split_for_impl();
+            quote! {
+                impl #impl_generics #fold_trait for #type_name #ty_generics #where_clause {
+                    #[inline]
+                    fn #method<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                        Self::new(iter.map(|value| #inner_value).#method::<#inner_type>())
+                    }
+                }
+
+                impl #ref_impl_generics #fold_trait<&'__nutype_a Self> for #type_name #ty_generics #where_clause {
+                    #[inline]
+                    fn #method<I: ::core::iter::Iterator<Item = &'__nutype_a Self>>(iter: I) -> Self {
+                        Self::new(iter.map(|value| #inner_value).#method::<#inner_type>())
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Which of the folding traits to generate.
+pub enum Fold {
+    Sum,
+    Product,
+}
+
+/// Which of the arithmetic operator traits to generate.
+#[derive(Debug, Clone, Copy)]
+pub enum ArithmeticOperator {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl ArithmeticOperator {
+    pub fn method(&self) -> TokenStream {
+        match self {
+            Self::Add => quote!(add),
+            Self::Sub => quote!(sub),
+            Self::Mul => quote!(mul),
+        }
+    }
+
+    pub fn operator(&self) -> TokenStream {
+        match self {
+            Self::Add => quote!(+),
+            Self::Sub => quote!(-),
+            Self::Mul => quote!(*),
+        }
+    }
+}
+
+/// The right-hand side operand of `Add`, `Sub` or `Mul`.
+#[derive(Debug, Clone, Copy)]
+pub enum ArithmeticRhs {
+    /// The newtype itself, e.g. `impl Add for Age`.
+    Newtype,
+    /// The inner type, e.g. `impl Add<u8> for Age`.
+    Inner,
+}
+
+/// Implement `AddAssign`, `SubAssign` or `MulAssign` for a newtype without validation.
+/// `raw_value` computes the result from the inner values `lhs` and `rhs`, which is then passed
+/// through `::new()` and replaces the current value.
+pub fn gen_impl_trait_arithmetic_assign_operator(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    storage: &InnerStorage,
+    operator: ArithmeticOperator,
+    raw_value: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (operator_trait, method) = match operator {
+        ArithmeticOperator::Add => (quote!(::core::ops::AddAssign), quote!(add_assign)),
+        ArithmeticOperator::Sub => (quote!(::core::ops::SubAssign), quote!(sub_assign)),
+        ArithmeticOperator::Mul => (quote!(::core::ops::MulAssign), quote!(mul_assign)),
+    };
+    let lhs = storage.gen_load(quote!(self.0));
+    let rhs = storage.gen_load(quote!(rhs.0));
+
+    quote! {
+        impl #impl_generics #operator_trait for #type_name #ty_generics #where_clause {
+            #[allow(clippy::suspicious_op_assign_impl)]
+            fn #method(&mut self, rhs: Self) {
+                let (lhs, rhs): (#inner_type, #inner_type) = (#lhs, #rhs);
+                let raw_value: #inner_type = #raw_value;
+                *self = Self::new(raw_value);
+            }
+        }
+    }
+}
+
+/// Implement `Add`, `Sub` or `Mul` for the newtype, with either the newtype or the inner type
+/// on the right-hand side.
+/// `raw_value` computes the result from the inner values `lhs` and `rhs`, which is then passed
+/// through `::new()`. A result rejected by the validators leads to a panic.
+#[allow(clippy::too_many_arguments)]
+pub fn gen_impl_trait_arithmetic_operator(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    operator: ArithmeticOperator,
+    rhs_kind: ArithmeticRhs,
+    raw_value: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let method = operator.method();
+    let operator_trait = match operator {
+        ArithmeticOperator::Add => quote!(::core::ops::Add),
+        ArithmeticOperator::Sub => quote!(::core::ops::Sub),
+        ArithmeticOperator::Mul => quote!(::core::ops::Mul),
+    };
+    let lhs = storage.gen_load(quote!(self.0));
+    let (rhs_type, rhs) = match rhs_kind {
+        ArithmeticRhs::Newtype => (quote!(Self), storage.gen_load(quote!(rhs.0))),
+        ArithmeticRhs::Inner => (quote!(#inner_type), quote!(rhs)),
+    };
+    let operator_trait = match rhs_kind {
+        ArithmeticRhs::Newtype => operator_trait,
+        ArithmeticRhs::Inner => quote!(#operator_trait<#inner_type>),
+    };
+    let new_value = gen_new_or_panic(type_name, maybe_error_type_name);
+
+    quote! {
+        impl #impl_generics #operator_trait for #type_name #ty_generics #where_clause {
+            type Output = Self;
+
+            // Bounds like `greater = 0` turn into `0 + 1`, which clippy finds suspicious in `Sub` or `Mul`.
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn #method(self, rhs: #rhs_type) -> Self {
+                let (lhs, rhs): (#inner_type, #inner_type) = (#lhs, #rhs);
+                let raw_value: #inner_type = #raw_value;
+                #new_value
+            }
+        }
+    }
+}
+
+/// Implement `Neg` for the newtype.
+/// `raw_value` computes the result from the inner value `value`, which is then passed through
+/// `::new()`. A result rejected by the validators leads to a panic.
+pub fn gen_impl_trait_neg_operator(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    raw_value: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let value = storage.gen_load(quote!(self.0));
+    let new_value = gen_new_or_panic(type_name, maybe_error_type_name);
+
+    quote! {
+        impl #impl_generics ::core::ops::Neg for #type_name #ty_generics #where_clause {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                let value: #inner_type = #value;
+                let raw_value: #inner_type = #raw_value;
+                #new_value
+            }
+        }
+    }
+}
+
+fn gen_new_or_panic(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    if maybe_error_type_name.is_some() {
+        let tp = type_name.to_string();
+        quote!(
+            Self::new(raw_value).unwrap_or_else(|err| {
+                let tp = #tp;
+                panic!("\nArithmetic operation on `{tp}` produced an invalid value.\nERROR: {err:?}\n");
+            })
+        )
+    } else {
+        quote!(Self::new(raw_value))
+    }
+}
+
+/// Validated types must define what `Add`, `Sub` and `Mul` do with a result that is invalid.
+pub fn gen_missing_arithmetic_policy_error(type_name: &TypeName, trait_name: &str) -> syn::Error {
+    let msg = format!("Trait `{trait_name}` is derived for type {type_name} with validation, but `arithmetic = ` parameter is missing in #[nutype] macro.\nUse `arithmetic = panic`, `arithmetic = saturate` or `arithmetic = wrapping` (integer types only) to define what happens with an invalid result, or `checked_arithmetic` to get a `Result` instead.");
+    syn::Error::new(proc_macro2::Span::call_site(), msg)
+}
+
+/// Generate `TryFrom` in both directions between the newtype and every type specified with
+/// `convertible_with = `. The value is revalidated against the rules of the target type.
+///
+/// The other type is expected to be a newtype with the same inner type that implements
+/// `TryFrom<InnerType>` (e.g. derived with `derive(TryFrom)`).
+pub fn gen_impl_convertible_with(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    convertible_with: &[syn::Type],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (error_type, construct, maybe_allow_infallible) = match maybe_error_type_name {
+        Some(error_type_name) => (quote!(#error_type_name), quote!(Self::new(raw_value)), None),
+        None => (
+            quote!(::core::convert::Infallible),
+            quote!(Ok(Self::new(raw_value))),
+            Some(quote!(#[allow(clippy::infallible_try_from)])),
+        ),
+    };
+
+    convertible_with
+        .iter()
+        .map(|other_type| {
+            quote! {
+                #maybe_allow_infallible
+                impl #impl_generics ::core::convert::TryFrom<#other_type> for #type_name #ty_generics #where_clause {
+                    type Error = #error_type;
+
+                    #[inline]
+                    fn try_from(other: #other_type) -> Result<Self, Self::Error> {
+                        let raw_value: #inner_type = other.into_inner();
+                        #construct
+                    }
+                }
+
+                // The other type may have no validation, so the conversion can be infallible.
+                #[allow(clippy::infallible_try_from)]
+                impl #impl_generics ::core::convert::TryFrom<#type_name #ty_generics> for #other_type #where_clause {
+                    type Error = <#other_type as ::core::convert::TryFrom<#inner_type>>::Error;
+
+                    #[inline]
+                    fn try_from(value: #type_name #ty_generics) -> Result<Self, Self::Error> {
+                        <#other_type as ::core::convert::TryFrom<#inner_type>>::try_from(value.into_inner())
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
 /// Generate implementation of FromStr trait for non-string types (e.g. integers or floats).
 pub fn gen_impl_trait_from_str(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl Into<InnerType>,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let inner_type: InnerType = inner_type.into();
     let parse_error_type_name = gen_parse_error_name(type_name);
     let def_parse_error = gen_def_parse_error(
@@ -183,6 +679,7 @@ pub fn gen_impl_trait_from_str(
         type_name,
         maybe_error_type_name,
         &parse_error_type_name,
+        false,
     );
 
     if let Some(_error_type_name) = maybe_error_type_name {
@@ -190,7 +687,7 @@ pub fn gen_impl_trait_from_str(
         quote! {
             #def_parse_error
 
-            impl ::core::str::FromStr for #type_name {
+            impl #impl_generics ::core::str::FromStr for #type_name #ty_generics #where_clause {
                 type Err = #parse_error_type_name;
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
@@ -204,22 +701,61 @@ pub fn gen_impl_trait_from_str(
         quote! {
             #def_parse_error
 
-            impl ::core::str::FromStr for #type_name {
+            impl #impl_generics ::core::str::FromStr for #type_name #ty_generics #where_clause {
                 type Err = #parse_error_type_name;
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
                     let value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
-                    Ok(#type_name::new(value))
+                    Ok(Self::new(value))
                 }
             }
         }
     }
 }
 
-pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
+/// Generate implementation of FromStr trait, that delegates to `::parse()` constructor.
+/// Used by the types that always have `::parse()` (e.g. integers or floats).
+pub fn gen_impl_trait_from_str_via_parse(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_error_type_name = gen_parse_error_name(type_name);
+
+    quote! {
+        impl #impl_generics ::core::str::FromStr for #type_name #ty_generics #where_clause {
+            type Err = #parse_error_type_name;
+
+            #[inline]
+            fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::parse(raw_string)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `TryFrom<&str>` trait, that delegates to `::parse()` constructor.
+pub fn gen_impl_trait_try_from_str_via_parse(
+    type_name: &TypeName,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let parse_error_type_name = gen_parse_error_name(type_name);
+
+    quote! {
+        impl #impl_generics ::core::convert::TryFrom<&str> for #type_name #ty_generics #where_clause {
+            type Error = #parse_error_type_name;
+
+            #[inline]
+            fn try_from(raw_string: &str) -> ::core::result::Result<Self, Self::Error> {
+                Self::parse(raw_string)
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_serde_serialize(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let type_name_str = type_name.to_string();
     quote! {
-        impl ::serde::Serialize for #type_name {
+        impl #impl_generics ::serde::Serialize for #type_name #ty_generics #where_clause {
             fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
             where
                 S: ::serde::Serializer
@@ -232,10 +768,40 @@ pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
 
 pub fn gen_impl_trait_serde_deserialize(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl Into<InnerType>,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
+    let deserialize_raw_value = quote! {
+        <#inner_type as ::serde::Deserialize>::deserialize(deserializer)
+    };
+    gen_impl_trait_serde_deserialize_with(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        deserialize_raw_value,
+    )
+}
+
+/// Same as `gen_impl_trait_serde_deserialize`, but allows to customize how the inner value is
+/// deserialized. `deserialize_raw_value` must evaluate to `Result<InnerType, DE::Error>`
+/// using `deserializer: DE`.
+pub fn gen_impl_trait_serde_deserialize_with(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    deserialize_raw_value: TokenStream,
+) -> TokenStream {
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    // Both the impl block and the visitor need the `'de` lifetime along with the generic
+    // parameters of the type.
+    let mut generics_with_de = generics.clone();
+    generics_with_de.params.insert(0, parse_quote!('de));
+    let (de_impl_generics, de_ty_generics, _) = generics_with_de.split_for_impl();
+
     let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
         let type_name_str = type_name.to_string();
         quote! {
@@ -255,15 +821,15 @@ pub fn gen_impl_trait_serde_deserialize(
     let type_name_str = type_name.to_string();
 
     quote! {
-        impl<'de> ::serde::Deserialize<'de> for #type_name {
+        impl #de_impl_generics ::serde::Deserialize<'de> for #type_name #ty_generics #where_clause {
             fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                struct __Visitor<'de> {
-                    marker: ::std::marker::PhantomData<#type_name>,
+                struct __Visitor #de_impl_generics #where_clause {
+                    marker: ::std::marker::PhantomData<#type_name #ty_generics>,
                     lifetime: ::std::marker::PhantomData<&'de ()>,
                 }
 
-                impl<'de> ::serde::de::Visitor<'de> for __Visitor<'de> {
-                    type Value = #type_name;
+                impl #de_impl_generics ::serde::de::Visitor<'de> for __Visitor #de_ty_generics #where_clause {
+                    type Value = #type_name #ty_generics;
 
                     fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                         write!(formatter, #expecting_str)
@@ -273,7 +839,7 @@ pub fn gen_impl_trait_serde_deserialize(
                     where
                         DE: ::serde::Deserializer<'de>
                     {
-                        let raw_value: #inner_type = match <#inner_type as ::serde::Deserialize>::deserialize(deserializer) {
+                        let raw_value: #inner_type = match #deserialize_raw_value {
                             Ok(val) => val,
                             Err(err) => return Err(err)
                         };
@@ -294,15 +860,63 @@ pub fn gen_impl_trait_serde_deserialize(
     }
 }
 
+/// Generate implementation of `Serialize` for `serde(as_string)`: the value is serialized as the
+/// string produced by `Display` of the inner type.
+pub fn gen_impl_trait_serde_serialize_as_string(
+    type_name: &TypeName,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name_str = type_name.to_string();
+    quote! {
+        impl #impl_generics ::serde::Serialize for #type_name #ty_generics #where_clause {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer
+            {
+                let raw_string = ::std::string::ToString::to_string(&self.0);
+                serializer.serialize_newtype_struct(#type_name_str, &raw_string)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `Deserialize` for `serde(as_string)`: a string is deserialized,
+/// parsed into the inner type with `FromStr` and then sanitized and validated as usual.
+pub fn gen_impl_trait_serde_deserialize_as_string(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let deserialize_raw_value = quote! {
+        <::std::string::String as ::serde::Deserialize>::deserialize(deserializer).and_then(|raw_string| {
+            raw_string.parse::<#inner_type>().map_err(|err| {
+                let err_msg = format!("{err} Expected a string representation of {}", stringify!(#inner_type));
+                <DE::Error as ::serde::de::Error>::custom(err_msg)
+            })
+        })
+    };
+    gen_impl_trait_serde_deserialize_with(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        deserialize_raw_value,
+    )
+}
+
 pub fn gen_impl_trait_default(
     type_name: &TypeName,
+    generics: &Generics,
     default_value: impl ToTokens,
     has_validation: bool,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     if has_validation {
         let tp = type_name.to_string();
         quote!(
-            impl ::core::default::Default for #type_name {
+            impl #impl_generics ::core::default::Default for #type_name #ty_generics #where_clause {
                 fn default() -> Self {
                     Self::new(#default_value)
                         .unwrap_or_else(|err| {
@@ -314,7 +928,7 @@ pub fn gen_impl_trait_default(
         )
     } else {
         quote!(
-            impl ::core::default::Default for #type_name {
+            impl #impl_generics ::core::default::Default for #type_name #ty_generics #where_clause {
                 #[inline]
                 fn default() -> Self {
                     Self::new(#default_value)
@@ -323,3 +937,222 @@ pub fn gen_impl_trait_default(
         )
     }
 }
+
+/// Derived standard traits put bounds on every type parameter (e.g. `T: Clone`), however the type
+/// parameters are used only as a tag in the `PhantomData` field. So for those traits we generate
+/// implementations that delegate to the inner value and leave the rest of the traits derived.
+///
+/// Returns the remaining `#[derive(...)]` attribute and the generated implementations.
+pub fn gen_unbound_transparent_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    derive_transparent_traits: TokenStream,
+) -> Result<(TokenStream, TokenStream), syn::Error> {
+    if generics.type_params().next().is_none() {
+        return Ok((derive_transparent_traits, quote!()));
+    }
+    replace_derived_traits(derive_transparent_traits, |path| {
+        gen_unbound_impl(type_name, generics, path)
+    })
+}
+
+/// With `serde(as_string)` the derived `Serialize` and `Deserialize` go through the string
+/// representation of the inner type instead of the inner type itself.
+///
+/// Returns the remaining traits and the generated implementation.
+pub fn gen_serde_as_string<T: TypeTrait + Eq + Hash>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    traits: HashSet<T>,
+    maybe_serde_mode: Option<SerdeMode>,
+) -> (HashSet<T>, TokenStream) {
+    match maybe_serde_mode {
+        None => (traits, quote!()),
+        Some(SerdeMode::AsString) => {
+            let (serde_traits, traits): (HashSet<T>, HashSet<T>) = traits
+                .into_iter()
+                .partition(|t| t.is_serde_serialize() || t.is_serde_deserialize());
+            let implementation: TokenStream = serde_traits
+                .iter()
+                .map(|t| {
+                    if t.is_serde_serialize() {
+                        gen_impl_trait_serde_serialize_as_string(type_name, generics)
+                    } else {
+                        gen_impl_trait_serde_deserialize_as_string(
+                            type_name,
+                            generics,
+                            &inner_type,
+                            maybe_error_type_name,
+                        )
+                    }
+                })
+                .collect();
+            (traits, implementation)
+        }
+    }
+}
+
+/// For `sensitive` types `PartialEq` is implemented with constant-time comparison of the bytes,
+/// so comparing secrets (e.g. API tokens) does not leak them through timing side-channels.
+///
+/// Returns the remaining `#[derive(...)]` attribute and the generated implementation.
+pub fn gen_constant_time_eq(
+    type_name: &TypeName,
+    generics: &Generics,
+    sensitive: Sensitive,
+    derive_transparent_traits: TokenStream,
+) -> Result<(TokenStream, TokenStream), syn::Error> {
+    match sensitive {
+        Sensitive::Off => Ok((derive_transparent_traits, quote!())),
+        Sensitive::On => {
+            let (derive, implementation) =
+                replace_derived_traits(derive_transparent_traits, |path| {
+                    path.is_ident("PartialEq")
+                        .then(|| gen_impl_constant_time_eq(type_name, generics))
+                })?;
+            // The comparison is still byte-wise equality, so it agrees with the derived `Hash`.
+            let derive = quote! {
+                #[allow(clippy::derived_hash_with_manual_eq)]
+                #derive
+            };
+            Ok((derive, implementation))
+        }
+    }
+}
+
+/// With `debug = redacted` the derived `Debug` prints only the type name (e.g. `ApiKey(***)`),
+/// so the inner value does not end up in logs.
+///
+/// Returns the remaining `#[derive(...)]` attribute and the generated implementation.
+pub fn gen_redacted_debug(
+    type_name: &TypeName,
+    generics: &Generics,
+    maybe_debug_mode: Option<DebugMode>,
+    derive_transparent_traits: TokenStream,
+) -> Result<(TokenStream, TokenStream), syn::Error> {
+    match maybe_debug_mode {
+        None => Ok((derive_transparent_traits, quote!())),
+        Some(DebugMode::Redacted) => replace_derived_traits(derive_transparent_traits, |path| {
+            path.is_ident("Debug")
+                .then(|| gen_impl_redacted_debug(type_name, generics))
+        }),
+    }
+}
+
+fn gen_impl_redacted_debug(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name_str = type_name.to_string();
+    quote! {
+        impl #impl_generics ::core::fmt::Debug for #type_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(#type_name_str)
+                    .field(&::core::format_args!("***"))
+                    .finish()
+            }
+        }
+    }
+}
+
+fn gen_impl_constant_time_eq(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #type_name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                let this_bytes: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(&self.0);
+                let other_bytes: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(&other.0);
+                ::subtle::ConstantTimeEq::ct_eq(this_bytes, other_bytes).into()
+            }
+        }
+    }
+}
+
+/// Split `#[derive(...)]` attribute into the traits that remain derived and the implementations
+/// generated by `gen_impl` for the traits it takes over.
+fn replace_derived_traits(
+    derive_transparent_traits: TokenStream,
+    mut gen_impl: impl FnMut(&syn::Path) -> Option<TokenStream>,
+) -> Result<(TokenStream, TokenStream), syn::Error> {
+    let attrs = syn::Attribute::parse_outer.parse2(derive_transparent_traits)?;
+    let mut derived_paths: Vec<syn::Path> = Vec::new();
+    let mut implementations = TokenStream::new();
+    for attr in attrs {
+        let paths =
+            attr.parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)?;
+        for path in paths {
+            match gen_impl(&path) {
+                Some(implementation) => implementations.extend(implementation),
+                None => derived_paths.push(path),
+            }
+        }
+    }
+
+    let derive = quote! {
+        #[derive(#(#derived_paths,)*)]
+    };
+    Ok((derive, implementations))
+}
+
+fn gen_unbound_impl(
+    type_name: &TypeName,
+    generics: &Generics,
+    path: &syn::Path,
+) -> Option<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let trait_name = path.get_ident()?.to_string();
+    let implementation = match trait_name.as_str() {
+        "Debug" => quote! {
+            impl #impl_generics ::core::fmt::Debug for #type_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(stringify!(#type_name)).field(&self.0).finish()
+                }
+            }
+        },
+        "Clone" => quote! {
+            #[allow(clippy::non_canonical_clone_impl)]
+            impl #impl_generics ::core::clone::Clone for #type_name #ty_generics #where_clause {
+                fn clone(&self) -> Self {
+                    Self(::core::clone::Clone::clone(&self.0), ::core::marker::PhantomData)
+                }
+            }
+        },
+        "Copy" => quote! {
+            impl #impl_generics ::core::marker::Copy for #type_name #ty_generics #where_clause {}
+        },
+        "PartialEq" => quote! {
+            impl #impl_generics ::core::cmp::PartialEq for #type_name #ty_generics #where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+        },
+        "Eq" => quote! {
+            impl #impl_generics ::core::cmp::Eq for #type_name #ty_generics #where_clause {}
+        },
+        "PartialOrd" => quote! {
+            #[allow(clippy::non_canonical_partial_ord_impl)]
+            impl #impl_generics ::core::cmp::PartialOrd for #type_name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::cmp::PartialOrd::partial_cmp(&self.0, &other.0)
+                }
+            }
+        },
+        "Ord" => quote! {
+            impl #impl_generics ::core::cmp::Ord for #type_name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.0, &other.0)
+                }
+            }
+        },
+        "Hash" => quote! {
+            impl #impl_generics ::core::hash::Hash for #type_name #ty_generics #where_clause {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.0, state)
+                }
+            }
+        },
+        _ => return None,
+    };
+    Some(implementation)
+}
@@ -0,0 +1,46 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::TypeName;
+
+/// Generate `::zod_schema()`, which renders the sanitizers and validators as a Zod schema.
+/// `base_schema` is the schema of the inner type (e.g. `z.number().int()`), every item of
+/// `methods` must evaluate to a `String` with the chained method call (e.g. `.gte(18)`).
+pub fn gen_impl_zod_schema(
+    type_name: &TypeName,
+    generics: &Generics,
+    base_schema: &str,
+    methods: Vec<TokenStream>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Returns [Zod](https://zod.dev) schema that mirrors the sanitizers and validators,
+            /// e.g. `z.number().int().gte(18).lt(150)`, so they can be applied on the frontend
+            /// as well.
+            pub fn zod_schema() -> ::std::string::String {
+                let mut schema = ::std::string::String::from(#base_schema);
+                #(
+                    schema.push_str(&#methods);
+                )*
+                schema
+            }
+        }
+    )
+}
+
+/// Method call that takes a value of the inner type, e.g. `.gte(18)`.
+/// The value is rendered with `Debug`, so floats always look like floats (e.g. `1.0` or
+/// `3.4028235e38`).
+pub fn gen_zod_method(name: &str, value: impl ToTokens, inner_type: impl ToTokens) -> TokenStream {
+    quote!({
+        let value: #inner_type = #value;
+        ::std::format!(".{}({:?})", #name, value)
+    })
+}
+
+pub fn unsupported_zod_rule(rule_name: &str) -> syn::Error {
+    let msg = format!("`zod` can not express `{rule_name}` in Zod schema.");
+    syn::Error::new(Span::call_site(), msg)
+}
@@ -10,7 +10,7 @@ use syn::{
 
 use crate::{
     any::models::AnyInnerType, float::models::FloatInnerType, integer::models::IntegerInnerType,
-    string::models::StringInnerType,
+    range::models::RangeInnerType, string::models::StringInnerType, tuple::models::TupleInnerType,
 };
 
 use super::gen::type_custom_closure;
@@ -43,10 +43,13 @@ impl<T: Kinded> Kinded for SpannedItem<T> {
 
 /// Represents the inner type of a newtype.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum InnerType {
     String(StringInnerType),
     Integer(IntegerInnerType),
     Float(FloatInnerType),
+    Range(RangeInnerType),
+    Tuple(TupleInnerType),
     Any(AnyInnerType),
 }
 
@@ -80,6 +83,30 @@ impl From<StringInnerType> for InnerType {
     }
 }
 
+impl From<RangeInnerType> for InnerType {
+    fn from(range_inner_type: RangeInnerType) -> InnerType {
+        InnerType::Range(range_inner_type)
+    }
+}
+
+impl From<&RangeInnerType> for InnerType {
+    fn from(range_inner_type: &RangeInnerType) -> InnerType {
+        InnerType::Range(range_inner_type.clone())
+    }
+}
+
+impl From<TupleInnerType> for InnerType {
+    fn from(tuple_inner_type: TupleInnerType) -> InnerType {
+        InnerType::Tuple(tuple_inner_type)
+    }
+}
+
+impl From<&TupleInnerType> for InnerType {
+    fn from(tuple_inner_type: &TupleInnerType) -> InnerType {
+        InnerType::Tuple(tuple_inner_type.clone())
+    }
+}
+
 impl From<AnyInnerType> for InnerType {
     fn from(any_inner_type: AnyInnerType) -> InnerType {
         InnerType::Any(any_inner_type)
@@ -104,6 +131,12 @@ impl ToTokens for InnerType {
             InnerType::Float(float_type) => {
                 float_type.to_tokens(token_stream);
             }
+            InnerType::Range(range_type) => {
+                range_type.to_tokens(token_stream);
+            }
+            InnerType::Tuple(tuple_type) => {
+                tuple_type.to_tokens(token_stream);
+            }
             InnerType::Any(any_type) => {
                 any_type.to_tokens(token_stream);
             }
@@ -148,12 +181,38 @@ define_ident_type!(ErrorTypeName);
 // For example, if `TypeName` is `Amount`, then this would be `AmountParseError`.
 define_ident_type!(ParseErrorTypeName);
 
+// A type that represents an error name which is returned by `TryFrom<f64>` generated with
+// `from_float = `. For example, if `TypeName` is `Amount`, then this would be `AmountFromFloatError`.
+define_ident_type!(FromFloatErrorTypeName);
+
 // Module name, where the type is placed.
 define_ident_type!(ModuleName);
 
+// A borrowed companion of a String based newtype, requested with `borrowed = `.
+// For example, if `TypeName` is `Username`, then this could be `UsernameRef`, which wraps `str`.
+define_ident_type!(BorrowedTypeName);
+
+// A guard which gives mutable access to the inner value, requested with `mut_guard = `.
+// For example, if `TypeName` is `Tags`, then this would be `TagsMutGuard`.
+define_ident_type!(MutGuardTypeName);
+
+// An error returned by `::new_slice()`, requested with `new_slice`.
+// For example, if `TypeName` is `Sample`, then this would be `SampleSliceError`.
+define_ident_type!(SliceErrorTypeName);
+
+// An error returned by `checked_add()` and friends, requested with `checked_arithmetic`.
+// For example, if `TypeName` is `Amount`, then this would be `AmountArithmeticError`.
+define_ident_type!(ArithmeticErrorTypeName);
+
+// Name of the public constructor, if it's not `new`.
+// For example: `try_new` or `from_raw`.
+define_ident_type!(ConstructorName);
+
 #[derive(Debug)]
 pub struct Meta {
     pub type_name: TypeName,
+    pub generics: syn::Generics,
+    pub maybe_phantom_type: Option<syn::Type>,
     pub inner_type: InnerType,
     pub vis: syn::Visibility,
     pub doc_attrs: Vec<Attribute>,
@@ -164,12 +223,16 @@ impl Meta {
         let Self {
             doc_attrs,
             type_name,
+            generics,
+            maybe_phantom_type,
             inner_type,
             vis,
         } = self;
         let typed_meta = TypedMeta {
             doc_attrs,
             type_name,
+            generics,
+            maybe_phantom_type,
             attrs,
             vis,
         };
@@ -183,6 +246,12 @@ impl Meta {
 pub struct TypedMeta {
     pub type_name: TypeName,
 
+    /// Generic parameters of the type: const generics and type parameters used as a tag
+    pub generics: syn::Generics,
+
+    /// Type of the `PhantomData` field, if the type is tagged with a type parameter
+    pub maybe_phantom_type: Option<syn::Type>,
+
     /// Attributes given to #[nutype] macro
     pub attrs: TokenStream,
 
@@ -211,12 +280,71 @@ pub struct Attributes<G, DT> {
     /// `new_unchecked` flag
     pub new_unchecked: NewUnchecked,
 
+    /// `no_new` flag
+    pub no_new: NoNew,
+
+    /// Name of the public constructor instead of `new`. Provide with `try_new` flag or `constructor = `
+    pub maybe_constructor_name: Option<ConstructorName>,
+
+    /// `new_slice` flag
+    pub new_slice: NewSlice,
+
+    /// `checked_arithmetic` flag
+    pub checked_arithmetic: CheckedArithmetic,
+
+    /// `sql_check` flag
+    pub sql_check: SqlCheck,
+
+    /// `zod` flag
+    pub zod_schema: ZodSchema,
+
+    /// `sensitive` flag
+    pub sensitive: Sensitive,
+
     /// Value for Default trait. Provide with `default = `
     pub default: Option<syn::Expr>,
 
+    /// Other newtypes with the same inner type to convert from and into.
+    /// Provide with `convertible_with = `
+    pub convertible_with: Vec<syn::Type>,
+
+    /// Name of the borrowed companion type. Provide with `borrowed = `
+    pub maybe_borrowed_type_name: Option<BorrowedTypeName>,
+
+    /// Named associated constants. Provide with `consts(NAME = value, ...)`
+    pub consts: Vec<AssociatedConst>,
+
+    /// Callback invoked when the validation fails. Provide with `on_error = `
+    pub maybe_on_error: Option<CustomFunction>,
+
+    /// Rounding mode for `TryFrom<f64>` of integer based types. Provide with `from_float = `
+    pub maybe_from_float: Option<FromFloat>,
+
+    /// Visibility and module of the validation error type. Provide with `error(...)`
+    pub maybe_error_placement: Option<ErrorPlacement>,
+
+    /// Policy of the mutable guard of String and other inner types. Provide with `mut_guard = `
+    pub maybe_mut_guard: Option<MutGuardPolicy>,
+
+    /// Representation of the value in serde. Provide with `serde(...)`
+    pub maybe_serde_mode: Option<SerdeMode>,
+
+    /// Out-of-range policy of the derived arithmetic operators. Provide with `arithmetic = `
+    pub maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+
+    /// Output of the derived `Debug`. Provide with `debug = `
+    pub maybe_debug_mode: Option<DebugMode>,
+
     pub derive_traits: Vec<DT>,
 }
 
+/// Associated constant of the newtype, e.g. `ZERO = 0` within `consts(...)`.
+#[derive(Debug)]
+pub struct AssociatedConst {
+    pub name: syn::Ident,
+    pub value: syn::Expr,
+}
+
 /// Represents a value known at compile time or an expression.
 /// Knowing value at compile time allows to run some extra validations to prevent potential errors.
 #[derive(Debug)]
@@ -252,6 +380,13 @@ impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
             Self::WithoutValidation { .. } => None,
         }
     }
+
+    pub fn sanitizers(&self) -> &[Sanitizer] {
+        match self {
+            Self::WithValidation { sanitizers, .. } => sanitizers,
+            Self::WithoutValidation { sanitizers } => sanitizers,
+        }
+    }
 }
 
 /// Parsed by not yet validated
@@ -268,6 +403,10 @@ pub enum DeriveTrait {
     Clone,
     Copy,
     PartialEq,
+    /// `PartialEq<Inner>`, comparison with the inner type.
+    PartialEqInner,
+    /// `PartialOrd<Inner>`, comparison with the inner type.
+    PartialOrdInner,
     Eq,
     PartialOrd,
     Ord,
@@ -275,12 +414,35 @@ pub enum DeriveTrait {
     AsRef,
     From,
     TryFrom,
+    /// `TryFrom<&str>`, parsing of the inner value from a string.
+    TryFromStr,
     Into,
     Hash,
     Borrow,
     Display,
     Default,
     Deref,
+    LowerHex,
+    UpperHex,
+    Binary,
+    Octal,
+    FromIterator,
+    TryFromIterator,
+    Sum,
+    Product,
+    Add,
+    Sub,
+    Mul,
+    /// `Add<Inner>`, addition of the inner type.
+    AddInner,
+    /// `Sub<Inner>`, subtraction of the inner type.
+    SubInner,
+    /// `Mul<Inner>`, multiplication by the inner type.
+    MulInner,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
 
     // External crates
     //
@@ -294,6 +456,22 @@ pub enum DeriveTrait {
 
     #[cfg_attr(not(feature = "arbitrary"), allow(dead_code))]
     ArbitraryArbitrary,
+
+    #[cfg_attr(not(feature = "num_traits"), allow(dead_code))]
+    NumTraitsToPrimitive,
+    #[cfg_attr(not(feature = "num_traits"), allow(dead_code))]
+    NumTraitsFromPrimitive,
+    #[cfg_attr(not(feature = "num_traits"), allow(dead_code))]
+    NumTraitsBounded,
+    #[cfg_attr(not(feature = "num_traits"), allow(dead_code))]
+    NumTraitsZero,
+    #[cfg_attr(not(feature = "num_traits"), allow(dead_code))]
+    NumTraitsOne,
+
+    #[cfg_attr(not(feature = "ordered_float"), allow(dead_code))]
+    OrderedFloatNotNan,
+    #[cfg_attr(not(feature = "ordered_float"), allow(dead_code))]
+    OrderedFloatOrderedFloat,
 }
 
 pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
@@ -301,6 +479,12 @@ pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
 pub trait TypeTrait {
     // If this is FromStr variant?
     fn is_from_str(&self) -> bool;
+
+    // If this is Serialize variant?
+    fn is_serde_serialize(&self) -> bool;
+
+    // If this is Deserialize variant?
+    fn is_serde_deserialize(&self) -> bool;
 }
 
 /// The flag the indicates that a newtype will be generated with extra constructor,
@@ -316,15 +500,178 @@ pub enum NewUnchecked {
     On,
 }
 
+/// The flag that indicates that `::new()` (and `::new_or_return()`) must not be public, so the
+/// type can be constructed only through the derived traits (e.g. `TryFrom`, `FromStr`).
+#[derive(Debug, Default, Clone, Copy)]
+pub enum NoNew {
+    #[default]
+    Off,
+    On,
+}
+
+/// The flag that indicates that the newtype is `#[repr(transparent)]` and has `::new_slice()`,
+/// which validates a slice of the inner values and reinterprets it without copying.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum NewSlice {
+    #[default]
+    Off,
+    On,
+}
+
+/// The flag that indicates that the newtype has `checked_add()`, `checked_sub()` and
+/// `checked_mul()`, which revalidate the result of the operation.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum CheckedArithmetic {
+    #[default]
+    Off,
+    On,
+}
+
+/// The flag that indicates that the newtype has `::sql_check()`, which renders the validators
+/// as a condition of SQL `CHECK` constraint.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum SqlCheck {
+    #[default]
+    Off,
+    On,
+}
+
+/// The flag that indicates that the newtype has `::zod_schema()`, which renders the sanitizers
+/// and validators as a [Zod](https://zod.dev) schema.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum ZodSchema {
+    #[default]
+    Off,
+    On,
+}
+
+/// The flag that indicates that the newtype holds a secret (e.g. an API token),
+/// so `PartialEq` is implemented with constant-time comparison.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Sensitive {
+    #[default]
+    Off,
+
+    // `On` variant can be constructed when `subtle` feature flag is enabled.
+    #[allow(dead_code)]
+    On,
+}
+
+/// Rounding mode of `from_float = ` attribute, which generates `TryFrom<f64>` for integer based
+/// types. Values with a fractional part are rejected with `Exact`.
+#[derive(Debug, Clone, Copy)]
+pub enum FromFloat {
+    Floor,
+    Ceil,
+    Round,
+    Exact,
+}
+
+pub type SpannedFromFloat = SpannedItem<FromFloat>;
+
+/// What to do with a value mutated through the guard generated with `mut_guard = `, if it does
+/// not pass the validation anymore.
+#[derive(Debug, Clone, Copy)]
+pub enum MutGuardPolicy {
+    /// Restore the value the guard was created with.
+    Restore,
+    /// Restore the value the guard was created with and panic.
+    Panic,
+}
+
+pub type SpannedMutGuardPolicy = SpannedItem<MutGuardPolicy>;
+
+/// What the length of String based types is measured in, set with `len_unit = `.
+/// Applies to `len_char_min`, `len_char_max` and `truncate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LenUnit {
+    /// UTF-8 bytes.
+    Bytes,
+    /// Unicode scalar values (`char`).
+    #[default]
+    Chars,
+    /// Extended grapheme clusters (requires `unicode_segmentation` feature).
+    Graphemes,
+}
+
+pub type SpannedLenUnit = SpannedItem<LenUnit>;
+
+/// What the derived arithmetic operators (`Add`, `Sub`, `Mul`, `Neg` and `*Assign`) do with a
+/// result that overflows or does not pass the validation.
+/// Provide with `arithmetic = `.
+#[derive(Debug, Clone, Copy)]
+pub enum ArithmeticPolicy {
+    /// Panic.
+    Panic,
+    /// Clamp the result to the boundaries.
+    Saturate,
+    /// Wrap the result around the range defined by the boundaries.
+    Wrapping,
+}
+
+pub type SpannedArithmeticPolicy = SpannedItem<ArithmeticPolicy>;
+
+/// Representation of the value used by the derived `Serialize` and `Deserialize`.
+/// Provide with `serde(...)`.
+#[derive(Debug, Clone, Copy)]
+pub enum SerdeMode {
+    /// Serialize with `Display` and deserialize with `FromStr` of the inner type, so the value
+    /// always travels as a string.
+    AsString,
+}
+
+pub type SpannedSerdeMode = SpannedItem<SerdeMode>;
+
+/// Output of the derived `Debug`. Provide with `debug = `.
+#[derive(Debug, Clone, Copy)]
+pub enum DebugMode {
+    /// Print only the type name (e.g. `ApiKey(***)`), so the inner value never ends up in logs.
+    Redacted,
+}
+
+pub type SpannedDebugMode = SpannedItem<DebugMode>;
+
+/// Placement of the validation error type. Provide with `error(vis = ..., module = ...)`.
+#[derive(Debug, Clone)]
+pub struct ErrorPlacement {
+    /// Visibility of the error type, by default it's the same as the visibility of the newtype.
+    pub maybe_vis: Option<syn::Visibility>,
+
+    /// Submodule to re-export the error type from as `Error` (e.g. `age::Error`) instead of
+    /// re-exporting it next to the newtype.
+    pub maybe_module: Option<syn::Ident>,
+
+    pub span: Span,
+}
+
 pub struct GenerateParams<IT, Trait, Guard> {
     pub inner_type: IT,
     pub doc_attrs: Vec<Attribute>,
     pub traits: HashSet<Trait>,
     pub vis: syn::Visibility,
     pub type_name: TypeName,
+    pub generics: syn::Generics,
+    pub maybe_phantom_type: Option<syn::Type>,
     pub guard: Guard,
     pub new_unchecked: NewUnchecked,
+    pub no_new: NoNew,
+    pub maybe_constructor_name: Option<ConstructorName>,
+    pub new_slice: NewSlice,
+    pub checked_arithmetic: CheckedArithmetic,
+    pub sql_check: SqlCheck,
+    pub zod_schema: ZodSchema,
+    pub sensitive: Sensitive,
     pub maybe_default_value: Option<syn::Expr>,
+    pub convertible_with: Vec<syn::Type>,
+    pub maybe_borrowed_type_name: Option<BorrowedTypeName>,
+    pub consts: Vec<AssociatedConst>,
+    pub maybe_on_error: Option<CustomFunction>,
+    pub maybe_from_float: Option<FromFloat>,
+    pub maybe_error_placement: Option<ErrorPlacement>,
+    pub maybe_mut_guard: Option<MutGuardPolicy>,
+    pub maybe_serde_mode: Option<SerdeMode>,
+    pub maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    pub maybe_debug_mode: Option<DebugMode>,
 }
 
 pub trait Newtype {
@@ -359,24 +706,80 @@ pub trait Newtype {
         let TypedMeta {
             doc_attrs,
             type_name,
+            generics,
+            maybe_phantom_type,
             attrs,
             vis,
         } = typed_meta;
         let Attributes {
             guard,
             new_unchecked,
+            no_new,
+            maybe_constructor_name,
+            new_slice,
+            checked_arithmetic,
+            sql_check,
+            zod_schema,
+            sensitive,
             default: maybe_default_value,
+            convertible_with,
+            maybe_borrowed_type_name,
+            consts,
+            maybe_on_error,
+            maybe_from_float,
+            maybe_error_placement,
+            maybe_mut_guard,
+            maybe_serde_mode,
+            maybe_arithmetic_policy,
+            maybe_debug_mode,
             derive_traits,
         } = Self::parse_attributes(attrs)?;
+        if let (Some(on_error), false) = (&maybe_on_error, guard.has_validation()) {
+            let msg = "`on_error` callback requires validation to be defined, otherwise the type can not fail to be constructed.";
+            return Err(syn::Error::new_spanned(on_error, msg));
+        }
+        if let (Some(error_placement), false) = (&maybe_error_placement, guard.has_validation()) {
+            let msg = "`error` attribute requires validation to be defined, otherwise there is no error type.";
+            return Err(syn::Error::new(error_placement.span, msg));
+        }
+        if let (Some(constructor_name), false) = (&maybe_constructor_name, guard.has_validation()) {
+            if constructor_name.to_string() == "try_new" {
+                let msg = "`try_new` requires validation to be defined, otherwise the type can not fail to be constructed and `::new()` should be used.";
+                return Err(syn::Error::new_spanned(constructor_name, msg));
+            }
+        }
+        if let (SqlCheck::On, false) = (sql_check, guard.has_validation()) {
+            let msg = "`sql_check` requires validation to be defined, otherwise there is nothing to check.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
         let traits = Self::validate(&guard, derive_traits)?;
         let generated_output = Self::generate(GenerateParams {
             doc_attrs,
             traits,
             vis,
             type_name,
+            generics,
+            maybe_phantom_type,
             guard,
             new_unchecked,
+            no_new,
+            maybe_constructor_name,
+            new_slice,
+            checked_arithmetic,
+            sql_check,
+            zod_schema,
+            sensitive,
             maybe_default_value,
+            convertible_with,
+            maybe_borrowed_type_name,
+            consts,
+            maybe_on_error,
+            maybe_from_float,
+            maybe_error_placement,
+            maybe_mut_guard,
+            maybe_serde_mode,
+            maybe_arithmetic_policy,
+            maybe_debug_mode,
             inner_type,
         })?;
         Ok(generated_output)
@@ -1,6 +1,9 @@
 use cfg_if::cfg_if;
 use proc_macro2::Ident;
-use syn::parse::{Parse, ParseStream};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
 
 use crate::common::models::{DeriveTrait, SpannedDeriveTrait};
 
@@ -13,19 +16,58 @@ impl Parse for SpannedDeriveTrait {
             "Display" => DeriveTrait::Display,
             "Clone" => DeriveTrait::Clone,
             "Copy" => DeriveTrait::Copy,
+            "PartialEq" if input.peek(Token![<]) => {
+                parse_inner_type_param(input, &ident)?;
+                DeriveTrait::PartialEqInner
+            }
             "PartialEq" => DeriveTrait::PartialEq,
             "Eq" => DeriveTrait::Eq,
+            "PartialOrd" if input.peek(Token![<]) => {
+                parse_inner_type_param(input, &ident)?;
+                DeriveTrait::PartialOrdInner
+            }
             "PartialOrd" => DeriveTrait::PartialOrd,
             "Ord" => DeriveTrait::Ord,
             "FromStr" => DeriveTrait::FromStr,
             "AsRef" => DeriveTrait::AsRef,
             "Deref" => DeriveTrait::Deref,
+            "TryFrom" if input.peek(Token![<]) => {
+                parse_str_ref_type_param(input, &ident)?;
+                DeriveTrait::TryFromStr
+            }
             "TryFrom" => DeriveTrait::TryFrom,
             "From" => DeriveTrait::From,
             "Into" => DeriveTrait::Into,
             "Hash" => DeriveTrait::Hash,
             "Borrow" => DeriveTrait::Borrow,
             "Default" => DeriveTrait::Default,
+            "LowerHex" => DeriveTrait::LowerHex,
+            "UpperHex" => DeriveTrait::UpperHex,
+            "Binary" => DeriveTrait::Binary,
+            "Octal" => DeriveTrait::Octal,
+            "FromIterator" => DeriveTrait::FromIterator,
+            "TryFromIterator" => DeriveTrait::TryFromIterator,
+            "Sum" => DeriveTrait::Sum,
+            "Product" => DeriveTrait::Product,
+            "Add" if input.peek(Token![<]) => {
+                parse_inner_type_param(input, &ident)?;
+                DeriveTrait::AddInner
+            }
+            "Add" => DeriveTrait::Add,
+            "Sub" if input.peek(Token![<]) => {
+                parse_inner_type_param(input, &ident)?;
+                DeriveTrait::SubInner
+            }
+            "Sub" => DeriveTrait::Sub,
+            "Mul" if input.peek(Token![<]) => {
+                parse_inner_type_param(input, &ident)?;
+                DeriveTrait::MulInner
+            }
+            "Mul" => DeriveTrait::Mul,
+            "Neg" => DeriveTrait::Neg,
+            "AddAssign" => DeriveTrait::AddAssign,
+            "SubAssign" => DeriveTrait::SubAssign,
+            "MulAssign" => DeriveTrait::MulAssign,
             "Serialize" => {
                 cfg_if! {
                     if #[cfg(feature = "serde")] {
@@ -62,6 +104,69 @@ impl Parse for SpannedDeriveTrait {
                     }
                 }
             }
+            "ToPrimitive" => {
+                cfg_if! {
+                    if #[cfg(feature = "num_traits")] {
+                        DeriveTrait::NumTraitsToPrimitive
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ToPrimitive, the feature `num_traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromPrimitive" => {
+                cfg_if! {
+                    if #[cfg(feature = "num_traits")] {
+                        DeriveTrait::NumTraitsFromPrimitive
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromPrimitive, the feature `num_traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Bounded" => {
+                cfg_if! {
+                    if #[cfg(feature = "num_traits")] {
+                        DeriveTrait::NumTraitsBounded
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Bounded, the feature `num_traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Zero" => {
+                cfg_if! {
+                    if #[cfg(feature = "num_traits")] {
+                        DeriveTrait::NumTraitsZero
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Zero, the feature `num_traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "One" => {
+                cfg_if! {
+                    if #[cfg(feature = "num_traits")] {
+                        DeriveTrait::NumTraitsOne
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive One, the feature `num_traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "NotNan" => {
+                cfg_if! {
+                    if #[cfg(feature = "ordered_float")] {
+                        DeriveTrait::OrderedFloatNotNan
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive NotNan, the feature `ordered_float` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "OrderedFloat" => {
+                cfg_if! {
+                    if #[cfg(feature = "ordered_float")] {
+                        DeriveTrait::OrderedFloatOrderedFloat
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive OrderedFloat, the feature `ordered_float` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
             _ => {
                 return Err(syn::Error::new(
                     ident.span(),
@@ -76,3 +181,28 @@ impl Parse for SpannedDeriveTrait {
         Ok(spanned_trait)
     }
 }
+
+/// Parses `<Inner>` of `PartialEq<Inner>`, `PartialOrd<Inner>`, `Add<Inner>`, etc.
+fn parse_inner_type_param(input: ParseStream, trait_ident: &Ident) -> syn::Result<()> {
+    input.parse::<Token![<]>()?;
+    let inner: Ident = input.parse()?;
+    if inner != "Inner" {
+        let msg = format!("Unexpected `{trait_ident}<{inner}>`. Only `{trait_ident}<Inner>` is supported to compare with the inner type.");
+        return Err(syn::Error::new(inner.span(), msg));
+    }
+    input.parse::<Token![>]>()?;
+    Ok(())
+}
+
+/// Parses `<&str>` of `TryFrom<&str>`.
+fn parse_str_ref_type_param(input: ParseStream, trait_ident: &Ident) -> syn::Result<()> {
+    input.parse::<Token![<]>()?;
+    input.parse::<Token![&]>()?;
+    let str_ident: Ident = input.parse()?;
+    if str_ident != "str" {
+        let msg = format!("Unexpected `{trait_ident}<&{str_ident}>`. Only `{trait_ident}<&str>` is supported to parse from a string.");
+        return Err(syn::Error::new(str_ident.span(), msg));
+    }
+    input.parse::<Token![>]>()?;
+    Ok(())
+}
@@ -10,7 +10,10 @@ use crate::{
     },
     float::models::FloatInnerType,
     integer::models::IntegerInnerType,
+    range::models::{RangeInnerType, RangeKind},
     string::models::StringInnerType,
+    tuple::models::TupleInnerType,
+    utils::tokens::contains_ident,
 };
 
 pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
@@ -22,7 +25,7 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         data,
         vis,
         ident: type_name,
-        generics: _,
+        generics,
     } = input;
 
     let type_name = TypeName::new(type_name);
@@ -50,7 +53,8 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         }
     };
 
-    let seg = fields_unnamed.unnamed.iter().next().ok_or_else(|| {
+    let mut fields = fields_unnamed.unnamed.iter();
+    let seg = fields.next().ok_or_else(|| {
         let suggested_struct = quote::quote!(
             #vis struct #type_name(i32)
         )
@@ -65,8 +69,33 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
     })?;
     validate_inner_field_visibility(&seg.vis)?;
 
+    let maybe_phantom_type = match fields.next() {
+        Some(field) => {
+            validate_inner_field_visibility(&field.vis)?;
+            validate_phantom_field(field)?;
+            Some(field.ty.clone())
+        }
+        None => None,
+    };
+    if let Some(field) = fields.next() {
+        let msg = "#[nutype] accepts only the inner type and optionally a `PhantomData` field.";
+        return Err(syn::Error::new(field.span(), msg));
+    }
+    validate_generics(&generics, &seg.ty, maybe_phantom_type.as_ref())?;
+
     let type_path = match seg.ty.clone() {
         syn::Type::Path(tp) => tp,
+        syn::Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            let elems: Vec<syn::Type> = tuple.elems.into_iter().collect();
+            return Ok(Meta {
+                doc_attrs,
+                type_name,
+                generics,
+                maybe_phantom_type,
+                inner_type: InnerType::Tuple(TupleInnerType::new(seg.clone(), elems)),
+                vis,
+            });
+        }
         _ => {
             let error = syn::Error::new(
                 seg.span(),
@@ -76,10 +105,10 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         }
     };
 
-    let type_path_str = type_path.into_token_stream().to_string();
+    let type_path_str = type_path.to_token_stream().to_string();
 
     let inner_type = match type_path_str.as_ref() {
-        "String" => InnerType::String(StringInnerType),
+        "String" => InnerType::String(StringInnerType::String),
         "u8" => InnerType::Integer(IntegerInnerType::U8),
         "u16" => InnerType::Integer(IntegerInnerType::U16),
         "u32" => InnerType::Integer(IntegerInnerType::U32),
@@ -94,17 +123,74 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         "isize" => InnerType::Integer(IntegerInnerType::Isize),
         "f32" => InnerType::Float(FloatInnerType::F32),
         "f64" => InnerType::Float(FloatInnerType::F64),
-        _ => InnerType::Any(AnyInnerType::new(seg.clone())),
+        _ => match (
+            detect_range_kind(&type_path),
+            detect_str_container(&type_path_str),
+        ) {
+            (Some(kind), _) => InnerType::Range(RangeInnerType::new(kind, seg.clone())),
+            (None, Some(string_inner_type)) => InnerType::String(string_inner_type),
+            (None, None) => InnerType::Any(AnyInnerType::new(seg.clone())),
+        },
     };
 
     Ok(Meta {
         doc_attrs,
         type_name,
+        generics,
+        maybe_phantom_type,
         inner_type,
         vis,
     })
 }
 
+/// Detect `Range<T>` and `RangeInclusive<T>` from the standard library.
+/// Both the short names and the full paths (e.g. `std::ops::Range<T>`) are recognized.
+fn detect_range_kind(type_path: &syn::TypePath) -> Option<RangeKind> {
+    let segments: Vec<&syn::PathSegment> = type_path.path.segments.iter().collect();
+    let (last, prefix) = segments.split_last()?;
+
+    let prefix: Vec<String> = prefix.iter().map(|s| s.ident.to_string()).collect();
+    let is_std_path = prefix.is_empty() || prefix == ["std", "ops"] || prefix == ["core", "ops"];
+    if !is_std_path || type_path.qself.is_some() {
+        return None;
+    }
+
+    let has_single_type_argument = match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) => {
+            args.args.len() == 1 && matches!(args.args[0], syn::GenericArgument::Type(_))
+        }
+        _ => false,
+    };
+    if !has_single_type_argument {
+        return None;
+    }
+
+    match last.ident.to_string().as_ref() {
+        "Range" => Some(RangeKind::Range),
+        "RangeInclusive" => Some(RangeKind::RangeInclusive),
+        _ => None,
+    }
+}
+
+/// Detect `Box<str>`, `Arc<str>` and `Cow<'static, str>` from the standard library, which are
+/// handled as String based types.
+fn detect_str_container(type_path_str: &str) -> Option<StringInnerType> {
+    let type_path_str: String = type_path_str.split_whitespace().collect();
+    let type_path_str = type_path_str.strip_prefix("::").unwrap_or(&type_path_str);
+    match type_path_str {
+        "Box<str>" | "std::boxed::Box<str>" | "alloc::boxed::Box<str>" => {
+            Some(StringInnerType::BoxStr)
+        }
+        "Arc<str>" | "std::sync::Arc<str>" | "alloc::sync::Arc<str>" => {
+            Some(StringInnerType::ArcStr)
+        }
+        "Cow<'static,str>"
+        | "std::borrow::Cow<'static,str>"
+        | "alloc::borrow::Cow<'static,str>" => Some(StringInnerType::CowStr),
+        _ => None,
+    }
+}
+
 fn validate_supported_attrs(attrs: &[syn::Attribute]) -> Result<(), syn::Error> {
     fn is_supported_attr(attr: &syn::Attribute) -> bool {
         is_doc_attribute(attr) || is_derive_attribute(attr)
@@ -122,6 +208,62 @@ fn validate_supported_attrs(attrs: &[syn::Attribute]) -> Result<(), syn::Error>
     Ok(())
 }
 
+/// Const generics can be used in sanitizers and validators (e.g. `validate(less_or_equal = MAX)`).
+/// Type parameters can be used only as a tag in the `PhantomData` field
+/// (e.g. `struct Id<T>(u64, PhantomData<T>)`), so the inner type does not depend on them.
+fn validate_generics(
+    generics: &syn::Generics,
+    inner_type: &syn::Type,
+    maybe_phantom_type: Option<&syn::Type>,
+) -> Result<(), syn::Error> {
+    for param in &generics.params {
+        match param {
+            syn::GenericParam::Const(_) => {}
+            syn::GenericParam::Type(type_param) => {
+                let ident = &type_param.ident;
+                if maybe_phantom_type.is_none() {
+                    let msg = format!("Type parameter `{ident}` can be used only as a tag in a `PhantomData` field.\nTry something like this:\n\n    struct Id<{ident}>(u64, PhantomData<{ident}>);\n");
+                    return Err(syn::Error::new(param.span(), msg));
+                }
+                if contains_ident(inner_type.to_token_stream(), ident) {
+                    let msg = format!("The inner type can not depend on type parameter `{ident}`.\nType parameters can be used only as a tag in the `PhantomData` field.");
+                    return Err(syn::Error::new(inner_type.span(), msg));
+                }
+            }
+            syn::GenericParam::Lifetime(_) => {
+                let msg = "#[nutype] does not support lifetime parameters.";
+                return Err(syn::Error::new(param.span(), msg));
+            }
+        }
+    }
+
+    if let Some(phantom_type) = maybe_phantom_type {
+        if generics.type_params().next().is_none() {
+            let msg = "`PhantomData` field is needed only to tag the type with a type parameter.\nTry something like this:\n\n    struct Id<T>(u64, PhantomData<T>);\n";
+            return Err(syn::Error::new(phantom_type.span(), msg));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_phantom_field(field: &syn::Field) -> Result<(), syn::Error> {
+    let is_phantom_data = match &field.ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    };
+    if is_phantom_data {
+        Ok(())
+    } else {
+        let msg = "#[nutype] accepts only the inner type and optionally a `PhantomData` field.";
+        Err(syn::Error::new(field.ty.span(), msg))
+    }
+}
+
 fn validate_inner_field_visibility(vis: &Visibility) -> Result<(), syn::Error> {
     match vis {
         Visibility::Inherited => Ok(()),
@@ -13,9 +13,15 @@ use syn::{
     Expr, Lit, Token,
 };
 
-use crate::common::models::SpannedDeriveTrait;
+use crate::common::models::{DeriveTrait, SpannedDeriveTrait};
 
-use super::models::{CustomFunction, NewUnchecked, TypedCustomFunction, ValueOrExpr};
+use super::models::{
+    ArithmeticPolicy, AssociatedConst, BorrowedTypeName, CheckedArithmetic, ConstructorName,
+    CustomFunction, DebugMode, ErrorPlacement, FromFloat, LenUnit, MutGuardPolicy, NewSlice,
+    NewUnchecked, NoNew, Sensitive, SerdeMode, SpannedArithmeticPolicy, SpannedDebugMode,
+    SpannedFromFloat, SpannedLenUnit, SpannedMutGuardPolicy, SpannedSerdeMode, SqlCheck,
+    TypedCustomFunction, ValueOrExpr, ZodSchema,
+};
 
 pub fn is_doc_attribute(attribute: &syn::Attribute) -> bool {
     match attribute.path().segments.first() {
@@ -59,9 +65,63 @@ pub struct ParseableAttributes<Sanitizer, Validator> {
     /// Parsed from `new_unchecked` attribute
     pub new_unchecked: NewUnchecked,
 
-    /// Parsed from `new_unchecked` attribute
+    /// Parsed from `no_new` attribute
+    pub no_new: NoNew,
+
+    /// Parsed from `try_new` or `constructor = ` attribute
+    pub constructor: Option<ConstructorName>,
+
+    /// Parsed from `new_slice` attribute
+    pub new_slice: NewSlice,
+
+    /// Parsed from `checked_arithmetic` attribute
+    pub checked_arithmetic: CheckedArithmetic,
+
+    /// Parsed from `sql_check` attribute
+    pub sql_check: SqlCheck,
+
+    /// Parsed from `zod` attribute
+    pub zod_schema: ZodSchema,
+
+    /// Parsed from `sensitive` attribute
+    pub sensitive: Sensitive,
+
+    /// Parsed from `default = ` attribute
     pub default: Option<Expr>,
 
+    /// Parsed from `convertible_with = ` attribute (can be repeated)
+    pub convertible_with: Vec<syn::Type>,
+
+    /// Parsed from `borrowed = ` attribute
+    pub borrowed: Option<BorrowedTypeName>,
+
+    /// Parsed from `consts(...)` attribute
+    pub consts: Vec<AssociatedConst>,
+
+    /// Parsed from `on_error = ` attribute
+    pub on_error: Option<CustomFunction>,
+
+    /// Parsed from `from_float = ` attribute
+    pub from_float: Option<SpannedFromFloat>,
+
+    /// Parsed from `error(...)` attribute
+    pub error: Option<ErrorPlacement>,
+
+    /// Parsed from `mut_guard = ` attribute
+    pub mut_guard: Option<SpannedMutGuardPolicy>,
+
+    /// Parsed from `len_unit = ` attribute
+    pub len_unit: Option<SpannedLenUnit>,
+
+    /// Parsed from `serde(...)` attribute
+    pub serde: Option<SpannedSerdeMode>,
+
+    /// Parsed from `arithmetic = ` attribute
+    pub arithmetic: Option<SpannedArithmeticPolicy>,
+
+    /// Parsed from `debug = ` attribute
+    pub debug: Option<SpannedDebugMode>,
+
     /// Parsed from `derive(...)` attribute
     pub derive_traits: Vec<SpannedDeriveTrait>,
 }
@@ -73,7 +133,25 @@ impl<Sanitizer, Validator> Default for ParseableAttributes<Sanitizer, Validator>
             sanitizers: vec![],
             validators: vec![],
             new_unchecked: NewUnchecked::Off,
+            no_new: NoNew::Off,
+            constructor: None,
+            new_slice: NewSlice::Off,
+            checked_arithmetic: CheckedArithmetic::Off,
+            sql_check: SqlCheck::Off,
+            zod_schema: ZodSchema::Off,
+            sensitive: Sensitive::Off,
             default: None,
+            convertible_with: vec![],
+            borrowed: None,
+            consts: vec![],
+            on_error: None,
+            from_float: None,
+            error: None,
+            mut_guard: None,
+            len_unit: None,
+            serde: None,
+            arithmetic: None,
+            debug: None,
             derive_traits: vec![],
         }
     }
@@ -127,10 +205,174 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                     );
                     return Err(syn::Error::new(ident.span(), msg));
                 }
+            } else if ident == "consts" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(AssociatedConst::parse, Token![,])?;
+                    attrs.consts = items.into_iter().collect();
+                } else {
+                    let msg = concat!(
+                        "`consts` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    consts(ZERO = 0)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "error" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    attrs.error = Some(parse_error_placement(&content, ident.span())?);
+                } else {
+                    let msg = concat!(
+                        "`error` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    error(vis = pub(crate), module = age)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "serde" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let mode: Ident = content.parse()?;
+                    let serde_mode = match mode.to_string().as_str() {
+                        "as_string" => SerdeMode::AsString,
+                        _ => {
+                            let msg = format!(
+                                "Unknown serde mode `{mode}`.\nPossible values are `as_string`."
+                            );
+                            return Err(syn::Error::new(mode.span(), msg));
+                        }
+                    };
+                    attrs.serde = Some(SpannedSerdeMode::new(serde_mode, ident.span()));
+                } else {
+                    let msg = concat!(
+                        "`serde` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    serde(as_string)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
             } else if ident == "default" {
                 let _eq: Token![=] = input.parse()?;
                 let default_expr: Expr = input.parse()?;
                 attrs.default = Some(default_expr);
+            } else if ident == "convertible_with" {
+                let _eq: Token![=] = input.parse()?;
+                let other_type: syn::Type = input.parse()?;
+                attrs.convertible_with.push(other_type);
+            } else if ident == "borrowed" {
+                let _eq: Token![=] = input.parse()?;
+                let borrowed_type_name: Ident = input.parse()?;
+                attrs.borrowed = Some(BorrowedTypeName::new(borrowed_type_name));
+            } else if ident == "on_error" {
+                let _eq: Token![=] = input.parse()?;
+                let callback: CustomFunction = input.parse()?;
+                attrs.on_error = Some(callback);
+            } else if ident == "from_float" {
+                let _eq: Token![=] = input.parse()?;
+                let mode: Ident = input.parse()?;
+                let from_float = match mode.to_string().as_str() {
+                    "floor" => FromFloat::Floor,
+                    "ceil" => FromFloat::Ceil,
+                    "round" => FromFloat::Round,
+                    "exact" => FromFloat::Exact,
+                    _ => {
+                        let msg = format!("Unknown rounding mode `{mode}`.\nPossible values are `floor`, `ceil`, `round`, `exact`.");
+                        return Err(syn::Error::new(mode.span(), msg));
+                    }
+                };
+                attrs.from_float = Some(SpannedFromFloat::new(from_float, ident.span()));
+            } else if ident == "no_new" {
+                attrs.no_new = NoNew::On;
+            } else if ident == "try_new" || ident == "constructor" {
+                let constructor_name = if ident == "constructor" {
+                    let _eq: Token![=] = input.parse()?;
+                    input.parse()?
+                } else {
+                    ident.clone()
+                };
+                if attrs.constructor.is_some() {
+                    let msg = "The constructor name is already set. `try_new` and `constructor = ` can be used only once and not together.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                // `constructor = new` is the same as the default.
+                if constructor_name != "new" {
+                    attrs.constructor = Some(ConstructorName::new(constructor_name));
+                }
+            } else if ident == "new_slice" {
+                attrs.new_slice = NewSlice::On;
+            } else if ident == "checked_arithmetic" {
+                attrs.checked_arithmetic = CheckedArithmetic::On;
+            } else if ident == "sql_check" {
+                attrs.sql_check = SqlCheck::On;
+            } else if ident == "zod" {
+                attrs.zod_schema = ZodSchema::On;
+            } else if ident == "mut_guard" {
+                let _eq: Token![=] = input.parse()?;
+                let policy: Ident = input.parse()?;
+                let mut_guard = match policy.to_string().as_str() {
+                    "restore" => MutGuardPolicy::Restore,
+                    "panic" => MutGuardPolicy::Panic,
+                    _ => {
+                        let msg = format!("Unknown policy `{policy}`.\nPossible values are `restore` and `panic`.");
+                        return Err(syn::Error::new(policy.span(), msg));
+                    }
+                };
+                attrs.mut_guard = Some(SpannedMutGuardPolicy::new(mut_guard, ident.span()));
+            } else if ident == "len_unit" {
+                let _eq: Token![=] = input.parse()?;
+                let unit: Ident = input.parse()?;
+                let len_unit = match unit.to_string().as_str() {
+                    "bytes" => LenUnit::Bytes,
+                    "chars" => LenUnit::Chars,
+                    "graphemes" => LenUnit::Graphemes,
+                    _ => {
+                        let msg = format!("Unknown length unit `{unit}`.\nPossible values are `bytes`, `chars` and `graphemes`.");
+                        return Err(syn::Error::new(unit.span(), msg));
+                    }
+                };
+                attrs.len_unit = Some(SpannedLenUnit::new(len_unit, ident.span()));
+            } else if ident == "arithmetic" {
+                let _eq: Token![=] = input.parse()?;
+                let policy: Ident = input.parse()?;
+                let arithmetic = match policy.to_string().as_str() {
+                    "panic" => ArithmeticPolicy::Panic,
+                    "saturate" => ArithmeticPolicy::Saturate,
+                    "wrapping" => ArithmeticPolicy::Wrapping,
+                    _ => {
+                        let msg = format!("Unknown policy `{policy}`.\nPossible values are `panic`, `saturate` and `wrapping`.");
+                        return Err(syn::Error::new(policy.span(), msg));
+                    }
+                };
+                attrs.arithmetic = Some(SpannedArithmeticPolicy::new(arithmetic, ident.span()));
+            } else if ident == "debug" {
+                let _eq: Token![=] = input.parse()?;
+                let mode: Ident = input.parse()?;
+                let debug_mode = match mode.to_string().as_str() {
+                    "redacted" => DebugMode::Redacted,
+                    _ => {
+                        let msg = format!(
+                            "Unknown debug mode `{mode}`.\nPossible values are `redacted`."
+                        );
+                        return Err(syn::Error::new(mode.span(), msg));
+                    }
+                };
+                attrs.debug = Some(SpannedDebugMode::new(debug_mode, ident.span()));
+            } else if ident == "sensitive" {
+                cfg_if! {
+                    if #[cfg(feature = "subtle")] {
+                        attrs.sensitive = Sensitive::On;
+                    } else {
+                        let msg = concat!(
+                            "To compare `sensitive` types in constant time, the feature `subtle` of crate `nutype` needs to be enabled.\n",
+                            "The crate also has to explicitly include `subtle` dependency."
+                        );
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
             } else if ident == "new_unchecked" {
                 cfg_if! {
                     if #[cfg(feature = "new_unchecked")] {
@@ -155,10 +397,246 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
             }
         }
 
+        if let (NewSlice::On, false) = (attrs.new_slice, attrs.sanitizers.is_empty()) {
+            let msg = "`new_slice` can not be combined with sanitizers, because the elements are reinterpreted as they are, without being sanitized.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+
+        if let Some(serde) = &attrs.serde {
+            let derives_serde = attrs.derive_traits.iter().any(|t| {
+                matches!(
+                    t.item,
+                    DeriveTrait::SerdeSerialize | DeriveTrait::SerdeDeserialize
+                )
+            });
+            if !derives_serde {
+                let msg = "`serde` attribute requires `Serialize` or `Deserialize` to be derived.";
+                return Err(syn::Error::new(serde.span(), msg));
+            }
+        }
+
+        if let Some(arithmetic) = &attrs.arithmetic {
+            let derives_arithmetic = attrs.derive_traits.iter().any(|t| {
+                matches!(
+                    t.item,
+                    DeriveTrait::Add
+                        | DeriveTrait::Sub
+                        | DeriveTrait::Mul
+                        | DeriveTrait::AddInner
+                        | DeriveTrait::SubInner
+                        | DeriveTrait::MulInner
+                        | DeriveTrait::Neg
+                        | DeriveTrait::AddAssign
+                        | DeriveTrait::SubAssign
+                        | DeriveTrait::MulAssign
+                )
+            });
+            if !derives_arithmetic {
+                let msg =
+                    "`arithmetic` attribute requires `Add`, `Sub`, `Mul`, `Neg`, `AddAssign`, `SubAssign` or `MulAssign` to be derived.";
+                return Err(syn::Error::new(arithmetic.span(), msg));
+            }
+        }
+
+        let derives =
+            |derive_trait: DeriveTrait| attrs.derive_traits.iter().find(|t| t.item == derive_trait);
+        if let Some(debug) = &attrs.debug {
+            if derives(DeriveTrait::Debug).is_none() {
+                let msg = "`debug` attribute requires `Debug` to be derived.";
+                return Err(syn::Error::new(debug.span(), msg));
+            }
+        }
+        if let Some(partial_ord_inner) = derives(DeriveTrait::PartialOrdInner) {
+            if derives(DeriveTrait::PartialEqInner).is_none() {
+                let msg = "Trait `PartialOrd<Inner>` requires `PartialEq<Inner>`.";
+                return Err(syn::Error::new(partial_ord_inner.span, msg));
+            }
+        }
+
         Ok(attrs)
     }
 }
 
+impl Parse for AssociatedConst {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let value: Expr = input.parse()?;
+        Ok(AssociatedConst { name, value })
+    }
+}
+
+/// Parse the content of `error(...)` attribute, e.g. `vis = pub(crate), module = age`.
+fn parse_error_placement(input: ParseStream, span: Span) -> syn::Result<ErrorPlacement> {
+    let mut placement = ErrorPlacement {
+        maybe_vis: None,
+        maybe_module: None,
+        span,
+    };
+
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        if ident == "vis" {
+            placement.maybe_vis = Some(input.parse()?);
+        } else if ident == "module" {
+            placement.maybe_module = Some(input.parse()?);
+        } else {
+            let msg = format!("Unknown option `{ident}` of `error` attribute.\nPossible options are `vis` and `module`.");
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+
+        if !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+        }
+    }
+
+    Ok(placement)
+}
+
+/// `borrowed = ` attribute is supported only by String based types.
+pub fn reject_borrowed(borrowed: Option<BorrowedTypeName>) -> Result<(), syn::Error> {
+    match borrowed {
+        None => Ok(()),
+        Some(borrowed_type_name) => {
+            let msg = "`borrowed` attribute is supported only by String based types.";
+            Err(syn::Error::new_spanned(borrowed_type_name, msg))
+        }
+    }
+}
+
+/// `from_float = ` attribute is supported only by integer based types.
+pub fn reject_from_float(from_float: Option<SpannedFromFloat>) -> Result<(), syn::Error> {
+    match from_float {
+        None => Ok(()),
+        Some(from_float) => {
+            let msg = "`from_float` attribute is supported only by integer based types.";
+            Err(syn::Error::new(from_float.span(), msg))
+        }
+    }
+}
+
+/// `mut_guard = ` attribute is supported only by String based types and other inner types
+/// (e.g. collections).
+pub fn reject_mut_guard(mut_guard: Option<SpannedMutGuardPolicy>) -> Result<(), syn::Error> {
+    match mut_guard {
+        None => Ok(()),
+        Some(mut_guard) => {
+            let msg = "`mut_guard` attribute is supported only by String based types and other inner types (e.g. `Vec<T>`).";
+            Err(syn::Error::new(mut_guard.span(), msg))
+        }
+    }
+}
+
+/// `len_unit = ` attribute is supported only by String based types.
+pub fn reject_len_unit(len_unit: Option<SpannedLenUnit>) -> Result<(), syn::Error> {
+    match len_unit {
+        None => Ok(()),
+        Some(len_unit) => {
+            let msg = "`len_unit` attribute is supported only by String based types.";
+            Err(syn::Error::new(len_unit.span(), msg))
+        }
+    }
+}
+
+/// Integer and float types always get `::parse()`, which parses a string, so the constructor can
+/// not take that name.
+pub fn reject_parse_constructor_name(
+    maybe_constructor_name: Option<&ConstructorName>,
+) -> Result<(), syn::Error> {
+    match maybe_constructor_name {
+        Some(constructor_name) if constructor_name.to_string() == "parse" => {
+            let msg = concat!(
+                "`constructor = parse` conflicts with `::parse()`, which parses a string into integer and float types.\n",
+                "Pick another name for the constructor, e.g. `constructor = from_raw`."
+            );
+            Err(syn::Error::new_spanned(constructor_name, msg))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// `serde(as_string)` relies on `Display` and `FromStr` of the inner type, which are not
+/// implemented by tuples and ranges.
+pub fn reject_serde_mode(serde: Option<SpannedSerdeMode>) -> Result<(), syn::Error> {
+    match serde {
+        None => Ok(()),
+        Some(serde) => {
+            let msg = "`serde` attribute is not supported by tuple and range based types, because they do not implement `Display` and `FromStr`.";
+            Err(syn::Error::new(serde.span(), msg))
+        }
+    }
+}
+
+/// `new_slice` attribute is supported only by integer and float based types.
+pub fn reject_new_slice(new_slice: NewSlice) -> Result<(), syn::Error> {
+    match new_slice {
+        NewSlice::Off => Ok(()),
+        NewSlice::On => {
+            let msg = "`new_slice` attribute is supported only by integer and float based types.";
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
+
+/// `checked_arithmetic` attribute is supported only by integer and float based types.
+pub fn reject_checked_arithmetic(checked_arithmetic: CheckedArithmetic) -> Result<(), syn::Error> {
+    match checked_arithmetic {
+        CheckedArithmetic::Off => Ok(()),
+        CheckedArithmetic::On => {
+            let msg =
+                "`checked_arithmetic` attribute is supported only by integer and float based types.";
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
+
+/// `arithmetic = ` attribute is supported only by integer and float based types.
+pub fn reject_arithmetic(arithmetic: Option<SpannedArithmeticPolicy>) -> Result<(), syn::Error> {
+    match arithmetic {
+        None => Ok(()),
+        Some(arithmetic) => {
+            let msg = "`arithmetic` attribute is supported only by integer and float based types.";
+            Err(syn::Error::new(arithmetic.span(), msg))
+        }
+    }
+}
+
+/// `sql_check` attribute is supported only by integer, float and String based types.
+pub fn reject_sql_check(sql_check: SqlCheck) -> Result<(), syn::Error> {
+    match sql_check {
+        SqlCheck::Off => Ok(()),
+        SqlCheck::On => {
+            let msg =
+                "`sql_check` attribute is supported only by integer, float and String based types.";
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
+
+/// `zod` attribute is supported only by integer, float and String based types.
+pub fn reject_zod_schema(zod_schema: ZodSchema) -> Result<(), syn::Error> {
+    match zod_schema {
+        ZodSchema::Off => Ok(()),
+        ZodSchema::On => {
+            let msg = "`zod` attribute is supported only by integer, float and String based types.";
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
+
+/// `sensitive` attribute is supported only by String based types and other types which
+/// can be viewed as bytes (e.g. `Vec<u8>`).
+pub fn reject_sensitive(sensitive: Sensitive) -> Result<(), syn::Error> {
+    match sensitive {
+        Sensitive::Off => Ok(()),
+        Sensitive::On => {
+            let msg = "`sensitive` attribute is supported only by String based types and types that implement `AsRef<[u8]>` (e.g. `Vec<u8>`).";
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
+
 pub fn parse_number<T>(input: ParseStream) -> syn::Result<(T, Span)>
 where
     T: FromStr,
@@ -189,6 +667,27 @@ where
     Ok((number, lit.span()))
 }
 
+/// Get a number from an expression if it's a number literal (e.g. `42` or `-0.5`).
+pub fn parse_number_from_expr<T: FromStr>(expr: &Expr) -> Option<T> {
+    let (sign, expr) = match expr {
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => ("-", expr.as_ref()),
+        _ => ("", expr),
+    };
+    let Expr::Lit(syn::ExprLit { lit, .. }) = expr else {
+        return None;
+    };
+    let digits = match lit {
+        Lit::Int(lit_int) => lit_int.base10_digits(),
+        Lit::Float(lit_float) => lit_float.base10_digits(),
+        _ => return None,
+    };
+    format!("{sign}{digits}").parse().ok()
+}
+
 /// Try to parse input as a number of type T (if the value specified directly)
 /// If that fails then try to parse it as an expression (if the value is specified as an expression, a constant, etc.)
 pub fn parse_number_or_expr<T>(input: ParseStream) -> syn::Result<(ValueOrExpr<T>, Span)>
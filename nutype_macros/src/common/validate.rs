@@ -1,7 +1,14 @@
 use kinded::Kinded;
 use proc_macro2::Span;
 
-use super::models::{NumericBoundValidator, SpannedItem};
+use std::str::FromStr;
+
+use syn::Expr;
+
+use super::{
+    models::{AssociatedConst, NumericBoundValidator, SpannedItem},
+    parse::parse_number_from_expr,
+};
 
 pub fn validate_duplicates<T>(
     items: &[SpannedItem<T>],
@@ -102,3 +109,81 @@ where
 
     Ok(())
 }
+
+/// Associated constants bypass `::new()`, so the ones given as number literals are checked against
+/// the numeric bounds at compile time. The other ones are covered by the generated test.
+pub fn validate_consts_numeric_bounds<V, T>(
+    consts: &[AssociatedConst],
+    validators: &[V],
+) -> Result<(), syn::Error>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + FromStr,
+{
+    for associated_const in consts {
+        let Some(value) = parse_number_from_expr::<T>(&associated_const.value) else {
+            continue;
+        };
+        if let Some(violated) = find_violated_numeric_bound(&value, validators) {
+            let name = &associated_const.name;
+            let msg = format!("Constant `{name}` violates `{violated}` validator.");
+            return Err(syn::Error::new_spanned(&associated_const.value, msg));
+        }
+    }
+    Ok(())
+}
+
+/// The default value goes through `::new()`, so unlike the associated constants it's sanitized first.
+/// Without sanitizers a default value given as a number literal is checked against the numeric
+/// bounds at compile time. The other ones are covered by the generated test.
+pub fn validate_default_numeric_bounds<V, T>(
+    maybe_default_value: Option<&Expr>,
+    validators: &[V],
+) -> Result<(), syn::Error>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + FromStr,
+{
+    let Some(default_value) = maybe_default_value else {
+        return Ok(());
+    };
+    let Some(value) = parse_number_from_expr::<T>(default_value) else {
+        return Ok(());
+    };
+    if let Some(violated) = find_violated_numeric_bound(&value, validators) {
+        let msg = format!("Default value violates `{violated}` validator.");
+        return Err(syn::Error::new_spanned(default_value, msg));
+    }
+    Ok(())
+}
+
+/// Returns the name of the first bound validator the value does not satisfy.
+fn find_violated_numeric_bound<V, T>(value: &T, validators: &[V]) -> Option<&'static str>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd,
+{
+    validators.iter().find_map(|validator| {
+        [
+            validator
+                .greater()
+                .filter(|bound| value <= bound)
+                .map(|_| "greater"),
+            validator
+                .greater_or_equal()
+                .filter(|bound| value < bound)
+                .map(|_| "greater_or_equal"),
+            validator
+                .less()
+                .filter(|bound| value >= bound)
+                .map(|_| "less"),
+            validator
+                .less_or_equal()
+                .filter(|bound| value > bound)
+                .map(|_| "less_or_equal"),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
+    })
+}
@@ -1,8 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
+    gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
     models::{ErrorTypeName, TypeName},
 };
 
@@ -10,11 +11,13 @@ use super::super::models::FloatValidator;
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     validators: &[FloatValidator<T>],
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
     let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -51,6 +54,18 @@ fn gen_definition<T>(
             FloatValidator::Finite => {
                 quote!(FiniteViolated,)
             }
+            FloatValidator::Latitude => {
+                quote!(LatitudeViolated,)
+            }
+            FloatValidator::Longitude => {
+                quote!(LongitudeViolated,)
+            }
+            FloatValidator::Percentage => {
+                quote!(PercentageViolated,)
+            }
+            FloatValidator::Ratio => {
+                quote!(RatioViolated,)
+            }
         })
         .collect();
 
@@ -64,28 +79,53 @@ fn gen_definition<T>(
 
 fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     error_type_name: &ErrorTypeName,
     validators: &[FloatValidator<T>],
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
-        FloatValidator::Greater(val) => quote! {
-             #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::GreaterOrEqual(val) => quote! {
-             #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::LessOrEqual(val) => quote! {
-             #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::Less(val) => quote! {
-             #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
-        },
+        FloatValidator::Greater(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        FloatValidator::GreaterOrEqual(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        FloatValidator::LessOrEqual(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        FloatValidator::Less(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+            }
+        }
         FloatValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
         FloatValidator::Finite => quote! {
              #error_type_name::FiniteViolated => write!(f, "{} is not finite.", stringify!(#type_name))
         },
+        FloatValidator::Latitude => quote! {
+             #error_type_name::LatitudeViolated => write!(f, "{} is not a valid latitude. The value must be between -90.0 and 90.0.", stringify!(#type_name))
+        },
+        FloatValidator::Longitude => quote! {
+             #error_type_name::LongitudeViolated => write!(f, "{} is not a valid longitude. The value must be between -180.0 and 180.0.", stringify!(#type_name))
+        },
+        FloatValidator::Percentage => quote! {
+             #error_type_name::PercentageViolated => write!(f, "{} is not a valid percentage. The value must be between 0.0 and 100.0.", stringify!(#type_name))
+        },
+        FloatValidator::Ratio => quote! {
+             #error_type_name::RatioViolated => write!(f, "{} is not a valid ratio. The value must be between 0.0 and 1.0.", stringify!(#type_name))
+        },
     });
 
     quote! {
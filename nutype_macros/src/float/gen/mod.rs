@@ -1,12 +1,15 @@
 pub mod error;
+mod sql_check;
 pub mod traits;
+mod zod;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
-use self::error::gen_validation_error_type;
+use self::{error::gen_validation_error_type, sql_check::gen_sql_conditions, zod::gen_zod_methods};
 use super::{
     models::{FloatDeriveTrait, FloatGuard, FloatSanitizer, FloatType, FloatValidator},
     FloatNewtype,
@@ -15,14 +18,17 @@ use crate::{
     common::{
         gen::{
             error::gen_error_type_name,
+            sql_check::gen_impl_sql_check,
             tests::{
+                gen_kani_proof_should_satisfy_validators,
                 gen_test_should_have_consistent_lower_and_upper_boundaries,
                 gen_test_should_have_valid_default_value,
             },
             traits::GeneratedTraits,
+            zod::gen_impl_zod_schema,
             GenerateNewtype,
         },
-        models::{ErrorTypeName, Guard, TypeName},
+        models::{ArithmeticPolicy, ErrorTypeName, Guard, TypeName, ValueOrExpr},
     },
     float::models::FloatInnerType,
 };
@@ -37,6 +43,8 @@ where
     type InnerType = FloatInnerType;
     type TypedTrait = FloatDeriveTrait;
 
+    const HAS_PARSE_CONSTRUCTOR: bool = true;
+
     fn gen_fn_sanitize(
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
@@ -49,6 +57,20 @@ where
                         value = (#custom_sanitizer)(value);
                     )
                 }
+                FloatSanitizer::Clamp { min, max } => {
+                    quote!(
+                        value = value.clamp(#min, #max);
+                    )
+                }
+                FloatSanitizer::Round(decimal_places) => {
+                    gen_round_to_decimal_places(inner_type, *decimal_places, quote!(round))
+                }
+                FloatSanitizer::Floor(decimal_places) => {
+                    gen_round_to_decimal_places(inner_type, *decimal_places, quote!(floor))
+                }
+                FloatSanitizer::Ceil(decimal_places) => {
+                    gen_round_to_decimal_places(inner_type, *decimal_places, quote!(ceil))
+                }
                 FloatSanitizer::_Phantom(_) => {
                     unreachable!("float::gen FloatSanitizer::_Phantom must not be used")
                 }
@@ -75,28 +97,28 @@ where
             .map(|validator| match validator {
                 FloatValidator::Less(exclusive_upper_bound) => {
                     quote!(
-                        if val >= #exclusive_upper_bound {
+                        if val.is_nan() || val >= #exclusive_upper_bound {
                             return Err(#error_name::LessViolated);
                         }
                     )
                 }
                 FloatValidator::LessOrEqual(max) => {
                     quote!(
-                        if val > #max {
+                        if val.is_nan() || val > #max {
                             return Err(#error_name::LessOrEqualViolated);
                         }
                     )
                 }
                 FloatValidator::Greater(exclusive_lower_bound) => {
                     quote!(
-                        if val <= #exclusive_lower_bound {
+                        if val.is_nan() || val <= #exclusive_lower_bound {
                             return Err(#error_name::GreaterViolated);
                         }
                     )
                 }
                 FloatValidator::GreaterOrEqual(min) => {
                     quote!(
-                        if val < #min {
+                        if val.is_nan() || val < #min {
                             return Err(#error_name::GreaterOrEqualViolated);
                         }
                     )
@@ -115,6 +137,34 @@ where
                         }
                     )
                 }
+                FloatValidator::Latitude => {
+                    quote!(
+                        if !(-90.0..=90.0).contains(&val) {
+                            return Err(#error_name::LatitudeViolated);
+                        }
+                    )
+                }
+                FloatValidator::Longitude => {
+                    quote!(
+                        if !(-180.0..=180.0).contains(&val) {
+                            return Err(#error_name::LongitudeViolated);
+                        }
+                    )
+                }
+                FloatValidator::Percentage => {
+                    quote!(
+                        if !(0.0..=100.0).contains(&val) {
+                            return Err(#error_name::PercentageViolated);
+                        }
+                    )
+                }
+                FloatValidator::Ratio => {
+                    quote!(
+                        if !(0.0..=1.0).contains(&val) {
+                            return Err(#error_name::RatioViolated);
+                        }
+                    )
+                }
             })
             .collect();
 
@@ -129,24 +179,71 @@ where
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        generics: &Generics,
         validators: &[Self::Validator],
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, generics, validators)
+    }
+
+    fn is_new_const(guard: &Guard<Self::Sanitizer, Self::Validator>) -> bool {
+        // An expression may call a function, which is not `const`.
+        let is_literal = |value: &ValueOrExpr<T>| matches!(value, ValueOrExpr::Value(_));
+        let validators = guard.validators().map_or(&[][..], |v| v.as_slice());
+        guard.sanitizers().iter().all(|s| match s {
+            FloatSanitizer::Clamp { min, max } => is_literal(min) && is_literal(max),
+            _ => false,
+        }) && validators.iter().all(|v| match v {
+            FloatValidator::Greater(value)
+            | FloatValidator::GreaterOrEqual(value)
+            | FloatValidator::Less(value)
+            | FloatValidator::LessOrEqual(value) => is_literal(value),
+            _ => false,
+        })
+    }
+
+    fn gen_sql_check(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+    ) -> Result<TokenStream, syn::Error> {
+        let conditions = gen_sql_conditions(inner_type, validators)?;
+        Ok(gen_impl_sql_check(type_name, generics, conditions))
+    }
+
+    fn gen_zod_schema(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> Result<TokenStream, syn::Error> {
+        let methods = gen_zod_methods(inner_type, guard)?;
+        Ok(gen_impl_zod_schema(
+            type_name,
+            generics,
+            "z.number()",
+            methods,
+        ))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_arithmetic_policy: Option<ArithmeticPolicy>,
         guard: &FloatGuard<T>,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
             inner_type,
             maybe_error_type_name,
             maybe_default_value,
+            maybe_arithmetic_policy,
             traits,
             guard,
         )
@@ -154,7 +251,7 @@ where
 
     fn gen_tests(
         type_name: &TypeName,
-        _inner_type: &Self::InnerType,
+        inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         _traits: &HashSet<Self::TypedTrait>,
@@ -169,9 +266,42 @@ where
             guard.has_validation(),
         );
 
+        let proof_satisfy_validators =
+            gen_kani_proof_should_satisfy_validators(type_name, inner_type, guard.has_validation());
+
         quote! {
             #test_lower_vs_upper
             #test_valid_default_value
+            #proof_satisfy_validators
         }
     }
 }
+
+/// Rounds the value with `method` (`round`, `floor` or `ceil`) to the given number of decimal
+/// places. If scaling overflows (e.g. for very large values), the value is kept as it is.
+fn gen_round_to_decimal_places(
+    inner_type: &FloatInnerType,
+    decimal_places: u32,
+    method: TokenStream,
+) -> TokenStream {
+    if decimal_places == 0 {
+        return quote!(
+            value = value.#method();
+        );
+    }
+    let factor = syn::LitFloat::new(
+        &format!("1e{decimal_places}"),
+        proc_macro2::Span::call_site(),
+    );
+    quote!(
+        value = {
+            let factor: #inner_type = #factor;
+            let scaled = value * factor;
+            if scaled.is_finite() {
+                scaled.#method() / factor
+            } else {
+                value
+            }
+        };
+    )
+}
@@ -0,0 +1,39 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::gen::sql_check::{gen_sql_between, gen_sql_comparison, unsupported_sql_validator},
+    float::models::{FloatInnerType, FloatValidator},
+};
+
+pub fn gen_sql_conditions<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    validators: &[FloatValidator<T>],
+) -> Result<Vec<TokenStream>, syn::Error> {
+    validators
+        .iter()
+        .map(|validator| match validator {
+            FloatValidator::Greater(gt) => Ok(gen_sql_comparison(">", gt, inner_type)),
+            FloatValidator::GreaterOrEqual(gte) => Ok(gen_sql_comparison(">=", gte, inner_type)),
+            FloatValidator::Less(lt) => Ok(gen_sql_comparison("<", lt, inner_type)),
+            FloatValidator::LessOrEqual(lte) => Ok(gen_sql_comparison("<=", lte, inner_type)),
+            // NaN and infinities are outside of the range of finite values.
+            FloatValidator::Finite => Ok(gen_sql_between(
+                quote!(-#inner_type::MAX),
+                quote!(#inner_type::MAX),
+                inner_type,
+            )),
+            FloatValidator::Latitude
+            | FloatValidator::Longitude
+            | FloatValidator::Percentage
+            | FloatValidator::Ratio => {
+                let (min, max) = validator
+                    .preset_range()
+                    .expect("Preset validators must have a range");
+                let (min, max) = (Literal::f64_unsuffixed(min), Literal::f64_unsuffixed(max));
+                Ok(gen_sql_between(min, max, inner_type))
+            }
+            FloatValidator::Predicate(_) => Err(unsupported_sql_validator("predicate")),
+        })
+        .collect()
+}
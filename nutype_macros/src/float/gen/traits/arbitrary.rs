@@ -1,5 +1,6 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::models::TypeName,
@@ -12,9 +13,11 @@ use crate::{
 
 pub fn gen_impl_trait_arbitrary<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &FloatInnerType,
     guard: &FloatGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let construct_value = if guard.has_validation() {
         // If by some reason we generate an invalid value, make it very easy for the user to report
         let report_issue_msg =
@@ -35,7 +38,7 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
     let generate_inner_value = gen_generate_valid_inner_value(inner_type, guard)?;
 
     Ok(quote!(
-        impl ::arbitrary::Arbitrary<'_> for #type_name {
+        impl #impl_generics ::arbitrary::Arbitrary<'_> for #type_name #ty_generics #where_clause {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
                 let inner_value: #inner_type = {
                     #generate_inner_value
@@ -86,15 +89,16 @@ fn gen_generate_valid_inner_value_with_validators<T: ToTokens>(
         let msg = "It's not possible to derive `Arbitrary` trait for a type with `predicate` validator.\nYou have to implement `Arbitrary` trait on you own.";
         return Err(syn::Error::new(span, msg));
     }
-    if sanitizer_kinds.contains(&FloatSanitizerKind::With) {
+    // The sanitizers may turn a valid value into an invalid one (e.g. clamp it out of the bounds).
+    if let Some(sanitizer_kind) = sanitizer_kinds.first() {
         let span = Span::call_site();
-        let msg = "It's not possible to derive `Arbitrary` trait for a type with `with` sanitizer and validations.\nYou have to implement `Arbitrary` trait on you own.";
+        let msg = format!("It's not possible to derive `Arbitrary` trait for a type with `{sanitizer_kind}` sanitizer and validations.\nYou have to implement `Arbitrary` trait on you own.");
         return Err(syn::Error::new(span, msg));
     }
 
     let basic_value_kind = compute_basic_value_kind(&validator_kinds);
     let basic_value = generate_basic_value(inner_type, basic_value_kind);
-    let boundaries = compute_boundaries(validators);
+    let boundaries = compute_boundaries(inner_type, validators);
 
     Ok(normalize_basic_value_for_boundaries(
         inner_type,
@@ -117,13 +121,21 @@ fn normalize_basic_value_for_boundaries(
             let lower_value = &lower.value;
             let upper_value = &upper.value;
             let adjust_x_lower = gen_adjust_x_for_lower_boundary(inner_type, &lower);
-            let adjust_x_upper = gen_adjust_x_for_upper_boundary(inner_type, &lower);
+            let adjust_x_upper = gen_adjust_x_for_upper_boundary(inner_type, &upper);
             quote! {
+                // The first byte picks the boundary the value is counted from. Fuzzers shrink
+                // the input towards fewer and smaller bytes, so a failing value converges to
+                // the nearest boundary.
+                let from_upper: bool = u.arbitrary()?;
                 let from0to1 = #arbitrary_in_01_range;
 
                 // Scale range [0; 1] to the range of the boundaries
                 let range = (#upper_value - #lower_value).abs();
-                let x = #lower_value + from0to1 * range;
+                let x = if from_upper {
+                    #upper_value - from0to1 * range
+                } else {
+                    #lower_value + from0to1 * range
+                };
 
                 // Make sure we satisfy the exclusive boundaries
                 let x = #adjust_x_lower;
@@ -259,7 +271,12 @@ fn compute_basic_value_kind(validators: &[FloatValidatorKind]) -> BasicValueKind
             || validators.contains(&FloatValidatorKind::LessOrEqual)
     };
 
-    if validators.contains(&FloatValidatorKind::Finite) {
+    if validators.contains(&FloatValidatorKind::Finite)
+        || validators.contains(&FloatValidatorKind::Latitude)
+        || validators.contains(&FloatValidatorKind::Longitude)
+        || validators.contains(&FloatValidatorKind::Percentage)
+        || validators.contains(&FloatValidatorKind::Ratio)
+    {
         BasicValueKind::Finite
     } else if has_boundaries() {
         BasicValueKind::NotNaN
@@ -268,9 +285,12 @@ fn compute_basic_value_kind(validators: &[FloatValidatorKind]) -> BasicValueKind
     }
 }
 
-fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundaries {
-    let mut lower = None;
-    let mut upper = None;
+fn compute_boundaries<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    validators: &[FloatValidator<T>],
+) -> Boundaries {
+    let mut lower: Option<Boundary> = None;
+    let mut upper: Option<Boundary> = None;
 
     // NOTE: It's guaranteed that either Greater or GreaterOrEqual present, but not both,
     // Same for Less and LessOrEqual.
@@ -309,12 +329,54 @@ fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundari
                     is_inclusive,
                 });
             }
-            FloatValidator::Finite | FloatValidator::Predicate(..) => {
+            FloatValidator::Finite
+            | FloatValidator::Predicate(..)
+            | FloatValidator::Latitude
+            | FloatValidator::Longitude
+            | FloatValidator::Percentage
+            | FloatValidator::Ratio => {
                 // We don't care about these validators here.
             }
         }
     }
 
+    // Preset validators (e.g. `latitude`) come with their own inclusive bounds.
+    // If explicit bounds are set as well, the value must satisfy both, so the narrowest
+    // bound wins.
+    if let Some((min, max)) = validators.iter().find_map(|v| v.preset_range()) {
+        let to_literal = |value: f64| match inner_type {
+            FloatInnerType::F32 => Literal::f32_suffixed(value as f32),
+            FloatInnerType::F64 => Literal::f64_suffixed(value),
+        };
+        let (min, max) = (to_literal(min), to_literal(max));
+        lower = Some(match lower {
+            Some(Boundary {
+                value,
+                is_inclusive,
+            }) => Boundary {
+                value: quote!(#inner_type::max(#value, #min)),
+                is_inclusive,
+            },
+            None => Boundary {
+                value: quote!((#min)),
+                is_inclusive: true,
+            },
+        });
+        upper = Some(match upper {
+            Some(Boundary {
+                value,
+                is_inclusive,
+            }) => Boundary {
+                value: quote!(#inner_type::min(#value, #max)),
+                is_inclusive,
+            },
+            None => Boundary {
+                value: quote!(#max),
+                is_inclusive: true,
+            },
+        });
+    }
+
     Boundaries { lower, upper }
 }
 
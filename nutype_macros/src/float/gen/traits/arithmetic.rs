@@ -0,0 +1,186 @@
+//! `Add`, `Sub`, `Mul`, `Neg` and the compound assignment traits.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{
+            traits::{
+                gen_impl_trait_arithmetic_assign_operator, gen_impl_trait_arithmetic_operator,
+                gen_impl_trait_neg_operator, gen_missing_arithmetic_policy_error,
+                ArithmeticOperator, ArithmeticRhs,
+            },
+            InnerStorage,
+        },
+        models::{ArithmeticPolicy, ErrorTypeName, TypeName},
+    },
+    float::models::{FloatGuard, FloatInnerType, FloatValidator},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn gen_impl_trait_arithmetic<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    guard: &FloatGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+    rhs_kind: ArithmeticRhs,
+) -> Result<TokenStream, syn::Error> {
+    let trait_name = match rhs_kind {
+        ArithmeticRhs::Newtype => format!("{operator:?}"),
+        ArithmeticRhs::Inner => format!("{operator:?}<Inner>"),
+    };
+    let raw_value = gen_raw_value(
+        type_name,
+        inner_type,
+        guard,
+        maybe_arithmetic_policy,
+        operator,
+        &trait_name,
+    )?;
+    Ok(gen_impl_trait_arithmetic_operator(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        &InnerStorage::Plain,
+        operator,
+        rhs_kind,
+        raw_value,
+    ))
+}
+
+pub fn gen_impl_trait_arithmetic_assign<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+) -> Result<TokenStream, syn::Error> {
+    let raw_value = gen_raw_value(
+        type_name,
+        inner_type,
+        guard,
+        maybe_arithmetic_policy,
+        operator,
+        &format!("{operator:?}Assign"),
+    )?;
+    Ok(gen_impl_trait_arithmetic_assign_operator(
+        type_name,
+        generics,
+        inner_type,
+        &InnerStorage::Plain,
+        operator,
+        raw_value,
+    ))
+}
+
+/// Compute the result of the operation on the inner values `lhs` and `rhs` according to the
+/// policy.
+fn gen_raw_value<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+    trait_name: &str,
+) -> Result<TokenStream, syn::Error> {
+    let op = operator.operator();
+    let raw_value = match maybe_arithmetic_policy {
+        Some(ArithmeticPolicy::Saturate) if guard.has_validation() => {
+            let Some((min, max)) = gen_bounds(inner_type, guard) else {
+                let msg = format!("`arithmetic = saturate` can be used for type {type_name} only if it's validated by `greater_or_equal` and `less_or_equal` alone.");
+                return Err(syn::Error::new(Span::call_site(), msg));
+            };
+            quote!((lhs #op rhs).clamp(#min, #max))
+        }
+        Some(ArithmeticPolicy::Wrapping) => return Err(gen_wrapping_policy_error(type_name)),
+        None if guard.has_validation() => {
+            return Err(gen_missing_arithmetic_policy_error(type_name, trait_name));
+        }
+        // Float operations do not overflow, they produce infinity instead.
+        Some(ArithmeticPolicy::Saturate) | Some(ArithmeticPolicy::Panic) | None => {
+            quote!(lhs #op rhs)
+        }
+    };
+    Ok(raw_value)
+}
+
+pub fn gen_impl_trait_neg<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    guard: &FloatGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+) -> Result<TokenStream, syn::Error> {
+    let raw_value = match maybe_arithmetic_policy {
+        Some(ArithmeticPolicy::Saturate) if guard.has_validation() => {
+            let Some((min, max)) = gen_bounds(inner_type, guard) else {
+                let msg = format!("`arithmetic = saturate` can be used for type {type_name} only if it's validated by `greater_or_equal` and `less_or_equal` alone.");
+                return Err(syn::Error::new(Span::call_site(), msg));
+            };
+            quote!((-value).clamp(#min, #max))
+        }
+        Some(ArithmeticPolicy::Wrapping) => return Err(gen_wrapping_policy_error(type_name)),
+        None if guard.has_validation() => {
+            return Err(gen_missing_arithmetic_policy_error(type_name, "Neg"));
+        }
+        Some(ArithmeticPolicy::Saturate) | Some(ArithmeticPolicy::Panic) | None => quote!(-value),
+    };
+
+    Ok(gen_impl_trait_neg_operator(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        &InnerStorage::Plain,
+        raw_value,
+    ))
+}
+
+fn gen_wrapping_policy_error(type_name: &TypeName) -> syn::Error {
+    let msg = format!("`arithmetic = wrapping` cannot be used for type {type_name}, because it's supported only for integer based types.");
+    syn::Error::new(Span::call_site(), msg)
+}
+
+/// Returns the smallest and the largest valid values, if the type is validated by
+/// `greater_or_equal` and `less_or_equal` alone.
+/// Exclusive bounds have no largest or smallest value to clamp to.
+fn gen_bounds<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Option<(TokenStream, TokenStream)> {
+    let validators: &[FloatValidator<T>] = guard.validators().map_or(&[], |v| v.as_slice());
+
+    let has_only_bounds = validators.iter().all(|validator| {
+        matches!(
+            validator,
+            FloatValidator::GreaterOrEqual(_) | FloatValidator::LessOrEqual(_)
+        )
+    });
+    if !has_only_bounds {
+        return None;
+    }
+
+    let min = validators
+        .iter()
+        .find_map(|validator| match validator {
+            FloatValidator::GreaterOrEqual(min) => Some(quote!(#min)),
+            _ => None,
+        })
+        .unwrap_or_else(|| quote!(#inner_type::NEG_INFINITY));
+    let max = validators
+        .iter()
+        .find_map(|validator| match validator {
+            FloatValidator::LessOrEqual(max) => Some(quote!(#max)),
+            _ => None,
+        })
+        .unwrap_or_else(|| quote!(#inner_type::INFINITY));
+    Some((min, max))
+}
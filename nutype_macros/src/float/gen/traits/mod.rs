@@ -1,19 +1,30 @@
 pub mod arbitrary;
+mod arithmetic;
+
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
+                gen_impl_trait_from_str_via_parse, gen_impl_trait_into,
+                gen_impl_trait_num_traits_from_primitive, gen_impl_trait_num_traits_to_primitive,
+                gen_impl_trait_partial_eq_inner, gen_impl_trait_partial_ord_inner,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_sum_or_product, gen_impl_trait_try_from,
+                gen_impl_trait_try_from_str_via_parse, split_into_generatable_traits,
+                ArithmeticOperator, ArithmeticRhs, Fold, GeneratableTrait, GeneratableTraits,
+                GeneratedTraits,
+            },
+            InnerStorage,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ArithmeticPolicy, ErrorTypeName, TypeName},
     },
     float::models::{FloatDeriveTrait, FloatGuard, FloatInnerType},
 };
@@ -35,6 +46,8 @@ enum FloatTransparentTrait {
 /// an implementation for it.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 enum FloatIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
     FromStr,
     AsRef,
     Deref,
@@ -42,13 +55,31 @@ enum FloatIrregularTrait {
     From,
     Eq,
     Ord,
+    Hash,
     TryFrom,
+    TryFromStr,
     Borrow,
     Display,
     Default,
+    Sum,
+    Product,
+    Add,
+    Sub,
+    Mul,
+    AddInner,
+    SubInner,
+    MulInner,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    OrderedFloatNotNan,
+    OrderedFloatOrderedFloat,
 }
 
 impl From<FloatDeriveTrait> for FloatGeneratableTrait {
@@ -66,21 +97,57 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
             FloatDeriveTrait::PartialEq => {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::PartialEq)
             }
+            FloatDeriveTrait::PartialEqInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::PartialEqInner)
+            }
+            FloatDeriveTrait::PartialOrdInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::PartialOrdInner)
+            }
             FloatDeriveTrait::Eq => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Eq),
             FloatDeriveTrait::PartialOrd => {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::PartialOrd)
             }
             FloatDeriveTrait::Ord => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Ord),
+            FloatDeriveTrait::Hash => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Hash),
             FloatDeriveTrait::FromStr => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::FromStr)
             }
             FloatDeriveTrait::AsRef => FloatGeneratableTrait::Irregular(FloatIrregularTrait::AsRef),
             FloatDeriveTrait::Deref => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Deref),
+            FloatDeriveTrait::Sum => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sum),
+            FloatDeriveTrait::Product => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::Product)
+            }
+            FloatDeriveTrait::Add => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Add),
+            FloatDeriveTrait::Sub => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sub),
+            FloatDeriveTrait::Mul => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Mul),
+            FloatDeriveTrait::AddInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::AddInner)
+            }
+            FloatDeriveTrait::SubInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SubInner)
+            }
+            FloatDeriveTrait::MulInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::MulInner)
+            }
+            FloatDeriveTrait::Neg => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Neg),
+            FloatDeriveTrait::AddAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::AddAssign)
+            }
+            FloatDeriveTrait::SubAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SubAssign)
+            }
+            FloatDeriveTrait::MulAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::MulAssign)
+            }
             FloatDeriveTrait::From => FloatGeneratableTrait::Irregular(FloatIrregularTrait::From),
             FloatDeriveTrait::Into => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Into),
             FloatDeriveTrait::TryFrom => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::TryFrom)
             }
+            FloatDeriveTrait::TryFromStr => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::TryFromStr)
+            }
             FloatDeriveTrait::Borrow => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::Borrow)
             }
@@ -99,6 +166,18 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
             FloatDeriveTrait::ArbitraryArbitrary => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::ArbitraryArbitrary)
             }
+            FloatDeriveTrait::NumTraitsToPrimitive => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsToPrimitive)
+            }
+            FloatDeriveTrait::NumTraitsFromPrimitive => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsFromPrimitive)
+            }
+            FloatDeriveTrait::OrderedFloatNotNan => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::OrderedFloatNotNan)
+            }
+            FloatDeriveTrait::OrderedFloatOrderedFloat => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::OrderedFloatOrderedFloat)
+            }
             FloatDeriveTrait::SchemarsJsonSchema => {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::SchemarsJsonSchema)
             }
@@ -120,11 +199,14 @@ impl ToTokens for FloatTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &FloatInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
     traits: HashSet<FloatDeriveTrait>,
     guard: &FloatGuard<T>,
 ) -> Result<GeneratedTraits, syn::Error> {
@@ -141,9 +223,11 @@ pub fn gen_traits<T: ToTokens>(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
         inner_type,
         maybe_error_type_name,
         maybe_default_value,
+        maybe_arithmetic_policy,
         irregular_traits,
         guard,
     )?;
@@ -154,33 +238,37 @@ pub fn gen_traits<T: ToTokens>(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &FloatInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
     impl_traits: Vec<FloatIrregularTrait>,
     guard: &FloatGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
         .map(|t| match t {
-            FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
-            FloatIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
-            }
-            FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
+            FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            FloatIrregularTrait::FromStr => Ok(gen_impl_trait_from_str_via_parse(type_name, generics)),
+            FloatIrregularTrait::TryFromStr => Ok(gen_impl_trait_try_from_str_via_parse(type_name, generics)),
+            FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
+            FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
             FloatIrregularTrait::TryFrom => {
-                Ok(gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_try_from(type_name, generics, inner_type, maybe_error_type_name.as_ref()))
             }
-            FloatIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
-            FloatIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
+            FloatIrregularTrait::PartialEqInner => Ok(gen_impl_trait_partial_eq_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            FloatIrregularTrait::PartialOrdInner => Ok(gen_impl_trait_partial_ord_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            FloatIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            FloatIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics)),
             FloatIrregularTrait::Default => match maybe_default_value {
                 Some(ref default_value) => {
                     let has_validation = maybe_error_type_name.is_some();
-                    Ok(gen_impl_trait_default(type_name, default_value, has_validation))
+                    Ok(gen_impl_trait_default(type_name, generics, default_value, has_validation))
                 }
                 None => {
                     let span = proc_macro2::Span::call_site();
@@ -188,37 +276,172 @@ fn gen_implemented_traits<T: ToTokens>(
                     Err(syn::Error::new(span, msg))
                 }
             },
-            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
+            FloatIrregularTrait::Sum => Ok(gen_impl_trait_sum_or_product(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &InnerStorage::Plain,
+                Fold::Sum,
+            )),
+            FloatIrregularTrait::Product => Ok(gen_impl_trait_sum_or_product(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &InnerStorage::Plain,
+                Fold::Product,
+            )),
+            FloatIrregularTrait::Add => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+                ArithmeticRhs::Newtype,
+            ),
+            FloatIrregularTrait::AddInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+                ArithmeticRhs::Inner,
+            ),
+            FloatIrregularTrait::Sub => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+                ArithmeticRhs::Newtype,
+            ),
+            FloatIrregularTrait::SubInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+                ArithmeticRhs::Inner,
+            ),
+            FloatIrregularTrait::Mul => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+                ArithmeticRhs::Newtype,
+            ),
+            FloatIrregularTrait::MulInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+                ArithmeticRhs::Inner,
+            ),
+            FloatIrregularTrait::Neg => arithmetic::gen_impl_trait_neg(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard,
+                maybe_arithmetic_policy,
+            ),
+            FloatIrregularTrait::AddAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+            ),
+            FloatIrregularTrait::SubAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+            ),
+            FloatIrregularTrait::MulAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+            ),
+            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name, generics)),
             FloatIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
                 type_name,
+                generics,
                 inner_type,
                 maybe_error_type_name.as_ref(),
             )),
-            FloatIrregularTrait::Eq => Ok(gen_impl_trait_eq(type_name)),
-            FloatIrregularTrait::Ord => Ok(gen_impl_trait_ord(type_name)),
+            FloatIrregularTrait::Eq => Ok(gen_impl_trait_eq(type_name, generics)),
+            FloatIrregularTrait::Ord => Ok(gen_impl_trait_ord(type_name, generics)),
+            FloatIrregularTrait::Hash => Ok(gen_impl_trait_hash(type_name, generics)),
             FloatIrregularTrait::ArbitraryArbitrary => {
-                arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
+                arbitrary::gen_impl_trait_arbitrary(type_name, generics, inner_type, guard)
             }
+            FloatIrregularTrait::NumTraitsToPrimitive => {
+                Ok(gen_impl_trait_num_traits_to_primitive(type_name, generics))
+            }
+            FloatIrregularTrait::NumTraitsFromPrimitive => Ok(gen_impl_trait_num_traits_from_primitive(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::OrderedFloatNotNan => Ok(gen_impl_trait_ordered_float(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                FloatIrregularTrait::OrderedFloatNotNan,
+            )),
+            FloatIrregularTrait::OrderedFloatOrderedFloat => Ok(gen_impl_trait_ordered_float(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                FloatIrregularTrait::OrderedFloatOrderedFloat,
+            )),
         })
         .collect()
 }
 
-fn gen_impl_trait_eq(type_name: &TypeName) -> TokenStream {
+fn gen_impl_trait_eq(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::cmp::Eq for #type_name { }
+        impl #impl_generics ::core::cmp::Eq for #type_name #ty_generics #where_clause { }
     }
 }
 
 // The implementation below may panic.
 // Function `partial_cmp` returns `None` only for `NaN` values, but
-// `NaN` values are supposed to be excluded by `finite` validation rule.
-// Without `finite` validation deriving `Ord` is not allowed.
-fn gen_impl_trait_ord(type_name: &TypeName) -> TokenStream {
+// `NaN` values are supposed to be excluded by `finite` validation rule or by boundaries.
+// Without such validation deriving `Ord` is not allowed.
+fn gen_impl_trait_ord(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let tp = type_name.to_string();
     quote! {
         // Make clippy ignore this manual implementation of Ord even when PartialOrd is derived.
         #[allow(clippy::derive_ord_xor_partial_ord)]
-        impl ::core::cmp::Ord for #type_name {
+        impl #impl_generics ::core::cmp::Ord for #type_name #ty_generics #where_clause {
             fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
                 self.partial_cmp(other)
                     .unwrap_or_else(|| {
@@ -229,3 +452,65 @@ fn gen_impl_trait_ord(type_name: &TypeName) -> TokenStream {
         }
     }
 }
+
+// Hashing the bits is consistent with `Eq` only because `NaN` is excluded by validation.
+// `0.0` and `-0.0` are equal, but have different bits, so the sign of zero is dropped.
+fn gen_impl_trait_hash(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::core::hash::Hash for #type_name #ty_generics #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                let value = if self.0 == 0.0 { 0.0 } else { self.0 };
+                ::core::hash::Hash::hash(&value.to_bits(), state);
+            }
+        }
+    }
+}
+
+/// Conversions between the newtype and `NotNan<T>` or `OrderedFloat<T>` of `ordered_float` crate.
+/// Deriving is allowed only if `NaN` is excluded by validation, so the error type always exists.
+fn gen_impl_trait_ordered_float(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    ordered_float_trait: FloatIrregularTrait,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let error_type_name = maybe_error_type_name.expect(
+        "Deriving NotNan or OrderedFloat requires validation, so the error type must exist",
+    );
+    let tp = type_name.to_string();
+    let (wrapper, convert) = match ordered_float_trait {
+        FloatIrregularTrait::OrderedFloatNotNan => (
+            quote!(::ordered_float::NotNan),
+            quote!(
+                ::ordered_float::NotNan::new(value.0).unwrap_or_else(|_| {
+                    let tp = #tp;
+                    panic!("{tp} contains NaN. Could it be that you're using unsafe {tp}::new_unchecked() ?", tp=tp);
+                })
+            ),
+        ),
+        _ => (
+            quote!(::ordered_float::OrderedFloat),
+            quote!(::ordered_float::OrderedFloat(value.0)),
+        ),
+    };
+    quote! {
+        impl #impl_generics ::core::convert::From<#type_name #ty_generics> for #wrapper<#inner_type> #where_clause {
+            #[inline]
+            fn from(value: #type_name #ty_generics) -> Self {
+                #convert
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#wrapper<#inner_type>> for #type_name #ty_generics #where_clause {
+            type Error = #error_type_name;
+
+            #[inline]
+            fn try_from(value: #wrapper<#inner_type>) -> ::core::result::Result<Self, Self::Error> {
+                Self::new(value.into_inner())
+            }
+        }
+    }
+}
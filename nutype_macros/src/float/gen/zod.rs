@@ -0,0 +1,53 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+
+use crate::{
+    common::gen::zod::{gen_zod_method, unsupported_zod_rule},
+    float::models::{FloatGuard, FloatInnerType, FloatSanitizer, FloatValidator},
+};
+
+pub fn gen_zod_methods<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<Vec<TokenStream>, syn::Error> {
+    let (sanitizers, validators): (&[FloatSanitizer<T>], &[FloatValidator<T>]) = match guard {
+        FloatGuard::WithoutValidation { sanitizers } => (sanitizers, &[]),
+        FloatGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers, validators),
+    };
+    if !sanitizers.is_empty() {
+        return Err(unsupported_zod_rule("with"));
+    }
+
+    let mut methods = Vec::with_capacity(validators.len() + 1);
+    for validator in validators {
+        match validator {
+            FloatValidator::Greater(gt) => methods.push(gen_zod_method("gt", gt, inner_type)),
+            FloatValidator::GreaterOrEqual(gte) => {
+                methods.push(gen_zod_method("gte", gte, inner_type))
+            }
+            FloatValidator::Less(lt) => methods.push(gen_zod_method("lt", lt, inner_type)),
+            FloatValidator::LessOrEqual(lte) => {
+                methods.push(gen_zod_method("lte", lte, inner_type))
+            }
+            FloatValidator::Finite => {
+                methods.push(quote::quote!(::std::string::String::from(".finite()")))
+            }
+            FloatValidator::Latitude
+            | FloatValidator::Longitude
+            | FloatValidator::Percentage
+            | FloatValidator::Ratio => {
+                let (min, max) = validator
+                    .preset_range()
+                    .expect("Preset validators must have a range");
+                let (min, max) = (Literal::f64_unsuffixed(min), Literal::f64_unsuffixed(max));
+                methods.push(gen_zod_method("gte", min, inner_type));
+                methods.push(gen_zod_method("lte", max, inner_type));
+            }
+            FloatValidator::Predicate(_) => return Err(unsupported_zod_rule("predicate")),
+        }
+    }
+    Ok(methods)
+}
@@ -13,6 +13,20 @@ use crate::common::models::{
 #[kinded(display = "snake_case", derive(Hash))]
 pub enum FloatSanitizer<T> {
     With(TypedCustomFunction),
+    /// Inclusive boundaries, e.g. `clamp(min = 0.0, max = 100.0)`.
+    Clamp {
+        min: ValueOrExpr<T>,
+        max: ValueOrExpr<T>,
+    },
+    /// Round to the given number of decimal places, e.g. `round = 2`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    Round(u32),
+    /// Round down to the given number of decimal places, e.g. `floor = 2`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    Floor(u32),
+    /// Round up to the given number of decimal places, e.g. `ceil = 2`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    Ceil(u32),
     _Phantom(std::marker::PhantomData<T>),
 }
 
@@ -30,6 +44,27 @@ pub enum FloatValidator<T> {
     LessOrEqual(ValueOrExpr<T>),
     Predicate(TypedCustomFunction),
     Finite,
+    /// Finite value in range from -90 to 90 (inclusive).
+    Latitude,
+    /// Finite value in range from -180 to 180 (inclusive).
+    Longitude,
+    /// Finite value in range from 0 to 100 (inclusive).
+    Percentage,
+    /// Finite value in range from 0 to 1 (inclusive).
+    Ratio,
+}
+
+impl<T> FloatValidator<T> {
+    /// The inclusive bounds of the preset validators, which stand for a well-known range.
+    pub fn preset_range(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::Latitude => Some((-90.0, 90.0)),
+            Self::Longitude => Some((-180.0, 180.0)),
+            Self::Percentage => Some((0.0, 100.0)),
+            Self::Ratio => Some((0.0, 1.0)),
+            _ => None,
+        }
+    }
 }
 
 impl_numeric_bound_validator!(FloatValidator);
@@ -46,36 +81,65 @@ pub enum FloatDeriveTrait {
     Clone,
     Copy,
     PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
     Eq,
     PartialOrd,
     Ord,
+    Hash,
     FromStr,
     AsRef,
     Into,
     From,
     TryFrom,
+    TryFromStr,
     Borrow,
     Display,
     Default,
     Deref,
+    Sum,
+    Product,
+    Add,
+    Sub,
+    Mul,
+    AddInner,
+    SubInner,
+    MulInner,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    OrderedFloatNotNan,
+    OrderedFloatOrderedFloat,
 }
 
 impl TypeTrait for FloatDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &FloatDeriveTrait::FromStr
     }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &FloatDeriveTrait::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &FloatDeriveTrait::SerdeDeserialize
+    }
 }
 
 pub type FloatRawGuard<T> = RawGuard<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>>;
 pub type FloatGuard<T> = Guard<FloatSanitizer<T>, FloatValidator<T>>;
 
 pub trait FloatType {
+    #[allow(dead_code)]
     fn float_inner_type() -> FloatInnerType;
 }
 
@@ -4,16 +4,20 @@ use std::{
 };
 
 use crate::common::{
-    models::{Attributes, SpannedDeriveTrait},
+    models::{Attributes, CheckedArithmetic, Sensitive, SpannedDeriveTrait, ValueOrExpr},
     parse::{
         parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
-        parse_validator_kind, ParseableAttributes,
+        parse_validator_kind, reject_borrowed, reject_from_float, reject_len_unit,
+        reject_mut_guard, reject_parse_constructor_name, reject_sensitive, ParseableAttributes,
     },
+    validate::{validate_consts_numeric_bounds, validate_default_numeric_bounds},
 };
-use proc_macro2::TokenStream;
+use cfg_if::cfg_if;
+use proc_macro2::{Span, TokenStream};
 use syn::{
+    parenthesized,
     parse::{Parse, ParseStream},
-    Token,
+    Ident, Token,
 };
 
 use super::{
@@ -38,18 +42,69 @@ where
         sanitizers,
         validators,
         new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
         default,
+        convertible_with,
+        borrowed,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
         derive_traits,
     } = attrs;
+    reject_from_float(from_float)?;
+    reject_borrowed(borrowed)?;
+    reject_parse_constructor_name(constructor.as_ref())?;
+    reject_len_unit(len_unit)?;
+    reject_sensitive(sensitive)?;
+    reject_mut_guard(mut_guard)?;
     let raw_guard = FloatRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_number_meta(raw_guard)?;
+    if let (CheckedArithmetic::On, false) = (checked_arithmetic, guard.has_validation()) {
+        let msg = "`checked_arithmetic` on a float type requires validation to be defined, otherwise the operations can not fail.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if let Some(validators) = guard.validators() {
+        validate_consts_numeric_bounds(&consts, validators)?;
+        if guard.sanitizers().is_empty() {
+            validate_default_numeric_bounds(default.as_ref(), validators)?;
+        }
+    }
     Ok(Attributes {
         new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive: Sensitive::Off,
         guard,
         default,
+        convertible_with,
+        maybe_borrowed_type_name: None,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: None,
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: None,
+        maybe_serde_mode: serde.map(|serde| serde.item),
+        maybe_arithmetic_policy: arithmetic.map(|arithmetic| arithmetic.item),
+        maybe_debug_mode: debug.map(|debug| debug.item),
         derive_traits,
     })
 }
@@ -110,6 +165,22 @@ where
                     span: ident.span(),
                 })
             }
+            FloatValidatorKind::Latitude => Ok(SpannedFloatValidator {
+                item: FloatValidator::Latitude,
+                span: ident.span(),
+            }),
+            FloatValidatorKind::Longitude => Ok(SpannedFloatValidator {
+                item: FloatValidator::Longitude,
+                span: ident.span(),
+            }),
+            FloatValidatorKind::Percentage => Ok(SpannedFloatValidator {
+                item: FloatValidator::Percentage,
+                span: ident.span(),
+            }),
+            FloatValidatorKind::Ratio => Ok(SpannedFloatValidator {
+                item: FloatValidator::Ratio,
+                span: ident.span(),
+            }),
         }
     }
 }
@@ -131,6 +202,37 @@ where
                     span,
                 })
             }
+            FloatSanitizerKind::Clamp => {
+                let content;
+                parenthesized!(content in input);
+                let (min, max) = parse_clamp_boundaries(&content, ident.span())?;
+                Ok(SpannedFloatSanitizer {
+                    item: FloatSanitizer::Clamp { min, max },
+                    span: ident.span(),
+                })
+            }
+            FloatSanitizerKind::Round | FloatSanitizerKind::Floor | FloatSanitizerKind::Ceil => {
+                cfg_if! {
+                    if #[cfg(feature = "std")] {
+                        let _eq: Token![=] = input.parse()?;
+                        let (decimal_places, span) = crate::common::parse::parse_number::<u32>(input)?;
+                        // Beyond 15 decimal places even `f64` can not represent the result precisely
+                        if decimal_places > MAX_DECIMAL_PLACES {
+                            let msg = format!("Sanitizer `{kind}` supports up to {MAX_DECIMAL_PLACES} decimal places.");
+                            return Err(syn::Error::new(span, msg));
+                        }
+                        let item = match kind {
+                            FloatSanitizerKind::Round => FloatSanitizer::Round(decimal_places),
+                            FloatSanitizerKind::Floor => FloatSanitizer::Floor(decimal_places),
+                            _ => FloatSanitizer::Ceil(decimal_places),
+                        };
+                        Ok(SpannedFloatSanitizer { item, span })
+                    } else {
+                        let msg = format!("To use `{kind}` sanitizer, the feature `std` of the crate `nutype` must be enabled.");
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
             FloatSanitizerKind::_Phantom => {
                 let msg = format!("Unknown validator `{ident}`");
                 Err(syn::Error::new(ident.span(), msg))
@@ -138,3 +240,52 @@ where
         }
     }
 }
+
+#[cfg(feature = "std")]
+const MAX_DECIMAL_PLACES: u32 = 15;
+
+/// Parses `min = X, max = Y` of `clamp` sanitizer. Both boundaries are required.
+fn parse_clamp_boundaries<T>(
+    input: ParseStream,
+    span: Span,
+) -> syn::Result<(ValueOrExpr<T>, ValueOrExpr<T>)>
+where
+    T: FromStr,
+{
+    let mut maybe_min: Option<ValueOrExpr<T>> = None;
+    let mut maybe_max: Option<ValueOrExpr<T>> = None;
+
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let (value, _span) = parse_number_or_expr::<T>(input)?;
+
+        let bound = if ident == "min" {
+            &mut maybe_min
+        } else if ident == "max" {
+            &mut maybe_max
+        } else {
+            let msg = format!(
+                "Unknown parameter `{ident}` of `clamp` sanitizer.\nPossible values are `min`, `max`."
+            );
+            return Err(syn::Error::new(ident.span(), msg));
+        };
+        if bound.is_some() {
+            let msg = format!("Duplicated parameter `{ident}` of `clamp` sanitizer.");
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+        *bound = Some(value);
+
+        if !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+        }
+    }
+
+    match (maybe_min, maybe_max) {
+        (Some(min), Some(max)) => Ok((min, max)),
+        _ => {
+            let msg = "Sanitizer `clamp` requires both boundaries, e.g.:\n    clamp(min = 0.0, max = 100.0)";
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
@@ -2,7 +2,7 @@ use proc_macro2::Span;
 use std::collections::HashSet;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
+    models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
     validate::{validate_duplicates, validate_numeric_bounds},
 };
 
@@ -47,6 +47,19 @@ where
 
     validate_numeric_bounds(&validators)?;
 
+    let mut presets = validators
+        .iter()
+        .filter(|v| v.item.preset_range().is_some());
+    if let (Some(first), Some(second)) = (presets.next(), presets.next()) {
+        let span = first.span().join(second.span()).unwrap_or(second.span());
+        let msg = format!(
+            "Validators `{}` and `{}` cannot be used together.\nEach of them defines its own range of valid values.",
+            first.item.kind(),
+            second.item.kind()
+        );
+        return Err(syn::Error::new(span, msg));
+    }
+
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
@@ -61,6 +74,20 @@ where
         format!("Duplicated sanitizer `{kind}`.\nIt happens, don't worry. We still love you!")
     })?;
 
+    for sanitizer in &sanitizers {
+        if let FloatSanitizer::Clamp {
+            min: ValueOrExpr::Value(min),
+            max: ValueOrExpr::Value(max),
+        } = &sanitizer.item
+        {
+            if min > max {
+                let msg =
+                    "The `min` boundary of `clamp` sanitizer must be less than or equal to `max`.";
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+
     let sanitizers: Vec<_> = sanitizers.into_iter().map(|s| s.item).collect();
     Ok(sanitizers)
 }
@@ -68,9 +95,17 @@ where
 fn has_validation_against_nan<T>(guard: &FloatGuard<T>) -> bool {
     match guard {
         FloatGuard::WithoutValidation { .. } => false,
-        FloatGuard::WithValidation { ref validators, .. } => validators
-            .iter()
-            .any(|v| v.kind() == FloatValidatorKind::Finite),
+        FloatGuard::WithValidation { ref validators, .. } => validators.iter().any(|v| {
+            // NaN is rejected by the boundaries as well, because it is not comparable.
+            matches!(
+                v.kind(),
+                FloatValidatorKind::Finite
+                    | FloatValidatorKind::Greater
+                    | FloatValidatorKind::GreaterOrEqual
+                    | FloatValidatorKind::Less
+                    | FloatValidatorKind::LessOrEqual
+            ) || v.preset_range().is_some()
+        }),
     }
 }
 
@@ -155,6 +190,8 @@ fn to_float_derive_trait(
         DeriveTrait::Default => Ok(FloatDeriveTrait::Default),
         DeriveTrait::Clone => Ok(FloatDeriveTrait::Clone),
         DeriveTrait::PartialEq => Ok(FloatDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(FloatDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(FloatDeriveTrait::PartialOrdInner),
         DeriveTrait::Into => Ok(FloatDeriveTrait::Into),
         DeriveTrait::Eq => {
             if validation.has_nan_validation {
@@ -176,10 +213,47 @@ fn to_float_derive_trait(
         DeriveTrait::FromStr => Ok(FloatDeriveTrait::FromStr),
         DeriveTrait::AsRef => Ok(FloatDeriveTrait::AsRef),
         DeriveTrait::Deref => Ok(FloatDeriveTrait::Deref),
-        DeriveTrait::Hash => Err(syn::Error::new(
-            span,
-            "#[nutype] cannot derive `Hash` trait for float types.",
-        )),
+        DeriveTrait::Sum => Ok(FloatDeriveTrait::Sum),
+        DeriveTrait::Product => Ok(FloatDeriveTrait::Product),
+        DeriveTrait::Add => Ok(FloatDeriveTrait::Add),
+        DeriveTrait::Sub => Ok(FloatDeriveTrait::Sub),
+        DeriveTrait::Mul => Ok(FloatDeriveTrait::Mul),
+        DeriveTrait::AddInner => Ok(FloatDeriveTrait::AddInner),
+        DeriveTrait::SubInner => Ok(FloatDeriveTrait::SubInner),
+        DeriveTrait::MulInner => Ok(FloatDeriveTrait::MulInner),
+        DeriveTrait::Neg => Ok(FloatDeriveTrait::Neg),
+        DeriveTrait::AddAssign | DeriveTrait::SubAssign | DeriveTrait::MulAssign => {
+            if validation.has_validation {
+                let msg = format!("#[nutype] cannot derive `{tr:?}` trait, because there is validation defined. Use `checked_arithmetic` instead.");
+                Err(syn::Error::new(span, msg))
+            } else {
+                match tr {
+                    DeriveTrait::AddAssign => Ok(FloatDeriveTrait::AddAssign),
+                    DeriveTrait::SubAssign => Ok(FloatDeriveTrait::SubAssign),
+                    _ => Ok(FloatDeriveTrait::MulAssign),
+                }
+            }
+        }
+        DeriveTrait::Hash => {
+            if validation.has_nan_validation {
+                Ok(FloatDeriveTrait::Hash)
+            } else {
+                let msg = "To derive Hash trait on float-based type there must be validation that proves that inner value is not NaN.\nConsider adding:\n    validate(finite)";
+                Err(syn::Error::new(span, msg))
+            }
+        }
+        DeriveTrait::LowerHex
+        | DeriveTrait::UpperHex
+        | DeriveTrait::Binary
+        | DeriveTrait::Octal
+        | DeriveTrait::FromIterator
+        | DeriveTrait::TryFromIterator
+        | DeriveTrait::NumTraitsBounded
+        | DeriveTrait::NumTraitsZero
+        | DeriveTrait::NumTraitsOne => {
+            let msg = format!("#[nutype] cannot derive `{tr:?}` trait for float types.");
+            Err(syn::Error::new(span, msg))
+        }
         DeriveTrait::Borrow => Ok(FloatDeriveTrait::Borrow),
         DeriveTrait::Copy => Ok(FloatDeriveTrait::Copy),
         DeriveTrait::From => {
@@ -193,9 +267,29 @@ fn to_float_derive_trait(
             }
         }
         DeriveTrait::TryFrom => Ok(FloatDeriveTrait::TryFrom),
+        DeriveTrait::TryFromStr => Ok(FloatDeriveTrait::TryFromStr),
         DeriveTrait::SerdeSerialize => Ok(FloatDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(FloatDeriveTrait::SerdeDeserialize),
         DeriveTrait::SchemarsJsonSchema => Ok(FloatDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(FloatDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::NumTraitsToPrimitive => Ok(FloatDeriveTrait::NumTraitsToPrimitive),
+        DeriveTrait::NumTraitsFromPrimitive => Ok(FloatDeriveTrait::NumTraitsFromPrimitive),
+        DeriveTrait::OrderedFloatNotNan | DeriveTrait::OrderedFloatOrderedFloat => {
+            // Converting into `NotNan` can not fail and the values of both `NotNan` and
+            // `OrderedFloat` are expected to be totally ordered.
+            if validation.has_nan_validation {
+                match tr {
+                    DeriveTrait::OrderedFloatNotNan => Ok(FloatDeriveTrait::OrderedFloatNotNan),
+                    _ => Ok(FloatDeriveTrait::OrderedFloatOrderedFloat),
+                }
+            } else {
+                let name = match tr {
+                    DeriveTrait::OrderedFloatNotNan => "NotNan",
+                    _ => "OrderedFloat",
+                };
+                let msg = format!("To derive {name} on float-based type there must be validation that proves that inner value is not NaN.\nConsider adding:\n    validate(finite)");
+                Err(syn::Error::new(span, msg))
+            }
+        }
     }
 }
@@ -1,19 +1,22 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Generics, Ident};
 
 use super::super::models::IntegerValidator;
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
+    gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
     models::{ErrorTypeName, TypeName},
 };
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     validators: &[IntegerValidator<T>],
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
     let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -29,53 +32,134 @@ fn gen_definition<T>(
     error_type_name: &ErrorTypeName,
     validators: &[IntegerValidator<T>],
 ) -> TokenStream {
-    let error_variants: TokenStream = validators
-        .iter()
-        .map(|validator| match validator {
-            IntegerValidator::Greater(_) => {
-                quote!(GreaterViolated,)
-            }
-            IntegerValidator::GreaterOrEqual(_) => {
-                quote!(GreaterOrEqualViolated,)
-            }
-            IntegerValidator::Less(_) => {
-                quote!(LessViolated,)
-            }
-            IntegerValidator::LessOrEqual(_) => {
-                quote!(LessOrEqualViolated,)
-            }
-            IntegerValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
-        })
-        .collect();
+    let error_variants = validators.iter().map(gen_error_variant);
 
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
-            #error_variants
+            #(#error_variants,)*
         }
     }
 }
 
+/// Name of the error variant, which is returned when the validator fails.
+pub fn gen_error_variant<T>(validator: &IntegerValidator<T>) -> Ident {
+    let name = match validator {
+        IntegerValidator::Greater(_) => "GreaterViolated",
+        IntegerValidator::GreaterOrEqual(_) => "GreaterOrEqualViolated",
+        IntegerValidator::Less(_) => "LessViolated",
+        IntegerValidator::LessOrEqual(_) => "LessOrEqualViolated",
+        IntegerValidator::FitsBits(_) => "FitsBitsViolated",
+        IntegerValidator::AlignedTo(_) => "AlignedToViolated",
+        IntegerValidator::MultipleOf(_) => "MultipleOfViolated",
+        IntegerValidator::Percentage => "PercentageViolated",
+        IntegerValidator::Even => "EvenViolated",
+        IntegerValidator::Odd => "OddViolated",
+        IntegerValidator::PowerOfTwo => "PowerOfTwoViolated",
+        IntegerValidator::Nonzero => "NonzeroViolated",
+        IntegerValidator::OneOf(_) => "OneOfViolated",
+        IntegerValidator::NotIn(_) => "NotInViolated",
+        IntegerValidator::All(_) => "AllViolated",
+        IntegerValidator::Any(_) => "AnyViolated",
+        IntegerValidator::Not(_) => "NotViolated",
+        IntegerValidator::Predicate(_) => "PredicateViolated",
+    };
+    format_ident!("{name}")
+}
+
 fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     error_type_name: &ErrorTypeName,
     validators: &[IntegerValidator<T>],
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
-        IntegerValidator::Greater(val) => quote! {
-             #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+        IntegerValidator::Greater(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        IntegerValidator::GreaterOrEqual(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        IntegerValidator::Less(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        IntegerValidator::LessOrEqual(val) => {
+            let val = gen_error_message_value(val, generics);
+            quote! {
+                 #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+            }
+        }
+        IntegerValidator::FitsBits(bits) => quote! {
+             #error_type_name::FitsBitsViolated => write!(f, "{} does not fit in {} bits.", stringify!(#type_name), #bits)
+        },
+        IntegerValidator::AlignedTo(alignment) => quote! {
+             #error_type_name::AlignedToViolated => write!(f, "{} is not aligned to {}.", stringify!(#type_name), #alignment)
+        },
+        IntegerValidator::MultipleOf(factor) => quote! {
+             #error_type_name::MultipleOfViolated => write!(f, "{} is not a multiple of {}.", stringify!(#type_name), #factor)
         },
-        IntegerValidator::GreaterOrEqual(val) => quote! {
-             #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+        IntegerValidator::Percentage => quote! {
+             #error_type_name::PercentageViolated => write!(f, "{} is not a valid percentage. The value must be between 0 and 100.", stringify!(#type_name))
         },
-        IntegerValidator::Less(val) => quote! {
-             #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+        IntegerValidator::Even => quote! {
+             #error_type_name::EvenViolated => write!(f, "{} is not even.", stringify!(#type_name))
         },
-        IntegerValidator::LessOrEqual(val) => quote! {
-             #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+        IntegerValidator::Odd => quote! {
+             #error_type_name::OddViolated => write!(f, "{} is not odd.", stringify!(#type_name))
         },
+        IntegerValidator::PowerOfTwo => quote! {
+             #error_type_name::PowerOfTwoViolated => write!(f, "{} is not a power of two.", stringify!(#type_name))
+        },
+        IntegerValidator::Nonzero => quote! {
+             #error_type_name::NonzeroViolated => write!(f, "{} must not be zero.", stringify!(#type_name))
+        },
+        IntegerValidator::OneOf(values) => {
+            let write_values = gen_write_values(values);
+            quote! {
+                 #error_type_name::OneOfViolated => {
+                     write!(f, "{} is not one of the allowed values: ", stringify!(#type_name))?;
+                     #write_values
+                     f.write_str(".")
+                 }
+            }
+        }
+        IntegerValidator::NotIn(values) => {
+            let write_values = gen_write_values(values);
+            quote! {
+                 #error_type_name::NotInViolated => {
+                     write!(f, "{} is one of the excluded values: ", stringify!(#type_name))?;
+                     #write_values
+                     f.write_str(".")
+                 }
+            }
+        }
+        IntegerValidator::All(combination) => {
+            let source = &combination.source;
+            quote! {
+                 #error_type_name::AllViolated => write!(f, "{} does not satisfy `all({})`.", stringify!(#type_name), #source)
+            }
+        }
+        IntegerValidator::Any(combination) => {
+            let source = &combination.source;
+            quote! {
+                 #error_type_name::AnyViolated => write!(f, "{} does not satisfy `any({})`.", stringify!(#type_name), #source)
+            }
+        }
+        IntegerValidator::Not(combination) => {
+            let source = &combination.source;
+            quote! {
+                 #error_type_name::NotViolated => write!(f, "{} must not satisfy `{}`.", stringify!(#type_name), #source)
+            }
+        }
         IntegerValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
@@ -91,3 +175,15 @@ fn gen_impl_display_trait<T: ToTokens>(
         }
     }
 }
+
+/// Write the values separated by commas, e.g. `1, 2, 5`.
+fn gen_write_values<T: ToTokens>(values: &[T]) -> TokenStream {
+    quote!(
+        for (index, value) in [#(#values),*].iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+    )
+}
@@ -0,0 +1,144 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::{
+    gen::error::{gen_from_float_error_type_name, gen_impl_error_trait},
+    models::{ErrorTypeName, FromFloat, FromFloatErrorTypeName, TypeName},
+};
+
+/// Generate `TryFrom<f64>` for integer based types together with the error it returns.
+/// The float is rounded according to the given mode first, and then the value goes through the
+/// regular sanitization and validation.
+pub fn gen_impl_try_from_float(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    from_float: FromFloat,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let from_float_error_type_name = gen_from_float_error_type_name(type_name);
+    let def_error = gen_def_from_float_error(
+        &inner_type,
+        type_name,
+        from_float,
+        maybe_error_type_name,
+        &from_float_error_type_name,
+    );
+
+    // Rounding is done by hand, because `f64::round()` and friends are not available in `no_std`.
+    let rounded = match from_float {
+        FromFloat::Floor => quote!(if fraction < 0.0 {
+            truncated - 1.0
+        } else {
+            truncated
+        }),
+        FromFloat::Ceil => quote!(if fraction > 0.0 {
+            truncated + 1.0
+        } else {
+            truncated
+        }),
+        // Half-way cases are rounded away from zero, the same way as `f64::round()` does.
+        FromFloat::Round => quote!(if fraction >= 0.5 {
+            truncated + 1.0
+        } else if fraction <= -0.5 {
+            truncated - 1.0
+        } else {
+            truncated
+        }),
+        FromFloat::Exact => quote!({
+            if fraction != 0.0 {
+                return Err(#from_float_error_type_name::Fractional);
+            }
+            truncated
+        }),
+    };
+
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote!(Self::new(raw_value).map_err(#from_float_error_type_name::Validate))
+    } else {
+        quote!(Ok(Self::new(raw_value)))
+    };
+
+    quote! {
+        #def_error
+
+        impl #impl_generics ::core::convert::TryFrom<f64> for #type_name #ty_generics #where_clause {
+            type Error = #from_float_error_type_name;
+
+            fn try_from(value: f64) -> ::core::result::Result<Self, Self::Error> {
+                if !value.is_finite() {
+                    return Err(#from_float_error_type_name::NotFinite);
+                }
+
+                // Floats of such magnitude have no fractional part, the smaller ones fit into i64.
+                let truncated = if value <= -4503599627370496.0 || value >= 4503599627370496.0 {
+                    value
+                } else {
+                    value as i64 as f64
+                };
+                let fraction = value - truncated;
+                let rounded: f64 = #rounded;
+
+                // `MAX as f64` may be rounded up to the next power of two, so the upper boundary
+                // is exclusive.
+                if rounded < (<#inner_type>::MIN as f64) || rounded >= (<#inner_type>::MAX as f64) + 1.0 {
+                    return Err(#from_float_error_type_name::OutOfRange);
+                }
+                let raw_value = rounded as #inner_type;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+fn gen_def_from_float_error(
+    inner_type: impl ToTokens,
+    type_name: &TypeName,
+    from_float: FromFloat,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    from_float_error_type_name: &FromFloatErrorTypeName,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    let inner_type_str = inner_type.to_token_stream().to_string();
+
+    let (fractional_variant, fractional_display) = match from_float {
+        FromFloat::Exact => (
+            quote!(Fractional,),
+            quote!(#from_float_error_type_name::Fractional => write!(f, "Failed to convert {} from float: the value has a fractional part", #type_name_str),),
+        ),
+        FromFloat::Floor | FromFloat::Ceil | FromFloat::Round => (quote!(), quote!()),
+    };
+    let (validate_variant, validate_display) = match maybe_error_type_name {
+        Some(error_type_name) => (
+            quote!(Validate(#error_type_name),),
+            quote!(#from_float_error_type_name::Validate(err) => write!(f, "Failed to convert {} from float: {}", #type_name_str, err),),
+        ),
+        None => (quote!(), quote!()),
+    };
+    let impl_error_trait = gen_impl_error_trait(from_float_error_type_name);
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum #from_float_error_type_name {
+            NotFinite,
+            OutOfRange,
+            #fractional_variant
+            #validate_variant
+        }
+
+        impl ::core::fmt::Display for #from_float_error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #from_float_error_type_name::NotFinite => write!(f, "Failed to convert {} from float: the value is not finite", #type_name_str),
+                    #from_float_error_type_name::OutOfRange => write!(f, "Failed to convert {} from float: the value is out of range of {}", #type_name_str, #inner_type_str),
+                    #fractional_display
+                    #validate_display
+                }
+            }
+        }
+
+        #impl_error_trait
+    }
+}
@@ -1,12 +1,22 @@
 pub mod error;
+mod from_float;
+mod sql_check;
 pub mod traits;
+mod zod;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
-use self::{error::gen_validation_error_type, traits::gen_traits};
+use self::{
+    error::{gen_error_variant, gen_validation_error_type},
+    from_float::gen_impl_try_from_float,
+    sql_check::gen_sql_conditions,
+    traits::gen_traits,
+    zod::gen_zod_methods,
+};
 use super::{
     models::{
         IntegerDeriveTrait, IntegerGuard, IntegerInnerType, IntegerSanitizer, IntegerType,
@@ -17,14 +27,18 @@ use super::{
 use crate::common::{
     gen::{
         error::gen_error_type_name,
+        gen_phantom_value,
+        sql_check::gen_impl_sql_check,
         tests::{
+            gen_kani_proof_should_satisfy_validators,
             gen_test_should_have_consistent_lower_and_upper_boundaries,
             gen_test_should_have_valid_default_value,
         },
         traits::GeneratedTraits,
-        GenerateNewtype,
+        zod::gen_impl_zod_schema,
+        GenerateNewtype, InnerStorage,
     },
-    models::{ErrorTypeName, Guard, TypeName},
+    models::{ArithmeticPolicy, ErrorTypeName, FromFloat, Guard, TypeName},
 };
 
 impl<T> GenerateNewtype for IntegerNewtype<T>
@@ -36,6 +50,11 @@ where
     type InnerType = IntegerInnerType;
     type TypedTrait = IntegerDeriveTrait;
 
+    const HAS_PARSE_CONSTRUCTOR: bool = true;
+    const HAS_ARITHMETIC_OVERFLOW: bool = true;
+    const HAS_CONST_PARAM_INNER_TYPE: bool = true;
+    const HAS_PARSE_RADIX_CONSTRUCTOR: bool = true;
+
     fn gen_fn_sanitize(
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
@@ -71,46 +90,20 @@ where
 
         let validations: TokenStream = validators
             .iter()
-            .map(|validator| match validator {
-                IntegerValidator::Less(exclusive_upper_bound) => {
-                    quote!(
-                        if val >= #exclusive_upper_bound {
-                            return Err(#error_name::LessViolated);
-                        }
-                    )
-                }
-                IntegerValidator::LessOrEqual(max) => {
-                    quote!(
-                        if val > #max {
-                            return Err(#error_name::LessOrEqualViolated);
-                        }
-                    )
-                }
-                IntegerValidator::Greater(exclusive_lower_bound) => {
-                    quote!(
-                        if val <= #exclusive_lower_bound {
-                            return Err(#error_name::GreaterViolated);
-                        }
-                    )
-                }
-                IntegerValidator::GreaterOrEqual(min) => {
-                    quote!(
-                        if val < #min {
-                            return Err(#error_name::GreaterOrEqualViolated);
-                        }
-                    )
-                }
-                IntegerValidator::Predicate(custom_is_valid_fn) => {
-                    quote!(
-                        if !(#custom_is_valid_fn)(&val) {
-                            return Err(#error_name::PredicateViolated);
-                        }
-                    )
-                }
+            .map(|validator| {
+                let condition = gen_condition(inner_type, validator);
+                let error_variant = gen_error_variant(validator);
+                quote!(
+                    if !(#condition) {
+                        return Err(#error_name::#error_variant);
+                    }
+                )
             })
             .collect();
 
         quote!(
+            // Bounds combined with `all` look like a hand-written range check.
+            #[allow(clippy::manual_range_contains)]
             fn validate(val: &#inner_type) -> ::core::result::Result<(), #error_name> {
                 let val = *val;
                 #validations
@@ -121,32 +114,131 @@ where
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        generics: &Generics,
         validators: &[Self::Validator],
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, generics, validators)
     }
 
+    fn gen_impl_try_from_float(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        from_float: FromFloat,
+        maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> TokenStream {
+        gen_impl_try_from_float(
+            type_name,
+            generics,
+            inner_type,
+            from_float,
+            maybe_error_type_name,
+        )
+    }
+
+    fn gen_sql_check(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+    ) -> Result<TokenStream, syn::Error> {
+        let conditions = gen_sql_conditions(inner_type, validators)?;
+        Ok(gen_impl_sql_check(type_name, generics, conditions))
+    }
+
+    fn gen_inner_storage(
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> InnerStorage {
+        let is_nonzero = guard.validators().is_some_and(|validators| {
+            validators
+                .iter()
+                .any(|v| matches!(v, IntegerValidator::Nonzero))
+        });
+        if is_nonzero {
+            InnerStorage::NonZero(inner_type.nonzero_type())
+        } else {
+            InnerStorage::Plain
+        }
+    }
+
+    fn is_new_const(guard: &Guard<Self::Sanitizer, Self::Validator>) -> bool {
+        let validators = guard.validators().map_or(&[][..], |v| v.as_slice());
+        guard.sanitizers().is_empty()
+            && validators.iter().all(|v| {
+                matches!(
+                    v,
+                    IntegerValidator::Greater(_)
+                        | IntegerValidator::GreaterOrEqual(_)
+                        | IntegerValidator::Less(_)
+                        | IntegerValidator::LessOrEqual(_)
+                        | IntegerValidator::Nonzero
+                )
+            })
+    }
+
+    fn gen_validator_methods(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+        storage: &InnerStorage,
+    ) -> TokenStream {
+        let impl_log2 = gen_impl_log2(type_name, generics, validators);
+        let impl_all = gen_impl_all(type_name, generics, inner_type, validators, storage);
+        let impl_range = gen_impl_range(type_name, generics, storage);
+        let impl_saturating = gen_impl_saturating(type_name, generics, inner_type, validators);
+        let impl_new_or_clamp = gen_impl_new_or_clamp(type_name, generics, inner_type, validators);
+        quote!(
+            #impl_log2
+            #impl_all
+            #impl_range
+            #impl_saturating
+            #impl_new_or_clamp
+        )
+    }
+
+    fn gen_zod_schema(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> Result<TokenStream, syn::Error> {
+        let methods = gen_zod_methods(inner_type, guard)?;
+        Ok(gen_impl_zod_schema(
+            type_name,
+            generics,
+            "z.number().int()",
+            methods,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_arithmetic_policy: Option<ArithmeticPolicy>,
         guard: &IntegerGuard<T>,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
             inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            maybe_arithmetic_policy,
             guard,
         )
     }
 
     fn gen_tests(
         type_name: &TypeName,
-        _inner_type: &Self::InnerType,
+        inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         _traits: &HashSet<Self::TypedTrait>,
@@ -161,9 +253,293 @@ where
             guard.has_validation(),
         );
 
+        let proof_satisfy_validators =
+            gen_kani_proof_should_satisfy_validators(type_name, inner_type, guard.has_validation());
+
         quote! {
             #test_lower_vs_upper
             #test_valid_default_value
+            #proof_satisfy_validators
+        }
+    }
+}
+
+/// Expression that is `true` if `val` passes the validator.
+fn gen_condition<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    validator: &IntegerValidator<T>,
+) -> TokenStream {
+    match validator {
+        IntegerValidator::Less(exclusive_upper_bound) => quote!(val < #exclusive_upper_bound),
+        IntegerValidator::LessOrEqual(max) => quote!(val <= #max),
+        IntegerValidator::Greater(exclusive_lower_bound) => quote!(val > #exclusive_lower_bound),
+        IntegerValidator::GreaterOrEqual(min) => quote!(val >= #min),
+        IntegerValidator::FitsBits(bits) => {
+            // `checked_shr` returns None when the shift is not less than the bit width of
+            // the type, which means that any value fits.
+            if inner_type.is_signed() {
+                let sign_bit = bits - 1;
+                quote!({
+                    let high_bits = val.checked_shr(#sign_bit).unwrap_or(0);
+                    high_bits == 0 || high_bits == -1
+                })
+            } else {
+                quote!(val.checked_shr(#bits).unwrap_or(0) == 0)
+            }
+        }
+        IntegerValidator::AlignedTo(alignment) | IntegerValidator::MultipleOf(alignment) => {
+            quote!(val % #alignment == 0)
+        }
+        IntegerValidator::Percentage => quote!((0..=100).contains(&val)),
+        IntegerValidator::Even => quote!(val % 2 == 0),
+        IntegerValidator::Odd => quote!(val % 2 != 0),
+        IntegerValidator::PowerOfTwo => quote!(val.is_power_of_two()),
+        IntegerValidator::Nonzero => quote!(val != 0),
+        IntegerValidator::OneOf(values) => quote!(matches!(val, #(#values)|*)),
+        IntegerValidator::NotIn(values) => quote!(!matches!(val, #(#values)|*)),
+        IntegerValidator::All(combination) => {
+            let conditions = combination
+                .validators
+                .iter()
+                .map(|v| gen_condition(inner_type, v));
+            quote!(#((#conditions))&&*)
+        }
+        IntegerValidator::Any(combination) => {
+            let conditions = combination
+                .validators
+                .iter()
+                .map(|v| gen_condition(inner_type, v));
+            quote!(#((#conditions))||*)
+        }
+        IntegerValidator::Not(combination) => {
+            let conditions = combination
+                .validators
+                .iter()
+                .map(|v| gen_condition(inner_type, v));
+            quote!(!(#((#conditions))&&*))
+        }
+        IntegerValidator::Predicate(custom_is_valid_fn) => quote!((#custom_is_valid_fn)(&val)),
+    }
+}
+
+/// `log2()` for types with `power_of_two` validator.
+fn gen_impl_log2<T>(
+    type_name: &TypeName,
+    generics: &Generics,
+    validators: &[IntegerValidator<T>],
+) -> TokenStream {
+    let has_power_of_two = validators
+        .iter()
+        .any(|v| matches!(v, IntegerValidator::PowerOfTwo));
+    if !has_power_of_two {
+        return quote!();
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Returns `n` of the value `2^n`, which is exact because the value is a power of
+            /// two.
+            #[inline]
+            pub fn log2(&self) -> u32 {
+                self.0.trailing_zeros()
+            }
+        }
+    )
+}
+
+/// `all()` and `count()` for types with both lower and upper bounds.
+fn gen_impl_all<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+    storage: &InnerStorage,
+) -> TokenStream {
+    let maybe_min = validators.iter().find_map(|validator| match validator {
+        IntegerValidator::GreaterOrEqual(min) => Some(quote!(#min)),
+        IntegerValidator::Greater(gt) => Some(quote!(#gt + 1)),
+        _ => None,
+    });
+    let maybe_max = validators.iter().find_map(|validator| match validator {
+        IntegerValidator::LessOrEqual(max) => Some(quote!(#max)),
+        IntegerValidator::Less(lt) => Some(quote!(#lt - 1)),
+        _ => None,
+    });
+    let (Some(min), Some(max)) = (maybe_min, maybe_max) else {
+        return quote!();
+    };
+
+    // Validated by the bounds alone every value between them is valid, so the values don't have
+    // to be walked over to count them.
+    let impl_count = if gen_bounds(inner_type, validators).is_some() {
+        quote!(
+            /// Returns the number of valid values.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number does not fit in `usize`.
+            pub fn count() -> usize {
+                let min: #inner_type = #min;
+                let max: #inner_type = #max;
+                if min > max {
+                    return 0;
+                }
+                ::core::primitive::usize::try_from(max.abs_diff(min))
+                    .ok()
+                    .and_then(|diff| diff.checked_add(1))
+                    .expect("The number of valid values does not fit in usize")
+            }
+        )
+    } else {
+        quote!(
+            /// Returns the number of valid values.
+            /// It walks over every value between the bounds, since not all of them are valid.
+            pub fn count() -> usize {
+                Self::all().count()
+            }
+        )
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    let stored_value = storage.gen_store(quote!(value));
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Iterates over every valid value in ascending order.
+            pub fn all() -> impl ::core::iter::Iterator<Item = Self> {
+                let min: #inner_type = #min;
+                let max: #inner_type = #max;
+                (min..=max)
+                    .filter(|value| Self::is_valid(value))
+                    .map(|value| Self(#stored_value #phantom_value))
+            }
+
+            #impl_count
+        }
+    )
+}
+
+/// `range(from, to)`, which walks over the valid values between two instances.
+fn gen_impl_range(
+    type_name: &TypeName,
+    generics: &Generics,
+    storage: &InnerStorage,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    let stored_value = storage.gen_store(quote!(value));
+    let from_value = storage.gen_load(quote!(from.0));
+    let to_value = storage.gen_load(quote!(to.0));
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Iterates over every valid value from `from` to `to` (inclusive) in ascending order.
+            /// The iterator is empty if `from` is greater than `to`.
+            pub fn range(from: Self, to: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                (#from_value..=#to_value)
+                    .filter(|value| Self::is_valid(value))
+                    .map(|value| Self(#stored_value #phantom_value))
+            }
+        }
+    )
+}
+
+/// `saturating_add()` and `saturating_sub()` for types validated by the bounds alone.
+/// The result saturates at the bounds of the validators rather than at the bounds of the inner type.
+fn gen_impl_saturating<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+) -> TokenStream {
+    let Some((min, max)) = gen_bounds(inner_type, validators) else {
+        return quote!();
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Adds `rhs`, saturating at the upper bound instead of violating it.
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                let min: #inner_type = #min;
+                let max: #inner_type = #max;
+                let value = self.0.saturating_add(rhs.0).clamp(min, max);
+                Self(value #phantom_value)
+            }
+
+            /// Subtracts `rhs`, saturating at the lower bound instead of violating it.
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                let min: #inner_type = #min;
+                let max: #inner_type = #max;
+                let value = self.0.saturating_sub(rhs.0).clamp(min, max);
+                Self(value #phantom_value)
+            }
         }
+    )
+}
+
+/// `::new_or_clamp()` for types validated by the bounds alone.
+/// The sanitized value is clamped to the bounds of the validators, so the construction can not fail.
+fn gen_impl_new_or_clamp<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+) -> TokenStream {
+    let Some((min, max)) = gen_bounds(inner_type, validators) else {
+        return quote!();
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    quote!(
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// Same as `::new()`, but clamps the value to the bounds instead of returning an error.
+            pub fn new_or_clamp(raw_value: #inner_type) -> Self {
+                let min: #inner_type = #min;
+                let max: #inner_type = #max;
+                let value = Self::sanitize(raw_value).clamp(min, max);
+                Self(value #phantom_value)
+            }
+        }
+    )
+}
+
+/// Returns the smallest and the largest valid values, if the type is validated by
+/// `greater`, `greater_or_equal`, `less` and `less_or_equal` alone.
+/// With any other validator the bounds are not necessarily valid values themselves.
+fn gen_bounds<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+) -> Option<(TokenStream, TokenStream)> {
+    let has_only_bounds = validators.iter().all(|validator| {
+        matches!(
+            validator,
+            IntegerValidator::Greater(_)
+                | IntegerValidator::GreaterOrEqual(_)
+                | IntegerValidator::Less(_)
+                | IntegerValidator::LessOrEqual(_)
+        )
+    });
+    if !has_only_bounds {
+        return None;
     }
+
+    let min = validators
+        .iter()
+        .find_map(|validator| match validator {
+            IntegerValidator::GreaterOrEqual(min) => Some(quote!(#min)),
+            IntegerValidator::Greater(gt) => Some(quote!(#gt + 1)),
+            _ => None,
+        })
+        .unwrap_or_else(|| quote!(#inner_type::MIN));
+    let max = validators
+        .iter()
+        .find_map(|validator| match validator {
+            IntegerValidator::LessOrEqual(max) => Some(quote!(#max)),
+            IntegerValidator::Less(lt) => Some(quote!(#lt - 1)),
+            _ => None,
+        })
+        .unwrap_or_else(|| quote!(#inner_type::MAX));
+    Some((min, max))
 }
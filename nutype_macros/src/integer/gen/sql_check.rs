@@ -0,0 +1,88 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::gen::sql_check::{gen_sql_between, gen_sql_comparison, unsupported_sql_validator},
+    integer::models::{IntegerInnerType, IntegerValidator},
+};
+
+pub fn gen_sql_conditions<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+) -> Result<Vec<TokenStream>, syn::Error> {
+    validators
+        .iter()
+        .map(|validator| gen_sql_condition(inner_type, validator))
+        .collect()
+}
+
+fn gen_sql_condition<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    validator: &IntegerValidator<T>,
+) -> Result<TokenStream, syn::Error> {
+    match validator {
+        IntegerValidator::Greater(gt) => Ok(gen_sql_comparison(">", gt, inner_type)),
+        IntegerValidator::GreaterOrEqual(gte) => Ok(gen_sql_comparison(">=", gte, inner_type)),
+        IntegerValidator::Less(lt) => Ok(gen_sql_comparison("<", lt, inner_type)),
+        IntegerValidator::LessOrEqual(lte) => Ok(gen_sql_comparison("<=", lte, inner_type)),
+        IntegerValidator::FitsBits(bits) => Ok(gen_sql_between(
+            quote!(#inner_type::MIN >> (#inner_type::BITS - #bits)),
+            quote!(#inner_type::MAX >> (#inner_type::BITS - #bits)),
+            inner_type,
+        )),
+        IntegerValidator::AlignedTo(alignment) | IntegerValidator::MultipleOf(alignment) => {
+            Ok(quote!({
+                let alignment: #inner_type = #alignment;
+                ::std::format!("MOD({}, {}) = 0", column, alignment)
+            }))
+        }
+        IntegerValidator::Percentage => Ok(gen_sql_between(quote!(0), quote!(100), inner_type)),
+        IntegerValidator::Even => Ok(quote!(::std::format!("MOD({}, 2) = 0", column))),
+        IntegerValidator::Odd => Ok(quote!(::std::format!("MOD({}, 2) <> 0", column))),
+        IntegerValidator::PowerOfTwo => Err(unsupported_sql_validator("power_of_two")),
+        IntegerValidator::Nonzero => Ok(quote!(::std::format!("{} <> 0", column))),
+        IntegerValidator::OneOf(values) => Ok(gen_sql_values("IN", values, inner_type)),
+        IntegerValidator::NotIn(values) => Ok(gen_sql_values("NOT IN", values, inner_type)),
+        IntegerValidator::All(combination) => {
+            gen_sql_combination(inner_type, &combination.validators, " AND ", "({})")
+        }
+        IntegerValidator::Any(combination) => {
+            gen_sql_combination(inner_type, &combination.validators, " OR ", "({})")
+        }
+        IntegerValidator::Not(combination) => {
+            gen_sql_combination(inner_type, &combination.validators, " AND ", "NOT ({})")
+        }
+        IntegerValidator::Predicate(_) => Err(unsupported_sql_validator("predicate")),
+    }
+}
+
+/// Join the conditions of the combined validators, e.g. `(MOD(n, 3) = 0 OR MOD(n, 5) = 0)`.
+fn gen_sql_combination<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    validators: &[IntegerValidator<T>],
+    separator: &str,
+    wrapper: &str,
+) -> Result<TokenStream, syn::Error> {
+    let conditions = validators
+        .iter()
+        .map(|validator| gen_sql_condition(inner_type, validator))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote!({
+        let conditions: &[::std::string::String] = &[#(#conditions),*];
+        ::std::format!(#wrapper, conditions.join(#separator))
+    }))
+}
+
+/// Condition that checks the column against a list of values, e.g. `n IN (1, 2, 5)`.
+fn gen_sql_values<T: ToTokens>(
+    op: &str,
+    values: &[T],
+    inner_type: &IntegerInnerType,
+) -> TokenStream {
+    quote!({
+        let values: &[#inner_type] = &[#(#values),*];
+        let values: ::std::vec::Vec<::std::string::String> =
+            values.iter().map(::std::string::ToString::to_string).collect();
+        ::std::format!("{} {} ({})", column, #op, values.join(", "))
+    })
+}
@@ -1,5 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::models::TypeName,
@@ -9,10 +10,11 @@ use crate::{
 
 pub fn gen_impl_trait_arbitrary<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &IntegerInnerType,
     guard: &IntegerGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
-    let Boundary { min, max } = guard_to_boundary(inner_type, guard)?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let construct_value = if guard.has_validation() {
         // If by some reason we generate an invalid value, make it very easy for the user to report
@@ -29,19 +31,197 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
         quote!(Self::new(inner_value))
     };
 
+    let gen_inner_value = match guard
+        .validators()
+        .and_then(|validators| find_one_of(validators))
+    {
+        Some(values) => gen_inner_value_from_one_of(inner_type, values),
+        None => gen_inner_value_within_boundary(inner_type, guard)?,
+    };
+
     Ok(quote!(
-        impl ::arbitrary::Arbitrary<'_> for #type_name {
+        impl #impl_generics ::arbitrary::Arbitrary<'_> for #type_name #ty_generics #where_clause {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
-                let inner_value: #inner_type = u.int_in_range((#min)..=(#max))?;
+                #gen_inner_value
                 Ok(#construct_value)
             }
+
+            #[inline]
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                // One byte picks the boundary, the rest is the offset from it.
+                let n = 1 + ::core::mem::size_of::<#inner_type>();
+                (n, Some(n))
+            }
         }
+    ))
+}
+
+fn find_one_of<T>(validators: &[IntegerValidator<T>]) -> Option<&[T]> {
+    validators.iter().find_map(|validator| match validator {
+        IntegerValidator::OneOf(values) => Some(values.as_slice()),
+        _ => None,
+    })
+}
 
-        #[inline]
-        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
-            let n = ::core::mem::size_of::<#inner_type>();
-            (n, Some(n))
+/// Pick one of the allowed values, which pass the other validators as well.
+fn gen_inner_value_from_one_of<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    values: &[T],
+) -> TokenStream {
+    quote!(
+        let allowed: &[#inner_type] = &[#(#values),*];
+        let count = allowed.iter().filter(|value| Self::is_valid(value)).count();
+        if count == 0 {
+            return Err(::arbitrary::Error::IncorrectFormat);
         }
+        let index: usize = u.int_in_range(0..=count - 1)?;
+        let inner_value: #inner_type = match allowed
+            .iter()
+            .filter(|value| Self::is_valid(value))
+            .nth(index)
+        {
+            Some(value) => *value,
+            None => return Err(::arbitrary::Error::IncorrectFormat),
+        };
+    )
+}
+
+fn gen_inner_value_within_boundary<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let Boundary {
+        min,
+        max,
+        alignment,
+        power_of_two,
+        excluded,
+    } = guard_to_boundary(inner_type, guard)?;
+
+    let zero_step = match alignment {
+        Some(Alignment { ref alignment, .. }) => quote!(#alignment),
+        None => quote!(1),
+    };
+
+    // The value is generated as an offset from one of the boundaries, and the first byte picks
+    // which one. Fuzzers shrink the input towards fewer and smaller bytes, so a failing value
+    // converges to the nearest declared boundary, which is usually the most informative case.
+    // Wrapping arithmetic is fine here: the offset always fits into the range, so the result
+    // does too.
+    let gen_inner_value = match alignment {
+        None if power_of_two => quote!(
+            // Generate the exponent of a power of two, so the result stays within the boundaries.
+            let from_max: bool = u.arbitrary()?;
+            let min: #inner_type = #min;
+            let max: #inner_type = #max;
+            if max < 1 {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            }
+            let lower: u32 = if min <= 1 { 0 } else { (min - 1).ilog2() + 1 };
+            let upper: u32 = max.ilog2();
+            if lower > upper {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            }
+            let exponent: u32 = u.int_in_range(lower..=upper)?;
+            let exponent = if from_max {
+                upper - (exponent - lower)
+            } else {
+                exponent
+            };
+            let one: #inner_type = 1;
+            let inner_value: #inner_type = one << exponent;
+        ),
+        None => quote!(
+            let from_max: bool = u.arbitrary()?;
+            let min: #inner_type = #min;
+            let max: #inner_type = #max;
+            let value: #inner_type = u.int_in_range(min..=max)?;
+            let inner_value: #inner_type = if from_max {
+                max.wrapping_sub(value.wrapping_sub(min))
+            } else {
+                value
+            };
+        ),
+        Some(Alignment {
+            alignment,
+            remainder,
+        }) => quote!(
+            // Generate a multiplier of the alignment, so the result stays within the boundaries.
+            // The value is `multiplier * alignment + remainder`.
+            let from_max: bool = u.arbitrary()?;
+            let alignment: #inner_type = #alignment;
+            let remainder: #inner_type = #remainder;
+            let min: #inner_type = #min;
+            let max: #inner_type = #max;
+            let mut lower = min.div_euclid(alignment);
+            if min.rem_euclid(alignment) > remainder {
+                lower += 1;
+            }
+            let mut upper = max.div_euclid(alignment);
+            if max.rem_euclid(alignment) < remainder {
+                upper -= 1;
+            }
+            if lower > upper {
+                return Err(::arbitrary::Error::IncorrectFormat);
+            }
+            let multiplier: #inner_type = u.int_in_range(lower..=upper)?;
+            let multiplier = if from_max {
+                upper.wrapping_sub(multiplier.wrapping_sub(lower))
+            } else {
+                multiplier
+            };
+            let inner_value: #inner_type = multiplier * alignment + remainder;
+        ),
+    };
+
+    // An excluded value is moved step by step (1, the alignment or a factor of 2 for powers of
+    // two) towards a boundary, until a value that is not excluded is found. The result stays
+    // within the boundaries and keeps the alignment.
+    let skip_excluded = if excluded.is_empty() {
+        quote!()
+    } else {
+        let (step_up, step_down) = if power_of_two {
+            (
+                quote!(value.checked_mul(2)),
+                quote!(::core::option::Option::Some(value / 2).filter(|value| *value != 0)),
+            )
+        } else {
+            (
+                quote!(value.checked_add(#zero_step)),
+                quote!(value.checked_sub(#zero_step)),
+            )
+        };
+        quote!(
+            let excluded: &[#inner_type] = &[#(#excluded),*];
+            let inner_value: #inner_type = if !excluded.contains(&inner_value) {
+                inner_value
+            } else {
+                let max: #inner_type = #max;
+                let min: #inner_type = #min;
+                let is_allowed = |value: &#inner_type| !excluded.contains(value);
+                let next_up = |value: #inner_type| #step_up.filter(|value| *value <= max);
+                let next_down = |value: #inner_type| #step_down.filter(|value| *value >= min);
+                // Every step either finds an allowed value or goes over an excluded one, so
+                // the number of steps is limited by the number of the excluded values.
+                let mut up = next_up(inner_value);
+                while let Some(value) = up.filter(|value| !is_allowed(value)) {
+                    up = next_up(value);
+                }
+                let mut down = next_down(inner_value);
+                while let Some(value) = down.filter(|value| !is_allowed(value)) {
+                    down = next_down(value);
+                }
+                match up.or(down) {
+                    Some(value) => value,
+                    None => return Err(::arbitrary::Error::IncorrectFormat),
+                }
+            };
+        )
+    };
+
+    Ok(quote!(
+        #gen_inner_value
+        #skip_excluded
     ))
 }
 
@@ -49,15 +229,30 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
 struct Boundary {
     min: TokenStream,
     max: TokenStream,
+    alignment: Option<Alignment>,
+    power_of_two: bool,
+    excluded: Vec<TokenStream>,
+}
+
+/// Valid values are `multiplier * alignment + remainder`.
+#[derive(Debug)]
+struct Alignment {
+    alignment: TokenStream,
+    remainder: TokenStream,
 }
 
 fn guard_to_boundary<T: ToTokens>(
     inner_type: &IntegerInnerType,
     guard: &IntegerGuard<T>,
 ) -> Result<Boundary, syn::Error> {
+    let mut maybe_fits_bits = None;
+    let mut is_percentage = false;
     let mut boundary = Boundary {
         min: quote!(#inner_type::MIN),
         max: quote!(#inner_type::MAX),
+        alignment: None,
+        power_of_two: false,
+        excluded: Vec::new(),
     };
 
     match guard {
@@ -85,6 +280,45 @@ fn guard_to_boundary<T: ToTokens>(
                     IntegerValidator::LessOrEqual(lte) => {
                         boundary.max = quote!(#lte);
                     }
+                    IntegerValidator::FitsBits(bits) => {
+                        maybe_fits_bits = Some(*bits);
+                    }
+                    IntegerValidator::AlignedTo(alignment)
+                    | IntegerValidator::MultipleOf(alignment) => {
+                        set_alignment(&mut boundary, quote!(#alignment), quote!(0))?;
+                    }
+                    IntegerValidator::Even => {
+                        set_alignment(&mut boundary, quote!(2), quote!(0))?;
+                    }
+                    IntegerValidator::Odd => {
+                        set_alignment(&mut boundary, quote!(2), quote!(1))?;
+                    }
+                    IntegerValidator::PowerOfTwo => {
+                        boundary.power_of_two = true;
+                    }
+                    IntegerValidator::Percentage => {
+                        is_percentage = true;
+                    }
+                    IntegerValidator::Nonzero => {
+                        boundary.excluded.push(quote!(0));
+                    }
+                    IntegerValidator::NotIn(values) => {
+                        boundary
+                            .excluded
+                            .extend(values.iter().map(|value| quote!(#value)));
+                    }
+                    IntegerValidator::OneOf(_) => {
+                        // The values are picked from the list, so there are no boundaries.
+                    }
+                    IntegerValidator::All(_)
+                    | IntegerValidator::Any(_)
+                    | IntegerValidator::Not(_) => {
+                        let msg = format!(
+                            "Cannot derive trait `Arbitrary` for a type with `{}` validator",
+                            validator.kind()
+                        );
+                        return Err(syn::Error::new(proc_macro2::Span::call_site(), msg));
+                    }
                     IntegerValidator::Predicate(_) => {
                         return Err(syn::Error::new(
                             proc_macro2::Span::call_site(),
@@ -96,5 +330,67 @@ fn guard_to_boundary<T: ToTokens>(
         }
     }
 
+    // Narrow the boundaries down to the values that fit in the given number of bits.
+    // An arithmetic shift of MIN and MAX gives the range for both signed and unsigned types.
+    if let Some(bits) = maybe_fits_bits {
+        let Boundary {
+            min,
+            max,
+            alignment,
+            power_of_two,
+            excluded,
+        } = boundary;
+        boundary = Boundary {
+            min: quote!(::core::cmp::max(#min, #inner_type::MIN >> (#inner_type::BITS - #bits))),
+            max: quote!(::core::cmp::min(#max, #inner_type::MAX >> (#inner_type::BITS - #bits))),
+            alignment,
+            power_of_two,
+            excluded,
+        };
+    }
+
+    // Narrow the boundaries down to the range of percentage, respecting the explicit bounds.
+    if is_percentage {
+        let Boundary {
+            min,
+            max,
+            alignment,
+            power_of_two,
+            excluded,
+        } = boundary;
+        boundary = Boundary {
+            min: quote!(::core::cmp::max(#min, 0)),
+            max: quote!(::core::cmp::min(#max, 100)),
+            alignment,
+            power_of_two,
+            excluded,
+        };
+    }
+
+    if boundary.power_of_two && boundary.alignment.is_some() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "Cannot derive trait `Arbitrary` for a type with `power_of_two` and any of `aligned_to`, `multiple_of`, `even` and `odd` validators",
+        ));
+    }
+
     Ok(boundary)
 }
+
+fn set_alignment(
+    boundary: &mut Boundary,
+    alignment: TokenStream,
+    remainder: TokenStream,
+) -> Result<(), syn::Error> {
+    if boundary.alignment.is_some() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "Cannot derive trait `Arbitrary` for a type with more than one of `aligned_to`, `multiple_of`, `even` and `odd` validators",
+        ));
+    }
+    boundary.alignment = Some(Alignment {
+        alignment,
+        remainder,
+    });
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//! `Add`, `Sub`, `Mul`, `Neg` and the compound assignment traits.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{
+            traits::{
+                gen_impl_trait_arithmetic_assign_operator, gen_impl_trait_arithmetic_operator,
+                gen_impl_trait_neg_operator, gen_missing_arithmetic_policy_error,
+                ArithmeticOperator, ArithmeticRhs,
+            },
+            InnerStorage,
+        },
+        models::{ArithmeticPolicy, ErrorTypeName, TypeName},
+    },
+    integer::models::{IntegerGuard, IntegerInnerType},
+};
+
+use crate::integer::gen::gen_bounds;
+
+#[allow(clippy::too_many_arguments)]
+pub fn gen_impl_trait_arithmetic<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    guard: &IntegerGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+    rhs_kind: ArithmeticRhs,
+) -> Result<TokenStream, syn::Error> {
+    let trait_name = match rhs_kind {
+        ArithmeticRhs::Newtype => format!("{operator:?}"),
+        ArithmeticRhs::Inner => format!("{operator:?}<Inner>"),
+    };
+    let raw_value = gen_raw_value(
+        type_name,
+        inner_type,
+        guard,
+        maybe_arithmetic_policy,
+        operator,
+        &trait_name,
+    )?;
+    Ok(gen_impl_trait_arithmetic_operator(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        storage,
+        operator,
+        rhs_kind,
+        raw_value,
+    ))
+}
+
+pub fn gen_impl_trait_arithmetic_assign<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    storage: &InnerStorage,
+    guard: &IntegerGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+) -> Result<TokenStream, syn::Error> {
+    let raw_value = gen_raw_value(
+        type_name,
+        inner_type,
+        guard,
+        maybe_arithmetic_policy,
+        operator,
+        &format!("{operator:?}Assign"),
+    )?;
+    Ok(gen_impl_trait_arithmetic_assign_operator(
+        type_name, generics, inner_type, storage, operator, raw_value,
+    ))
+}
+
+/// Compute the result of the operation on the inner values `lhs` and `rhs` according to the
+/// policy.
+fn gen_raw_value<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+    operator: ArithmeticOperator,
+    trait_name: &str,
+) -> Result<TokenStream, syn::Error> {
+    let method = operator.method();
+    let raw_value = match maybe_arithmetic_policy {
+        Some(ArithmeticPolicy::Saturate) => {
+            let Some((min, max)) =
+                gen_bounds(inner_type, guard.validators().map_or(&[], |v| v.as_slice()))
+            else {
+                let msg = format!("`arithmetic = saturate` can be used for type {type_name} only if it's validated by `greater`, `greater_or_equal`, `less` and `less_or_equal` alone.");
+                return Err(syn::Error::new(Span::call_site(), msg));
+            };
+            let saturating_method = format_ident!("saturating_{method}");
+            quote!(lhs.#saturating_method(rhs).clamp(#min, #max))
+        }
+        Some(ArithmeticPolicy::Wrapping) => {
+            let wrapping_method = format_ident!("wrapping_{method}");
+            let residue = match operator {
+                ArithmeticOperator::Add => {
+                    quote!(((lhs as i128).rem_euclid(span) + (rhs as i128).rem_euclid(span)) % span)
+                }
+                ArithmeticOperator::Sub => quote!(((lhs as i128).rem_euclid(span)
+                    - (rhs as i128).rem_euclid(span))
+                .rem_euclid(span)),
+                ArithmeticOperator::Mul => quote!(
+                    ((lhs as i128).rem_euclid(span) as u128
+                        * (rhs as i128).rem_euclid(span) as u128
+                        % span as u128) as i128
+                ),
+            };
+            gen_wrapping_raw_value(
+                type_name,
+                inner_type,
+                guard,
+                quote!(lhs.#wrapping_method(rhs)),
+                residue,
+            )?
+        }
+        Some(ArithmeticPolicy::Panic) => {
+            let checked_method = format_ident!("checked_{method}");
+            let tp = type_name.to_string();
+            quote!(
+                lhs.#checked_method(rhs).unwrap_or_else(|| {
+                    let tp = #tp;
+                    panic!("\nArithmetic operation on `{tp}` overflowed.\n");
+                })
+            )
+        }
+        None if guard.has_validation() => {
+            return Err(gen_missing_arithmetic_policy_error(type_name, trait_name));
+        }
+        None => {
+            let operator = operator.operator();
+            quote!(lhs #operator rhs)
+        }
+    };
+    Ok(raw_value)
+}
+
+pub fn gen_impl_trait_neg<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    storage: &InnerStorage,
+    guard: &IntegerGuard<T>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+) -> Result<TokenStream, syn::Error> {
+    if !inner_type.is_signed() {
+        let msg = format!("Trait `Neg` cannot be derived for type {type_name}, because `{inner_type}` is unsigned.");
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    let raw_value = match maybe_arithmetic_policy {
+        Some(ArithmeticPolicy::Saturate) => {
+            let Some((min, max)) =
+                gen_bounds(inner_type, guard.validators().map_or(&[], |v| v.as_slice()))
+            else {
+                let msg = format!("`arithmetic = saturate` can be used for type {type_name} only if it's validated by `greater`, `greater_or_equal`, `less` and `less_or_equal` alone.");
+                return Err(syn::Error::new(Span::call_site(), msg));
+            };
+            quote!(value.saturating_neg().clamp(#min, #max))
+        }
+        Some(ArithmeticPolicy::Wrapping) => gen_wrapping_raw_value(
+            type_name,
+            inner_type,
+            guard,
+            quote!(value.wrapping_neg()),
+            quote!((-(value as i128)).rem_euclid(span)),
+        )?,
+        Some(ArithmeticPolicy::Panic) => {
+            let tp = type_name.to_string();
+            quote!(
+                value.checked_neg().unwrap_or_else(|| {
+                    let tp = #tp;
+                    panic!("\nArithmetic operation on `{tp}` overflowed.\n");
+                })
+            )
+        }
+        None if guard.has_validation() => {
+            return Err(gen_missing_arithmetic_policy_error(type_name, "Neg"));
+        }
+        None => quote!(-value),
+    };
+
+    Ok(gen_impl_trait_neg_operator(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        storage,
+        raw_value,
+    ))
+}
+
+/// Wrap the result around the range defined by the boundaries, e.g. `22 + 5` gives `3` for a type
+/// validated with `less_or_equal = 23`.
+/// Without validation the result wraps around the inner type with `wrapping_*` methods.
+/// Otherwise `residue` computes the result modulo `span` (the size of the range) in `i128`, which
+/// is then shifted into the range.
+fn gen_wrapping_raw_value<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+    unbounded: TokenStream,
+    residue: TokenStream,
+) -> Result<TokenStream, syn::Error> {
+    let validators = guard.validators().map_or(&[][..], |v| v.as_slice());
+    if validators.is_empty() {
+        return Ok(unbounded);
+    }
+    let Some((min, max)) = gen_bounds(inner_type, validators) else {
+        let msg = format!("`arithmetic = wrapping` can be used for type {type_name} only if it's validated by `greater`, `greater_or_equal`, `less` and `less_or_equal` alone.");
+        return Err(syn::Error::new(Span::call_site(), msg));
+    };
+    if matches!(inner_type, IntegerInnerType::U128 | IntegerInnerType::I128) {
+        let msg = format!("`arithmetic = wrapping` cannot be used for type {type_name} with validation, because `{inner_type}` has no wider type to compute the result in.");
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    Ok(quote!({
+        let (min, max): (#inner_type, #inner_type) = (#min, #max);
+        let (min, max): (i128, i128) = (min as i128, max as i128);
+        let span: i128 = max - min + 1;
+        let residue: i128 = #residue;
+        (min + (residue - min).rem_euclid(span)) as #inner_type
+    }))
+}
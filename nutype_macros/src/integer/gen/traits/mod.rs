@@ -1,32 +1,47 @@
 mod arbitrary;
+mod arithmetic;
+mod nonzero;
+mod num_traits;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
+                gen_impl_trait_from_str_via_parse, gen_impl_trait_into,
+                gen_impl_trait_num_traits_from_primitive, gen_impl_trait_num_traits_to_primitive,
+                gen_impl_trait_partial_eq_inner, gen_impl_trait_partial_ord_inner,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_sum_or_product, gen_impl_trait_try_from,
+                gen_impl_trait_try_from_str_via_parse, split_into_generatable_traits,
+                ArithmeticOperator, ArithmeticRhs, Fold, GeneratableTrait, GeneratableTraits,
+                GeneratedTraits,
+            },
+            InnerStorage,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ArithmeticPolicy, ErrorTypeName, TypeName},
     },
-    integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerInnerType},
+    integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerInnerType, IntegerValidator},
 };
 
 type IntegerGeneratableTrait = GeneratableTrait<IntegerTransparentTrait, IntegerIrregularTrait>;
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &IntegerInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<IntegerDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
     guard: &IntegerGuard<T>,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
@@ -42,10 +57,12 @@ pub fn gen_traits<T: ToTokens>(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
         inner_type,
         maybe_error_type_name,
         irregular_traits,
         maybe_default_value,
+        maybe_arithmetic_policy,
         guard,
     )?;
 
@@ -70,6 +87,12 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::PartialEq => {
                 IntegerGeneratableTrait::Transparent(IntegerTransparentTrait::PartialEq)
             }
+            IntegerDeriveTrait::PartialEqInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::PartialEqInner)
+            }
+            IntegerDeriveTrait::PartialOrdInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::PartialOrdInner)
+            }
             IntegerDeriveTrait::Eq => {
                 IntegerGeneratableTrait::Transparent(IntegerTransparentTrait::Eq)
             }
@@ -100,6 +123,9 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::TryFrom => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::TryFrom)
             }
+            IntegerDeriveTrait::TryFromStr => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::TryFromStr)
+            }
             IntegerDeriveTrait::Borrow => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Borrow)
             }
@@ -109,6 +135,54 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::Default => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Default)
             }
+            IntegerDeriveTrait::LowerHex => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::LowerHex)
+            }
+            IntegerDeriveTrait::UpperHex => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::UpperHex)
+            }
+            IntegerDeriveTrait::Binary => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Binary)
+            }
+            IntegerDeriveTrait::Octal => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Octal)
+            }
+            IntegerDeriveTrait::Sum => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sum)
+            }
+            IntegerDeriveTrait::Product => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Product)
+            }
+            IntegerDeriveTrait::Add => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Add)
+            }
+            IntegerDeriveTrait::Sub => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sub)
+            }
+            IntegerDeriveTrait::Mul => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Mul)
+            }
+            IntegerDeriveTrait::AddInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::AddInner)
+            }
+            IntegerDeriveTrait::SubInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SubInner)
+            }
+            IntegerDeriveTrait::MulInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::MulInner)
+            }
+            IntegerDeriveTrait::Neg => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Neg)
+            }
+            IntegerDeriveTrait::AddAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::AddAssign)
+            }
+            IntegerDeriveTrait::SubAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SubAssign)
+            }
+            IntegerDeriveTrait::MulAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::MulAssign)
+            }
             IntegerDeriveTrait::SerdeSerialize => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeSerialize)
             }
@@ -121,6 +195,21 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::ArbitraryArbitrary => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ArbitraryArbitrary)
             }
+            IntegerDeriveTrait::NumTraitsToPrimitive => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsToPrimitive)
+            }
+            IntegerDeriveTrait::NumTraitsFromPrimitive => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsFromPrimitive)
+            }
+            IntegerDeriveTrait::NumTraitsBounded => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsBounded)
+            }
+            IntegerDeriveTrait::NumTraitsZero => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsZero)
+            }
+            IntegerDeriveTrait::NumTraitsOne => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsOne)
+            }
         }
     }
 }
@@ -143,18 +232,42 @@ enum IntegerTransparentTrait {
 /// an implementation for it.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 enum IntegerIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
     FromStr,
     AsRef,
     Deref,
     From,
     TryFrom,
+    TryFromStr,
     Borrow,
     Into,
     Display,
     Default,
+    LowerHex,
+    UpperHex,
+    Binary,
+    Octal,
+    Sum,
+    Product,
+    Add,
+    Sub,
+    Mul,
+    AddInner,
+    SubInner,
+    MulInner,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    NumTraitsBounded,
+    NumTraitsZero,
+    NumTraitsOne,
 }
 
 impl ToTokens for IntegerTransparentTrait {
@@ -174,34 +287,64 @@ impl ToTokens for IntegerTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: &IntegerInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     impl_traits: Vec<IntegerIrregularTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_arithmetic_policy: Option<ArithmeticPolicy>,
     guard: &IntegerGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
+    let is_nonzero = guard.validators().is_some_and(|validators| {
+        validators
+            .iter()
+            .any(|v| matches!(v, IntegerValidator::Nonzero))
+    });
+    let storage = if is_nonzero {
+        InnerStorage::NonZero(inner_type.nonzero_type())
+    } else {
+        InnerStorage::Plain
+    };
+
     impl_traits
         .iter()
         .map(|t| match t {
-            IntegerIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            IntegerIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
-            IntegerIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
-            }
-            IntegerIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            IntegerIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
+            IntegerIrregularTrait::AsRef if is_nonzero => Ok(nonzero::gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            IntegerIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Deref if is_nonzero => Ok(nonzero::gen_impl_trait_deref(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            IntegerIrregularTrait::FromStr => Ok(gen_impl_trait_from_str_via_parse(type_name, generics)),
+            IntegerIrregularTrait::TryFromStr => Ok(gen_impl_trait_try_from_str_via_parse(type_name, generics)),
+            IntegerIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
             IntegerIrregularTrait::TryFrom => {
-                Ok(gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_try_from(type_name, generics, inner_type, maybe_error_type_name.as_ref()))
+            }
+            IntegerIrregularTrait::PartialEqInner => Ok(gen_impl_trait_partial_eq_inner(type_name, generics, inner_type, &storage)),
+            IntegerIrregularTrait::PartialOrdInner => Ok(gen_impl_trait_partial_ord_inner(type_name, generics, inner_type, &storage)),
+            IntegerIrregularTrait::Borrow if is_nonzero => Ok(nonzero::gen_impl_trait_borrow(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics)),
+            IntegerIrregularTrait::LowerHex => {
+                Ok(gen_impl_trait_fmt(type_name, generics, quote!(::core::fmt::LowerHex)))
+            }
+            IntegerIrregularTrait::UpperHex => {
+                Ok(gen_impl_trait_fmt(type_name, generics, quote!(::core::fmt::UpperHex)))
+            }
+            IntegerIrregularTrait::Binary => {
+                Ok(gen_impl_trait_fmt(type_name, generics, quote!(::core::fmt::Binary)))
+            }
+            IntegerIrregularTrait::Octal => {
+                Ok(gen_impl_trait_fmt(type_name, generics, quote!(::core::fmt::Octal)))
             }
-            IntegerIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
-            IntegerIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
             IntegerIrregularTrait::Default => {
                 match maybe_default_value {
                     Some(ref default_value) => {
                         let has_validation = maybe_error_type_name.is_some();
-                        Ok(gen_impl_trait_default(type_name, default_value, has_validation))
+                        Ok(gen_impl_trait_default(type_name, generics, default_value, has_validation))
                     },
                     None => {
                         let span = proc_macro2::Span::call_site();
@@ -210,15 +353,173 @@ fn gen_implemented_traits<T: ToTokens>(
                     }
                 }
             }
-            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
+            IntegerIrregularTrait::Sum => Ok(gen_impl_trait_sum_or_product(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                Fold::Sum,
+            )),
+            IntegerIrregularTrait::Product => Ok(gen_impl_trait_sum_or_product(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                Fold::Product,
+            )),
+            IntegerIrregularTrait::Add => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+                ArithmeticRhs::Newtype,
+            ),
+            IntegerIrregularTrait::AddInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+                ArithmeticRhs::Inner,
+            ),
+            IntegerIrregularTrait::Sub => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+                ArithmeticRhs::Newtype,
+            ),
+            IntegerIrregularTrait::SubInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+                ArithmeticRhs::Inner,
+            ),
+            IntegerIrregularTrait::Mul => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+                ArithmeticRhs::Newtype,
+            ),
+            IntegerIrregularTrait::MulInner => arithmetic::gen_impl_trait_arithmetic(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+                ArithmeticRhs::Inner,
+            ),
+            IntegerIrregularTrait::Neg => arithmetic::gen_impl_trait_neg(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+            ),
+            IntegerIrregularTrait::AddAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Add,
+            ),
+            IntegerIrregularTrait::SubAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Sub,
+            ),
+            IntegerIrregularTrait::MulAssign => arithmetic::gen_impl_trait_arithmetic_assign(
+                type_name,
+                generics,
+                inner_type,
+                &storage,
+                guard,
+                maybe_arithmetic_policy,
+                ArithmeticOperator::Mul,
+            ),
+            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name, generics)),
             IntegerIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
                 type_name,
+                generics,
                 inner_type,
                 maybe_error_type_name.as_ref(),
             )),
             IntegerIrregularTrait::ArbitraryArbitrary => {
-                arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
+                arbitrary::gen_impl_trait_arbitrary(type_name, generics, inner_type, guard)
+            }
+            IntegerIrregularTrait::NumTraitsToPrimitive if is_nonzero => {
+                Ok(nonzero::gen_impl_trait_num_traits_to_primitive(type_name, generics))
+            }
+            IntegerIrregularTrait::NumTraitsToPrimitive => {
+                Ok(gen_impl_trait_num_traits_to_primitive(type_name, generics))
+            }
+            IntegerIrregularTrait::NumTraitsFromPrimitive => Ok(gen_impl_trait_num_traits_from_primitive(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::NumTraitsBounded => {
+                num_traits::gen_impl_trait_bounded(type_name, generics, inner_type, guard)
+            }
+            IntegerIrregularTrait::NumTraitsZero => {
+                num_traits::gen_impl_trait_zero(type_name, generics, guard)
+            }
+            IntegerIrregularTrait::NumTraitsOne => {
+                Ok(num_traits::gen_impl_trait_one(type_name, generics, inner_type, guard))
             }
         })
         .collect()
 }
+
+/// Implement one of the formatting traits (e.g. `LowerHex`, `Binary`) by forwarding to the inner
+/// value, so the formatter flags (e.g. `{:#010x}`) are respected.
+fn gen_impl_trait_fmt(
+    type_name: &TypeName,
+    generics: &Generics,
+    fmt_trait: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #fmt_trait for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #fmt_trait::fmt(&self.0, f)
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! With `nonzero` validator the value is stored as `NonZero*`, so the traits that hand out
+//! references to the inner type can not simply return `&self.0`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{traits::PRIMITIVE_TYPES, InnerStorage},
+        models::TypeName,
+    },
+    integer::models::IntegerInnerType,
+};
+
+/// Reference to the stored `NonZero*` value as a reference to the inner type.
+fn gen_inner_ref(inner_type: &IntegerInnerType) -> TokenStream {
+    InnerStorage::NonZero(inner_type.nonzero_type()).gen_load_ref(quote!(self.0), inner_type)
+}
+
+pub fn gen_impl_trait_as_ref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_ref = gen_inner_ref(inner_type);
+    quote! {
+        impl #impl_generics ::core::convert::AsRef<#inner_type> for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn as_ref(&self) -> &#inner_type {
+                #inner_ref
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_deref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_ref = gen_inner_ref(inner_type);
+    quote! {
+        impl #impl_generics ::core::ops::Deref for #type_name #ty_generics #where_clause {
+            type Target = #inner_type;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                #inner_ref
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_borrow(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_ref = gen_inner_ref(inner_type);
+    quote! {
+        impl #impl_generics ::core::borrow::Borrow<#inner_type> for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn borrow(&self) -> &#inner_type {
+                #inner_ref
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_num_traits_to_primitive(
+    type_name: &TypeName,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let methods = PRIMITIVE_TYPES.iter().map(|primitive| {
+        let primitive = format_ident!("{primitive}");
+        let method = format_ident!("to_{primitive}");
+        quote! {
+            #[inline]
+            fn #method(&self) -> Option<#primitive> {
+                ::num_traits::ToPrimitive::#method(&self.0.get())
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::num_traits::ToPrimitive for #type_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
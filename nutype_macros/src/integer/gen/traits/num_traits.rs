@@ -0,0 +1,127 @@
+//! `Bounded`, `Zero` and `One` traits of `num_traits` crate.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{gen_phantom_value, InnerStorage},
+        models::TypeName,
+    },
+    integer::models::{IntegerGuard, IntegerInnerType, IntegerValidator},
+};
+
+use crate::integer::gen::gen_bounds;
+
+pub fn gen_impl_trait_bounded<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let Some((min, max)) = gen_bounds(inner_type, guard.validators().map_or(&[], |v| v.as_slice()))
+    else {
+        let msg = format!("Trait `Bounded` can be derived for type {type_name} only if it's validated by `greater`, `greater_or_equal`, `less` and `less_or_equal` alone.");
+        return Err(syn::Error::new(Span::call_site(), msg));
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    Ok(quote!(
+        impl #impl_generics ::num_traits::Bounded for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn min_value() -> Self {
+                Self(#min #phantom_value)
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self(#max #phantom_value)
+            }
+        }
+    ))
+}
+
+pub fn gen_impl_trait_zero<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    if is_nonzero(guard) {
+        let msg = format!(
+            "Trait `Zero` cannot be derived for type {type_name}, because it has `nonzero` validator."
+        );
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    let check_value = gen_check_value(type_name, guard, "Zero", quote!(0));
+    Ok(quote!(
+        impl #impl_generics ::num_traits::Zero for #type_name #ty_generics #where_clause {
+            fn zero() -> Self {
+                #check_value
+                Self(0 #phantom_value)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+    ))
+}
+
+pub fn gen_impl_trait_one<T: ToTokens>(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> TokenStream {
+    let storage = if is_nonzero(guard) {
+        InnerStorage::NonZero(inner_type.nonzero_type())
+    } else {
+        InnerStorage::Plain
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let phantom_value = gen_phantom_value(generics);
+    let check_value = gen_check_value(type_name, guard, "One", quote!(1));
+    let stored_value = storage.gen_store(quote!(1));
+    quote!(
+        impl #impl_generics ::num_traits::One for #type_name #ty_generics #where_clause {
+            fn one() -> Self {
+                #check_value
+                Self(#stored_value #phantom_value)
+            }
+        }
+    )
+}
+
+fn is_nonzero<T>(guard: &IntegerGuard<T>) -> bool {
+    guard.validators().is_some_and(|validators| {
+        validators
+            .iter()
+            .any(|v| matches!(v, IntegerValidator::Nonzero))
+    })
+}
+
+/// Panic if the value is rejected by the validators, the same way as the derived `Default` does.
+/// The value is not sanitized, since `zero()` and `one()` are expected to return exactly 0 and 1.
+fn gen_check_value<T>(
+    type_name: &TypeName,
+    guard: &IntegerGuard<T>,
+    name: &str,
+    value: TokenStream,
+) -> TokenStream {
+    if !guard.has_validation() {
+        return quote!();
+    }
+    let tp = type_name.to_string();
+    quote!(
+        if let Err(err) = Self::validate(&#value) {
+            let tp = #tp;
+            panic!("\n{} value for type `{tp}` is invalid.\nERROR: {err:?}\n", #name);
+        }
+    )
+}
@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::gen::zod::{gen_zod_method, unsupported_zod_rule},
+    integer::models::{IntegerGuard, IntegerInnerType, IntegerSanitizer, IntegerValidator},
+};
+
+pub fn gen_zod_methods<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<Vec<TokenStream>, syn::Error> {
+    let (sanitizers, validators): (&[IntegerSanitizer<T>], &[IntegerValidator<T>]) = match guard {
+        IntegerGuard::WithoutValidation { sanitizers } => (sanitizers, &[]),
+        IntegerGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers, validators),
+    };
+    if !sanitizers.is_empty() {
+        return Err(unsupported_zod_rule("with"));
+    }
+
+    let mut methods = Vec::with_capacity(validators.len() + 2);
+    let mut has_lower_bound = false;
+    let mut has_upper_bound = false;
+
+    for validator in validators {
+        match validator {
+            IntegerValidator::Greater(gt) => {
+                has_lower_bound = true;
+                methods.push(gen_zod_method("gt", gt, inner_type));
+            }
+            IntegerValidator::GreaterOrEqual(gte) => {
+                has_lower_bound = true;
+                methods.push(gen_zod_method("gte", gte, inner_type));
+            }
+            IntegerValidator::Less(lt) => {
+                has_upper_bound = true;
+                methods.push(gen_zod_method("lt", lt, inner_type));
+            }
+            IntegerValidator::LessOrEqual(lte) => {
+                has_upper_bound = true;
+                methods.push(gen_zod_method("lte", lte, inner_type));
+            }
+            IntegerValidator::FitsBits(bits) => {
+                has_lower_bound = true;
+                has_upper_bound = true;
+                let min = quote!(#inner_type::MIN >> (#inner_type::BITS - #bits));
+                let max = quote!(#inner_type::MAX >> (#inner_type::BITS - #bits));
+                methods.push(gen_zod_method("gte", min, inner_type));
+                methods.push(gen_zod_method("lte", max, inner_type));
+            }
+            IntegerValidator::AlignedTo(alignment) | IntegerValidator::MultipleOf(alignment) => {
+                methods.push(gen_zod_method("multipleOf", alignment, inner_type));
+            }
+            IntegerValidator::Percentage => {
+                has_lower_bound = true;
+                has_upper_bound = true;
+                methods.push(gen_zod_method("gte", quote!(0), inner_type));
+                methods.push(gen_zod_method("lte", quote!(100), inner_type));
+            }
+            IntegerValidator::Even => {
+                methods.push(gen_zod_method("multipleOf", quote!(2), inner_type));
+            }
+            IntegerValidator::Odd => {
+                return Err(unsupported_zod_rule("odd"));
+            }
+            IntegerValidator::PowerOfTwo => {
+                return Err(unsupported_zod_rule("power_of_two"));
+            }
+            IntegerValidator::Nonzero => {
+                return Err(unsupported_zod_rule("nonzero"));
+            }
+            IntegerValidator::OneOf(_) => {
+                return Err(unsupported_zod_rule("one_of"));
+            }
+            IntegerValidator::NotIn(_) => {
+                return Err(unsupported_zod_rule("not_in"));
+            }
+            IntegerValidator::All(_) => {
+                return Err(unsupported_zod_rule("all"));
+            }
+            IntegerValidator::Any(_) => {
+                return Err(unsupported_zod_rule("any"));
+            }
+            IntegerValidator::Not(_) => {
+                return Err(unsupported_zod_rule("not"));
+            }
+            IntegerValidator::Predicate(_) => {
+                return Err(unsupported_zod_rule("predicate"));
+            }
+        }
+    }
+
+    // JavaScript has only one number type, so the range of the inner type has to be checked
+    // explicitly, unless the validators narrow it down already.
+    if !has_lower_bound {
+        methods.insert(
+            0,
+            gen_zod_method("gte", quote!(#inner_type::MIN), inner_type),
+        );
+    }
+    if !has_upper_bound {
+        let position = usize::from(!has_lower_bound);
+        methods.insert(
+            position,
+            gen_zod_method("lte", quote!(#inner_type::MAX), inner_type),
+        );
+    }
+
+    Ok(methods)
+}
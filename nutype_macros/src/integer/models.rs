@@ -28,9 +28,44 @@ pub enum IntegerValidator<T> {
     GreaterOrEqual(ValueOrExpr<T>),
     Less(ValueOrExpr<T>),
     LessOrEqual(ValueOrExpr<T>),
+    /// The value must fit in the given number of bits.
+    FitsBits(u32),
+    /// The value must be a multiple of the given alignment.
+    AlignedTo(T),
+    /// Same check as `AlignedTo`, for quantities in fixed increments. Also spelled `divisible_by`.
+    MultipleOf(T),
+    /// The value must be in range from 0 to 100 (inclusive).
+    Percentage,
+    /// The value must be divisible by 2.
+    Even,
+    /// The value must not be divisible by 2.
+    Odd,
+    /// The value must be a power of two. Supported only by unsigned types.
+    PowerOfTwo,
+    /// The value must not be zero. The value is stored as `NonZero*` then.
+    Nonzero,
+    /// The value must be one of the given values.
+    OneOf(Vec<T>),
+    /// The value must not be any of the given values.
+    NotIn(Vec<T>),
+    /// All of the validators must pass. On the top level it's flattened into separate
+    /// validators, so the error names the one that failed.
+    All(IntegerCombination<T>),
+    /// At least one of the validators must pass.
+    Any(IntegerCombination<T>),
+    /// The validator must fail.
+    Not(IntegerCombination<T>),
     Predicate(TypedCustomFunction),
 }
 
+/// Validators combined with `all`, `any` or `not`.
+#[derive(Debug)]
+pub struct IntegerCombination<T> {
+    pub validators: Vec<IntegerValidator<T>>,
+    /// Source code of the combined validators, which is shown in the error message.
+    pub source: String,
+}
+
 impl_numeric_bound_validator!(IntegerValidator);
 impl_numeric_bound_on_vec_of!(IntegerValidator);
 
@@ -45,6 +80,8 @@ pub enum IntegerDeriveTrait {
     Clone,
     Copy,
     PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
     Eq,
     PartialOrd,
     Ord,
@@ -53,39 +90,89 @@ pub enum IntegerDeriveTrait {
     Into,
     From,
     TryFrom,
+    TryFromStr,
     Hash,
     Borrow,
     Display,
     Default,
     Deref,
+    LowerHex,
+    UpperHex,
+    Binary,
+    Octal,
+    Sum,
+    Product,
+    Add,
+    Sub,
+    Mul,
+    AddInner,
+    SubInner,
+    MulInner,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    NumTraitsBounded,
+    NumTraitsZero,
+    NumTraitsOne,
 }
 
 impl TypeTrait for IntegerDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &IntegerDeriveTrait::FromStr
     }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &IntegerDeriveTrait::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &IntegerDeriveTrait::SerdeDeserialize
+    }
 }
 
 pub type IntegerRawGuard<T> = RawGuard<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>>;
 pub type IntegerGuard<T> = Guard<IntegerSanitizer<T>, IntegerValidator<T>>;
 
 pub trait IntegerType {
+    #[allow(dead_code)]
     fn integer_inner_type() -> IntegerInnerType;
 }
 
 macro_rules! define_integer_inner_type {
-    ($($tp:ty => $variant:ident),*) => {
+    ($($tp:ty => $variant:ident => $nonzero:ident),*) => {
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub enum IntegerInnerType {
             $($variant),*
         }
 
+        impl IntegerInnerType {
+            pub fn is_signed(&self) -> bool {
+                match self {
+                    $(
+                        Self::$variant => <$tp>::MIN != 0,
+                    )*
+                }
+            }
+
+            /// The `NonZero*` counterpart of the type.
+            pub fn nonzero_type(&self) -> TokenStream {
+                match self {
+                    $(
+                        Self::$variant => quote::quote!(::core::num::$nonzero),
+                    )*
+                }
+            }
+        }
+
         $(
             impl IntegerType for $tp {
                 fn integer_inner_type() -> IntegerInnerType {
@@ -118,16 +205,16 @@ macro_rules! define_integer_inner_type {
 }
 
 define_integer_inner_type!(
-    u8 => U8,
-    u16 => U16,
-    u32 => U32,
-    u64 => U64,
-    u128 => U128,
-    usize => Usize,
-    i8 => I8,
-    i16 => I16,
-    i32 => I32,
-    i64 => I64,
-    i128 => I128,
-    isize => Isize
+    u8 => U8 => NonZeroU8,
+    u16 => U16 => NonZeroU16,
+    u32 => U32 => NonZeroU32,
+    u64 => U64 => NonZeroU64,
+    u128 => U128 => NonZeroU128,
+    usize => Usize => NonZeroUsize,
+    i8 => I8 => NonZeroI8,
+    i16 => I16 => NonZeroI16,
+    i32 => I32 => NonZeroI32,
+    i64 => I64 => NonZeroI64,
+    i128 => I128 => NonZeroI128,
+    isize => Isize => NonZeroIsize
 );
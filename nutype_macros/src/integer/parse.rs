@@ -4,22 +4,26 @@ use std::{
 };
 
 use crate::common::{
-    models::{Attributes, SpannedDeriveTrait},
+    models::{AssociatedConst, Attributes, Sensitive, SpannedDeriveTrait},
     parse::{
-        parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
-        parse_validator_kind, ParseableAttributes,
+        parse_number, parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
+        parse_validator_kind, reject_borrowed, reject_len_unit, reject_mut_guard,
+        reject_parse_constructor_name, reject_sensitive, ParseableAttributes,
     },
+    validate::{validate_consts_numeric_bounds, validate_default_numeric_bounds},
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
-    Token,
+    parse_quote, Ident, Token,
 };
 
 use super::{
     models::{
-        IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerSanitizerKind, IntegerValidator,
-        IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
+        IntegerCombination, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerSanitizerKind,
+        IntegerType, IntegerValidator, IntegerValidatorKind, SpannedIntegerSanitizer,
+        SpannedIntegerValidator,
     },
     validate::validate_number_meta,
 };
@@ -28,7 +32,7 @@ pub fn parse_attributes<T>(
     input: TokenStream,
 ) -> Result<Attributes<IntegerGuard<T>, SpannedDeriveTrait>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: IntegerType + ToTokens + FromStr + PartialOrd + Clone,
     <T as FromStr>::Err: Debug + Display,
 {
     let attrs: ParseableAttributes<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>> =
@@ -38,29 +42,77 @@ where
         sanitizers,
         validators,
         new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
         default,
+        convertible_with,
+        borrowed,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
         derive_traits,
     } = attrs;
+    reject_borrowed(borrowed)?;
+    reject_parse_constructor_name(constructor.as_ref())?;
+    reject_len_unit(len_unit)?;
+    reject_sensitive(sensitive)?;
+    reject_mut_guard(mut_guard)?;
     let raw_guard = IntegerRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_number_meta(raw_guard)?;
+    let mut consts = consts;
+    if let Some(validators) = guard.validators() {
+        validate_consts_numeric_bounds(&consts, validators)?;
+        if guard.sanitizers().is_empty() {
+            validate_default_numeric_bounds(default.as_ref(), validators)?;
+        }
+        add_bound_consts(&mut consts, validators);
+    }
     Ok(Attributes {
         new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive: Sensitive::Off,
         guard,
         default,
+        convertible_with,
+        maybe_borrowed_type_name: None,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: from_float.map(|from_float| from_float.item),
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: None,
+        maybe_serde_mode: serde.map(|serde| serde.item),
+        maybe_arithmetic_policy: arithmetic.map(|arithmetic| arithmetic.item),
+        maybe_debug_mode: debug.map(|debug| debug.item),
         derive_traits,
     })
 }
 
 impl<T> Parse for SpannedIntegerValidator<T>
 where
-    T: FromStr,
+    T: IntegerType + FromStr + PartialEq,
     <T as FromStr>::Err: Display,
 {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_integer_validator_kind(input)?;
 
         match kind {
             IntegerValidatorKind::Greater => {
@@ -95,6 +147,99 @@ where
                     span,
                 })
             }
+            IntegerValidatorKind::FitsBits => {
+                let _eq: Token![=] = input.parse()?;
+                let (bits, span) = parse_number::<u32>(input)?;
+                let max_bits = 8 * std::mem::size_of::<T>();
+                if bits == 0 || bits as usize > max_bits {
+                    let msg = format!("`fits_bits` must be in range from 1 to {max_bits}.");
+                    return Err(syn::Error::new(span, msg));
+                }
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::FitsBits(bits),
+                    span,
+                })
+            }
+            IntegerValidatorKind::AlignedTo | IntegerValidatorKind::MultipleOf => {
+                let _eq: Token![=] = input.parse()?;
+                let (alignment, span) = parse_number::<u128>(input)?;
+                if alignment == 0 {
+                    let msg = format!("`{ident}` must be greater than 0.");
+                    return Err(syn::Error::new(span, msg));
+                }
+                // Make sure the alignment can be represented by the inner type.
+                let alignment: T = alignment.to_string().parse().map_err(|_| {
+                    let msg = format!("`{ident} = {alignment}` is out of range of the inner type.");
+                    syn::Error::new(span, msg)
+                })?;
+                let item = if kind == IntegerValidatorKind::AlignedTo {
+                    IntegerValidator::AlignedTo(alignment)
+                } else {
+                    IntegerValidator::MultipleOf(alignment)
+                };
+                Ok(SpannedIntegerValidator { item, span })
+            }
+            IntegerValidatorKind::Percentage => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Percentage,
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::Even => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Even,
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::Odd => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Odd,
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::PowerOfTwo => {
+                if T::integer_inner_type().is_signed() {
+                    let msg = "`power_of_two` is supported only by unsigned integer types.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::PowerOfTwo,
+                    span: ident.span(),
+                })
+            }
+            IntegerValidatorKind::Nonzero => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Nonzero,
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::OneOf => {
+                let _eq: Token![=] = input.parse()?;
+                let values = parse_values::<T>(input, &ident)?;
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::OneOf(values),
+                    span: ident.span(),
+                })
+            }
+            IntegerValidatorKind::NotIn => {
+                let _eq: Token![=] = input.parse()?;
+                let values = parse_values::<T>(input, &ident)?;
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::NotIn(values),
+                    span: ident.span(),
+                })
+            }
+            IntegerValidatorKind::All => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::All(parse_combination(input)?),
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::Any => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::Any(parse_combination(input)?),
+                span: ident.span(),
+            }),
+            IntegerValidatorKind::Not => {
+                let combination = parse_combination(input)?;
+                if combination.validators.len() != 1 {
+                    let msg = "`not` expects exactly one validator.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::Not(combination),
+                    span: ident.span(),
+                })
+            }
             IntegerValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) = parse_typed_custom_function::<&T>(input)?;
@@ -107,6 +252,102 @@ where
     }
 }
 
+/// The lower and upper bounds become `MIN` and `MAX` constants, unless the names are already
+/// taken within `consts(...)`.
+/// It's done only if there are no other validators than the bounds, so the values are valid by
+/// construction. Like the other constants they're checked by the generated test.
+fn add_bound_consts<T: ToTokens>(
+    consts: &mut Vec<AssociatedConst>,
+    validators: &[IntegerValidator<T>],
+) {
+    let has_only_bounds = validators.iter().all(|validator| {
+        matches!(
+            validator,
+            IntegerValidator::Greater(_)
+                | IntegerValidator::GreaterOrEqual(_)
+                | IntegerValidator::Less(_)
+                | IntegerValidator::LessOrEqual(_)
+        )
+    });
+    if !has_only_bounds {
+        return;
+    }
+    let is_taken = |consts: &[AssociatedConst], name: &str| consts.iter().any(|c| c.name == name);
+    for validator in validators {
+        let (name, value): (&str, syn::Expr) = match validator {
+            IntegerValidator::GreaterOrEqual(min) => ("MIN", parse_quote!(#min)),
+            IntegerValidator::Greater(gt) => ("MIN", parse_quote!(#gt + 1)),
+            IntegerValidator::LessOrEqual(max) => ("MAX", parse_quote!(#max)),
+            IntegerValidator::Less(lt) => ("MAX", parse_quote!(#lt - 1)),
+            _ => continue,
+        };
+        if !is_taken(consts, name) {
+            consts.push(AssociatedConst {
+                name: Ident::new(name, Span::call_site()),
+                value,
+            });
+        }
+    }
+}
+
+/// Parse the values of `one_of` and `not_in`, e.g. `[1, 2, 5, 10]`.
+fn parse_values<T>(input: ParseStream, validator: &Ident) -> syn::Result<Vec<T>>
+where
+    T: FromStr + PartialEq,
+{
+    let content;
+    syn::bracketed!(content in input);
+    let mut values: Vec<T> = Vec::new();
+    while !content.is_empty() {
+        let (value, value_span) = parse_number::<T>(&content)?;
+        if values.contains(&value) {
+            let msg = format!("Duplicated value in `{validator}`.");
+            return Err(syn::Error::new(value_span, msg));
+        }
+        values.push(value);
+        if !content.is_empty() {
+            let _comma: Token![,] = content.parse()?;
+        }
+    }
+    if values.is_empty() {
+        let msg = format!("`{validator}` must contain at least one value.");
+        return Err(syn::Error::new(validator.span(), msg));
+    }
+    Ok(values)
+}
+
+/// Parse the validators combined with `all`, `any` or `not`, e.g.
+/// `(aligned_to = 3, aligned_to = 5)`.
+fn parse_combination<T>(input: ParseStream) -> syn::Result<IntegerCombination<T>>
+where
+    T: IntegerType + FromStr + PartialEq,
+    <T as FromStr>::Err: Display,
+{
+    let content;
+    let parens = syn::parenthesized!(content in input);
+    let source: TokenStream = content.fork().parse()?;
+    let validators =
+        content.parse_terminated(<SpannedIntegerValidator<T> as Parse>::parse, Token![,])?;
+    if validators.is_empty() {
+        let msg = "Expected at least one validator.";
+        return Err(syn::Error::new(parens.span.join(), msg));
+    }
+    Ok(IntegerCombination {
+        validators: validators.into_iter().map(|v| v.item).collect(),
+        source: source.to_string(),
+    })
+}
+
+/// Like `parse_validator_kind`, but also accepts `divisible_by`, which is a common spelling of
+/// `multiple_of` in other validation libraries.
+fn parse_integer_validator_kind(input: ParseStream) -> syn::Result<(IntegerValidatorKind, Ident)> {
+    if input.fork().parse::<Ident>()? == "divisible_by" {
+        let ident: Ident = input.parse()?;
+        return Ok((IntegerValidatorKind::MultipleOf, ident));
+    }
+    parse_validator_kind(input)
+}
+
 impl<T> Parse for SpannedIntegerSanitizer<T>
 where
     T: FromStr,
@@ -9,7 +9,7 @@ use crate::common::{
 
 use super::models::{
     IntegerDeriveTrait, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerValidator,
-    SpannedIntegerSanitizer, SpannedIntegerValidator,
+    IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
 };
 
 pub fn validate_number_meta<T>(raw_meta: IntegerRawGuard<T>) -> Result<IntegerGuard<T>, syn::Error>
@@ -40,6 +40,8 @@ fn validate_validators<T>(
 where
     T: PartialOrd + Clone,
 {
+    let validators = flatten_all(validators);
+
     validate_duplicates(&validators, |kind| {
         format!(
             "Duplicated validator `{kind}`.\nYou're a great engineer, but don't forget to take care of yourself!"
@@ -48,10 +50,46 @@ where
 
     validate_numeric_bounds(&validators)?;
 
+    let has_even = validators
+        .iter()
+        .any(|v| v.item.kind() == IntegerValidatorKind::Even);
+    if let Some(odd) = validators
+        .iter()
+        .find(|v| v.item.kind() == IntegerValidatorKind::Odd)
+    {
+        if has_even {
+            let msg = "Validators `even` and `odd` contradict each other.";
+            return Err(syn::Error::new(odd.span, msg));
+        }
+    }
+
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+/// `all(..)` on the top level is the same as listing the validators, so it's flattened and the
+/// error names the validator that failed.
+fn flatten_all<T>(validators: Vec<SpannedIntegerValidator<T>>) -> Vec<SpannedIntegerValidator<T>> {
+    validators
+        .into_iter()
+        .flat_map(|validator| match validator.item {
+            IntegerValidator::All(combination) => {
+                let span = validator.span;
+                let nested = combination
+                    .validators
+                    .into_iter()
+                    .map(|item| SpannedIntegerValidator { item, span })
+                    .collect();
+                flatten_all(nested)
+            }
+            item => vec![SpannedIntegerValidator {
+                item,
+                span: validator.span,
+            }],
+        })
+        .collect()
+}
+
 fn validate_sanitizers<T>(
     sanitizers: Vec<SpannedIntegerSanitizer<T>>,
 ) -> Result<Vec<IntegerSanitizer<T>>, syn::Error>
@@ -92,6 +130,8 @@ fn to_integer_derive_trait(
         DeriveTrait::Default => Ok(IntegerDeriveTrait::Default),
         DeriveTrait::Clone => Ok(IntegerDeriveTrait::Clone),
         DeriveTrait::PartialEq => Ok(IntegerDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(IntegerDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(IntegerDeriveTrait::PartialOrdInner),
         DeriveTrait::Eq => Ok(IntegerDeriveTrait::Eq),
         DeriveTrait::PartialOrd => Ok(IntegerDeriveTrait::PartialOrd),
         DeriveTrait::Ord => Ok(IntegerDeriveTrait::Ord),
@@ -102,11 +142,42 @@ fn to_integer_derive_trait(
         DeriveTrait::Hash => Ok(IntegerDeriveTrait::Hash),
         DeriveTrait::Borrow => Ok(IntegerDeriveTrait::Borrow),
         DeriveTrait::Copy => Ok(IntegerDeriveTrait::Copy),
+        DeriveTrait::LowerHex => Ok(IntegerDeriveTrait::LowerHex),
+        DeriveTrait::UpperHex => Ok(IntegerDeriveTrait::UpperHex),
+        DeriveTrait::Binary => Ok(IntegerDeriveTrait::Binary),
+        DeriveTrait::Octal => Ok(IntegerDeriveTrait::Octal),
+        DeriveTrait::Sum => Ok(IntegerDeriveTrait::Sum),
+        DeriveTrait::Product => Ok(IntegerDeriveTrait::Product),
+        DeriveTrait::Add => Ok(IntegerDeriveTrait::Add),
+        DeriveTrait::Sub => Ok(IntegerDeriveTrait::Sub),
+        DeriveTrait::Mul => Ok(IntegerDeriveTrait::Mul),
+        DeriveTrait::AddInner => Ok(IntegerDeriveTrait::AddInner),
+        DeriveTrait::SubInner => Ok(IntegerDeriveTrait::SubInner),
+        DeriveTrait::MulInner => Ok(IntegerDeriveTrait::MulInner),
+        DeriveTrait::Neg => Ok(IntegerDeriveTrait::Neg),
+        DeriveTrait::AddAssign | DeriveTrait::SubAssign | DeriveTrait::MulAssign => {
+            if has_validation {
+                let msg = format!("#[nutype] cannot derive `{tr:?}` trait, because there is validation defined. Use `checked_arithmetic` instead.");
+                Err(syn::Error::new(span, msg))
+            } else {
+                match tr {
+                    DeriveTrait::AddAssign => Ok(IntegerDeriveTrait::AddAssign),
+                    DeriveTrait::SubAssign => Ok(IntegerDeriveTrait::SubAssign),
+                    _ => Ok(IntegerDeriveTrait::MulAssign),
+                }
+            }
+        }
         DeriveTrait::SerdeSerialize => Ok(IntegerDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(IntegerDeriveTrait::SerdeDeserialize),
         DeriveTrait::SchemarsJsonSchema => Ok(IntegerDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(IntegerDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::NumTraitsToPrimitive => Ok(IntegerDeriveTrait::NumTraitsToPrimitive),
+        DeriveTrait::NumTraitsFromPrimitive => Ok(IntegerDeriveTrait::NumTraitsFromPrimitive),
+        DeriveTrait::NumTraitsBounded => Ok(IntegerDeriveTrait::NumTraitsBounded),
+        DeriveTrait::NumTraitsZero => Ok(IntegerDeriveTrait::NumTraitsZero),
+        DeriveTrait::NumTraitsOne => Ok(IntegerDeriveTrait::NumTraitsOne),
         DeriveTrait::TryFrom => Ok(IntegerDeriveTrait::TryFrom),
+        DeriveTrait::TryFromStr => Ok(IntegerDeriveTrait::TryFromStr),
         DeriveTrait::From => {
             if has_validation {
                 Err(syn::Error::new(
@@ -117,5 +188,12 @@ fn to_integer_derive_trait(
                 Ok(IntegerDeriveTrait::From)
             }
         }
+        DeriveTrait::FromIterator
+        | DeriveTrait::TryFromIterator
+        | DeriveTrait::OrderedFloatNotNan
+        | DeriveTrait::OrderedFloatOrderedFloat => {
+            let msg = format!("#[nutype] cannot derive `{tr:?}` trait for integer types.");
+            Err(syn::Error::new(span, msg))
+        }
     }
 }
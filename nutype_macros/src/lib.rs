@@ -8,7 +8,9 @@ mod any;
 mod common;
 mod float;
 mod integer;
+mod range;
 mod string;
+mod tuple;
 mod utils;
 
 use any::AnyNewtype;
@@ -19,7 +21,9 @@ use common::{
 use float::{models::FloatInnerType, FloatNewtype};
 use integer::{models::IntegerInnerType, IntegerNewtype};
 use proc_macro2::TokenStream;
+use range::RangeNewtype;
 use string::StringNewtype;
+use tuple::TupleNewtype;
 
 /// Defines sanitizers and validators on a newtype.
 /// Guarantees that the type can be instantiated only with valid values.
@@ -45,6 +49,8 @@ fn expand_nutype(
         InnerType::String(tp) => StringNewtype::expand(typed_meta, tp),
         InnerType::Integer(inner) => expand_nutype_integer(typed_meta, inner),
         InnerType::Float(inner) => expand_nutype_float(typed_meta, inner),
+        InnerType::Range(range_inner_type) => RangeNewtype::expand(typed_meta, range_inner_type),
+        InnerType::Tuple(tuple_inner_type) => TupleNewtype::expand(typed_meta, tuple_inner_type),
         InnerType::Any(any_inner_type) => AnyNewtype::expand(typed_meta, any_inner_type),
     }
 }
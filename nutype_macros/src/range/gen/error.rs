@@ -0,0 +1,96 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
+        models::{ErrorTypeName, TypeName},
+    },
+    range::models::RangeValidator,
+};
+
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    generics: &Generics,
+    validators: &[RangeValidator],
+) -> TokenStream {
+    let error_type_name = gen_error_type_name(type_name);
+    let definition = gen_definition(&error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
+    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #definition
+
+        #impl_display_trait
+        #impl_error_trait
+    }
+}
+
+fn gen_definition(error_type_name: &ErrorTypeName, validators: &[RangeValidator]) -> TokenStream {
+    let error_variants: TokenStream = validators
+        .iter()
+        .map(|validator| match validator {
+            RangeValidator::NonEmpty => {
+                quote!(NonEmptyViolated,)
+            }
+            RangeValidator::StartGreaterOrEqual(_) => {
+                quote!(StartGreaterOrEqualViolated,)
+            }
+            RangeValidator::EndLessOrEqual(_) => {
+                quote!(EndLessOrEqualViolated,)
+            }
+            RangeValidator::Predicate(_) => {
+                quote!(PredicateViolated,)
+            }
+        })
+        .collect();
+
+    quote! {
+        #[allow(clippy::enum_variant_names)]
+        pub enum #error_type_name {
+            #error_variants
+        }
+    }
+}
+
+fn gen_impl_display_trait(
+    type_name: &TypeName,
+    generics: &Generics,
+    error_type_name: &ErrorTypeName,
+    validators: &[RangeValidator],
+) -> TokenStream {
+    let match_arms = validators.iter().map(|validator| match validator {
+        RangeValidator::NonEmpty => quote! {
+             #error_type_name::NonEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
+        },
+        RangeValidator::StartGreaterOrEqual(min) => {
+            let min = gen_error_message_value(min, generics);
+            quote! {
+                 #error_type_name::StartGreaterOrEqualViolated => write!(f, "{} starts too early. The start must be greater or equal to {:#?}.", stringify!(#type_name), #min)
+            }
+        }
+        RangeValidator::EndLessOrEqual(max) => {
+            let max = gen_error_message_value(max, generics);
+            quote! {
+                 #error_type_name::EndLessOrEqualViolated => write!(f, "{} ends too late. The end must be less or equal to {:#?}.", stringify!(#type_name), #max)
+            }
+        }
+        RangeValidator::Predicate(_) => quote! {
+             #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
+        },
+    });
+
+    quote! {
+        impl ::core::fmt::Display for #error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+mod error;
+mod traits;
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+use syn::Generics;
+
+use crate::common::{
+    gen::{
+        error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
+        traits::GeneratedTraits, GenerateNewtype,
+    },
+    models::{ArithmeticPolicy, ErrorTypeName, Guard, TypeName, TypedCustomFunction},
+};
+
+use self::error::gen_validation_error_type;
+
+use super::{
+    models::{
+        RangeDeriveTrait, RangeGuard, RangeInnerType, RangeKind, RangeSanitizer, RangeValidator,
+    },
+    RangeNewtype,
+};
+
+use traits::gen_traits;
+
+impl GenerateNewtype for RangeNewtype {
+    type Sanitizer = RangeSanitizer;
+    type Validator = RangeValidator;
+    type InnerType = RangeInnerType;
+    type TypedTrait = RangeDeriveTrait;
+
+    fn gen_fn_sanitize(
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+    ) -> TokenStream {
+        let transformations: TokenStream = sanitizers
+            .iter()
+            .map(|san| match san {
+                RangeSanitizer::With(custom_sanitizer) => {
+                    let inner_type_ref: syn::Type = parse_quote!(
+                        #inner_type
+                    );
+                    let typed_sanitizer: TypedCustomFunction = custom_sanitizer
+                        .clone()
+                        .try_into_typed(&inner_type_ref)
+                        .expect("Failed to convert `with` sanitizer into a typed closure");
+                    quote!(
+                        value = (#typed_sanitizer)(value);
+                    )
+                }
+            })
+            .collect();
+
+        quote!(
+            fn sanitize(mut value: #inner_type) -> #inner_type {
+                #transformations
+                value
+            }
+        )
+    }
+
+    fn gen_fn_validate(
+        inner_type: &Self::InnerType,
+        type_name: &TypeName,
+        validators: &[Self::Validator],
+    ) -> TokenStream {
+        let error_name = gen_error_type_name(type_name);
+
+        // `Range` exposes its bounds as public fields, while `RangeInclusive` only has getters.
+        let (start, end) = match inner_type.kind() {
+            RangeKind::Range => (quote!(val.start), quote!(val.end)),
+            RangeKind::RangeInclusive => (quote!(*val.start()), quote!(*val.end())),
+        };
+
+        let validations: TokenStream = validators
+            .iter()
+            .map(|validator| match validator {
+                RangeValidator::NonEmpty => {
+                    quote!(
+                        if val.is_empty() {
+                            return Err(#error_name::NonEmptyViolated);
+                        }
+                    )
+                }
+                RangeValidator::StartGreaterOrEqual(min) => {
+                    quote!(
+                        if #start < #min {
+                            return Err(#error_name::StartGreaterOrEqualViolated);
+                        }
+                    )
+                }
+                RangeValidator::EndLessOrEqual(max) => {
+                    quote!(
+                        if #end > #max {
+                            return Err(#error_name::EndLessOrEqualViolated);
+                        }
+                    )
+                }
+                RangeValidator::Predicate(predicate) => {
+                    let inner_type_ref: syn::Type = parse_quote!(
+                        &'a #inner_type
+                    );
+                    let typed_predicate: TypedCustomFunction = predicate
+                        .clone()
+                        .try_into_typed(&inner_type_ref)
+                        .expect("Failed to convert predicate into a typed closure");
+                    quote!(
+                        if !(#typed_predicate)(val) {
+                            return Err(#error_name::PredicateViolated);
+                        }
+                    )
+                }
+            })
+            .collect();
+
+        quote!(
+            fn validate<'a>(val: &'a #inner_type) -> ::core::result::Result<(), #error_name> {
+                #validations
+                Ok(())
+            }
+        )
+    }
+
+    fn gen_validation_error_type(
+        type_name: &TypeName,
+        generics: &Generics,
+        validators: &[Self::Validator],
+    ) -> TokenStream {
+        gen_validation_error_type(type_name, generics, validators)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn gen_traits(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        maybe_error_type_name: Option<ErrorTypeName>,
+        traits: HashSet<Self::TypedTrait>,
+        maybe_default_value: Option<syn::Expr>,
+        _maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+        guard: &RangeGuard,
+    ) -> Result<GeneratedTraits, syn::Error> {
+        gen_traits(
+            type_name,
+            generics,
+            inner_type,
+            maybe_error_type_name,
+            traits,
+            maybe_default_value,
+            guard,
+        )
+    }
+
+    fn gen_tests(
+        type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        maybe_default_value: &Option<syn::Expr>,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        _traits: &HashSet<Self::TypedTrait>,
+    ) -> TokenStream {
+        let test_valid_default_value = gen_test_should_have_valid_default_value(
+            type_name,
+            maybe_default_value,
+            guard.has_validation(),
+        );
+
+        quote! {
+            #test_valid_default_value
+        }
+    }
+}
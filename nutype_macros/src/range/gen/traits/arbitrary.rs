@@ -0,0 +1,34 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::Generics;
+
+use crate::{
+    common::models::TypeName,
+    range::models::{RangeGuard, RangeInnerType},
+};
+
+pub fn gen_impl_trait_arbitrary(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &RangeInnerType,
+    guard: &RangeGuard,
+) -> Result<TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // An arbitrary range would violate the validation rules most of the time (e.g. `non_empty`),
+    // so we support only ranges without validation for now.
+    if guard.has_validation() {
+        let msg = format!(
+            "Cannot derive trait `Arbitrary` for a range type `{type_name}` which contains validation.\nYou have to implement `Arbitrary` trait manually to guarantee that it respects the validation rules.",
+        );
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    Ok(quote!(
+        impl #impl_generics ::arbitrary::Arbitrary<'_> for #type_name #ty_generics #where_clause {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
+                let inner_value: #inner_type = u.arbitrary()?;
+                Ok(Self::new(inner_value))
+            }
+        }
+    ))
+}
@@ -0,0 +1,197 @@
+pub mod arbitrary;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::collections::HashSet;
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_from, gen_impl_trait_into,
+                gen_impl_trait_partial_eq_inner, gen_impl_trait_partial_ord_inner,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
+                GeneratableTraits, GeneratedTraits,
+            },
+            InnerStorage,
+        },
+        models::{ErrorTypeName, TypeName},
+    },
+    range::models::{RangeDeriveTrait, RangeGuard, RangeInnerType},
+};
+
+type RangeGeneratableTrait = GeneratableTrait<RangeTransparentTrait, RangeIrregularTrait>;
+
+impl From<RangeDeriveTrait> for RangeGeneratableTrait {
+    fn from(derive_trait: RangeDeriveTrait) -> RangeGeneratableTrait {
+        match derive_trait {
+            RangeDeriveTrait::Debug => {
+                RangeGeneratableTrait::Transparent(RangeTransparentTrait::Debug)
+            }
+            RangeDeriveTrait::Clone => {
+                RangeGeneratableTrait::Transparent(RangeTransparentTrait::Clone)
+            }
+            RangeDeriveTrait::Hash => {
+                RangeGeneratableTrait::Transparent(RangeTransparentTrait::Hash)
+            }
+            RangeDeriveTrait::PartialEq => {
+                RangeGeneratableTrait::Transparent(RangeTransparentTrait::PartialEq)
+            }
+            RangeDeriveTrait::PartialEqInner => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::PartialEqInner)
+            }
+            RangeDeriveTrait::PartialOrdInner => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::PartialOrdInner)
+            }
+            RangeDeriveTrait::Eq => RangeGeneratableTrait::Transparent(RangeTransparentTrait::Eq),
+            RangeDeriveTrait::AsRef => RangeGeneratableTrait::Irregular(RangeIrregularTrait::AsRef),
+            RangeDeriveTrait::From => RangeGeneratableTrait::Irregular(RangeIrregularTrait::From),
+            RangeDeriveTrait::Into => RangeGeneratableTrait::Irregular(RangeIrregularTrait::Into),
+            RangeDeriveTrait::Deref => RangeGeneratableTrait::Irregular(RangeIrregularTrait::Deref),
+            RangeDeriveTrait::Borrow => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::Borrow)
+            }
+            RangeDeriveTrait::TryFrom => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::TryFrom)
+            }
+            RangeDeriveTrait::Default => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::Default)
+            }
+            RangeDeriveTrait::SerdeSerialize => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::SerdeSerialize)
+            }
+            RangeDeriveTrait::SerdeDeserialize => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::SerdeDeserialize)
+            }
+            RangeDeriveTrait::ArbitraryArbitrary => {
+                RangeGeneratableTrait::Irregular(RangeIrregularTrait::ArbitraryArbitrary)
+            }
+        }
+    }
+}
+
+/// A trait that can be automatically derived.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum RangeTransparentTrait {
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+}
+
+impl ToTokens for RangeTransparentTrait {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        let tokens = match self {
+            Self::Debug => quote!(Debug),
+            Self::Clone => quote!(Clone),
+            Self::PartialEq => quote!(PartialEq),
+            Self::Eq => quote!(Eq),
+            Self::Hash => quote!(Hash),
+        };
+        tokens.to_tokens(token_stream)
+    }
+}
+
+/// A trait that can not be automatically derived and we need to generate
+/// an implementation for it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum RangeIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
+    AsRef,
+    From,
+    Into,
+    Deref,
+    Borrow,
+    TryFrom,
+    Default,
+    SerdeSerialize,
+    SerdeDeserialize,
+    ArbitraryArbitrary,
+}
+
+pub fn gen_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &RangeInnerType,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    traits: HashSet<RangeDeriveTrait>,
+    maybe_default_value: Option<syn::Expr>,
+    guard: &RangeGuard,
+) -> Result<GeneratedTraits, syn::Error> {
+    let GeneratableTraits {
+        transparent_traits,
+        irregular_traits,
+    } = split_into_generatable_traits(traits);
+
+    let derive_transparent_traits = quote! {
+        #[derive(
+            #(#transparent_traits,)*
+        )]
+    };
+
+    let implement_traits = gen_implemented_traits(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        irregular_traits,
+        maybe_default_value,
+        guard,
+    )?;
+
+    Ok(GeneratedTraits {
+        derive_transparent_traits,
+        implement_traits,
+    })
+}
+
+fn gen_implemented_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &RangeInnerType,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    impl_traits: Vec<RangeIrregularTrait>,
+    maybe_default_value: Option<syn::Expr>,
+    guard: &RangeGuard,
+) -> Result<TokenStream, syn::Error> {
+    impl_traits
+        .iter()
+        .map(|t| match t {
+            RangeIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            RangeIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
+            RangeIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type.clone())),
+            RangeIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            RangeIrregularTrait::PartialEqInner => Ok(gen_impl_trait_partial_eq_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            RangeIrregularTrait::PartialOrdInner => Ok(gen_impl_trait_partial_ord_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            RangeIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            RangeIrregularTrait::TryFrom => Ok(
+                gen_impl_trait_try_from(type_name, generics, inner_type, maybe_error_type_name.as_ref())
+            ),
+            RangeIrregularTrait::Default => Ok(
+                match maybe_default_value {
+                    Some(ref default_value) => {
+                        let has_validation = maybe_error_type_name.is_some();
+                        gen_impl_trait_default(type_name, generics, default_value, has_validation)
+                    }
+                    None => {
+                        panic!(
+                            "Default trait is derived for type {type_name}, but `default = ` is missing"
+                        );
+                    }
+                }
+            ),
+            RangeIrregularTrait::SerdeSerialize => Ok(
+                gen_impl_trait_serde_serialize(type_name, generics)
+            ),
+            RangeIrregularTrait::SerdeDeserialize => Ok(
+                gen_impl_trait_serde_deserialize(type_name, generics, inner_type, maybe_error_type_name.as_ref())
+            ),
+            RangeIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, generics, inner_type, guard),
+        })
+        .collect()
+}
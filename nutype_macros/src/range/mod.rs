@@ -0,0 +1,42 @@
+pub mod gen;
+pub mod models;
+pub mod parse;
+pub mod validate;
+
+use proc_macro2::TokenStream;
+use std::collections::HashSet;
+
+use self::models::{RangeDeriveTrait, RangeGuard, RangeInnerType, RangeSanitizer, RangeValidator};
+use crate::common::gen::GenerateNewtype;
+use crate::{
+    common::models::{Attributes, GenerateParams, Newtype, SpannedDeriveTrait},
+    range::validate::validate_range_derive_traits,
+};
+
+pub struct RangeNewtype;
+
+impl Newtype for RangeNewtype {
+    type Sanitizer = RangeSanitizer;
+    type Validator = RangeValidator;
+    type TypedTrait = RangeDeriveTrait;
+    type InnerType = RangeInnerType;
+
+    fn parse_attributes(
+        attrs: TokenStream,
+    ) -> Result<Attributes<RangeGuard, SpannedDeriveTrait>, syn::Error> {
+        parse::parse_attributes(attrs)
+    }
+
+    fn validate(
+        guard: &RangeGuard,
+        derive_traits: Vec<SpannedDeriveTrait>,
+    ) -> Result<HashSet<Self::TypedTrait>, syn::Error> {
+        validate_range_derive_traits(guard, derive_traits)
+    }
+
+    fn generate(
+        params: GenerateParams<RangeInnerType, Self::TypedTrait, RangeGuard>,
+    ) -> Result<TokenStream, syn::Error> {
+        RangeNewtype::gen_nutype(params)
+    }
+}
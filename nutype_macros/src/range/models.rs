@@ -0,0 +1,103 @@
+use kinded::Kinded;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::fmt::Debug;
+use syn::Field;
+
+use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait};
+
+/// Sanitizer for range types.
+#[derive(Debug, Kinded)]
+#[kinded(display = "snake_case")]
+pub enum RangeSanitizer {
+    With(CustomFunction),
+}
+
+pub type SpannedRangeSanitizer = SpannedItem<RangeSanitizer>;
+
+/// Validator for range types.
+#[derive(Debug, Kinded)]
+#[kinded(display = "snake_case")]
+pub enum RangeValidator {
+    /// The range must contain at least one value.
+    NonEmpty,
+    /// The start bound must be greater or equal to the given value.
+    StartGreaterOrEqual(syn::Expr),
+    /// The end bound must be less or equal to the given value.
+    EndLessOrEqual(syn::Expr),
+    Predicate(CustomFunction),
+}
+
+pub type SpannedRangeValidator = SpannedItem<RangeValidator>;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum RangeDeriveTrait {
+    // Standard
+    Debug,
+    Clone,
+    PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
+    Eq,
+    AsRef,
+    Into,
+    From,
+    Deref,
+    Borrow,
+    TryFrom,
+    Default,
+    Hash,
+
+    // External crates
+    SerdeSerialize,
+    SerdeDeserialize,
+    ArbitraryArbitrary,
+}
+
+impl TypeTrait for RangeDeriveTrait {
+    fn is_from_str(&self) -> bool {
+        false
+    }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &RangeDeriveTrait::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &RangeDeriveTrait::SerdeDeserialize
+    }
+}
+
+pub type RangeRawGuard = RawGuard<SpannedRangeSanitizer, SpannedRangeValidator>;
+pub type RangeGuard = Guard<RangeSanitizer, RangeValidator>;
+
+/// Kind of a standard range, that can be used as an inner type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeKind {
+    /// `Range<T>`, which excludes the end bound.
+    Range,
+    /// `RangeInclusive<T>`, which includes the end bound.
+    RangeInclusive,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeInnerType {
+    kind: RangeKind,
+    field: Field,
+}
+
+impl RangeInnerType {
+    pub fn new(kind: RangeKind, field: Field) -> Self {
+        Self { kind, field }
+    }
+
+    pub fn kind(&self) -> RangeKind {
+        self.kind
+    }
+}
+
+impl ToTokens for RangeInnerType {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        self.field.to_tokens(token_stream)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::common::{
+    models::{
+        Attributes, CheckedArithmetic, CustomFunction, NewSlice, Sensitive, SpannedDeriveTrait,
+        SqlCheck, ZodSchema,
+    },
+    parse::{
+        parse_sanitizer_kind, parse_validator_kind, reject_arithmetic, reject_borrowed,
+        reject_checked_arithmetic, reject_from_float, reject_len_unit, reject_mut_guard,
+        reject_new_slice, reject_sensitive, reject_serde_mode, reject_sql_check, reject_zod_schema,
+        ParseableAttributes,
+    },
+};
+use proc_macro2::TokenStream;
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Token,
+};
+
+use super::{
+    models::{
+        RangeGuard, RangeRawGuard, RangeSanitizer, RangeSanitizerKind, RangeValidator,
+        RangeValidatorKind, SpannedRangeSanitizer, SpannedRangeValidator,
+    },
+    validate::validate_range_guard,
+};
+
+pub fn parse_attributes(
+    input: TokenStream,
+) -> Result<Attributes<RangeGuard, SpannedDeriveTrait>, syn::Error> {
+    let attrs: ParseableAttributes<SpannedRangeSanitizer, SpannedRangeValidator> =
+        syn::parse2(input)?;
+
+    let ParseableAttributes {
+        sanitizers,
+        validators,
+        new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
+        default,
+        convertible_with,
+        borrowed,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
+        derive_traits,
+    } = attrs;
+    reject_from_float(from_float)?;
+    reject_new_slice(new_slice)?;
+    reject_checked_arithmetic(checked_arithmetic)?;
+    reject_arithmetic(arithmetic)?;
+    reject_sql_check(sql_check)?;
+    reject_zod_schema(zod_schema)?;
+    reject_borrowed(borrowed)?;
+    reject_len_unit(len_unit)?;
+    reject_sensitive(sensitive)?;
+    reject_mut_guard(mut_guard)?;
+    reject_serde_mode(serde)?;
+    let raw_guard = RangeRawGuard {
+        sanitizers,
+        validators,
+    };
+    let guard = validate_range_guard(raw_guard)?;
+    Ok(Attributes {
+        new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice: NewSlice::Off,
+        checked_arithmetic: CheckedArithmetic::Off,
+        sql_check: SqlCheck::Off,
+        zod_schema: ZodSchema::Off,
+        sensitive: Sensitive::Off,
+        guard,
+        default,
+        convertible_with,
+        maybe_borrowed_type_name: None,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: None,
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: None,
+        maybe_serde_mode: None,
+        maybe_arithmetic_policy: None,
+        maybe_debug_mode: debug.map(|debug| debug.item),
+        derive_traits,
+    })
+}
+
+impl Parse for SpannedRangeSanitizer {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (kind, _ident) = parse_sanitizer_kind(input)?;
+
+        match kind {
+            RangeSanitizerKind::With => {
+                let _eq: Token![=] = input.parse()?;
+                let span = input.span();
+                let custom_function: CustomFunction = input.parse()?;
+                Ok(SpannedRangeSanitizer {
+                    item: RangeSanitizer::With(custom_function),
+                    span,
+                })
+            }
+        }
+    }
+}
+
+impl Parse for SpannedRangeValidator {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (kind, ident) = parse_validator_kind(input)?;
+
+        match kind {
+            RangeValidatorKind::NonEmpty => Ok(SpannedRangeValidator {
+                item: RangeValidator::NonEmpty,
+                span: ident.span(),
+            }),
+            RangeValidatorKind::StartGreaterOrEqual => {
+                let _eq: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedRangeValidator {
+                    span: expr.span(),
+                    item: RangeValidator::StartGreaterOrEqual(expr),
+                })
+            }
+            RangeValidatorKind::EndLessOrEqual => {
+                let _eq: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedRangeValidator {
+                    span: expr.span(),
+                    item: RangeValidator::EndLessOrEqual(expr),
+                })
+            }
+            RangeValidatorKind::Predicate => {
+                let _eq: Token![=] = input.parse()?;
+                let span = input.span();
+                let custom_function: CustomFunction = input.parse()?;
+                Ok(SpannedRangeValidator {
+                    item: RangeValidator::Predicate(custom_function),
+                    span,
+                })
+            }
+        }
+    }
+}
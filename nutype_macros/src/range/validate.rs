@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+
+use crate::common::{
+    models::{DeriveTrait, SpannedDeriveTrait},
+    validate::validate_duplicates,
+};
+
+use super::models::{
+    RangeDeriveTrait, RangeGuard, RangeRawGuard, RangeSanitizer, RangeValidator,
+    SpannedRangeSanitizer, SpannedRangeValidator,
+};
+
+pub fn validate_range_guard(raw_guard: RangeRawGuard) -> Result<RangeGuard, syn::Error> {
+    let RangeRawGuard {
+        sanitizers,
+        validators,
+    } = raw_guard;
+
+    let validators = validate_validators(validators)?;
+    let sanitizers = validate_sanitizers(sanitizers)?;
+
+    if validators.is_empty() {
+        Ok(RangeGuard::WithoutValidation { sanitizers })
+    } else {
+        Ok(RangeGuard::WithValidation {
+            sanitizers,
+            validators,
+        })
+    }
+}
+
+fn validate_validators(
+    validators: Vec<SpannedRangeValidator>,
+) -> Result<Vec<RangeValidator>, syn::Error> {
+    validate_duplicates(&validators, |kind| {
+        format!(
+            "Duplicated validators `{kind}`.\nDon't worry, you still have time to get it right."
+        )
+    })?;
+
+    let validators: Vec<RangeValidator> = validators.into_iter().map(|v| v.item).collect();
+    Ok(validators)
+}
+
+fn validate_sanitizers(
+    sanitizers: Vec<SpannedRangeSanitizer>,
+) -> Result<Vec<RangeSanitizer>, syn::Error> {
+    validate_duplicates(&sanitizers, |kind| {
+        format!("Duplicated sanitizer `{kind}`.\nOnce is enough, twice is a crowd.")
+    })?;
+
+    let sanitizers: Vec<_> = sanitizers.into_iter().map(|s| s.item).collect();
+    Ok(sanitizers)
+}
+
+pub fn validate_range_derive_traits(
+    guard: &RangeGuard,
+    spanned_derive_traits: Vec<SpannedDeriveTrait>,
+) -> Result<HashSet<RangeDeriveTrait>, syn::Error> {
+    let mut traits = HashSet::with_capacity(24);
+    let has_validation = guard.has_validation();
+
+    for spanned_trait in spanned_derive_traits {
+        let range_derive_trait =
+            to_range_derive_trait(spanned_trait.item, has_validation, spanned_trait.span)?;
+        traits.insert(range_derive_trait);
+    }
+
+    Ok(traits)
+}
+
+fn to_range_derive_trait(
+    tr: DeriveTrait,
+    has_validation: bool,
+    span: Span,
+) -> Result<RangeDeriveTrait, syn::Error> {
+    match tr {
+        DeriveTrait::Debug => Ok(RangeDeriveTrait::Debug),
+        DeriveTrait::Clone => Ok(RangeDeriveTrait::Clone),
+        DeriveTrait::PartialEq => Ok(RangeDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(RangeDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(RangeDeriveTrait::PartialOrdInner),
+        DeriveTrait::Eq => Ok(RangeDeriveTrait::Eq),
+        DeriveTrait::AsRef => Ok(RangeDeriveTrait::AsRef),
+        DeriveTrait::Into => Ok(RangeDeriveTrait::Into),
+        DeriveTrait::Deref => Ok(RangeDeriveTrait::Deref),
+        DeriveTrait::Borrow => Ok(RangeDeriveTrait::Borrow),
+        DeriveTrait::TryFrom => Ok(RangeDeriveTrait::TryFrom),
+        DeriveTrait::Default => Ok(RangeDeriveTrait::Default),
+        DeriveTrait::Hash => Ok(RangeDeriveTrait::Hash),
+        DeriveTrait::SerdeSerialize => Ok(RangeDeriveTrait::SerdeSerialize),
+        DeriveTrait::SerdeDeserialize => Ok(RangeDeriveTrait::SerdeDeserialize),
+        DeriveTrait::ArbitraryArbitrary => Ok(RangeDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::From => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead.",
+                ))
+            } else {
+                Ok(RangeDeriveTrait::From)
+            }
+        }
+        DeriveTrait::Copy => Err(syn::Error::new(
+            span,
+            "#[nutype] cannot derive `Copy` trait for range types, because ranges are not `Copy`.",
+        )),
+        DeriveTrait::PartialOrd
+        | DeriveTrait::Ord
+        | DeriveTrait::Display
+        | DeriveTrait::FromStr
+        | DeriveTrait::SchemarsJsonSchema
+        | DeriveTrait::NumTraitsToPrimitive
+        | DeriveTrait::NumTraitsFromPrimitive
+        | DeriveTrait::NumTraitsBounded
+        | DeriveTrait::NumTraitsZero
+        | DeriveTrait::NumTraitsOne
+        | DeriveTrait::OrderedFloatNotNan
+        | DeriveTrait::OrderedFloatOrderedFloat
+        | DeriveTrait::LowerHex
+        | DeriveTrait::UpperHex
+        | DeriveTrait::Binary
+        | DeriveTrait::Octal
+        | DeriveTrait::Sum
+        | DeriveTrait::Product
+        | DeriveTrait::Add
+        | DeriveTrait::Sub
+        | DeriveTrait::Mul
+        | DeriveTrait::AddInner
+        | DeriveTrait::SubInner
+        | DeriveTrait::MulInner
+        | DeriveTrait::Neg
+        | DeriveTrait::AddAssign
+        | DeriveTrait::SubAssign
+        | DeriveTrait::MulAssign
+        | DeriveTrait::FromIterator
+        | DeriveTrait::TryFromIterator
+        | DeriveTrait::TryFromStr => {
+            let msg = format!("#[nutype] cannot derive `{tr:?}` trait for range types.");
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Generics;
+
+use crate::common::models::{BorrowedTypeName, ErrorTypeName, TypeName};
+
+/// Generate a borrowed companion type (e.g. `UsernameRef`) that wraps `str` the same way
+/// the newtype wraps `String`, so validated values can be passed around without copying.
+pub fn gen_borrowed_type(
+    type_name: &TypeName,
+    generics: &Generics,
+    borrowed_type_name: &BorrowedTypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> Result<TokenStream, syn::Error> {
+    if !generics.params.is_empty() {
+        let msg = "`borrowed` type can not be generated for a type with generic parameters.";
+        return Err(syn::Error::new_spanned(borrowed_type_name, msg));
+    }
+
+    let doc = format!(
+        "Borrowed counterpart of [`{type_name}`], which relates to it the same way `str` relates to `String`."
+    );
+
+    let fn_from_str = match maybe_error_type_name {
+        Some(error_type_name) => quote! {
+            /// Validates the string slice and wraps it without copying.
+            pub fn from_str(value: &str) -> ::core::result::Result<&Self, #error_type_name> {
+                #type_name::validate(value)?;
+                Ok(Self::from_str_unchecked(value))
+            }
+        },
+        None => quote! {
+            /// Wraps the string slice without copying.
+            #[inline]
+            pub fn from_str(value: &str) -> &Self {
+                Self::from_str_unchecked(value)
+            }
+        },
+    };
+
+    let impl_to_owned = gen_impl_to_owned(type_name, borrowed_type_name);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct #borrowed_type_name(str);
+
+        impl #borrowed_type_name {
+            #fn_from_str
+
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            // The value must be already validated.
+            #[inline]
+            fn from_str_unchecked(value: &str) -> &Self {
+                // SAFETY: the type is `#[repr(transparent)]` over `str`, so both have the same layout.
+                unsafe { &*(value as *const str as *const Self) }
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #borrowed_type_name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::core::fmt::Display for #borrowed_type_name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::borrow::Borrow<#borrowed_type_name> for #type_name {
+            #[inline]
+            fn borrow(&self) -> &#borrowed_type_name {
+                #borrowed_type_name::from_str_unchecked(&self.0)
+            }
+        }
+
+        #impl_to_owned
+    })
+}
+
+// NOTE: `ToOwned` lives in `alloc` crate, which is not necessarily declared in `no_std` crates.
+// So for `no_std` we just don't implement `ToOwned` trait.
+#[allow(unused_variables)]
+fn gen_impl_to_owned(type_name: &TypeName, borrowed_type_name: &BorrowedTypeName) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            quote! {
+                impl ::std::borrow::ToOwned for #borrowed_type_name {
+                    type Owned = #type_name;
+
+                    #[inline]
+                    fn to_owned(&self) -> #type_name {
+                        // `Into` turns `String` into the container of the newtype
+                        #type_name(::core::convert::Into::into(::std::borrow::ToOwned::to_owned(&self.0)))
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        }
+    }
+}
@@ -1,21 +1,24 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::Generics;
 
 use crate::{
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
-        models::{ErrorTypeName, TypeName},
+        gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
+        models::{ErrorTypeName, LenUnit, TypeName},
     },
-    string::models::StringValidator,
+    string::models::{NumericRange, StringValidator, UrlSchemes},
 };
 
 pub fn gen_validation_error_type(
     type_name: &TypeName,
+    generics: &Generics,
     validators: &[StringValidator],
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
     let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -31,15 +34,54 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
-            StringValidator::LenCharMax(_len) => {
+            StringValidator::LenCharMax(_len, _unit) => {
                 quote!(LenCharMaxViolated,)
             }
-            StringValidator::LenCharMin(_len) => {
+            StringValidator::LenCharMin(_len, _unit) => {
                 quote!(LenCharMinViolated,)
             }
+            StringValidator::LenByteMax(_len) => {
+                quote!(LenByteMaxViolated,)
+            }
+            StringValidator::LenByteMin(_len) => {
+                quote!(LenByteMinViolated,)
+            }
+            StringValidator::LenGraphemeMax(_len) => {
+                quote!(LenGraphemeMaxViolated,)
+            }
+            StringValidator::LenGraphemeMin(_len) => {
+                quote!(LenGraphemeMinViolated,)
+            }
             StringValidator::NotEmpty => {
                 quote!(NotEmptyViolated,)
             }
+            StringValidator::Ascii => {
+                quote!(AsciiViolated,)
+            }
+            StringValidator::Alphanumeric => {
+                quote!(AlphanumericViolated,)
+            }
+            StringValidator::Alphabetic => {
+                quote!(AlphabeticViolated,)
+            }
+            StringValidator::Email => {
+                quote!(EmailViolated,)
+            }
+            StringValidator::StartsWith(_) => {
+                quote!(StartsWithViolated,)
+            }
+            StringValidator::EndsWith(_) => {
+                quote!(EndsWithViolated,)
+            }
+            StringValidator::Contains(_) => {
+                quote!(ContainsViolated,)
+            }
+            StringValidator::Url(_) => {
+                quote!(UrlViolated,)
+            }
+            StringValidator::Numeric(_) => {
+                quote!(NumericViolated,)
+            }
             StringValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
@@ -59,19 +101,85 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator
 
 fn gen_impl_display_trait(
     type_name: &TypeName,
+    generics: &Generics,
     error_type_name: &ErrorTypeName,
     validators: &[StringValidator],
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
-        StringValidator::LenCharMax(len_char_max) => quote! {
-             #error_type_name::LenCharMaxViolated => write!(f, "{} is too long. The value length must be less than {:#?} character(s).", stringify!(#type_name), #len_char_max)
-        },
-        StringValidator::LenCharMin(len_char_min) => quote! {
-             #error_type_name::LenCharMinViolated => write!(f, "{} is too short. The value length must be more than {:#?} character(s).", stringify!(#type_name), #len_char_min)
-        },
+        StringValidator::LenCharMax(len_char_max, len_unit) => {
+            let len_char_max = gen_error_message_value(len_char_max, generics);
+            let msg = format!("{{}} is too long. The value length must be less than {{:#?}} {}.", len_unit_noun(*len_unit));
+            quote! {
+                 #error_type_name::LenCharMaxViolated => write!(f, #msg, stringify!(#type_name), #len_char_max)
+            }
+        }
+        StringValidator::LenCharMin(len_char_min, len_unit) => {
+            let len_char_min = gen_error_message_value(len_char_min, generics);
+            let msg = format!("{{}} is too short. The value length must be more than {{:#?}} {}.", len_unit_noun(*len_unit));
+            quote! {
+                 #error_type_name::LenCharMinViolated => write!(f, #msg, stringify!(#type_name), #len_char_min)
+            }
+        }
+        StringValidator::LenByteMax(len_byte_max) => {
+            let len_byte_max = gen_error_message_value(len_byte_max, generics);
+            quote! {
+                 #error_type_name::LenByteMaxViolated => write!(f, "{} is too long. The value length must be less than {:#?} byte(s).", stringify!(#type_name), #len_byte_max)
+            }
+        }
+        StringValidator::LenByteMin(len_byte_min) => {
+            let len_byte_min = gen_error_message_value(len_byte_min, generics);
+            quote! {
+                 #error_type_name::LenByteMinViolated => write!(f, "{} is too short. The value length must be more than {:#?} byte(s).", stringify!(#type_name), #len_byte_min)
+            }
+        }
+        StringValidator::LenGraphemeMax(len_grapheme_max) => {
+            let len_grapheme_max = gen_error_message_value(len_grapheme_max, generics);
+            quote! {
+                 #error_type_name::LenGraphemeMaxViolated => write!(f, "{} is too long. The value length must be less than {:#?} grapheme(s).", stringify!(#type_name), #len_grapheme_max)
+            }
+        }
+        StringValidator::LenGraphemeMin(len_grapheme_min) => {
+            let len_grapheme_min = gen_error_message_value(len_grapheme_min, generics);
+            quote! {
+                 #error_type_name::LenGraphemeMinViolated => write!(f, "{} is too short. The value length must be more than {:#?} grapheme(s).", stringify!(#type_name), #len_grapheme_min)
+            }
+        }
         StringValidator::NotEmpty => quote! {
              #error_type_name::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
         },
+        StringValidator::Ascii => quote! {
+             #error_type_name::AsciiViolated => write!(f, "{} contains non-ASCII characters.", stringify!(#type_name))
+        },
+        StringValidator::Alphanumeric => quote! {
+             #error_type_name::AlphanumericViolated => write!(f, "{} contains characters that are neither letters nor digits.", stringify!(#type_name))
+        },
+        StringValidator::Alphabetic => quote! {
+             #error_type_name::AlphabeticViolated => write!(f, "{} contains characters that are not letters.", stringify!(#type_name))
+        },
+        StringValidator::StartsWith(prefix) => quote! {
+             #error_type_name::StartsWithViolated => write!(f, "{} must start with {:?}.", stringify!(#type_name), #prefix)
+        },
+        StringValidator::EndsWith(suffix) => quote! {
+             #error_type_name::EndsWithViolated => write!(f, "{} must end with {:?}.", stringify!(#type_name), #suffix)
+        },
+        StringValidator::Contains(substring) => quote! {
+             #error_type_name::ContainsViolated => write!(f, "{} must contain {:?}.", stringify!(#type_name), #substring)
+        },
+        StringValidator::Email => quote! {
+             #error_type_name::EmailViolated => write!(f, "{} is not a valid email address.", stringify!(#type_name))
+        },
+        StringValidator::Url(schemes) => {
+            let msg = url_violated_message(schemes);
+            quote! {
+                #error_type_name::UrlViolated => write!(f, "{} {}", stringify!(#type_name), #msg)
+            }
+        }
+        StringValidator::Numeric(range) => {
+            let msg = numeric_violated_message(range);
+            quote! {
+                #error_type_name::NumericViolated => write!(f, "{} {}", stringify!(#type_name), #msg)
+            }
+        }
         StringValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
@@ -90,3 +198,29 @@ fn gen_impl_display_trait(
         }
     }
 }
+
+fn url_violated_message(UrlSchemes(schemes): &UrlSchemes) -> String {
+    if schemes.is_empty() {
+        "is not a valid URL.".to_string()
+    } else {
+        let schemes = schemes.join(", ");
+        format!("is not a valid URL with one of the schemes: {schemes}.")
+    }
+}
+
+fn len_unit_noun(len_unit: LenUnit) -> &'static str {
+    match len_unit {
+        LenUnit::Bytes => "byte(s)",
+        LenUnit::Chars => "character(s)",
+        LenUnit::Graphemes => "grapheme(s)",
+    }
+}
+
+fn numeric_violated_message(range: &NumericRange) -> String {
+    match (&range.min, &range.max) {
+        (None, None) => "is not a number.".to_string(),
+        (Some(min), None) => format!("is not a number greater or equal to {min}."),
+        (None, Some(max)) => format!("is not a number less or equal to {max}."),
+        (Some(min), Some(max)) => format!("is not a number between {min} and {max}."),
+    }
+}
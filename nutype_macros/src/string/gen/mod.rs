@@ -1,24 +1,35 @@
+pub mod borrowed;
 pub mod error;
+mod sql_check;
 pub mod tests;
 pub mod traits;
+mod zod;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::Generics;
 
 use crate::{
     common::{
         gen::{
-            error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
-            traits::GeneratedTraits, GenerateNewtype,
+            error::gen_error_type_name, sql_check::gen_impl_sql_check,
+            tests::gen_test_should_have_valid_default_value, traits::GeneratedTraits,
+            zod::gen_impl_zod_schema, GenerateNewtype,
         },
-        models::{ErrorTypeName, Guard, TypeName},
+        models::{ArithmeticPolicy, BorrowedTypeName, ErrorTypeName, Guard, LenUnit, TypeName},
+    },
+    string::models::{
+        DecimalNumber, NumericRange, RegexDef, StringInnerType, StringSanitizer, StringValidator,
+        UrlSchemes,
     },
-    string::models::{RegexDef, StringInnerType, StringSanitizer, StringValidator},
 };
 
-use self::{error::gen_validation_error_type, traits::gen_traits};
+use self::{
+    borrowed::gen_borrowed_type, error::gen_validation_error_type, sql_check::gen_sql_conditions,
+    traits::gen_traits, zod::gen_zod_methods,
+};
 
 use super::{
     models::{StringDeriveTrait, StringGuard},
@@ -39,7 +50,7 @@ impl GenerateNewtype for StringNewtype {
     const NEW_CONVERT_INTO_INNER_TYPE: bool = true;
 
     fn gen_fn_sanitize(
-        _inner_type: &Self::InnerType,
+        inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
     ) -> TokenStream {
         let transformations: TokenStream = sanitizers
@@ -62,6 +73,100 @@ impl GenerateNewtype for StringNewtype {
                         let value: String = value.to_uppercase();
                     )
                 }
+                StringSanitizer::NormalizeNewlines => {
+                    // Replace CRLF first, so it does not turn into two line feeds
+                    quote!(
+                        let value: String = value.replace("\r\n", "\n").replace('\r', "\n");
+                    )
+                }
+                StringSanitizer::CollapseWhitespace => {
+                    quote!(
+                        let value: String = {
+                            let mut collapsed = String::with_capacity(value.len());
+                            let mut prev_is_whitespace = false;
+                            for c in value.chars() {
+                                if c.is_whitespace() {
+                                    if !prev_is_whitespace {
+                                        collapsed.push(' ');
+                                    }
+                                    prev_is_whitespace = true;
+                                } else {
+                                    collapsed.push(c);
+                                    prev_is_whitespace = false;
+                                }
+                            }
+                            collapsed
+                        };
+                    )
+                }
+                StringSanitizer::RemoveControlChars => {
+                    quote!(
+                        let value: String = value.chars().filter(|c| !c.is_control()).collect();
+                    )
+                }
+                StringSanitizer::Truncate(max_len, LenUnit::Chars) => {
+                    // Cut on the char boundary, so the length is counted the same way as
+                    // `len_char_max` does.
+                    quote!(
+                        let value: String = {
+                            let max_chars: usize = #max_len;
+                            let mut value = value;
+                            if let Some((byte_index, _)) = value.char_indices().nth(max_chars) {
+                                value.truncate(byte_index);
+                            }
+                            value
+                        };
+                    )
+                }
+                StringSanitizer::Truncate(max_len, LenUnit::Bytes) => {
+                    // Step back to the closest char boundary, so the value remains valid UTF-8.
+                    quote!(
+                        let value: String = {
+                            let max_bytes: usize = #max_len;
+                            let mut value = value;
+                            if value.len() > max_bytes {
+                                let mut byte_index = max_bytes;
+                                while !value.is_char_boundary(byte_index) {
+                                    byte_index -= 1;
+                                }
+                                value.truncate(byte_index);
+                            }
+                            value
+                        };
+                    )
+                }
+                StringSanitizer::Truncate(max_len, LenUnit::Graphemes) => {
+                    quote!(
+                        let value: String = {
+                            let max_graphemes: usize = #max_len;
+                            let mut value = value;
+                            let cut = ::unicode_segmentation::UnicodeSegmentation::grapheme_indices(
+                                value.as_str(),
+                                true,
+                            )
+                            .nth(max_graphemes)
+                            .map(|(byte_index, _)| byte_index);
+                            if let Some(byte_index) = cut {
+                                value.truncate(byte_index);
+                            }
+                            value
+                        };
+                    )
+                }
+                StringSanitizer::Nfc => {
+                    quote!(
+                        let value: String =
+                            ::unicode_normalization::UnicodeNormalization::nfc(value.as_str())
+                                .collect();
+                    )
+                }
+                StringSanitizer::Nfkc => {
+                    quote!(
+                        let value: String =
+                            ::unicode_normalization::UnicodeNormalization::nfkc(value.as_str())
+                                .collect();
+                    )
+                }
                 StringSanitizer::With(typed_custom_function) => {
                     quote!(
                         let value: String = (#typed_custom_function)(value);
@@ -70,12 +175,29 @@ impl GenerateNewtype for StringNewtype {
             })
             .collect();
 
-        quote!(
-            fn sanitize(value: String) -> String {
-                #transformations
-                value
-            }
-        )
+        match inner_type {
+            StringInnerType::String => quote!(
+                fn sanitize(value: String) -> String {
+                    #transformations
+                    value
+                }
+            ),
+            // The other containers are rebuilt only if there is anything to sanitize.
+            _ if sanitizers.is_empty() => quote!(
+                fn sanitize(value: #inner_type) -> #inner_type {
+                    value
+                }
+            ),
+            _ => quote!(
+                // `Box<str>` is replaced with the sanitized value, so it is fine to take it by value.
+                #[allow(clippy::boxed_local)]
+                fn sanitize(value: #inner_type) -> #inner_type {
+                    let value: String = String::from(&*value);
+                    #transformations
+                    value.into()
+                }
+            ),
+        }
     }
 
     fn gen_fn_validate(
@@ -88,26 +210,67 @@ impl GenerateNewtype for StringNewtype {
         // Indicates that `chars_count` variable needs to be set, which is used within
         // min_len and max_len validations.
         let mut requires_chars_count = false;
+        // The same for `graphemes_count`, which is used within len_grapheme_min and
+        // len_grapheme_max validations.
+        let mut requires_graphemes_count = false;
 
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
-                StringValidator::LenCharMax(max_len) => {
-                    requires_chars_count = true;
+                StringValidator::LenCharMax(max_len, len_unit) => {
+                    let len = gen_len_expr(
+                        *len_unit,
+                        &mut requires_chars_count,
+                        &mut requires_graphemes_count,
+                    );
                     quote!(
-                        if chars_count > #max_len {
+                        if #len > #max_len {
                             return Err(#error_name::LenCharMaxViolated);
                         }
                     )
                 }
-                StringValidator::LenCharMin(min_len) => {
-                    requires_chars_count = true;
+                StringValidator::LenCharMin(min_len, len_unit) => {
+                    let len = gen_len_expr(
+                        *len_unit,
+                        &mut requires_chars_count,
+                        &mut requires_graphemes_count,
+                    );
                     quote!(
-                        if chars_count < #min_len {
+                        if #len < #min_len {
                             return Err(#error_name::LenCharMinViolated);
                         }
                     )
                 }
+                StringValidator::LenByteMax(max_len) => {
+                    quote!(
+                        if val.len() > #max_len {
+                            return Err(#error_name::LenByteMaxViolated);
+                        }
+                    )
+                }
+                StringValidator::LenByteMin(min_len) => {
+                    quote!(
+                        if val.len() < #min_len {
+                            return Err(#error_name::LenByteMinViolated);
+                        }
+                    )
+                }
+                StringValidator::LenGraphemeMax(max_len) => {
+                    requires_graphemes_count = true;
+                    quote!(
+                        if graphemes_count > #max_len {
+                            return Err(#error_name::LenGraphemeMaxViolated);
+                        }
+                    )
+                }
+                StringValidator::LenGraphemeMin(min_len) => {
+                    requires_graphemes_count = true;
+                    quote!(
+                        if graphemes_count < #min_len {
+                            return Err(#error_name::LenGraphemeMinViolated);
+                        }
+                    )
+                }
                 StringValidator::NotEmpty => {
                     quote!(
                         if val.is_empty() {
@@ -115,6 +278,163 @@ impl GenerateNewtype for StringNewtype {
                         }
                     )
                 }
+                StringValidator::Ascii => {
+                    quote!(
+                        if !val.is_ascii() {
+                            return Err(#error_name::AsciiViolated);
+                        }
+                    )
+                }
+                StringValidator::Alphanumeric => {
+                    quote!(
+                        if !val.chars().all(char::is_alphanumeric) {
+                            return Err(#error_name::AlphanumericViolated);
+                        }
+                    )
+                }
+                StringValidator::Alphabetic => {
+                    quote!(
+                        if !val.chars().all(char::is_alphabetic) {
+                            return Err(#error_name::AlphabeticViolated);
+                        }
+                    )
+                }
+                StringValidator::StartsWith(prefix) => {
+                    quote!(
+                        if !val.starts_with(#prefix) {
+                            return Err(#error_name::StartsWithViolated);
+                        }
+                    )
+                }
+                StringValidator::EndsWith(suffix) => {
+                    quote!(
+                        if !val.ends_with(#suffix) {
+                            return Err(#error_name::EndsWithViolated);
+                        }
+                    )
+                }
+                StringValidator::Contains(substring) => {
+                    quote!(
+                        if !val.contains(#substring) {
+                            return Err(#error_name::ContainsViolated);
+                        }
+                    )
+                }
+                StringValidator::Email => {
+                    // A pragmatic subset of RFC 5322: dot-atom local part (no quoted strings or
+                    // comments) and a domain name with at least two labels (no IP literals).
+                    quote!(
+                        let is_valid_email = |email: &str| -> bool {
+                            let Some((local, domain)) = email.rsplit_once('@') else {
+                                return false;
+                            };
+                            let is_valid_local_char = |c: char| {
+                                c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c)
+                            };
+                            let is_valid_label = |label: &str| {
+                                !label.is_empty()
+                                    && label.len() <= 63
+                                    && !label.starts_with('-')
+                                    && !label.ends_with('-')
+                                    && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+                            };
+                            email.len() <= 254
+                                && !local.is_empty()
+                                && local.len() <= 64
+                                && local.split('.').all(|atom| !atom.is_empty() && atom.chars().all(is_valid_local_char))
+                                && domain.len() <= 253
+                                && domain.contains('.')
+                                && domain.split('.').all(is_valid_label)
+                        };
+                        if !is_valid_email(val) {
+                            return Err(#error_name::EmailViolated);
+                        }
+                    )
+                }
+                StringValidator::Url(UrlSchemes(schemes)) => {
+                    if schemes.is_empty() {
+                        quote!(
+                            if ::url::Url::parse(val).is_err() {
+                                return Err(#error_name::UrlViolated);
+                            }
+                        )
+                    } else {
+                        quote!(
+                            match ::url::Url::parse(val) {
+                                Ok(url) if [#(#schemes),*].contains(&url.scheme()) => {}
+                                _ => return Err(#error_name::UrlViolated),
+                            }
+                        )
+                    }
+                }
+                StringValidator::Numeric(NumericRange { min, max }) => {
+                    // The stored value is kept as it is, the number is parsed only to be checked.
+                    // The digits are compared as they are, so there is no loss of precision.
+                    let gen_bound_check = |bound: &DecimalNumber, violated_order| {
+                        let DecimalNumber {
+                            negative,
+                            integer,
+                            fraction,
+                        } = bound;
+                        quote!(
+                            if cmp_numeric(number, (#negative, #integer, #fraction)) == #violated_order {
+                                return Err(#error_name::NumericViolated);
+                            }
+                        )
+                    };
+                    let min_check = min
+                        .as_ref()
+                        .map(|min| gen_bound_check(min, quote!(::core::cmp::Ordering::Less)));
+                    let max_check = max
+                        .as_ref()
+                        .map(|max| gen_bound_check(max, quote!(::core::cmp::Ordering::Greater)));
+                    let bounds_check = if min.is_some() || max.is_some() {
+                        quote!(
+                            // Compares numbers given as (is negative, integer digits without leading zeros,
+                            // fractional digits without trailing zeros).
+                            fn cmp_numeric(lhs: (bool, &str, &str), rhs: (bool, &str, &str)) -> ::core::cmp::Ordering {
+                                let (lhs_negative, lhs_integer, lhs_fraction) = lhs;
+                                let (rhs_negative, rhs_integer, rhs_fraction) = rhs;
+                                let abs_order = lhs_integer
+                                    .len()
+                                    .cmp(&rhs_integer.len())
+                                    .then_with(|| lhs_integer.cmp(rhs_integer))
+                                    .then_with(|| lhs_fraction.cmp(rhs_fraction));
+                                match (lhs_negative, rhs_negative) {
+                                    (false, false) => abs_order,
+                                    (true, true) => abs_order.reverse(),
+                                    (false, true) => ::core::cmp::Ordering::Greater,
+                                    (true, false) => ::core::cmp::Ordering::Less,
+                                }
+                            }
+
+                            let integer = integer.trim_start_matches('0');
+                            let fraction = fraction.unwrap_or("").trim_end_matches('0');
+                            // Negative zero is just zero
+                            let negative = val.starts_with('-') && !(integer.is_empty() && fraction.is_empty());
+                            let number = (negative, integer, fraction);
+                            #min_check
+                            #max_check
+                        )
+                    } else {
+                        quote!()
+                    };
+                    quote!(
+                        {
+                            // Only an optional `-`, digits and an optional `.` followed by digits are accepted.
+                            let digits = val.strip_prefix('-').unwrap_or(val);
+                            let (integer, fraction) = match digits.split_once('.') {
+                                Some((integer, fraction)) => (integer, Some(fraction)),
+                                None => (digits, None),
+                            };
+                            let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+                            if !is_digits(integer) || matches!(fraction, Some(fraction) if !is_digits(fraction)) {
+                                return Err(#error_name::NumericViolated);
+                            }
+                            #bounds_check
+                        }
+                    )
+                }
                 StringValidator::Predicate(typed_custom_function) => {
                     quote!(
                         if !(#typed_custom_function)(&val) {
@@ -157,9 +477,19 @@ impl GenerateNewtype for StringNewtype {
             quote!()
         };
 
+        let graphemes_count_if_required = if requires_graphemes_count {
+            quote!(
+                let graphemes_count =
+                    ::unicode_segmentation::UnicodeSegmentation::graphemes(val, true).count();
+            )
+        } else {
+            quote!()
+        };
+
         quote!(
             fn validate(val: &str) -> ::core::result::Result<(), #error_name> {
                 #chars_count_if_required
+                #graphemes_count_if_required
                 #validations
                 Ok(())
             }
@@ -168,21 +498,71 @@ impl GenerateNewtype for StringNewtype {
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        generics: &Generics,
         validators: &[Self::Validator],
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, generics, validators)
     }
 
-    fn gen_traits(
+    fn gen_borrowed_type(
+        type_name: &TypeName,
+        generics: &Generics,
+        borrowed_type_name: &BorrowedTypeName,
+        maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> Result<TokenStream, syn::Error> {
+        gen_borrowed_type(
+            type_name,
+            generics,
+            borrowed_type_name,
+            maybe_error_type_name,
+        )
+    }
+
+    // `::validate()` and `::is_valid()` accept &str, so there is no need to allocate a String.
+    fn gen_validate_input_type(_inner_type: &Self::InnerType) -> TokenStream {
+        quote!(&str)
+    }
+
+    fn gen_sql_check(
+        type_name: &TypeName,
+        generics: &Generics,
+        _inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+    ) -> Result<TokenStream, syn::Error> {
+        let conditions = gen_sql_conditions(validators)?;
+        Ok(gen_impl_sql_check(type_name, generics, conditions))
+    }
+
+    fn gen_zod_schema(
         type_name: &TypeName,
+        generics: &Generics,
         _inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> Result<TokenStream, syn::Error> {
+        let methods = gen_zod_methods(guard)?;
+        Ok(gen_impl_zod_schema(
+            type_name,
+            generics,
+            "z.string()",
+            methods,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn gen_traits(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        _maybe_arithmetic_policy: Option<ArithmeticPolicy>,
         _guard: &StringGuard,
     ) -> Result<GeneratedTraits, syn::Error> {
         Ok(gen_traits(
             type_name,
+            generics,
+            inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
@@ -196,8 +576,8 @@ impl GenerateNewtype for StringNewtype {
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         _traits: &HashSet<Self::TypedTrait>,
     ) -> TokenStream {
-        let test_len_char_min_vs_max = guard.validators().and_then(|validators| {
-            tests::gen_test_should_have_consistent_len_char_boundaries(type_name, validators)
+        let test_len_min_vs_max = guard.validators().map(|validators| {
+            tests::gen_test_should_have_consistent_len_boundaries(type_name, validators)
         });
 
         let test_valid_default_value = gen_test_should_have_valid_default_value(
@@ -207,8 +587,28 @@ impl GenerateNewtype for StringNewtype {
         );
 
         quote! {
-            #test_len_char_min_vs_max
+            #test_len_min_vs_max
             #test_valid_default_value
         }
     }
 }
+
+/// Expression that gives the length of `val` in the given unit.
+/// Sets the flag for the count variable, which has to be computed before the validations.
+fn gen_len_expr(
+    len_unit: LenUnit,
+    requires_chars_count: &mut bool,
+    requires_graphemes_count: &mut bool,
+) -> TokenStream {
+    match len_unit {
+        LenUnit::Bytes => quote!(val.len()),
+        LenUnit::Chars => {
+            *requires_chars_count = true;
+            quote!(chars_count)
+        }
+        LenUnit::Graphemes => {
+            *requires_graphemes_count = true;
+            quote!(graphemes_count)
+        }
+    }
+}
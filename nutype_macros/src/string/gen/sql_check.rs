@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    common::{gen::sql_check::unsupported_sql_validator, models::LenUnit},
+    string::models::StringValidator,
+};
+
+/// The length is measured in characters with `CHAR_LENGTH`, the same way as `len_char_min` and
+/// `len_char_max` do, and in bytes with `OCTET_LENGTH` for `len_byte_min` and `len_byte_max`.
+pub fn gen_sql_conditions(validators: &[StringValidator]) -> Result<Vec<TokenStream>, syn::Error> {
+    validators
+        .iter()
+        .map(|validator| match validator {
+            StringValidator::LenCharMin(min_len, len_unit) => {
+                let length_fn = sql_length_function(*len_unit, "len_char_min")?;
+                Ok(quote!({
+                    let min_len: usize = #min_len;
+                    ::std::format!("{}({}) >= {}", #length_fn, column, min_len)
+                }))
+            }
+            StringValidator::LenCharMax(max_len, len_unit) => {
+                let length_fn = sql_length_function(*len_unit, "len_char_max")?;
+                Ok(quote!({
+                    let max_len: usize = #max_len;
+                    ::std::format!("{}({}) <= {}", #length_fn, column, max_len)
+                }))
+            }
+            StringValidator::LenByteMin(min_len) => Ok(quote!({
+                let min_len: usize = #min_len;
+                ::std::format!("OCTET_LENGTH({}) >= {}", column, min_len)
+            })),
+            StringValidator::LenByteMax(max_len) => Ok(quote!({
+                let max_len: usize = #max_len;
+                ::std::format!("OCTET_LENGTH({}) <= {}", column, max_len)
+            })),
+            StringValidator::LenGraphemeMin(_) => {
+                Err(unsupported_sql_validator("len_grapheme_min"))
+            }
+            StringValidator::LenGraphemeMax(_) => {
+                Err(unsupported_sql_validator("len_grapheme_max"))
+            }
+            StringValidator::NotEmpty => Ok(quote!(::std::format!("CHAR_LENGTH({}) > 0", column))),
+            StringValidator::Ascii => Err(unsupported_sql_validator("ascii")),
+            StringValidator::Alphanumeric => Err(unsupported_sql_validator("alphanumeric")),
+            StringValidator::Alphabetic => Err(unsupported_sql_validator("alphabetic")),
+            StringValidator::Email => Err(unsupported_sql_validator("email")),
+            StringValidator::StartsWith(_) => Err(unsupported_sql_validator("starts_with")),
+            StringValidator::EndsWith(_) => Err(unsupported_sql_validator("ends_with")),
+            StringValidator::Contains(_) => Err(unsupported_sql_validator("contains")),
+            StringValidator::Url(_) => Err(unsupported_sql_validator("url")),
+            StringValidator::Numeric(_) => Err(unsupported_sql_validator("numeric")),
+            StringValidator::Predicate(_) => Err(unsupported_sql_validator("predicate")),
+            StringValidator::Regex(_) => Err(unsupported_sql_validator("regex")),
+        })
+        .collect()
+}
+
+/// `len_char_min` and `len_char_max` follow `len_unit = `, SQL can not count grapheme clusters.
+fn sql_length_function(
+    len_unit: LenUnit,
+    validator_name: &str,
+) -> Result<&'static str, syn::Error> {
+    match len_unit {
+        LenUnit::Bytes => Ok("OCTET_LENGTH"),
+        LenUnit::Chars => Ok("CHAR_LENGTH"),
+        LenUnit::Graphemes => Err(unsupported_sql_validator(validator_name)),
+    }
+}
@@ -1,36 +1,62 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 
 use crate::{common::models::TypeName, string::models::StringValidator};
 
-pub fn gen_test_should_have_consistent_len_char_boundaries(
+pub fn gen_test_should_have_consistent_len_boundaries(
     type_name: &TypeName,
     validators: &[StringValidator],
+) -> TokenStream {
+    let len_char = gen_test_for_unit(type_name, validators, "char", |v| match v {
+        StringValidator::LenCharMin(len, _) => Some((true, len.to_token_stream())),
+        StringValidator::LenCharMax(len, _) => Some((false, len.to_token_stream())),
+        _ => None,
+    });
+    let len_byte = gen_test_for_unit(type_name, validators, "byte", |v| match v {
+        StringValidator::LenByteMin(len) => Some((true, len.to_token_stream())),
+        StringValidator::LenByteMax(len) => Some((false, len.to_token_stream())),
+        _ => None,
+    });
+    let len_grapheme = gen_test_for_unit(type_name, validators, "grapheme", |v| match v {
+        StringValidator::LenGraphemeMin(len) => Some((true, len.to_token_stream())),
+        StringValidator::LenGraphemeMax(len) => Some((false, len.to_token_stream())),
+        _ => None,
+    });
+
+    quote!(
+        #len_char
+        #len_byte
+        #len_grapheme
+    )
+}
+
+/// `get_boundary` returns `(is_min, len)` for `len_{unit}_min` and `len_{unit}_max` validators.
+fn gen_test_for_unit(
+    type_name: &TypeName,
+    validators: &[StringValidator],
+    unit: &str,
+    get_boundary: impl Fn(&StringValidator) -> Option<(bool, TokenStream)>,
 ) -> Option<TokenStream> {
-    let maybe_len_char_min: Option<TokenStream> = validators
-        .iter()
-        .flat_map(|v| match v {
-            StringValidator::LenCharMin(len) => Some(len.to_token_stream()),
-            _ => None,
-        })
-        .next();
-    let maybe_len_char_max: Option<TokenStream> = validators
-        .iter()
-        .flat_map(|v| match v {
-            StringValidator::LenCharMax(len) => Some(len.to_token_stream()),
-            _ => None,
-        })
-        .next();
-    let (Some(len_char_min), Some(len_char_max)) = (maybe_len_char_min, maybe_len_char_max) else {
+    let mut maybe_len_min: Option<TokenStream> = None;
+    let mut maybe_len_max: Option<TokenStream> = None;
+    for (is_min, len) in validators.iter().flat_map(get_boundary) {
+        if is_min {
+            maybe_len_min = Some(len);
+        } else {
+            maybe_len_max = Some(len);
+        }
+    }
+    let (Some(len_min), Some(len_max)) = (maybe_len_min, maybe_len_max) else {
         return None;
     };
 
-    let msg = format!("\nInconsistent lower and upper boundaries for type `{type_name}`\nThe upper boundary `{len_char_max}` must be greater than or equal to the lower boundary `{len_char_min}`\n");
+    let msg = format!("\nInconsistent lower and upper boundaries for type `{type_name}`\nThe upper boundary `{len_max}` must be greater than or equal to the lower boundary `{len_min}`\n");
+    let test_name = format_ident!("should_have_consistent_len_{unit}_boundaries");
 
     Some(quote!(
         #[test]
-        fn should_have_consistent_len_char_boundaries() {
-            assert!(#len_char_max >= #len_char_min, #msg);
+        fn #test_name() {
+            assert!(#len_max >= #len_min, #msg);
         }
     ))
 }
@@ -2,15 +2,21 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from, gen_impl_trait_into,
-            gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
-            gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
-            GeneratableTraits, GeneratedTraits,
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
+                gen_impl_trait_into, gen_impl_trait_partial_eq_inner,
+                gen_impl_trait_partial_ord_inner, gen_impl_trait_serde_deserialize,
+                gen_impl_trait_serde_deserialize_with, gen_impl_trait_serde_serialize,
+                gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
+                GeneratableTraits, GeneratedTraits,
+            },
+            InnerStorage,
         },
         models::{ErrorTypeName, TypeName},
     },
@@ -36,6 +42,8 @@ enum StringTransparentTrait {
 /// an implementation for it.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 enum StringIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
     FromStr,
     AsRef,
     Deref,
@@ -61,6 +69,12 @@ impl From<StringDeriveTrait> for StringGeneratableTrait {
             StringDeriveTrait::PartialEq => {
                 StringGeneratableTrait::Transparent(StringTransparentTrait::PartialEq)
             }
+            StringDeriveTrait::PartialEqInner => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::PartialEqInner)
+            }
+            StringDeriveTrait::PartialOrdInner => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::PartialOrdInner)
+            }
             StringDeriveTrait::Eq => {
                 StringGeneratableTrait::Transparent(StringTransparentTrait::Eq)
             }
@@ -131,6 +145,8 @@ impl ToTokens for StringTransparentTrait {
 
 pub fn gen_traits(
     type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &StringInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<StringDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
@@ -148,6 +164,8 @@ pub fn gen_traits(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
+        inner_type,
         maybe_error_type_name,
         maybe_default_value,
         irregular_traits,
@@ -161,31 +179,60 @@ pub fn gen_traits(
 
 fn gen_implemented_traits(
     type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &StringInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
     impl_traits: Vec<StringIrregularTrait>,
 ) -> TokenStream {
-    let inner_type = StringInnerType;
-
     impl_traits
         .iter()
         .map(|t| match t {
-            StringIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, quote!(str)),
-            StringIrregularTrait::Deref => gen_impl_trait_deref(type_name, quote!(String)),
-            StringIrregularTrait::FromStr => {
-                gen_impl_from_str(type_name, maybe_error_type_name.as_ref())
-            }
-            StringIrregularTrait::From => gen_impl_from_str_and_string(type_name),
-            StringIrregularTrait::Into => gen_impl_trait_into(type_name, inner_type),
-            StringIrregularTrait::TryFrom => {
-                gen_impl_try_from(type_name, maybe_error_type_name.as_ref())
+            StringIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, generics, quote!(str)),
+            StringIrregularTrait::Deref => match inner_type {
+                StringInnerType::String => {
+                    gen_impl_trait_deref(type_name, generics, quote!(String))
+                }
+                _ => gen_impl_trait_deref(type_name, generics, quote!(str)),
+            },
+            StringIrregularTrait::FromStr => gen_impl_from_str(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::From => {
+                gen_impl_from_str_and_string(type_name, generics, inner_type)
             }
-            StringIrregularTrait::Borrow => gen_impl_borrow_str_and_string(type_name),
-            StringIrregularTrait::Display => gen_impl_trait_display(type_name),
+            StringIrregularTrait::Into => gen_impl_trait_into(type_name, generics, *inner_type),
+            StringIrregularTrait::TryFrom => gen_impl_try_from(
+                type_name,
+                generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::PartialEqInner => gen_impl_trait_partial_eq_inner(
+                type_name,
+                generics,
+                inner_type,
+                &InnerStorage::Plain,
+            ),
+            StringIrregularTrait::PartialOrdInner => gen_impl_trait_partial_ord_inner(
+                type_name,
+                generics,
+                inner_type,
+                &InnerStorage::Plain,
+            ),
+            StringIrregularTrait::Borrow => match inner_type {
+                StringInnerType::String => gen_impl_borrow_str_and_string(type_name, generics),
+                // `Borrow<String>` can not be implemented, because there is no `String` inside
+                _ => gen_impl_trait_borrow(type_name, generics, quote!(str)),
+            },
+            StringIrregularTrait::Display => gen_impl_trait_display(type_name, generics),
             StringIrregularTrait::Default => match maybe_default_value {
                 Some(ref default_value) => {
                     let has_validation = maybe_error_type_name.is_some();
-                    gen_impl_trait_default(type_name, default_value, has_validation)
+                    gen_impl_trait_default(type_name, generics, default_value, has_validation)
                 }
                 None => {
                     panic!(
@@ -193,48 +240,93 @@ fn gen_implemented_traits(
                     );
                 }
             },
-            StringIrregularTrait::SerdeSerialize => gen_impl_trait_serde_serialize(type_name),
-            StringIrregularTrait::SerdeDeserialize => gen_impl_trait_serde_deserialize(
-                type_name,
-                inner_type,
-                maybe_error_type_name.as_ref(),
-            ),
+            StringIrregularTrait::SerdeSerialize => match inner_type {
+                StringInnerType::String => gen_impl_trait_serde_serialize(type_name, generics),
+                _ => gen_impl_trait_serde_serialize_str(type_name, generics),
+            },
+            StringIrregularTrait::SerdeDeserialize => match inner_type {
+                StringInnerType::String => gen_impl_trait_serde_deserialize(
+                    type_name,
+                    generics,
+                    *inner_type,
+                    maybe_error_type_name.as_ref(),
+                ),
+                // Deserialize into `String`, so `Arc<str>` does not require `rc` feature of serde.
+                _ => gen_impl_trait_serde_deserialize_with(
+                    type_name,
+                    generics,
+                    quote!(String),
+                    maybe_error_type_name.as_ref(),
+                    quote!(<String as ::serde::Deserialize>::deserialize(deserializer)),
+                ),
+            },
         })
         .collect()
 }
 
 fn gen_impl_from_str(
     type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &StringInnerType,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let raw_value = gen_raw_value_from_str(inner_type, quote!(raw_string));
     if let Some(error_type_name) = maybe_error_type_name {
         quote! {
-            impl core::str::FromStr for #type_name {
+            impl #impl_generics core::str::FromStr for #type_name #ty_generics #where_clause {
                 type Err = #error_type_name;
 
                 #[inline]
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
-                    #type_name::new(raw_string)
+                    Self::new(#raw_value)
                 }
             }
         }
     } else {
         quote! {
-            impl core::str::FromStr for #type_name {
+            impl #impl_generics core::str::FromStr for #type_name #ty_generics #where_clause {
                 type Err = ::core::convert::Infallible;
 
                 #[inline]
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
-                    Ok(#type_name::new(raw_string))
+                    Ok(Self::new(#raw_value))
                 }
             }
         }
     }
 }
 
-fn gen_impl_from_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_from_string = gen_impl_trait_from(type_name, quote!(String));
-    let impl_from_str = gen_impl_trait_from(type_name, quote!(&str));
+/// `&str` can be turned into every container with `Into`, except `Cow<'static, str>`, which
+/// needs an owned `String`.
+fn gen_raw_value_from_str(inner_type: &StringInnerType, value: TokenStream) -> TokenStream {
+    match inner_type {
+        StringInnerType::CowStr => quote!(::std::string::String::from(#value)),
+        _ => value,
+    }
+}
+
+fn gen_impl_from_str_and_string(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &StringInnerType,
+) -> TokenStream {
+    let impl_from_string = gen_impl_trait_from(type_name, generics, quote!(String));
+    let impl_from_str = match inner_type {
+        StringInnerType::CowStr => {
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let raw_value = gen_raw_value_from_str(inner_type, quote!(raw_value));
+            quote! {
+                impl #impl_generics ::core::convert::From<&str> for #type_name #ty_generics #where_clause {
+                    #[inline]
+                    fn from(raw_value: &str) -> Self {
+                        Self::new(#raw_value)
+                    }
+                }
+            }
+        }
+        _ => gen_impl_trait_from(type_name, generics, quote!(&str)),
+    };
 
     quote! {
         #impl_from_string
@@ -244,11 +336,43 @@ fn gen_impl_from_str_and_string(type_name: &TypeName) -> TokenStream {
 
 fn gen_impl_try_from(
     type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &StringInnerType,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
     let impl_try_from_string =
-        gen_impl_trait_try_from(type_name, quote!(String), maybe_error_type_name);
-    let impl_try_from_str = gen_impl_trait_try_from(type_name, quote!(&str), maybe_error_type_name);
+        gen_impl_trait_try_from(type_name, generics, quote!(String), maybe_error_type_name);
+    let impl_try_from_str = match inner_type {
+        StringInnerType::CowStr => {
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let raw_value = gen_raw_value_from_str(inner_type, quote!(raw_value));
+            match maybe_error_type_name {
+                Some(error_type_name) => quote! {
+                    impl #impl_generics ::core::convert::TryFrom<&str> for #type_name #ty_generics #where_clause {
+                        type Error = #error_type_name;
+
+                        #[inline]
+                        fn try_from(raw_value: &str) -> Result<Self, Self::Error> {
+                            Self::new(#raw_value)
+                        }
+                    }
+                },
+                None => quote! {
+                    // Deriving `TryFrom` on a type without validation is explicitly requested by the user.
+                    #[allow(clippy::infallible_try_from)]
+                    impl #impl_generics ::core::convert::TryFrom<&str> for #type_name #ty_generics #where_clause {
+                        type Error = ::core::convert::Infallible;
+
+                        #[inline]
+                        fn try_from(raw_value: &str) -> Result<Self, Self::Error> {
+                            Ok(Self::new(#raw_value))
+                        }
+                    }
+                },
+            }
+        }
+        _ => gen_impl_trait_try_from(type_name, generics, quote!(&str), maybe_error_type_name),
+    };
 
     quote! {
         #impl_try_from_string
@@ -256,12 +380,29 @@ fn gen_impl_try_from(
     }
 }
 
-fn gen_impl_borrow_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_borrow_string = gen_impl_trait_borrow(type_name, quote!(String));
-    let impl_borrow_str = gen_impl_trait_borrow(type_name, quote!(str));
+fn gen_impl_borrow_str_and_string(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let impl_borrow_string = gen_impl_trait_borrow(type_name, generics, quote!(String));
+    let impl_borrow_str = gen_impl_trait_borrow(type_name, generics, quote!(str));
 
     quote! {
         #impl_borrow_string
         #impl_borrow_str
     }
 }
+
+/// Serialize the content as `&str`, so `Arc<str>` does not require `rc` feature of serde.
+fn gen_impl_trait_serde_serialize_str(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let type_name_str = type_name.to_string();
+    quote! {
+        impl #impl_generics ::serde::Serialize for #type_name #ty_generics #where_clause {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer
+            {
+                let value: &str = &self.0;
+                serializer.serialize_newtype_struct(#type_name_str, value)
+            }
+        }
+    }
+}
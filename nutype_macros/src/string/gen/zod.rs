@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    common::{gen::zod::unsupported_zod_rule, models::LenUnit},
+    string::models::{RegexDef, StringGuard, StringSanitizer, StringValidator},
+};
+
+/// Note that Zod measures the length in UTF-16 code units, so for the characters outside of
+/// the Basic Multilingual Plane (e.g. emoji) it's slightly stricter than `len_char_max`.
+pub fn gen_zod_methods(guard: &StringGuard) -> Result<Vec<TokenStream>, syn::Error> {
+    let (sanitizers, validators): (&[StringSanitizer], &[StringValidator]) = match guard {
+        StringGuard::WithoutValidation { sanitizers } => (sanitizers, &[]),
+        StringGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers, validators),
+    };
+
+    let sanitizer_methods = sanitizers.iter().map(|sanitizer| match sanitizer {
+        StringSanitizer::Trim => Ok(quote!(::std::string::String::from(".trim()"))),
+        StringSanitizer::Lowercase => Ok(quote!(::std::string::String::from(".toLowerCase()"))),
+        StringSanitizer::Uppercase => Ok(quote!(::std::string::String::from(".toUpperCase()"))),
+        StringSanitizer::NormalizeNewlines => Err(unsupported_zod_rule("normalize_newlines")),
+        StringSanitizer::CollapseWhitespace => Err(unsupported_zod_rule("collapse_whitespace")),
+        StringSanitizer::RemoveControlChars => Err(unsupported_zod_rule("remove_control_chars")),
+        StringSanitizer::Truncate(..) => Err(unsupported_zod_rule("truncate")),
+        StringSanitizer::Nfc => Err(unsupported_zod_rule("nfc")),
+        StringSanitizer::Nfkc => Err(unsupported_zod_rule("nfkc")),
+        StringSanitizer::With(_) => Err(unsupported_zod_rule("with")),
+    });
+
+    let validator_methods = validators.iter().map(|validator| match validator {
+        StringValidator::LenCharMin(_, LenUnit::Bytes | LenUnit::Graphemes) => {
+            Err(unsupported_zod_rule("len_char_min"))
+        }
+        StringValidator::LenCharMax(_, LenUnit::Bytes | LenUnit::Graphemes) => {
+            Err(unsupported_zod_rule("len_char_max"))
+        }
+        StringValidator::LenCharMin(min_len, LenUnit::Chars) => Ok(quote!({
+            let min_len: usize = #min_len;
+            ::std::format!(".min({})", min_len)
+        })),
+        StringValidator::LenCharMax(max_len, LenUnit::Chars) => Ok(quote!({
+            let max_len: usize = #max_len;
+            ::std::format!(".max({})", max_len)
+        })),
+        StringValidator::LenByteMin(_) => Err(unsupported_zod_rule("len_byte_min")),
+        StringValidator::LenByteMax(_) => Err(unsupported_zod_rule("len_byte_max")),
+        StringValidator::LenGraphemeMin(_) => Err(unsupported_zod_rule("len_grapheme_min")),
+        StringValidator::LenGraphemeMax(_) => Err(unsupported_zod_rule("len_grapheme_max")),
+        StringValidator::NotEmpty => Ok(quote!(::std::string::String::from(".min(1)"))),
+        StringValidator::Regex(regex_def) => {
+            let pattern = match regex_def {
+                RegexDef::StringLiteral(regex_str_lit) => quote!(#regex_str_lit),
+                RegexDef::Path(regex_path) => quote!(#regex_path.as_str()),
+            };
+            // The pattern is put into a JavaScript regex literal, where `/` must be escaped.
+            Ok(quote!({
+                let pattern: &str = #pattern;
+                ::std::format!(".regex(/{}/)", pattern.replace('/', "\\/"))
+            }))
+        }
+        StringValidator::Ascii => Err(unsupported_zod_rule("ascii")),
+        StringValidator::Alphanumeric => Err(unsupported_zod_rule("alphanumeric")),
+        StringValidator::Alphabetic => Err(unsupported_zod_rule("alphabetic")),
+        // Zod has its own (stricter) pattern, but the intent is the same.
+        StringValidator::Email => Ok(quote!(::std::string::String::from(".email()"))),
+        StringValidator::StartsWith(prefix) => {
+            Ok(quote!(::std::format!(".startsWith({:?})", #prefix)))
+        }
+        StringValidator::EndsWith(suffix) => Ok(quote!(::std::format!(".endsWith({:?})", #suffix))),
+        StringValidator::Contains(substring) => {
+            Ok(quote!(::std::format!(".includes({:?})", #substring)))
+        }
+        StringValidator::Url(_) => Err(unsupported_zod_rule("url")),
+        StringValidator::Numeric(_) => Err(unsupported_zod_rule("numeric")),
+        StringValidator::Predicate(_) => Err(unsupported_zod_rule("predicate")),
+    });
+
+    sanitizer_methods.chain(validator_methods).collect()
+}
@@ -3,7 +3,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::common::models::{
-    Guard, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
+    Guard, LenUnit, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
 };
 
 // Sanitizer
@@ -16,6 +16,14 @@ pub enum StringSanitizer {
     Trim,
     Lowercase,
     Uppercase,
+    NormalizeNewlines,
+    CollapseWhitespace,
+    RemoveControlChars,
+    Truncate(ValueOrExpr<usize>, LenUnit),
+    #[cfg_attr(not(feature = "unicode_normalization"), allow(dead_code))]
+    Nfc,
+    #[cfg_attr(not(feature = "unicode_normalization"), allow(dead_code))]
+    Nfkc,
     With(TypedCustomFunction),
 }
 
@@ -27,9 +35,25 @@ pub type SpannedStringValidator = SpannedItem<StringValidator>;
 #[derive(Debug, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum StringValidator {
-    LenCharMin(ValueOrExpr<usize>),
-    LenCharMax(ValueOrExpr<usize>),
+    LenCharMin(ValueOrExpr<usize>, LenUnit),
+    LenCharMax(ValueOrExpr<usize>, LenUnit),
+    LenByteMin(ValueOrExpr<usize>),
+    LenByteMax(ValueOrExpr<usize>),
+    #[cfg_attr(not(feature = "unicode_segmentation"), allow(dead_code))]
+    LenGraphemeMin(ValueOrExpr<usize>),
+    #[cfg_attr(not(feature = "unicode_segmentation"), allow(dead_code))]
+    LenGraphemeMax(ValueOrExpr<usize>),
     NotEmpty,
+    Ascii,
+    Alphanumeric,
+    Alphabetic,
+    Email,
+    StartsWith(syn::LitStr),
+    EndsWith(syn::LitStr),
+    Contains(syn::LitStr),
+    #[cfg_attr(not(feature = "url"), allow(dead_code))]
+    Url(UrlSchemes),
+    Numeric(NumericRange),
     Predicate(TypedCustomFunction),
     #[cfg_attr(not(feature = "regex"), allow(dead_code))]
     Regex(RegexDef),
@@ -47,6 +71,102 @@ pub enum RegexDef {
     Path(syn::Path),
 }
 
+/// Optional inclusive boundaries for `numeric` validator, e.g.:
+///     numeric(min = 0, max = 9999)
+#[derive(Debug, Default)]
+pub struct NumericRange {
+    pub min: Option<DecimalNumber>,
+    pub max: Option<DecimalNumber>,
+}
+
+/// A boundary of `numeric` validator.
+/// It's kept as digits (not as `f64`), so it can be compared with the validated string exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecimalNumber {
+    pub negative: bool,
+    /// Digits of the integer part without leading zeros.
+    pub integer: String,
+    /// Digits of the fractional part without trailing zeros.
+    pub fraction: String,
+}
+
+impl DecimalNumber {
+    /// Parses a number in the form accepted by `numeric` validator:
+    /// optional `-`, digits and optional `.` followed by digits.
+    pub fn parse(input: &str) -> Option<Self> {
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(integer) || matches!(fraction, Some(fraction) if !is_digits(fraction)) {
+            return None;
+        }
+        let integer = integer.trim_start_matches('0').to_string();
+        let fraction = fraction.unwrap_or("").trim_end_matches('0').to_string();
+        // Negative zero is just zero
+        let negative = negative && !(integer.is_empty() && fraction.is_empty());
+        Some(Self {
+            negative,
+            integer,
+            fraction,
+        })
+    }
+}
+
+impl Ord for DecimalNumber {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        let abs_order = self
+            .integer
+            .len()
+            .cmp(&other.integer.len())
+            .then_with(|| self.integer.cmp(&other.integer))
+            .then_with(|| self.fraction.cmp(&other.fraction));
+        match (self.negative, other.negative) {
+            (false, false) => abs_order,
+            (true, true) => abs_order.reverse(),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for DecimalNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for DecimalNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        if self.integer.is_empty() {
+            write!(f, "0")?;
+        } else {
+            write!(f, "{}", self.integer)?;
+        }
+        if !self.fraction.is_empty() {
+            write!(f, ".{}", self.fraction)?;
+        }
+        Ok(())
+    }
+}
+
+/// Allowed schemes of `url` validator, e.g.:
+///     url(schemes = ["https", "http"])
+/// Empty means that any scheme is allowed.
+#[cfg_attr(not(feature = "url"), allow(dead_code))]
+#[derive(Debug, Default)]
+pub struct UrlSchemes(pub Vec<String>);
+
 // Traits
 //
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -55,6 +175,8 @@ pub enum StringDeriveTrait {
     Debug,
     Clone,
     PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
     Eq,
     PartialOrd,
     Ord,
@@ -81,16 +203,38 @@ impl TypeTrait for StringDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &Self::FromStr
     }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &Self::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &Self::SerdeDeserialize
+    }
 }
 
 pub type StringRawGuard = RawGuard<SpannedStringSanitizer, SpannedStringValidator>;
 pub type StringGuard = Guard<StringSanitizer, StringValidator>;
 
+/// The container of a String based type. Sanitizers always work with `String` and validators
+/// with `&str`, the other containers are converted from and to `String` when it's necessary.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct StringInnerType;
+pub enum StringInnerType {
+    String,
+    BoxStr,
+    ArcStr,
+    /// `Cow<'static, str>`
+    CowStr,
+}
 
 impl ToTokens for StringInnerType {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
-        quote!(String).to_tokens(token_stream);
+        let tokens = match self {
+            Self::String => quote!(String),
+            Self::BoxStr => quote!(::std::boxed::Box<str>),
+            Self::ArcStr => quote!(::std::sync::Arc<str>),
+            Self::CowStr => quote!(::std::borrow::Cow<'static, str>),
+        };
+        tokens.to_tokens(token_stream);
     }
 }
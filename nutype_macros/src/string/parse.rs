@@ -1,29 +1,38 @@
 use crate::{
     common::{
-        models::{Attributes, SpannedDeriveTrait, SpannedItem},
+        models::{
+            Attributes, CheckedArithmetic, LenUnit, NewSlice, SpannedDeriveTrait, SpannedItem,
+            SpannedLenUnit,
+        },
         parse::{
             parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function_raw,
-            parse_validator_kind, ParseableAttributes,
+            parse_validator_kind, reject_arithmetic, reject_checked_arithmetic, reject_from_float,
+            reject_new_slice, ParseableAttributes,
         },
     },
     string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 use cfg_if::cfg_if;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use syn::{
+    parenthesized,
     parse::{Parse, ParseStream},
     spanned::Spanned,
-    LitStr, Path, Token,
+    token::Paren,
+    Lit, LitStr, Path, Token,
 };
 
 use super::{
     models::{
-        RegexDef, SpannedStringSanitizer, SpannedStringValidator, StringSanitizerKind,
-        StringValidatorKind,
+        DecimalNumber, NumericRange, RegexDef, SpannedStringSanitizer, SpannedStringValidator,
+        StringSanitizerKind, StringValidatorKind,
     },
-    validate::validate_string_meta,
+    validate::{validate_borrowed, validate_consts, validate_string_meta},
 };
 
+#[cfg(feature = "url")]
+use super::models::UrlSchemes;
+
 pub fn parse_attributes(
     input: TokenStream,
 ) -> Result<Attributes<StringGuard, SpannedDeriveTrait>, syn::Error> {
@@ -34,18 +43,60 @@ pub fn parse_attributes(
         sanitizers,
         validators,
         new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
         default,
+        convertible_with,
+        borrowed: maybe_borrowed_type_name,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
         derive_traits,
     } = attrs;
+    reject_from_float(from_float)?;
+    reject_new_slice(new_slice)?;
+    reject_checked_arithmetic(checked_arithmetic)?;
+    reject_arithmetic(arithmetic)?;
+    let (sanitizers, validators) = apply_len_unit(len_unit, sanitizers, validators)?;
     let raw_guard = StringRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_string_meta(raw_guard)?;
+    validate_borrowed(&guard, maybe_borrowed_type_name.as_ref())?;
+    validate_consts(&consts)?;
     Ok(Attributes {
         new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice: NewSlice::Off,
+        checked_arithmetic: CheckedArithmetic::Off,
+        sql_check,
+        zod_schema,
+        sensitive,
         guard,
         default,
+        convertible_with,
+        maybe_borrowed_type_name,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: None,
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: mut_guard.map(|mut_guard| mut_guard.item),
+        maybe_serde_mode: serde.map(|serde| serde.item),
+        maybe_arithmetic_policy: None,
+        maybe_debug_mode: debug.map(|debug| debug.item),
         derive_traits,
     })
 }
@@ -67,6 +118,46 @@ impl Parse for SpannedStringSanitizer {
                 item: StringSanitizer::Uppercase,
                 span: ident.span(),
             }),
+            StringSanitizerKind::NormalizeNewlines => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::NormalizeNewlines,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::CollapseWhitespace => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::CollapseWhitespace,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::RemoveControlChars => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::RemoveControlChars,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::Truncate => {
+                let _: Token![=] = input.parse()?;
+                let (max_chars, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::Truncate(max_chars, LenUnit::default()),
+                    span,
+                })
+            }
+            StringSanitizerKind::Nfc | StringSanitizerKind::Nfkc => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode_normalization")] {
+                        let item = match kind {
+                            StringSanitizerKind::Nfc => StringSanitizer::Nfc,
+                            _ => StringSanitizer::Nfkc,
+                        };
+                        Ok(SpannedStringSanitizer {
+                            item,
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = concat!(
+                            "To normalize Unicode of string types, the feature `unicode_normalization` of the crate `nutype` must be enabled.\n",
+                            "The crate also has to explicitly include `unicode-normalization` dependency."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
             StringSanitizerKind::With => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) =
@@ -82,6 +173,7 @@ impl Parse for SpannedStringSanitizer {
 
 impl Parse for SpannedStringValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        reject_len_aliases(input)?;
         let (kind, ident) = parse_validator_kind(input)?;
 
         match kind {
@@ -89,7 +181,7 @@ impl Parse for SpannedStringValidator {
                 let _: Token![=] = input.parse()?;
                 let (min_len, span) = parse_number_or_expr::<usize>(input)?;
                 Ok(SpannedStringValidator {
-                    item: StringValidator::LenCharMin(min_len),
+                    item: StringValidator::LenCharMin(min_len, LenUnit::default()),
                     span,
                 })
             }
@@ -97,14 +189,114 @@ impl Parse for SpannedStringValidator {
                 let _: Token![=] = input.parse()?;
                 let (max_len, span) = parse_number_or_expr::<usize>(input)?;
                 Ok(SpannedStringValidator {
-                    item: StringValidator::LenCharMax(max_len),
+                    item: StringValidator::LenCharMax(max_len, LenUnit::default()),
+                    span,
+                })
+            }
+            StringValidatorKind::LenByteMin => {
+                let _: Token![=] = input.parse()?;
+                let (min_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::LenByteMin(min_len),
+                    span,
+                })
+            }
+            StringValidatorKind::LenByteMax => {
+                let _: Token![=] = input.parse()?;
+                let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::LenByteMax(max_len),
                     span,
                 })
             }
+            StringValidatorKind::LenGraphemeMin | StringValidatorKind::LenGraphemeMax => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode_segmentation")] {
+                        let _: Token![=] = input.parse()?;
+                        let (len, span) = parse_number_or_expr::<usize>(input)?;
+                        let item = match kind {
+                            StringValidatorKind::LenGraphemeMin => StringValidator::LenGraphemeMin(len),
+                            _ => StringValidator::LenGraphemeMax(len),
+                        };
+                        Ok(SpannedStringValidator { item, span })
+                    } else {
+                        let msg = concat!(
+                            "To count grapheme clusters of string types, the feature `unicode_segmentation` of the crate `nutype` must be enabled.\n",
+                            "The crate also has to explicitly include `unicode-segmentation` dependency."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
             StringValidatorKind::NotEmpty => Ok(SpannedStringValidator {
                 item: StringValidator::NotEmpty,
                 span: ident.span(),
             }),
+            StringValidatorKind::Ascii => Ok(SpannedStringValidator {
+                item: StringValidator::Ascii,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Alphanumeric => Ok(SpannedStringValidator {
+                item: StringValidator::Alphanumeric,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Alphabetic => Ok(SpannedStringValidator {
+                item: StringValidator::Alphabetic,
+                span: ident.span(),
+            }),
+            StringValidatorKind::StartsWith
+            | StringValidatorKind::EndsWith
+            | StringValidatorKind::Contains => {
+                let _eq: Token![=] = input.parse()?;
+                let lit: LitStr = input.parse()?;
+                let span = lit.span();
+                let item = match kind {
+                    StringValidatorKind::StartsWith => StringValidator::StartsWith(lit),
+                    StringValidatorKind::EndsWith => StringValidator::EndsWith(lit),
+                    _ => StringValidator::Contains(lit),
+                };
+                Ok(SpannedStringValidator { item, span })
+            }
+            StringValidatorKind::Email => Ok(SpannedStringValidator {
+                item: StringValidator::Email,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Url => {
+                cfg_if! {
+                    if #[cfg(feature = "url")] {
+                        let schemes = if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            parse_url_schemes(&content)?
+                        } else {
+                            UrlSchemes::default()
+                        };
+                        Ok(SpannedStringValidator {
+                            item: StringValidator::Url(schemes),
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = concat!(
+                            "To validate string types with `url`, the feature `url` of the crate `nutype` must be enabled.\n",
+                            "The crate also has to explicitly include `url` dependency."
+                        );
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringValidatorKind::Numeric => {
+                let range = if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    parse_numeric_range(&content)?
+                } else {
+                    NumericRange::default()
+                };
+                Ok(SpannedStringValidator {
+                    item: StringValidator::Numeric(range),
+                    span: ident.span(),
+                })
+            }
             StringValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) = parse_typed_custom_function_raw(input, "&str")?;
@@ -139,6 +331,151 @@ impl Parse for SpannedStringValidator {
     }
 }
 
+/// Sets the unit given with `len_unit = ` to `len_char_min`, `len_char_max` and `truncate`.
+fn apply_len_unit(
+    maybe_len_unit: Option<SpannedLenUnit>,
+    mut sanitizers: Vec<SpannedStringSanitizer>,
+    mut validators: Vec<SpannedStringValidator>,
+) -> syn::Result<(Vec<SpannedStringSanitizer>, Vec<SpannedStringValidator>)> {
+    let Some(SpannedLenUnit {
+        item: len_unit,
+        span,
+    }) = maybe_len_unit
+    else {
+        return Ok((sanitizers, validators));
+    };
+
+    #[cfg(not(feature = "unicode_segmentation"))]
+    if len_unit == LenUnit::Graphemes {
+        let msg = concat!(
+            "To count grapheme clusters of string types, the feature `unicode_segmentation` of the crate `nutype` must be enabled.\n",
+            "The crate also has to explicitly include `unicode-segmentation` dependency."
+        );
+        return Err(syn::Error::new(span, msg));
+    }
+
+    let mut is_applied = false;
+    for sanitizer in sanitizers.iter_mut() {
+        if let StringSanitizer::Truncate(_, unit) = &mut sanitizer.item {
+            *unit = len_unit;
+            is_applied = true;
+        }
+    }
+    for validator in validators.iter_mut() {
+        if let StringValidator::LenCharMin(_, unit) | StringValidator::LenCharMax(_, unit) =
+            &mut validator.item
+        {
+            *unit = len_unit;
+            is_applied = true;
+        }
+    }
+    if !is_applied {
+        let msg = "`len_unit` attribute requires `len_char_min`, `len_char_max` or `truncate`.";
+        return Err(syn::Error::new(span, msg));
+    }
+
+    Ok((sanitizers, validators))
+}
+
+/// `min_len` and `max_len` are common spellings in other validation libraries, so point to
+/// the names used by nutype, which make it explicit that characters (not bytes) are counted.
+fn reject_len_aliases(input: ParseStream) -> syn::Result<()> {
+    let ident: Ident = input.fork().parse()?;
+    let suggestion = if ident == "min_len" {
+        "len_char_min"
+    } else if ident == "max_len" {
+        "len_char_max"
+    } else {
+        return Ok(());
+    };
+    let msg = format!("Unknown validator `{ident}`. Did you mean `{suggestion}`?");
+    Err(syn::Error::new(ident.span(), msg))
+}
+
+/// Parse the content of `url(..)`, e.g. `schemes = ["https", "http"]`.
+#[cfg(feature = "url")]
+fn parse_url_schemes(input: ParseStream) -> syn::Result<UrlSchemes> {
+    let ident: Ident = input.parse()?;
+    if ident != "schemes" {
+        let msg = format!(
+            "Unknown parameter `{ident}` of `url` validator.\nPossible values are `schemes`."
+        );
+        return Err(syn::Error::new(ident.span(), msg));
+    }
+    let _eq: Token![=] = input.parse()?;
+    let content;
+    syn::bracketed!(content in input);
+    let lits = content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+    if lits.is_empty() {
+        let msg = "`schemes` of `url` validator must not be empty.";
+        return Err(syn::Error::new(ident.span(), msg));
+    }
+    let schemes = lits.iter().map(|lit| lit.value().to_lowercase()).collect();
+    Ok(UrlSchemes(schemes))
+}
+
+/// Parse the content of `numeric(..)`, e.g. `min = 0, max = 9999`.
+fn parse_numeric_range(input: ParseStream) -> syn::Result<NumericRange> {
+    let mut range = NumericRange::default();
+
+    while !input.is_empty() {
+        let ident: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let number = parse_decimal_number(input)?;
+
+        let bound = if ident == "min" {
+            &mut range.min
+        } else if ident == "max" {
+            &mut range.max
+        } else {
+            let msg = format!(
+                "Unknown parameter `{ident}` of `numeric` validator.\nPossible values are `min`, `max`."
+            );
+            return Err(syn::Error::new(ident.span(), msg));
+        };
+        if bound.is_some() {
+            let msg = format!("Duplicated parameter `{ident}` of `numeric` validator.");
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+        *bound = Some(number);
+
+        if !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+        }
+    }
+
+    Ok(range)
+}
+
+/// Parses a number literal (e.g. `-12.50`) of `numeric` validator keeping all its digits.
+fn parse_decimal_number(input: ParseStream) -> syn::Result<DecimalNumber> {
+    let mut number_str = String::with_capacity(16);
+    if input.peek(Token![-]) {
+        let _: Token![-] = input.parse()?;
+        number_str.push('-');
+    }
+
+    let lit: Lit = input.parse()?;
+    let (digits, suffix) = match &lit {
+        Lit::Float(lf) => (lf.base10_digits().to_string(), lf.suffix()),
+        Lit::Int(li) => (li.base10_digits().to_string(), li.suffix()),
+        _ => {
+            let msg = "Expected number literal";
+            return Err(syn::Error::new(lit.span(), msg));
+        }
+    };
+    number_str.push_str(&digits);
+
+    match DecimalNumber::parse(&number_str) {
+        Some(number) if suffix.is_empty() => Ok(number),
+        _ => {
+            let msg =
+                format!("Expected a decimal number (e.g. `-12.50`), got `{number_str}{suffix}`");
+            Err(syn::Error::new(lit.span(), msg))
+        }
+    }
+}
+
 type SpannedRegexDef = SpannedItem<RegexDef>;
 
 impl Parse for SpannedRegexDef {
@@ -5,10 +5,10 @@ use proc_macro2::Span;
 
 use crate::{
     common::{
-        models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
+        models::{AssociatedConst, BorrowedTypeName, DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
         validate::validate_duplicates,
     },
-    string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
+    string::models::{NumericRange, StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 
 use super::models::{
@@ -34,6 +34,38 @@ pub fn validate_string_meta(raw_meta: StringRawGuard) -> Result<StringGuard, syn
     }
 }
 
+/// A borrowed value can not be sanitized, so a borrowed companion type could hold values
+/// that the owned type would never have.
+pub fn validate_borrowed(
+    guard: &StringGuard,
+    maybe_borrowed_type_name: Option<&BorrowedTypeName>,
+) -> Result<(), syn::Error> {
+    let Some(borrowed_type_name) = maybe_borrowed_type_name else {
+        return Ok(());
+    };
+    let has_sanitizers = match guard {
+        StringGuard::WithoutValidation { sanitizers } => !sanitizers.is_empty(),
+        StringGuard::WithValidation { sanitizers, .. } => !sanitizers.is_empty(),
+    };
+    if has_sanitizers {
+        let msg = "`borrowed` type can not be used along with sanitizers, because a borrowed value can not be sanitized.";
+        return Err(syn::Error::new_spanned(borrowed_type_name, msg));
+    }
+    Ok(())
+}
+
+/// `String` can not be constructed in a const context (except an empty one), so associated
+/// constants are not supported.
+pub fn validate_consts(consts: &[AssociatedConst]) -> Result<(), syn::Error> {
+    match consts.first() {
+        None => Ok(()),
+        Some(associated_const) => {
+            let msg = "`consts` are not supported by String based types, because `String` can not be constructed in a const context.";
+            Err(syn::Error::new_spanned(&associated_const.name, msg))
+        }
+    }
+}
+
 fn validate_validators(
     validators: Vec<SpannedStringValidator>,
 ) -> Result<Vec<StringValidator>, syn::Error> {
@@ -43,29 +75,36 @@ fn validate_validators(
         format!("Duplicated validators `{kind}`.\nDon't worry, you still remain ingenious!")
     })?;
 
-    // len_char_max VS len_char_min
+    // len_char_max VS len_char_min, etc.
     //
-    let maybe_len_char_min = validators
-        .iter()
-        .flat_map(|v| match v.item {
-            StringValidator::LenCharMin(ValueOrExpr::Value(len)) => Some((v.span, len)),
-            _ => None,
-        })
-        .next();
-    let maybe_len_char_max = validators
-        .iter()
-        .flat_map(|v| match v.item {
-            StringValidator::LenCharMax(ValueOrExpr::Value(len)) => Some((v.span, len)),
-            _ => None,
-        })
-        .next();
-    if let (Some((_, len_char_min)), Some((len_char_max_span, len_char_max))) =
-        (maybe_len_char_min, maybe_len_char_max)
-    {
-        if len_char_min > len_char_max {
-            let msg = "`len_char_min` cannot be greater than `len_char_max`.\nDon't you find this obvious?";
-            let err = syn::Error::new(len_char_max_span, msg);
-            return Err(err);
+    validate_len_boundaries(&validators, "len_char", |v| match v {
+        StringValidator::LenCharMin(ValueOrExpr::Value(len), _) => Some(LenBoundary::Min(*len)),
+        StringValidator::LenCharMax(ValueOrExpr::Value(len), _) => Some(LenBoundary::Max(*len)),
+        _ => None,
+    })?;
+    validate_len_boundaries(&validators, "len_byte", |v| match v {
+        StringValidator::LenByteMin(ValueOrExpr::Value(len)) => Some(LenBoundary::Min(*len)),
+        StringValidator::LenByteMax(ValueOrExpr::Value(len)) => Some(LenBoundary::Max(*len)),
+        _ => None,
+    })?;
+    validate_len_boundaries(&validators, "len_grapheme", |v| match v {
+        StringValidator::LenGraphemeMin(ValueOrExpr::Value(len)) => Some(LenBoundary::Min(*len)),
+        StringValidator::LenGraphemeMax(ValueOrExpr::Value(len)) => Some(LenBoundary::Max(*len)),
+        _ => None,
+    })?;
+
+    // numeric(min) VS numeric(max)
+    //
+    for v in validators.iter() {
+        if let StringValidator::Numeric(NumericRange {
+            min: Some(min),
+            max: Some(max),
+        }) = &v.item
+        {
+            if min > max {
+                let msg = "`min` of `numeric` validator cannot be greater than `max`.";
+                return Err(syn::Error::new(v.span, msg));
+            }
         }
     }
 
@@ -82,6 +121,37 @@ fn validate_validators(
     Ok(validators)
 }
 
+enum LenBoundary {
+    Min(usize),
+    Max(usize),
+}
+
+/// Checks that `{prefix}_min` is not greater than `{prefix}_max`, when both are given as literals.
+fn validate_len_boundaries(
+    validators: &[SpannedStringValidator],
+    prefix: &str,
+    get_boundary: impl Fn(&StringValidator) -> Option<LenBoundary>,
+) -> Result<(), syn::Error> {
+    let mut maybe_min = None;
+    let mut maybe_max = None;
+    for v in validators {
+        match get_boundary(&v.item) {
+            Some(LenBoundary::Min(len)) => maybe_min = Some(len),
+            Some(LenBoundary::Max(len)) => maybe_max = Some((v.span, len)),
+            None => {}
+        }
+    }
+    if let (Some(min), Some((max_span, max))) = (maybe_min, maybe_max) {
+        if min > max {
+            let msg = format!(
+                "`{prefix}_min` cannot be greater than `{prefix}_max`.\nDon't you find this obvious?"
+            );
+            return Err(syn::Error::new(max_span, msg));
+        }
+    }
+    Ok(())
+}
+
 fn validate_sanitizers(
     sanitizers: Vec<SpannedStringSanitizer>,
 ) -> Result<Vec<StringSanitizer>, syn::Error> {
@@ -140,6 +210,8 @@ fn to_string_derive_trait(
         DeriveTrait::Default => Ok(StringDeriveTrait::Default),
         DeriveTrait::Clone => Ok(StringDeriveTrait::Clone),
         DeriveTrait::PartialEq => Ok(StringDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(StringDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(StringDeriveTrait::PartialOrdInner),
         DeriveTrait::Eq => Ok(StringDeriveTrait::Eq),
         DeriveTrait::PartialOrd => Ok(StringDeriveTrait::PartialOrd),
         DeriveTrait::Ord => Ok(StringDeriveTrait::Ord),
@@ -156,6 +228,34 @@ fn to_string_derive_trait(
             span,
             "Copy trait cannot be derived for a String based type",
         )),
+        DeriveTrait::LowerHex
+        | DeriveTrait::UpperHex
+        | DeriveTrait::Binary
+        | DeriveTrait::Octal
+        | DeriveTrait::Sum
+        | DeriveTrait::Product
+        | DeriveTrait::Add
+        | DeriveTrait::Sub
+        | DeriveTrait::Mul
+        | DeriveTrait::AddInner
+        | DeriveTrait::SubInner
+        | DeriveTrait::MulInner
+        | DeriveTrait::Neg
+        | DeriveTrait::AddAssign
+        | DeriveTrait::SubAssign
+        | DeriveTrait::MulAssign
+        | DeriveTrait::FromIterator
+        | DeriveTrait::TryFromIterator
+        | DeriveTrait::NumTraitsToPrimitive
+        | DeriveTrait::NumTraitsFromPrimitive
+        | DeriveTrait::NumTraitsBounded
+        | DeriveTrait::NumTraitsZero
+        | DeriveTrait::NumTraitsOne
+        | DeriveTrait::OrderedFloatNotNan
+        | DeriveTrait::OrderedFloatOrderedFloat => {
+            let msg = format!("{tr:?} trait cannot be derived for a String based type");
+            Err(syn::Error::new(span, msg))
+        }
         DeriveTrait::From => {
             if has_validation {
                 Err(syn::Error::new(
@@ -166,7 +266,8 @@ fn to_string_derive_trait(
                 Ok(StringDeriveTrait::From)
             }
         }
-        DeriveTrait::TryFrom => Ok(StringDeriveTrait::TryFrom),
+        // `TryFrom` implements both `TryFrom<String>` and `TryFrom<&str>`.
+        DeriveTrait::TryFrom | DeriveTrait::TryFromStr => Ok(StringDeriveTrait::TryFrom),
         DeriveTrait::ArbitraryArbitrary => {
             // TODO: Implement deriving Arbitrary
             let msg = "Deriving Arbitrary trait for string types is not yet implemented";
@@ -0,0 +1,122 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::error::{gen_error_message_value, gen_error_type_name, gen_impl_error_trait},
+        models::{ErrorTypeName, TypeName},
+    },
+    tuple::models::{TupleValidator, TupleValidatorRule},
+};
+
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    generics: &Generics,
+    validators: &[TupleValidator],
+) -> TokenStream {
+    let error_type_name = gen_error_type_name(type_name);
+    let definition = gen_definition(&error_type_name, validators);
+    let impl_display_trait =
+        gen_impl_display_trait(type_name, generics, &error_type_name, validators);
+    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #definition
+
+        #impl_display_trait
+        #impl_error_trait
+    }
+}
+
+/// Name of the error variant, e.g. `Component0LessOrEqualViolated` for a component validator
+/// or `PredicateViolated` for a validator of the whole tuple.
+pub fn gen_variant_name(validator: &TupleValidator) -> Ident {
+    let rule_name = match validator.rule {
+        TupleValidatorRule::Greater(_) => "Greater",
+        TupleValidatorRule::GreaterOrEqual(_) => "GreaterOrEqual",
+        TupleValidatorRule::Less(_) => "Less",
+        TupleValidatorRule::LessOrEqual(_) => "LessOrEqual",
+        TupleValidatorRule::Finite => "Finite",
+        TupleValidatorRule::Predicate(_) => "Predicate",
+    };
+    match validator.position {
+        Some(position) => format_ident!("Component{}{rule_name}Violated", position.index),
+        None => format_ident!("{rule_name}Violated"),
+    }
+}
+
+fn gen_definition(error_type_name: &ErrorTypeName, validators: &[TupleValidator]) -> TokenStream {
+    let error_variants: TokenStream = validators
+        .iter()
+        .map(|validator| {
+            let variant = gen_variant_name(validator);
+            quote!(#variant,)
+        })
+        .collect();
+
+    quote! {
+        #[allow(clippy::enum_variant_names)]
+        pub enum #error_type_name {
+            #error_variants
+        }
+    }
+}
+
+fn gen_impl_display_trait(
+    type_name: &TypeName,
+    generics: &Generics,
+    error_type_name: &ErrorTypeName,
+    validators: &[TupleValidator],
+) -> TokenStream {
+    let match_arms = validators.iter().map(|validator| {
+        let variant = gen_variant_name(validator);
+        let subject = match validator.position {
+            Some(position) => format!("{type_name}.{}", position.index),
+            None => type_name.to_string(),
+        };
+        match &validator.rule {
+            TupleValidatorRule::Greater(val) => {
+                let val = gen_error_message_value(val, generics);
+                quote! {
+                     #error_type_name::#variant => write!(f, "{} is too small. The value must be greater than {:#?}.", #subject, #val)
+                }
+            }
+            TupleValidatorRule::GreaterOrEqual(val) => {
+                let val = gen_error_message_value(val, generics);
+                quote! {
+                     #error_type_name::#variant => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", #subject, #val)
+                }
+            }
+            TupleValidatorRule::Less(val) => {
+                let val = gen_error_message_value(val, generics);
+                quote! {
+                     #error_type_name::#variant => write!(f, "{} is too big. The value must be less than {:#?}.", #subject, #val)
+                }
+            }
+            TupleValidatorRule::LessOrEqual(val) => {
+                let val = gen_error_message_value(val, generics);
+                quote! {
+                     #error_type_name::#variant => write!(f, "{} is too big. The value must be less or equal to {:#?}.", #subject, #val)
+                }
+            }
+            TupleValidatorRule::Finite => quote! {
+                 #error_type_name::#variant => write!(f, "{} is not finite.", #subject)
+            },
+            TupleValidatorRule::Predicate(_) => quote! {
+                 #error_type_name::#variant => write!(f, "{} failed the predicate test.", #subject)
+            },
+        }
+    });
+
+    quote! {
+        impl ::core::fmt::Display for #error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    }
+}
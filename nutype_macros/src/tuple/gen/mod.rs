@@ -0,0 +1,208 @@
+mod error;
+mod traits;
+
+use std::collections::HashSet;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::parse_quote;
+use syn::Generics;
+
+use crate::common::{
+    gen::{
+        error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
+        traits::GeneratedTraits, GenerateNewtype,
+    },
+    models::{ArithmeticPolicy, ErrorTypeName, Guard, TypeName, TypedCustomFunction},
+};
+
+use self::error::{gen_validation_error_type, gen_variant_name};
+
+use super::{
+    models::{
+        TupleDeriveTrait, TupleGuard, TupleInnerType, TupleSanitizer, TupleSanitizerRule,
+        TupleValidator, TupleValidatorRule,
+    },
+    TupleNewtype,
+};
+
+use traits::gen_traits;
+
+impl GenerateNewtype for TupleNewtype {
+    type Sanitizer = TupleSanitizer;
+    type Validator = TupleValidator;
+    type InnerType = TupleInnerType;
+    type TypedTrait = TupleDeriveTrait;
+
+    fn gen_fn_sanitize(
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+    ) -> TokenStream {
+        let transformations: TokenStream = sanitizers
+            .iter()
+            .map(|sanitizer| {
+                let (target, target_type) = gen_target(
+                    inner_type,
+                    quote!(value),
+                    sanitizer.position.map(|p| p.index),
+                );
+                match &sanitizer.rule {
+                    TupleSanitizerRule::With(custom_sanitizer) => {
+                        let typed_sanitizer: TypedCustomFunction = custom_sanitizer
+                            .clone()
+                            .try_into_typed(&target_type)
+                            .expect("Failed to convert `with` sanitizer into a typed closure");
+                        quote!(
+                            #target = (#typed_sanitizer)(#target);
+                        )
+                    }
+                }
+            })
+            .collect();
+
+        quote!(
+            fn sanitize(mut value: #inner_type) -> #inner_type {
+                #transformations
+                value
+            }
+        )
+    }
+
+    fn gen_fn_validate(
+        inner_type: &Self::InnerType,
+        type_name: &TypeName,
+        validators: &[Self::Validator],
+    ) -> TokenStream {
+        let error_name = gen_error_type_name(type_name);
+
+        let validations: TokenStream = validators
+            .iter()
+            .map(|validator| {
+                let (target, target_type) =
+                    gen_target(inner_type, quote!(val), validator.position.map(|p| p.index));
+                let variant = gen_variant_name(validator);
+                match &validator.rule {
+                    TupleValidatorRule::Greater(min) => quote!(
+                        if #target <= #min {
+                            return Err(#error_name::#variant);
+                        }
+                    ),
+                    TupleValidatorRule::GreaterOrEqual(min) => quote!(
+                        if #target < #min {
+                            return Err(#error_name::#variant);
+                        }
+                    ),
+                    TupleValidatorRule::Less(max) => quote!(
+                        if #target >= #max {
+                            return Err(#error_name::#variant);
+                        }
+                    ),
+                    TupleValidatorRule::LessOrEqual(max) => quote!(
+                        if #target > #max {
+                            return Err(#error_name::#variant);
+                        }
+                    ),
+                    TupleValidatorRule::Finite => quote!(
+                        if !#target.is_finite() {
+                            return Err(#error_name::#variant);
+                        }
+                    ),
+                    TupleValidatorRule::Predicate(predicate) => {
+                        let target_type_ref: syn::Type = parse_quote!(
+                            &'a #target_type
+                        );
+                        let typed_predicate: TypedCustomFunction = predicate
+                            .clone()
+                            .try_into_typed(&target_type_ref)
+                            .expect("Failed to convert predicate into a typed closure");
+                        // `val` is already a reference, but a component has to be borrowed.
+                        let arg = match validator.position {
+                            Some(_) => quote!(&#target),
+                            None => target,
+                        };
+                        quote!(
+                            if !(#typed_predicate)(#arg) {
+                                return Err(#error_name::#variant);
+                            }
+                        )
+                    }
+                }
+            })
+            .collect();
+
+        quote!(
+            fn validate<'a>(val: &'a #inner_type) -> ::core::result::Result<(), #error_name> {
+                #validations
+                Ok(())
+            }
+        )
+    }
+
+    fn gen_validation_error_type(
+        type_name: &TypeName,
+        generics: &Generics,
+        validators: &[Self::Validator],
+    ) -> TokenStream {
+        gen_validation_error_type(type_name, generics, validators)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn gen_traits(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        maybe_error_type_name: Option<ErrorTypeName>,
+        traits: HashSet<Self::TypedTrait>,
+        maybe_default_value: Option<syn::Expr>,
+        _maybe_arithmetic_policy: Option<ArithmeticPolicy>,
+        guard: &TupleGuard,
+    ) -> Result<GeneratedTraits, syn::Error> {
+        gen_traits(
+            type_name,
+            generics,
+            inner_type,
+            maybe_error_type_name,
+            traits,
+            maybe_default_value,
+            guard,
+        )
+    }
+
+    fn gen_tests(
+        type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        maybe_default_value: &Option<syn::Expr>,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        _traits: &HashSet<Self::TypedTrait>,
+    ) -> TokenStream {
+        let test_valid_default_value = gen_test_should_have_valid_default_value(
+            type_name,
+            maybe_default_value,
+            guard.has_validation(),
+        );
+
+        quote! {
+            #test_valid_default_value
+        }
+    }
+}
+
+/// Generate an expression, which refers either to the whole tuple or to its component,
+/// along with the type of the expression.
+fn gen_target(
+    inner_type: &TupleInnerType,
+    tuple: TokenStream,
+    maybe_index: Option<usize>,
+) -> (TokenStream, syn::Type) {
+    match maybe_index {
+        None => (tuple, parse_quote!(#inner_type)),
+        Some(index) => {
+            let component_type = inner_type
+                .component_type(index)
+                .expect("Position of a tuple component is expected to be validated")
+                .clone();
+            let index = Literal::usize_unsuffixed(index);
+            (quote!(#tuple.#index), component_type)
+        }
+    }
+}
@@ -0,0 +1,210 @@
+pub mod arbitrary;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::collections::HashSet;
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::{
+            traits::{
+                gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_from, gen_impl_trait_into,
+                gen_impl_trait_partial_eq_inner, gen_impl_trait_partial_ord_inner,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
+                GeneratableTraits, GeneratedTraits,
+            },
+            InnerStorage,
+        },
+        models::{ErrorTypeName, TypeName},
+    },
+    tuple::models::{TupleDeriveTrait, TupleGuard, TupleInnerType},
+};
+
+type TupleGeneratableTrait = GeneratableTrait<TupleTransparentTrait, TupleIrregularTrait>;
+
+impl From<TupleDeriveTrait> for TupleGeneratableTrait {
+    fn from(derive_trait: TupleDeriveTrait) -> TupleGeneratableTrait {
+        match derive_trait {
+            TupleDeriveTrait::Debug => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::Debug)
+            }
+            TupleDeriveTrait::Clone => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::Clone)
+            }
+            TupleDeriveTrait::Copy => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::Copy)
+            }
+            TupleDeriveTrait::Hash => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::Hash)
+            }
+            TupleDeriveTrait::PartialEq => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::PartialEq)
+            }
+            TupleDeriveTrait::PartialEqInner => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::PartialEqInner)
+            }
+            TupleDeriveTrait::PartialOrdInner => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::PartialOrdInner)
+            }
+            TupleDeriveTrait::Eq => TupleGeneratableTrait::Transparent(TupleTransparentTrait::Eq),
+            TupleDeriveTrait::PartialOrd => {
+                TupleGeneratableTrait::Transparent(TupleTransparentTrait::PartialOrd)
+            }
+            TupleDeriveTrait::Ord => TupleGeneratableTrait::Transparent(TupleTransparentTrait::Ord),
+            TupleDeriveTrait::AsRef => TupleGeneratableTrait::Irregular(TupleIrregularTrait::AsRef),
+            TupleDeriveTrait::From => TupleGeneratableTrait::Irregular(TupleIrregularTrait::From),
+            TupleDeriveTrait::Into => TupleGeneratableTrait::Irregular(TupleIrregularTrait::Into),
+            TupleDeriveTrait::Deref => TupleGeneratableTrait::Irregular(TupleIrregularTrait::Deref),
+            TupleDeriveTrait::Borrow => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::Borrow)
+            }
+            TupleDeriveTrait::TryFrom => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::TryFrom)
+            }
+            TupleDeriveTrait::Default => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::Default)
+            }
+            TupleDeriveTrait::SerdeSerialize => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::SerdeSerialize)
+            }
+            TupleDeriveTrait::SerdeDeserialize => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::SerdeDeserialize)
+            }
+            TupleDeriveTrait::ArbitraryArbitrary => {
+                TupleGeneratableTrait::Irregular(TupleIrregularTrait::ArbitraryArbitrary)
+            }
+        }
+    }
+}
+
+/// A trait that can be automatically derived.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum TupleTransparentTrait {
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+}
+
+impl ToTokens for TupleTransparentTrait {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        let tokens = match self {
+            Self::Debug => quote!(Debug),
+            Self::Clone => quote!(Clone),
+            Self::Copy => quote!(Copy),
+            Self::PartialEq => quote!(PartialEq),
+            Self::Eq => quote!(Eq),
+            Self::PartialOrd => quote!(PartialOrd),
+            Self::Ord => quote!(Ord),
+            Self::Hash => quote!(Hash),
+        };
+        tokens.to_tokens(token_stream)
+    }
+}
+
+/// A trait that can not be automatically derived and we need to generate
+/// an implementation for it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum TupleIrregularTrait {
+    PartialEqInner,
+    PartialOrdInner,
+    AsRef,
+    From,
+    Into,
+    Deref,
+    Borrow,
+    TryFrom,
+    Default,
+    SerdeSerialize,
+    SerdeDeserialize,
+    ArbitraryArbitrary,
+}
+
+pub fn gen_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &TupleInnerType,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    traits: HashSet<TupleDeriveTrait>,
+    maybe_default_value: Option<syn::Expr>,
+    guard: &TupleGuard,
+) -> Result<GeneratedTraits, syn::Error> {
+    let GeneratableTraits {
+        transparent_traits,
+        irregular_traits,
+    } = split_into_generatable_traits(traits);
+
+    let derive_transparent_traits = quote! {
+        #[derive(
+            #(#transparent_traits,)*
+        )]
+    };
+
+    let implement_traits = gen_implemented_traits(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name,
+        irregular_traits,
+        maybe_default_value,
+        guard,
+    )?;
+
+    Ok(GeneratedTraits {
+        derive_transparent_traits,
+        implement_traits,
+    })
+}
+
+fn gen_implemented_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &TupleInnerType,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    impl_traits: Vec<TupleIrregularTrait>,
+    maybe_default_value: Option<syn::Expr>,
+    guard: &TupleGuard,
+) -> Result<TokenStream, syn::Error> {
+    impl_traits
+        .iter()
+        .map(|t| match t {
+            TupleIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            TupleIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, inner_type)),
+            TupleIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type.clone())),
+            TupleIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            TupleIrregularTrait::PartialEqInner => Ok(gen_impl_trait_partial_eq_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            TupleIrregularTrait::PartialOrdInner => Ok(gen_impl_trait_partial_ord_inner(type_name, generics, inner_type, &InnerStorage::Plain)),
+            TupleIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            TupleIrregularTrait::TryFrom => Ok(
+                gen_impl_trait_try_from(type_name, generics, inner_type, maybe_error_type_name.as_ref())
+            ),
+            TupleIrregularTrait::Default => Ok(
+                match maybe_default_value {
+                    Some(ref default_value) => {
+                        let has_validation = maybe_error_type_name.is_some();
+                        gen_impl_trait_default(type_name, generics, default_value, has_validation)
+                    }
+                    None => {
+                        panic!(
+                            "Default trait is derived for type {type_name}, but `default = ` is missing"
+                        );
+                    }
+                }
+            ),
+            TupleIrregularTrait::SerdeSerialize => Ok(
+                gen_impl_trait_serde_serialize(type_name, generics)
+            ),
+            TupleIrregularTrait::SerdeDeserialize => Ok(
+                gen_impl_trait_serde_deserialize(type_name, generics, inner_type, maybe_error_type_name.as_ref())
+            ),
+            TupleIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, generics, inner_type, guard),
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+pub mod gen;
+pub mod models;
+pub mod parse;
+pub mod validate;
+
+use proc_macro2::TokenStream;
+use std::collections::HashSet;
+
+use self::models::{TupleDeriveTrait, TupleGuard, TupleInnerType, TupleSanitizer, TupleValidator};
+use crate::common::gen::GenerateNewtype;
+use crate::{
+    common::models::{Attributes, GenerateParams, Newtype, SpannedDeriveTrait},
+    tuple::validate::{validate_tuple_derive_traits, validate_tuple_positions},
+};
+
+pub struct TupleNewtype;
+
+impl Newtype for TupleNewtype {
+    type Sanitizer = TupleSanitizer;
+    type Validator = TupleValidator;
+    type TypedTrait = TupleDeriveTrait;
+    type InnerType = TupleInnerType;
+
+    fn parse_attributes(
+        attrs: TokenStream,
+    ) -> Result<Attributes<TupleGuard, SpannedDeriveTrait>, syn::Error> {
+        parse::parse_attributes(attrs)
+    }
+
+    fn validate(
+        guard: &TupleGuard,
+        derive_traits: Vec<SpannedDeriveTrait>,
+    ) -> Result<HashSet<Self::TypedTrait>, syn::Error> {
+        validate_tuple_derive_traits(guard, derive_traits)
+    }
+
+    fn generate(
+        params: GenerateParams<TupleInnerType, Self::TypedTrait, TupleGuard>,
+    ) -> Result<TokenStream, syn::Error> {
+        // Positions can be verified only now, when the inner type is known.
+        validate_tuple_positions(&params.inner_type, &params.guard)?;
+        TupleNewtype::gen_nutype(params)
+    }
+}
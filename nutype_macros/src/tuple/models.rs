@@ -0,0 +1,158 @@
+use kinded::Kinded;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use std::fmt::Debug;
+use syn::Field;
+
+use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait};
+
+/// Position of a tuple component, that a sanitizer or a validator is applied to.
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    pub index: usize,
+    pub span: Span,
+}
+
+/// Sanitizer for tuple types.
+/// It's applied either to the whole tuple (no position) or to a single component.
+#[derive(Debug, Clone)]
+pub struct TupleSanitizer {
+    pub position: Option<Position>,
+    pub rule: TupleSanitizerRule,
+}
+
+#[derive(Debug, Clone, Kinded)]
+#[kinded(display = "snake_case")]
+pub enum TupleSanitizerRule {
+    With(CustomFunction),
+}
+
+impl Kinded for TupleSanitizer {
+    type Kind = TupleSanitizerRuleKind;
+
+    fn kind(&self) -> Self::Kind {
+        self.rule.kind()
+    }
+}
+
+pub type SpannedTupleSanitizer = SpannedItem<TupleSanitizer>;
+
+/// Validator for tuple types.
+/// It's applied either to the whole tuple (no position) or to a single component.
+#[derive(Debug, Clone)]
+pub struct TupleValidator {
+    pub position: Option<Position>,
+    pub rule: TupleValidatorRule,
+}
+
+#[derive(Debug, Clone, Kinded)]
+#[kinded(display = "snake_case")]
+pub enum TupleValidatorRule {
+    Greater(syn::Expr),
+    GreaterOrEqual(syn::Expr),
+    Less(syn::Expr),
+    LessOrEqual(syn::Expr),
+    Finite,
+    Predicate(CustomFunction),
+}
+
+impl TupleValidatorRule {
+    /// Only `predicate` can be applied to the whole tuple, the rest of the rules make sense only
+    /// for a single component.
+    pub fn requires_position(&self) -> bool {
+        !matches!(self, Self::Predicate(_))
+    }
+}
+
+impl Kinded for TupleValidator {
+    type Kind = TupleValidatorRuleKind;
+
+    fn kind(&self) -> Self::Kind {
+        self.rule.kind()
+    }
+}
+
+pub type SpannedTupleValidator = SpannedItem<TupleValidator>;
+
+/// All sanitizers from `sanitize(...)` attribute.
+/// Tuple sanitizers are grouped by position (e.g. `0 => with = ...; 1 => with = ...`), so the
+/// whole content of the attribute is parsed at once.
+#[derive(Debug)]
+pub struct TupleSanitizers(pub Vec<SpannedTupleSanitizer>);
+
+/// All validators from `validate(...)` attribute.
+/// Tuple validators are grouped by position (e.g. `0 => less = 10, finite; 1 => less = 5`), so the
+/// whole content of the attribute is parsed at once.
+#[derive(Debug)]
+pub struct TupleValidators(pub Vec<SpannedTupleValidator>);
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum TupleDeriveTrait {
+    // Standard
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    PartialEqInner,
+    PartialOrdInner,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRef,
+    Into,
+    From,
+    Deref,
+    Borrow,
+    TryFrom,
+    Default,
+    Hash,
+
+    // External crates
+    SerdeSerialize,
+    SerdeDeserialize,
+    ArbitraryArbitrary,
+}
+
+impl TypeTrait for TupleDeriveTrait {
+    fn is_from_str(&self) -> bool {
+        false
+    }
+
+    fn is_serde_serialize(&self) -> bool {
+        self == &TupleDeriveTrait::SerdeSerialize
+    }
+
+    fn is_serde_deserialize(&self) -> bool {
+        self == &TupleDeriveTrait::SerdeDeserialize
+    }
+}
+
+pub type TupleRawGuard = RawGuard<SpannedTupleSanitizer, SpannedTupleValidator>;
+pub type TupleGuard = Guard<TupleSanitizer, TupleValidator>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TupleInnerType {
+    field: Field,
+    elems: Vec<syn::Type>,
+}
+
+impl TupleInnerType {
+    pub fn new(field: Field, elems: Vec<syn::Type>) -> Self {
+        Self { field, elems }
+    }
+
+    /// Type of the component at the given position.
+    pub fn component_type(&self, index: usize) -> Option<&syn::Type> {
+        self.elems.get(index)
+    }
+
+    pub fn arity(&self) -> usize {
+        self.elems.len()
+    }
+}
+
+impl ToTokens for TupleInnerType {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        self.field.to_tokens(token_stream)
+    }
+}
@@ -0,0 +1,208 @@
+use crate::common::{
+    models::{
+        Attributes, CheckedArithmetic, CustomFunction, NewSlice, Sensitive, SpannedDeriveTrait,
+        SqlCheck, ZodSchema,
+    },
+    parse::{
+        parse_sanitizer_kind, parse_validator_kind, reject_arithmetic, reject_borrowed,
+        reject_checked_arithmetic, reject_from_float, reject_len_unit, reject_mut_guard,
+        reject_new_slice, reject_sensitive, reject_serde_mode, reject_sql_check, reject_zod_schema,
+        ParseableAttributes,
+    },
+};
+use proc_macro2::TokenStream;
+use syn::{
+    parse::{Parse, ParseStream},
+    LitInt, Token,
+};
+
+use super::{
+    models::{
+        Position, SpannedTupleSanitizer, SpannedTupleValidator, TupleGuard, TupleRawGuard,
+        TupleSanitizer, TupleSanitizerRule, TupleSanitizerRuleKind, TupleSanitizers,
+        TupleValidator, TupleValidatorRule, TupleValidatorRuleKind, TupleValidators,
+    },
+    validate::validate_tuple_guard,
+};
+
+pub fn parse_attributes(
+    input: TokenStream,
+) -> Result<Attributes<TupleGuard, SpannedDeriveTrait>, syn::Error> {
+    let attrs: ParseableAttributes<TupleSanitizers, TupleValidators> = syn::parse2(input)?;
+
+    let ParseableAttributes {
+        sanitizers,
+        validators,
+        new_unchecked,
+        no_new,
+        constructor,
+        new_slice,
+        checked_arithmetic,
+        sql_check,
+        zod_schema,
+        sensitive,
+        default,
+        convertible_with,
+        borrowed,
+        consts,
+        on_error,
+        from_float,
+        error: error_placement,
+        mut_guard,
+        len_unit,
+        serde,
+        arithmetic,
+        debug,
+        derive_traits,
+    } = attrs;
+    reject_from_float(from_float)?;
+    reject_new_slice(new_slice)?;
+    reject_checked_arithmetic(checked_arithmetic)?;
+    reject_arithmetic(arithmetic)?;
+    reject_sql_check(sql_check)?;
+    reject_zod_schema(zod_schema)?;
+    reject_borrowed(borrowed)?;
+    reject_len_unit(len_unit)?;
+    reject_sensitive(sensitive)?;
+    reject_mut_guard(mut_guard)?;
+    reject_serde_mode(serde)?;
+    let raw_guard = TupleRawGuard {
+        sanitizers: sanitizers.into_iter().flat_map(|s| s.0).collect(),
+        validators: validators.into_iter().flat_map(|v| v.0).collect(),
+    };
+    let guard = validate_tuple_guard(raw_guard)?;
+    Ok(Attributes {
+        new_unchecked,
+        no_new,
+        maybe_constructor_name: constructor,
+        new_slice: NewSlice::Off,
+        checked_arithmetic: CheckedArithmetic::Off,
+        sql_check: SqlCheck::Off,
+        zod_schema: ZodSchema::Off,
+        sensitive: Sensitive::Off,
+        guard,
+        default,
+        convertible_with,
+        maybe_borrowed_type_name: None,
+        consts,
+        maybe_on_error: on_error,
+        maybe_from_float: None,
+        maybe_error_placement: error_placement,
+        maybe_mut_guard: None,
+        maybe_serde_mode: None,
+        maybe_arithmetic_policy: None,
+        maybe_debug_mode: debug.map(|debug| debug.item),
+        derive_traits,
+    })
+}
+
+impl Parse for TupleSanitizers {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let sanitizers = parse_positioned_items(input, |input, position| {
+            let (kind, _ident) = parse_sanitizer_kind(input)?;
+            match kind {
+                TupleSanitizerRuleKind::With => {
+                    let _eq: Token![=] = input.parse()?;
+                    let span = input.span();
+                    let custom_function: CustomFunction = input.parse()?;
+                    Ok(SpannedTupleSanitizer {
+                        item: TupleSanitizer {
+                            position,
+                            rule: TupleSanitizerRule::With(custom_function),
+                        },
+                        span,
+                    })
+                }
+            }
+        })?;
+        Ok(TupleSanitizers(sanitizers))
+    }
+}
+
+impl Parse for TupleValidators {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let validators = parse_positioned_items(input, |input, position| {
+            let (kind, ident) = parse_validator_kind(input)?;
+            let (rule, span) = match kind {
+                TupleValidatorRuleKind::Greater => {
+                    let expr = parse_expr_value(input)?;
+                    (TupleValidatorRule::Greater(expr), ident.span())
+                }
+                TupleValidatorRuleKind::GreaterOrEqual => {
+                    let expr = parse_expr_value(input)?;
+                    (TupleValidatorRule::GreaterOrEqual(expr), ident.span())
+                }
+                TupleValidatorRuleKind::Less => {
+                    let expr = parse_expr_value(input)?;
+                    (TupleValidatorRule::Less(expr), ident.span())
+                }
+                TupleValidatorRuleKind::LessOrEqual => {
+                    let expr = parse_expr_value(input)?;
+                    (TupleValidatorRule::LessOrEqual(expr), ident.span())
+                }
+                TupleValidatorRuleKind::Finite => (TupleValidatorRule::Finite, ident.span()),
+                TupleValidatorRuleKind::Predicate => {
+                    let _eq: Token![=] = input.parse()?;
+                    let custom_function: CustomFunction = input.parse()?;
+                    (TupleValidatorRule::Predicate(custom_function), ident.span())
+                }
+            };
+            if position.is_none() && rule.requires_position() {
+                let msg = format!(
+                    "Validator `{kind}` must be applied to a tuple component (e.g. `0 => {kind}`)."
+                );
+                return Err(syn::Error::new(span, msg));
+            }
+            Ok(SpannedTupleValidator {
+                item: TupleValidator { position, rule },
+                span,
+            })
+        })?;
+        Ok(TupleValidators(validators))
+    }
+}
+
+fn parse_expr_value(input: ParseStream) -> syn::Result<syn::Expr> {
+    let _eq: Token![=] = input.parse()?;
+    input.parse()
+}
+
+/// Parse items, that are optionally grouped by position of a tuple component:
+///
+///    predicate = is_valid, 0 => greater = 0, less = 10; 1 => finite
+///
+/// A group starts with `<index> =>` and lasts until `;` or until the next group.
+/// Items outside of a group apply to the whole tuple.
+fn parse_positioned_items<T>(
+    input: ParseStream,
+    parse_item: impl Fn(ParseStream, Option<Position>) -> syn::Result<T>,
+) -> syn::Result<Vec<T>> {
+    let mut items = Vec::new();
+    let mut position: Option<Position> = None;
+
+    while !input.is_empty() {
+        if input.peek(LitInt) && input.peek2(Token![=>]) {
+            let lit: LitInt = input.parse()?;
+            let _arrow: Token![=>] = input.parse()?;
+            let index: usize = lit.base10_parse()?;
+            position = Some(Position {
+                index,
+                span: lit.span(),
+            });
+        }
+
+        items.push(parse_item(input, position)?);
+
+        if input.is_empty() {
+            break;
+        }
+        if input.peek(Token![;]) {
+            let _semicolon: Token![;] = input.parse()?;
+            position = None;
+        } else {
+            let _comma: Token![,] = input.parse()?;
+        }
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeSet, HashSet};
+
+use proc_macro2::Span;
+
+use crate::common::{
+    models::{DeriveTrait, SpannedDeriveTrait},
+    validate::validate_duplicates,
+};
+
+use super::models::{
+    SpannedTupleSanitizer, SpannedTupleValidator, TupleDeriveTrait, TupleGuard, TupleInnerType,
+    TupleRawGuard, TupleSanitizer, TupleValidator,
+};
+
+pub fn validate_tuple_guard(raw_guard: TupleRawGuard) -> Result<TupleGuard, syn::Error> {
+    let TupleRawGuard {
+        sanitizers,
+        validators,
+    } = raw_guard;
+
+    let validators = validate_validators(validators)?;
+    let sanitizers = validate_sanitizers(sanitizers)?;
+
+    if validators.is_empty() {
+        Ok(TupleGuard::WithoutValidation { sanitizers })
+    } else {
+        Ok(TupleGuard::WithValidation {
+            sanitizers,
+            validators,
+        })
+    }
+}
+
+fn validate_validators(
+    validators: Vec<SpannedTupleValidator>,
+) -> Result<Vec<TupleValidator>, syn::Error> {
+    // The same validator may be used for different components, so duplicates are detected
+    // within every position separately.
+    for index in unique_positions(validators.iter().map(|v| v.item.position.map(|p| p.index))) {
+        let group: Vec<SpannedTupleValidator> = validators
+            .iter()
+            .filter(|v| v.item.position.map(|p| p.index) == index)
+            .cloned()
+            .collect();
+        validate_duplicates(&group, |kind| {
+            format!("Duplicated validators `{kind}`.\nOne per tuple component is plenty.")
+        })?;
+    }
+
+    let validators: Vec<TupleValidator> = validators.into_iter().map(|v| v.item).collect();
+    Ok(validators)
+}
+
+fn validate_sanitizers(
+    sanitizers: Vec<SpannedTupleSanitizer>,
+) -> Result<Vec<TupleSanitizer>, syn::Error> {
+    for index in unique_positions(sanitizers.iter().map(|s| s.item.position.map(|p| p.index))) {
+        let group: Vec<SpannedTupleSanitizer> = sanitizers
+            .iter()
+            .filter(|s| s.item.position.map(|p| p.index) == index)
+            .cloned()
+            .collect();
+        validate_duplicates(&group, |kind| {
+            format!("Duplicated sanitizer `{kind}`.\nOne per tuple component is plenty.")
+        })?;
+    }
+
+    let sanitizers: Vec<_> = sanitizers.into_iter().map(|s| s.item).collect();
+    Ok(sanitizers)
+}
+
+fn unique_positions(positions: impl Iterator<Item = Option<usize>>) -> BTreeSet<Option<usize>> {
+    positions.collect()
+}
+
+/// Make sure that every position refers to an existing component of the tuple.
+pub fn validate_tuple_positions(
+    inner_type: &TupleInnerType,
+    guard: &TupleGuard,
+) -> Result<(), syn::Error> {
+    let (sanitizers, validators): (&[TupleSanitizer], &[TupleValidator]) = match guard {
+        TupleGuard::WithoutValidation { sanitizers } => (sanitizers, &[]),
+        TupleGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers, validators),
+    };
+
+    let positions = sanitizers
+        .iter()
+        .filter_map(|s| s.position)
+        .chain(validators.iter().filter_map(|v| v.position));
+
+    for position in positions {
+        let arity = inner_type.arity();
+        if position.index >= arity {
+            let msg = format!(
+                "Position {} is out of bounds. The tuple has only {arity} components.",
+                position.index
+            );
+            return Err(syn::Error::new(position.span, msg));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_tuple_derive_traits(
+    guard: &TupleGuard,
+    spanned_derive_traits: Vec<SpannedDeriveTrait>,
+) -> Result<HashSet<TupleDeriveTrait>, syn::Error> {
+    let mut traits = HashSet::with_capacity(24);
+    let has_validation = guard.has_validation();
+
+    for spanned_trait in spanned_derive_traits {
+        let tuple_derive_trait =
+            to_tuple_derive_trait(spanned_trait.item, has_validation, spanned_trait.span)?;
+        traits.insert(tuple_derive_trait);
+    }
+
+    Ok(traits)
+}
+
+fn to_tuple_derive_trait(
+    tr: DeriveTrait,
+    has_validation: bool,
+    span: Span,
+) -> Result<TupleDeriveTrait, syn::Error> {
+    match tr {
+        DeriveTrait::Debug => Ok(TupleDeriveTrait::Debug),
+        DeriveTrait::Clone => Ok(TupleDeriveTrait::Clone),
+        DeriveTrait::Copy => Ok(TupleDeriveTrait::Copy),
+        DeriveTrait::PartialEq => Ok(TupleDeriveTrait::PartialEq),
+        DeriveTrait::PartialEqInner => Ok(TupleDeriveTrait::PartialEqInner),
+        DeriveTrait::PartialOrdInner => Ok(TupleDeriveTrait::PartialOrdInner),
+        DeriveTrait::Eq => Ok(TupleDeriveTrait::Eq),
+        DeriveTrait::PartialOrd => Ok(TupleDeriveTrait::PartialOrd),
+        DeriveTrait::Ord => Ok(TupleDeriveTrait::Ord),
+        DeriveTrait::AsRef => Ok(TupleDeriveTrait::AsRef),
+        DeriveTrait::Into => Ok(TupleDeriveTrait::Into),
+        DeriveTrait::Deref => Ok(TupleDeriveTrait::Deref),
+        DeriveTrait::Borrow => Ok(TupleDeriveTrait::Borrow),
+        DeriveTrait::TryFrom => Ok(TupleDeriveTrait::TryFrom),
+        DeriveTrait::Default => Ok(TupleDeriveTrait::Default),
+        DeriveTrait::Hash => Ok(TupleDeriveTrait::Hash),
+        DeriveTrait::SerdeSerialize => Ok(TupleDeriveTrait::SerdeSerialize),
+        DeriveTrait::SerdeDeserialize => Ok(TupleDeriveTrait::SerdeDeserialize),
+        DeriveTrait::ArbitraryArbitrary => Ok(TupleDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::From => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead.",
+                ))
+            } else {
+                Ok(TupleDeriveTrait::From)
+            }
+        }
+        DeriveTrait::Display
+        | DeriveTrait::FromStr
+        | DeriveTrait::SchemarsJsonSchema
+        | DeriveTrait::NumTraitsToPrimitive
+        | DeriveTrait::NumTraitsFromPrimitive
+        | DeriveTrait::NumTraitsBounded
+        | DeriveTrait::NumTraitsZero
+        | DeriveTrait::NumTraitsOne
+        | DeriveTrait::OrderedFloatNotNan
+        | DeriveTrait::OrderedFloatOrderedFloat
+        | DeriveTrait::LowerHex
+        | DeriveTrait::UpperHex
+        | DeriveTrait::Binary
+        | DeriveTrait::Octal
+        | DeriveTrait::Sum
+        | DeriveTrait::Product
+        | DeriveTrait::Add
+        | DeriveTrait::Sub
+        | DeriveTrait::Mul
+        | DeriveTrait::AddInner
+        | DeriveTrait::SubInner
+        | DeriveTrait::MulInner
+        | DeriveTrait::Neg
+        | DeriveTrait::AddAssign
+        | DeriveTrait::SubAssign
+        | DeriveTrait::MulAssign
+        | DeriveTrait::FromIterator
+        | DeriveTrait::TryFromIterator
+        | DeriveTrait::TryFromStr => {
+            let msg = format!("#[nutype] cannot derive `{tr:?}` trait for tuple types.");
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod issue_reporter;
+pub mod tokens;
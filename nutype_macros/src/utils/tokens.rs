@@ -0,0 +1,12 @@
+//! Helpers to inspect token streams.
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+
+/// Check if the identifier appears anywhere in the token stream, including nested groups.
+pub fn contains_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ref other) => other == ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
@@ -61,6 +61,7 @@ mod traits {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_clone() {
         let location = Location::new(Point::new(5, 8));
         let same_location = location.clone();
@@ -165,6 +166,16 @@ mod traits {
         assert_eq!(point, &Point::new(3, 4));
     }
 
+    #[test]
+    fn test_partial_eq_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>))]
+        pub struct Position(Point);
+
+        let position = Position::new(Point::new(3, 4));
+        assert_eq!(position, Point::new(3, 4));
+        assert_eq!(Point::new(3, 4), position);
+    }
+
     mod from_str {
         use super::*;
 
@@ -242,6 +253,53 @@ mod traits {
         }
     }
 
+    mod from_iterator {
+        use super::*;
+        use std::collections::BTreeSet;
+
+        #[test]
+        fn test_from_iterator() {
+            #[nutype(
+                sanitize(with = |mut ids: Vec<u32>| { ids.sort(); ids.dedup(); ids }),
+                derive(Debug, FromIterator, AsRef)
+            )]
+            pub struct UniqueIds(Vec<u32>);
+
+            let ids: UniqueIds = [3, 1, 3, 2].into_iter().collect();
+            assert_eq!(ids.as_ref(), &[1, 2, 3]);
+        }
+
+        #[test]
+        fn test_try_from_iterator() {
+            #[nutype(
+                validate(not_empty, len_max = 3),
+                derive(Debug, PartialEq, TryFromIterator, AsRef)
+            )]
+            pub struct Tags(BTreeSet<String>);
+
+            let tags = Tags::try_from_iter(["b", "a", "b"].map(String::from)).unwrap();
+            assert_eq!(tags.as_ref().iter().collect::<Vec<_>>(), vec!["a", "b"]);
+
+            assert_eq!(
+                Tags::try_from_iter(Vec::<String>::new()),
+                Err(TagsError::NotEmptyViolated)
+            );
+            assert_eq!(
+                Tags::try_from_iter((0..4).map(|n| n.to_string())),
+                Err(TagsError::LenMaxViolated)
+            );
+        }
+
+        #[test]
+        fn test_try_from_iterator_without_validation() {
+            #[nutype(derive(Debug, TryFromIterator, AsRef))]
+            pub struct Bytes(Vec<u8>);
+
+            let bytes = Bytes::try_from_iter(b"abc".iter().copied()).unwrap();
+            assert_eq!(bytes.as_ref(), b"abc");
+        }
+    }
+
     #[test]
     fn test_default() {
         #[nutype(
@@ -300,6 +358,76 @@ mod traits {
             }
         }
 
+        mod as_string {
+            use super::*;
+            use std::{fmt, str::FromStr};
+
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct Version {
+                major: u32,
+                minor: u32,
+            }
+
+            impl fmt::Display for Version {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}.{}", self.major, self.minor)
+                }
+            }
+
+            impl FromStr for Version {
+                type Err = String;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let (major, minor) = s
+                        .split_once('.')
+                        .ok_or_else(|| format!("`{s}` is not a version."))?;
+                    let parse = |n: &str| n.parse::<u32>().map_err(|e| e.to_string());
+                    Ok(Version {
+                        major: parse(major)?,
+                        minor: parse(minor)?,
+                    })
+                }
+            }
+
+            #[nutype(
+                validate(predicate = |v: &Version| v.major >= 1),
+                serde(as_string),
+                derive(Debug, PartialEq, Serialize, Deserialize)
+            )]
+            pub struct StableVersion(Version);
+
+            #[test]
+            fn test_roundtrip() {
+                let version = StableVersion::new(Version {
+                    major: 1,
+                    minor: 12,
+                })
+                .unwrap();
+
+                let json = serde_json::to_string(&version).unwrap();
+                assert_eq!(json, r#""1.12""#);
+                assert_eq!(
+                    serde_json::from_str::<StableVersion>(&json).unwrap(),
+                    version
+                );
+            }
+
+            #[test]
+            fn test_deserialize_rejects_invalid_values() {
+                let err = serde_json::from_str::<StableVersion>(r#""0.9""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "StableVersion failed the predicate test. Expected valid StableVersion"
+                );
+
+                let err = serde_json::from_str::<StableVersion>(r#""latest""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "`latest` is not a version. Expected a string representation of Version"
+                );
+            }
+        }
+
         mod ron_format {
             use super::*;
 
@@ -364,6 +492,282 @@ fn test_sanitize_with_untyped_mut_closure() {
     assert_eq!(pos.into_inner(), Point::new(100, 91))
 }
 
+#[test]
+fn test_new_or_return() {
+    #[nutype(
+        sanitize(with = |mut v| { v.sort(); v }),
+        validate(predicate = |v| v.len() <= 3),
+        derive(Debug)
+    )]
+    pub struct Podium(Vec<u8>);
+
+    let (values, err) = Podium::new_or_return(vec![4, 1, 3, 2]).unwrap_err();
+    assert_eq!(values, vec![1, 2, 3, 4]);
+    assert_eq!(err, PodiumError::PredicateViolated);
+
+    let podium = Podium::new_or_return(vec![2, 1]).unwrap();
+    assert_eq!(podium.into_inner(), vec![1, 2]);
+}
+
+#[cfg(test)]
+mod const_generics {
+    use super::*;
+
+    #[nutype(validate(len_max = N), derive(Debug, AsRef))]
+    pub struct Buffer<const N: usize>(Vec<u8>);
+
+    #[test]
+    fn test_validation_with_const_generics() {
+        assert!(Buffer::<2>::new(vec![1, 2]).is_ok());
+        assert_eq!(
+            Buffer::<2>::new(vec![1, 2, 3]).unwrap_err(),
+            BufferError::LenMaxViolated
+        );
+    }
+}
+
+#[cfg(test)]
+mod mut_guard {
+    use super::*;
+
+    #[nutype(
+        sanitize(with = |mut ids: Vec<u32>| { ids.sort(); ids.dedup(); ids }),
+        validate(len_max = 3),
+        mut_guard = restore,
+        derive(Debug, AsRef)
+    )]
+    pub struct UniqueIds(Vec<u32>);
+
+    #[nutype(
+        sanitize(with = |mut points: Vec<Point>| { points.sort(); points }),
+        mut_guard = panic,
+        derive(Debug, AsRef)
+    )]
+    pub struct Path(Vec<Point>);
+
+    #[test]
+    fn test_bulk_edit() {
+        let mut ids = UniqueIds::new(vec![2]).unwrap();
+        ids.as_mut_guard().extend([3, 1, 3]);
+        assert_eq!(ids.as_ref(), &[1, 2, 3]);
+
+        ids.as_mut_guard().push(4);
+        assert_eq!(ids.as_ref(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_with_const_generics() {
+        #[nutype(validate(len_max = N), mut_guard = restore, derive(Debug, AsRef))]
+        pub struct Buffer<const N: usize>(Vec<u8>);
+
+        let mut buffer = Buffer::<2>::new(vec![1]).unwrap();
+        buffer.as_mut_guard().push(2);
+        buffer.as_mut_guard().push(3);
+        assert_eq!(buffer.as_ref(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_without_validation() {
+        let mut path = Path::new(vec![Point::new(2, 2)]);
+        {
+            let mut guard = path.as_mut_guard();
+            guard.push(Point::new(1, 1));
+            assert_eq!(guard.len(), 2);
+        }
+        assert_eq!(path.as_ref(), &[Point::new(1, 1), Point::new(2, 2)]);
+    }
+}
+
+#[cfg(test)]
+mod consts {
+    use super::*;
+
+    #[nutype(
+        validate(predicate = |p| p.x >= 0 && p.y >= 0),
+        consts(ORIGIN = Point { x: 0, y: 0 }),
+        derive(Debug, PartialEq)
+    )]
+    pub struct ScreenPoint(Point);
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(ScreenPoint::ORIGIN.into_inner(), Point::new(0, 0));
+    }
+}
+
+#[cfg(test)]
+mod len_validators {
+    use super::*;
+
+    #[nutype(validate(not_empty, len_max = 3), derive(Debug, PartialEq, AsRef))]
+    pub struct Tags(Vec<u8>);
+
+    #[nutype(validate(len_min = 3, len_max = 8), derive(Debug, PartialEq, Deref))]
+    pub struct DeviceName(heapless::String<8>);
+
+    #[nutype(validate(not_empty), derive(Debug))]
+    pub struct Samples(heapless::Vec<i16, 4>);
+
+    #[test]
+    fn test_vec() {
+        assert_eq!(Tags::new(vec![]).unwrap_err(), TagsError::NotEmptyViolated);
+        assert_eq!(
+            Tags::new(vec![1, 2, 3, 4]).unwrap_err(),
+            TagsError::LenMaxViolated
+        );
+        assert_eq!(Tags::new(vec![1, 2, 3]).unwrap().as_ref(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_heapless_string() {
+        let name = |s: &str| heapless::String::<8>::try_from(s).unwrap();
+
+        assert_eq!(
+            DeviceName::new(name("ab")).unwrap_err(),
+            DeviceNameError::LenMinViolated
+        );
+        assert_eq!(DeviceName::new(name("sensor")).unwrap().as_str(), "sensor");
+    }
+
+    #[test]
+    fn test_heapless_vec() {
+        let mut samples = heapless::Vec::<i16, 4>::new();
+        assert_eq!(
+            Samples::new(samples.clone()).unwrap_err(),
+            SamplesError::NotEmptyViolated
+        );
+
+        samples.push(-7).unwrap();
+        assert_eq!(Samples::new(samples).unwrap().into_inner(), [-7]);
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            TagsError::LenMaxViolated.to_string(),
+            "Tags is too long. The value length must be at most 3."
+        );
+        assert_eq!(
+            DeviceNameError::LenMinViolated.to_string(),
+            "DeviceName is too short. The value length must be at least 3."
+        );
+        assert_eq!(TagsError::NotEmptyViolated.to_string(), "Tags is empty.");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_inner {
+    use super::*;
+    use smallvec::{smallvec, SmallVec};
+
+    #[nutype(
+        validate(len_min = 1, len_max = 4),
+        derive(Debug, Clone, PartialEq, AsRef)
+    )]
+    pub struct Ports(SmallVec<[u16; 4]>);
+
+    #[test]
+    fn test_len_validators() {
+        assert_eq!(
+            Ports::new(SmallVec::new()).unwrap_err(),
+            PortsError::LenMinViolated
+        );
+        assert_eq!(
+            Ports::new(smallvec![1, 2, 3, 4, 5]).unwrap_err(),
+            PortsError::LenMaxViolated
+        );
+
+        let ports = Ports::new(smallvec![80, 443]).unwrap();
+        assert_eq!(ports.as_ref().as_slice(), &[80, 443]);
+        assert!(!ports.into_inner().spilled());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_inner {
+    use super::*;
+    use arrayvec::{ArrayString, ArrayVec};
+
+    #[nutype(validate(not_empty, len_max = 2), derive(Debug, PartialEq))]
+    pub struct Pair(ArrayVec<u8, 3>);
+
+    #[nutype(validate(len_min = 2), derive(Debug, PartialEq, Deref))]
+    pub struct Code(ArrayString<4>);
+
+    #[test]
+    fn test_array_vec() {
+        assert_eq!(
+            Pair::new(ArrayVec::new()).unwrap_err(),
+            PairError::NotEmptyViolated
+        );
+        assert_eq!(
+            Pair::new(ArrayVec::from([1, 2, 3])).unwrap_err(),
+            PairError::LenMaxViolated
+        );
+
+        let pair: ArrayVec<u8, 3> = [1, 2].into_iter().collect();
+        assert_eq!(Pair::new(pair).unwrap().into_inner().as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_array_string() {
+        let code = |s: &str| ArrayString::<4>::from(s).unwrap();
+
+        assert_eq!(Code::new(code("a")).unwrap_err(), CodeError::LenMinViolated);
+        assert_eq!(Code::new(code("UA")).unwrap().as_str(), "UA");
+    }
+}
+
+#[cfg(test)]
+mod debug_redacted {
+    use super::*;
+
+    #[test]
+    fn test_debug_hides_inner_value() {
+        #[nutype(debug = redacted, derive(Debug))]
+        pub struct Email(Vec<u8>);
+
+        let email = Email::new(b"alice@example.com".to_vec());
+        assert_eq!(format!("{email:?}"), "Email(***)");
+        assert_eq!(email.into_inner(), b"alice@example.com");
+    }
+
+    // The inner type is not required to implement `Debug`.
+    pub struct PrivateKey([u8; 4]);
+
+    #[nutype(debug = redacted, derive(Debug))]
+    pub struct Signer(PrivateKey);
+
+    #[test]
+    fn test_debug_without_debug_of_inner_type() {
+        let signer = Signer::new(PrivateKey([1, 2, 3, 4]));
+        assert_eq!(format!("{signer:?}"), "Signer(***)");
+        assert_eq!(signer.into_inner().0, [1, 2, 3, 4]);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "subtle")]
+mod sensitive {
+    use super::*;
+
+    #[nutype(
+        sensitive,
+        validate(predicate = |key| key.len() == 4),
+        derive(Debug, PartialEq)
+    )]
+    pub struct SecretKey(Vec<u8>);
+
+    #[test]
+    fn test_constant_time_eq() {
+        let key = SecretKey::new(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(key, SecretKey::new(vec![1, 2, 3, 4]).unwrap());
+        assert_ne!(key, SecretKey::new(vec![1, 2, 3, 5]).unwrap());
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "new_unchecked")]
 mod new_unchecked {
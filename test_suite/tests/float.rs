@@ -38,6 +38,82 @@ mod sanitizers {
         }
     }
 
+    const MAX_VOLUME: f32 = 11.0;
+
+    #[test]
+    fn test_clamp() {
+        #[nutype(sanitize(clamp(min = 0.0, max = 100.0)))]
+        pub struct Percent(f64);
+
+        assert_eq!(Percent::new(-10.0).into_inner(), 0.0);
+        assert_eq!(Percent::new(42.5).into_inner(), 42.5);
+        assert_eq!(Percent::new(222.0).into_inner(), 100.0);
+    }
+
+    #[test]
+    fn test_clamp_with_const_and_validation() {
+        #[nutype(
+            sanitize(clamp(min = 0.0, max = MAX_VOLUME)),
+            validate(finite),
+            derive(Debug)
+        )]
+        pub struct Volume(f32);
+
+        assert_eq!(Volume::new(12.0).unwrap().into_inner(), 11.0);
+        assert_eq!(Volume::new(f32::INFINITY).unwrap().into_inner(), 11.0);
+        assert_eq!(
+            Volume::new(f32::NAN).unwrap_err(),
+            VolumeError::FiniteViolated
+        );
+    }
+
+    #[test]
+    fn test_round() {
+        #[nutype(sanitize(round = 2))]
+        pub struct Price(f64);
+
+        assert_eq!(Price::new(9.994).into_inner(), 9.99);
+        assert_eq!(Price::new(9.996).into_inner(), 10.0);
+        assert_eq!(Price::new(-1.006).into_inner(), -1.01);
+        assert_eq!(Price::new(f64::MAX).into_inner(), f64::MAX);
+        assert!(Price::new(f64::NAN).into_inner().is_nan());
+    }
+
+    #[test]
+    fn test_round_to_integer() {
+        #[nutype(sanitize(round = 0))]
+        pub struct Score(f32);
+
+        assert_eq!(Score::new(2.5).into_inner(), 3.0);
+        assert_eq!(Score::new(2.4).into_inner(), 2.0);
+    }
+
+    #[test]
+    fn test_floor_and_ceil() {
+        #[nutype(sanitize(floor = 1))]
+        pub struct Lower(f64);
+
+        #[nutype(sanitize(ceil = 1))]
+        pub struct Upper(f64);
+
+        assert_eq!(Lower::new(1.29).into_inner(), 1.2);
+        assert_eq!(Lower::new(-1.21).into_inner(), -1.3);
+        assert_eq!(Upper::new(1.21).into_inner(), 1.3);
+        assert_eq!(Upper::new(-1.29).into_inner(), -1.2);
+    }
+
+    #[test]
+    fn test_round_before_validation() {
+        #[nutype(sanitize(round = 6), validate(latitude), derive(Debug, PartialEq))]
+        pub struct Latitude(f64);
+
+        assert_eq!(
+            Latitude::new(52.520_008_4).unwrap().into_inner(),
+            52.520_008
+        );
+        assert_eq!(Latitude::new(90.000_000_1).unwrap().into_inner(), 90.0);
+    }
+
     #[test]
     fn test_from_trait() {
         #[nutype(derive(From))]
@@ -104,6 +180,38 @@ mod validators {
     }
 
     #[test]
+    fn test_greater_and_less() {
+        #[nutype(validate(greater = 0.0, less = 1.0), derive(Debug))]
+        struct Probability(f64);
+
+        assert_eq!(
+            Probability::new(0.0).unwrap_err(),
+            ProbabilityError::GreaterViolated
+        );
+        assert_eq!(
+            Probability::new(1.0).unwrap_err(),
+            ProbabilityError::LessViolated
+        );
+        assert_eq!(Probability::new(0.5).unwrap().into_inner(), 0.5);
+    }
+
+    #[test]
+    fn test_boundaries_reject_nan() {
+        #[nutype(validate(greater_or_equal = 18.0), derive(Debug))]
+        struct Min(f64);
+
+        #[nutype(validate(less = 99.0), derive(Debug))]
+        struct Max(f32);
+
+        assert_eq!(
+            Min::new(f64::NAN).unwrap_err(),
+            MinError::GreaterOrEqualViolated
+        );
+        assert_eq!(Max::new(f32::NAN).unwrap_err(), MaxError::LessViolated);
+    }
+
+    #[test]
+    #[allow(clippy::zero_divided_by_zero)]
     fn test_finite_f64() {
         #[nutype(validate(finite), derive(Debug, PartialEq))]
         struct Dist(f64);
@@ -122,6 +230,7 @@ mod validators {
     }
 
     #[test]
+    #[allow(clippy::zero_divided_by_zero)]
     fn test_finite_f32() {
         #[nutype(
             validate(finite),
@@ -139,6 +248,96 @@ mod validators {
         assert_eq!(Dist::new(-999.12).unwrap().into_inner(), -999.12);
     }
 
+    #[test]
+    fn test_latitude() {
+        #[nutype(validate(latitude), derive(Debug, PartialEq))]
+        struct Latitude(f64);
+
+        assert_eq!(Latitude::new(-90.1), Err(LatitudeError::LatitudeViolated));
+        assert_eq!(Latitude::new(90.1), Err(LatitudeError::LatitudeViolated));
+        assert_eq!(
+            Latitude::new(f64::NAN),
+            Err(LatitudeError::LatitudeViolated)
+        );
+        assert_eq!(
+            Latitude::new(f64::INFINITY),
+            Err(LatitudeError::LatitudeViolated)
+        );
+
+        assert_eq!(Latitude::new(-90.0).unwrap().into_inner(), -90.0);
+        assert_eq!(Latitude::new(90.0).unwrap().into_inner(), 90.0);
+        assert_eq!(Latitude::new(50.45).unwrap().into_inner(), 50.45);
+    }
+
+    #[test]
+    fn test_longitude() {
+        #[nutype(validate(longitude), derive(Debug, PartialEq, Eq, PartialOrd, Ord))]
+        struct Longitude(f32);
+
+        assert_eq!(
+            Longitude::new(-180.5),
+            Err(LongitudeError::LongitudeViolated)
+        );
+        assert_eq!(
+            Longitude::new(180.5),
+            Err(LongitudeError::LongitudeViolated)
+        );
+        assert_eq!(
+            Longitude::new(f32::NEG_INFINITY),
+            Err(LongitudeError::LongitudeViolated)
+        );
+
+        assert_eq!(Longitude::new(-180.0).unwrap().into_inner(), -180.0);
+        assert_eq!(Longitude::new(30.52).unwrap().into_inner(), 30.52);
+    }
+
+    #[test]
+    fn test_latitude_with_bounds() {
+        #[nutype(validate(latitude, greater_or_equal = 0.0), derive(Debug, PartialEq))]
+        struct NorthernLatitude(f64);
+
+        assert_eq!(
+            NorthernLatitude::new(-1.0),
+            Err(NorthernLatitudeError::GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            NorthernLatitude::new(91.0),
+            Err(NorthernLatitudeError::LatitudeViolated)
+        );
+        assert_eq!(NorthernLatitude::new(45.0).unwrap().into_inner(), 45.0);
+    }
+
+    #[test]
+    fn test_percentage() {
+        #[nutype(validate(percentage), derive(Debug, PartialEq))]
+        struct Humidity(f32);
+
+        assert_eq!(Humidity::new(-0.1), Err(HumidityError::PercentageViolated));
+        assert_eq!(Humidity::new(100.1), Err(HumidityError::PercentageViolated));
+        assert_eq!(
+            Humidity::new(f32::NAN),
+            Err(HumidityError::PercentageViolated)
+        );
+
+        assert_eq!(Humidity::new(0.0).unwrap().into_inner(), 0.0);
+        assert_eq!(Humidity::new(100.0).unwrap().into_inner(), 100.0);
+        assert_eq!(Humidity::new(42.5).unwrap().into_inner(), 42.5);
+    }
+
+    #[test]
+    fn test_ratio() {
+        #[nutype(validate(ratio), derive(Debug, PartialEq, Eq, PartialOrd, Ord))]
+        struct Opacity(f64);
+
+        assert_eq!(Opacity::new(-0.1), Err(OpacityError::RatioViolated));
+        assert_eq!(Opacity::new(1.1), Err(OpacityError::RatioViolated));
+        assert_eq!(Opacity::new(f64::NAN), Err(OpacityError::RatioViolated));
+
+        assert_eq!(Opacity::new(0.0).unwrap().into_inner(), 0.0);
+        assert_eq!(Opacity::new(1.0).unwrap().into_inner(), 1.0);
+        assert_eq!(Opacity::new(0.75).unwrap().into_inner(), 0.75);
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -258,6 +457,28 @@ mod validators {
                 "Percentage is too small. The value must be greater or equal to 0.0."
             );
         }
+
+        #[test]
+        fn test_latitude_error_display() {
+            #[nutype(validate(latitude), derive(Debug))]
+            struct Latitude(f64);
+
+            assert_eq!(
+                Latitude::new(100.0).unwrap_err().to_string(),
+                "Latitude is not a valid latitude. The value must be between -90.0 and 90.0."
+            );
+        }
+
+        #[test]
+        fn test_ratio_error_display() {
+            #[nutype(validate(ratio), derive(Debug))]
+            struct Opacity(f32);
+
+            assert_eq!(
+                Opacity::new(1.5).unwrap_err().to_string(),
+                "Opacity is not a valid ratio. The value must be between 0.0 and 1.0."
+            );
+        }
     }
 }
 
@@ -428,6 +649,191 @@ mod traits {
         assert_eq!(error, DistError::LessOrEqualViolated);
     }
 
+    #[test]
+    fn test_trait_try_from_str() {
+        #[nutype(validate(less_or_equal = 12.34), derive(Debug, TryFrom<&str>))]
+        pub struct Dist(f64);
+
+        let dist = Dist::try_from("12.34").unwrap();
+        assert_eq!(dist.into_inner(), 12.34);
+
+        let error = Dist::try_from("far").unwrap_err();
+        assert!(matches!(error, DistParseError::Parse(_)));
+
+        let error = Dist::try_from("12.35").unwrap_err();
+        assert!(matches!(
+            error,
+            DistParseError::Validate(DistError::LessOrEqualViolated)
+        ));
+    }
+
+    #[test]
+    fn test_trait_sum_and_product_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Sum, Product))]
+        pub struct Weight(f64);
+
+        let weights = [Weight::new(0.5), Weight::new(4.0)];
+        assert_eq!(weights.iter().sum::<Weight>(), Weight::new(4.5));
+        assert_eq!(weights.into_iter().product::<Weight>(), Weight::new(2.0));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_without_validation() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul))]
+        pub struct Weight(f64);
+
+        assert_eq!(Weight::new(0.5) + Weight::new(4.0), Weight::new(4.5));
+        assert_eq!(Weight::new(0.5) - Weight::new(4.0), Weight::new(-3.5));
+        assert_eq!(Weight::new(0.5) * Weight::new(4.0), Weight::new(2.0));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_saturate_policy() {
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul)
+        )]
+        pub struct Probability(f32);
+
+        let p = |value| Probability::new(value).unwrap();
+        assert_eq!(p(0.5) + p(0.25), p(0.75));
+        assert_eq!(p(0.75) + p(0.5), p(1.0));
+        assert_eq!(p(0.25) - p(0.5), p(0.0));
+        assert_eq!(p(0.5) * p(0.5), p(0.25));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_panic_policy() {
+        #[nutype(
+            validate(finite),
+            arithmetic = panic,
+            derive(Debug, Clone, Copy, PartialEq, Add, Mul)
+        )]
+        pub struct Finite(f64);
+
+        let f = |value| Finite::new(value).unwrap();
+        assert_eq!(f(1.5) + f(2.0), f(3.5));
+
+        let result = std::panic::catch_unwind(|| f(f64::MAX) * f(2.0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_inner() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Add<Inner>, Sub<Inner>, Mul<Inner>))]
+        pub struct Weight(f64);
+
+        assert_eq!(Weight::new(0.5) + 4.0, Weight::new(4.5));
+        assert_eq!(Weight::new(0.5) - 4.0, Weight::new(-3.5));
+        assert_eq!(Weight::new(0.5) * 4.0, Weight::new(2.0));
+
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Mul<Inner>)
+        )]
+        pub struct Probability(f32);
+
+        let p = |value| Probability::new(value).unwrap();
+        assert_eq!(p(0.5) * 0.5, p(0.25));
+        assert_eq!(p(0.5) * 4.0, p(1.0));
+        assert_eq!(p(0.5) * -1.0, p(0.0));
+    }
+
+    #[test]
+    fn test_trait_neg() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Neg))]
+        pub struct Balance(f64);
+
+        assert_eq!(-Balance::new(1.5), Balance::new(-1.5));
+
+        #[nutype(
+            validate(greater_or_equal = -1.0, less_or_equal = 2.0),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Neg)
+        )]
+        pub struct Ratio(f32);
+
+        let r = |value| Ratio::new(value).unwrap();
+        assert_eq!(-r(0.5), r(-0.5));
+        assert_eq!(-r(2.0), r(-1.0));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_assign() {
+        #[nutype(
+            sanitize(clamp(min = 0.0, max = 1.0)),
+            derive(Debug, Clone, Copy, PartialEq, AddAssign, SubAssign, MulAssign)
+        )]
+        pub struct Opacity(f32);
+
+        let mut opacity = Opacity::new(0.5);
+        opacity += Opacity::new(0.25);
+        assert_eq!(opacity, Opacity::new(0.75));
+        opacity += Opacity::new(0.5);
+        assert_eq!(opacity, Opacity::new(1.0));
+        opacity *= Opacity::new(0.5);
+        assert_eq!(opacity, Opacity::new(0.5));
+        opacity -= Opacity::new(0.75);
+        assert_eq!(opacity, Opacity::new(0.0));
+    }
+
+    #[test]
+    fn test_trait_partial_eq_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>))]
+        pub struct Weight(f64);
+
+        let weight = Weight::new(1.5);
+        assert_eq!(weight, 1.5);
+        assert_eq!(1.5, weight);
+        assert_ne!(weight, f64::NAN);
+    }
+
+    #[test]
+    fn test_trait_partial_ord_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>, PartialOrd<Inner>))]
+        pub struct Weight(f64);
+
+        let weight = Weight::new(1.5);
+        assert!(weight > 1.0);
+        assert!(2.0 > weight);
+        assert_eq!(weight.partial_cmp(&f64::NAN), None);
+    }
+
+    #[test]
+    fn test_trait_sum_and_product_with_validation() {
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+            derive(Debug, Clone, Copy, PartialEq, Sum, Product)
+        )]
+        pub struct Probability(f32);
+
+        let probabilities = [
+            Probability::new(0.5).unwrap(),
+            Probability::new(0.25).unwrap(),
+        ];
+        assert_eq!(
+            Probability::try_sum(probabilities).unwrap().into_inner(),
+            0.75
+        );
+        assert_eq!(
+            Probability::try_product(probabilities)
+                .unwrap()
+                .into_inner(),
+            0.125
+        );
+
+        let probabilities = [
+            Probability::new(0.75).unwrap(),
+            Probability::new(0.5).unwrap(),
+        ];
+        assert_eq!(
+            Probability::try_sum(probabilities),
+            Err(ProbabilityError::LessOrEqualViolated)
+        );
+    }
+
     #[test]
     fn test_trait_from_str_without_validation() {
         #[nutype(derive(Debug, FromStr))]
@@ -466,6 +872,20 @@ mod traits {
         );
     }
 
+    #[test]
+    fn test_trait_from_str_error_source() {
+        use std::error::Error;
+
+        #[nutype(validate(less_or_equal = 12.34), derive(Debug, FromStr))]
+        pub struct Dist(f64);
+
+        let err = "far".parse::<Dist>().unwrap_err();
+        assert!(err.source().unwrap().is::<std::num::ParseFloatError>());
+
+        let err = "12.35".parse::<Dist>().unwrap_err();
+        assert!(err.source().unwrap().is::<DistError>());
+    }
+
     #[test]
     fn test_trait_display() {
         #[nutype(derive(Display))]
@@ -475,6 +895,17 @@ mod traits {
         assert_eq!(size.to_string(), "35.7");
     }
 
+    #[test]
+    fn test_trait_display_with_formatter_flags() {
+        #[nutype(derive(Display))]
+        pub struct Size(f64);
+
+        let size = Size::new(2.71849);
+        assert_eq!(format!("{size:.2}"), "2.72");
+        assert_eq!(format!("{size:>8.1}"), "     2.7");
+        assert_eq!(format!("{size:+09.3}"), "+0002.718");
+    }
+
     #[test]
     fn test_trait_eq() {
         #[nutype(validate(finite), derive(PartialEq, Eq, Debug))]
@@ -487,6 +918,41 @@ mod traits {
         assert_eq!(size1, size2);
     }
 
+    #[test]
+    fn test_trait_eq_with_boundaries() {
+        #[nutype(
+            validate(greater_or_equal = 0.0),
+            derive(PartialEq, Eq, PartialOrd, Ord, Debug)
+        )]
+        pub struct Weight(f64);
+
+        should_implement_eq::<Weight>();
+
+        let mut weights: Vec<Weight> = [3.5, 0.0, 1.25]
+            .into_iter()
+            .map(|w| Weight::new(w).unwrap())
+            .collect();
+        weights.sort();
+        assert_eq!(weights.first(), Some(&Weight::new(0.0).unwrap()));
+    }
+
+    #[test]
+    fn test_trait_hash() {
+        use std::collections::HashSet;
+
+        #[nutype(validate(finite), derive(PartialEq, Eq, Hash, Debug))]
+        pub struct Price(f64);
+
+        let mut prices = HashSet::new();
+        prices.insert(Price::new(9.99).unwrap());
+        prices.insert(Price::new(9.99).unwrap());
+        prices.insert(Price::new(0.0).unwrap());
+        // -0.0 is equal to 0.0, so it must have the same hash
+        prices.insert(Price::new(-0.0).unwrap());
+        assert_eq!(prices.len(), 2);
+        assert!(prices.contains(&Price::new(9.99).unwrap()));
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -541,6 +1007,7 @@ mod traits {
             use arbitrary::{Error, Unstructured};
 
             #[test]
+            #[allow(clippy::redundant_closure)]
             fn cmp_never_panics_f32() {
                 #[nutype(validate(finite), derive(PartialEq, Eq, PartialOrd, Ord))]
                 pub struct Size(f32);
@@ -559,6 +1026,7 @@ mod traits {
             }
 
             #[test]
+            #[allow(clippy::redundant_closure)]
             fn cmp_never_panics_f64() {
                 #[nutype(validate(finite), derive(PartialEq, Eq, PartialOrd, Ord))]
                 pub struct Size(f64);
@@ -691,7 +1159,7 @@ mod traits {
         #[test]
         #[should_panic(expected = "Default value for type `Number` is invalid")]
         fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(less_or_equal = 20.0), default = 20.1, derive(Default))]
+            #[nutype(validate(less_or_equal = 20.0), default = 20.0 + 0.1, derive(Default))]
             pub struct Number(f64);
 
             Number::default();
@@ -699,6 +1167,335 @@ mod traits {
     }
 }
 
+#[cfg(test)]
+mod parse {
+    use super::*;
+
+    #[test]
+    fn test_parse_without_validation() {
+        #[nutype(derive(Debug, PartialEq))]
+        pub struct Weight(f32);
+
+        assert_eq!(Weight::parse("72.5").unwrap(), Weight::new(72.5));
+
+        let err: WeightParseError = Weight::parse("heavy").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Weight: invalid float literal"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_validation() {
+        #[nutype(validate(finite, greater_or_equal = 0.0), derive(Debug))]
+        pub struct Distance(f64);
+
+        assert_eq!(Distance::parse("12.5").unwrap().into_inner(), 12.5);
+
+        let err = Distance::parse("-0.1").unwrap_err();
+        assert!(matches!(
+            err,
+            DistanceParseError::Validate(DistanceError::GreaterOrEqualViolated)
+        ));
+
+        let err = Distance::parse("inf").unwrap_err();
+        assert!(matches!(
+            err,
+            DistanceParseError::Validate(DistanceError::FiniteViolated)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod convertible_with {
+    use super::*;
+
+    #[nutype(validate(finite), derive(Debug, PartialEq, TryFrom))]
+    pub struct Distance(f64);
+
+    #[nutype(
+        validate(ratio),
+        derive(Debug, PartialEq, TryFrom),
+        convertible_with = Distance
+    )]
+    pub struct Fraction(f64);
+
+    #[test]
+    fn test_convert_both_directions() {
+        assert_eq!(
+            Fraction::try_from(Distance::new(0.5).unwrap()),
+            Ok(Fraction::new(0.5).unwrap())
+        );
+        assert_eq!(
+            Fraction::try_from(Distance::new(1.5).unwrap()),
+            Err(FractionError::RatioViolated)
+        );
+        assert_eq!(
+            Distance::try_from(Fraction::new(0.25).unwrap()),
+            Ok(Distance::new(0.25).unwrap())
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ordered_float")]
+mod derive_ordered_float {
+    use super::*;
+    use ordered_float::{NotNan, OrderedFloat};
+    use std::collections::BTreeSet;
+
+    #[nutype(
+        validate(greater_or_equal = 0.0),
+        derive(Debug, Clone, Copy, PartialEq, NotNan, OrderedFloat)
+    )]
+    pub struct Weight(f64);
+
+    #[test]
+    fn test_into_not_nan() {
+        let weights: BTreeSet<NotNan<f64>> = [2.5, 0.5, 1.0]
+            .into_iter()
+            .map(|w| Weight::new(w).unwrap().into())
+            .collect();
+        let first: f64 = weights.first().unwrap().into_inner();
+        assert_eq!(first, 0.5);
+    }
+
+    #[test]
+    fn test_into_ordered_float() {
+        let weight: OrderedFloat<f64> = Weight::new(2.5).unwrap().into();
+        assert_eq!(weight, OrderedFloat(2.5));
+    }
+
+    #[test]
+    fn test_try_from_not_nan() {
+        let weight = Weight::try_from(NotNan::new(2.5).unwrap()).unwrap();
+        assert_eq!(weight.into_inner(), 2.5);
+
+        assert_eq!(
+            Weight::try_from(NotNan::new(-1.0).unwrap()),
+            Err(WeightError::GreaterOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_try_from_ordered_float() {
+        assert_eq!(
+            Weight::try_from(OrderedFloat(f64::NAN)),
+            Err(WeightError::GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            Weight::try_from(OrderedFloat(3.0)).unwrap().into_inner(),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_f32() {
+        #[nutype(validate(finite), derive(NotNan))]
+        pub struct Ratio(f32);
+
+        let ratio: NotNan<f32> = Ratio::new(0.25).unwrap().into();
+        assert_eq!(ratio.into_inner(), 0.25);
+    }
+}
+
+#[cfg(feature = "num_traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    #[nutype(
+        validate(finite, greater_or_equal = 0.0),
+        derive(Debug, PartialEq, ToPrimitive, FromPrimitive)
+    )]
+    pub struct Price(f64);
+
+    #[test]
+    fn test_to_primitive() {
+        let price = Price::new(9.75).unwrap();
+        assert_eq!(price.to_f64(), Some(9.75));
+        assert_eq!(price.to_i32(), Some(9));
+        assert_eq!(price.to_f32(), Some(9.75));
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(Price::from_u8(5), Some(Price::new(5.0).unwrap()));
+        assert_eq!(Price::from_f64(0.5), Some(Price::new(0.5).unwrap()));
+        assert_eq!(Price::from_i64(-1), None);
+        assert_eq!(Price::from_f64(f64::INFINITY), None);
+    }
+}
+
+#[cfg(test)]
+mod sql_check {
+    use super::*;
+
+    #[test]
+    fn test_sql_check() {
+        #[nutype(sql_check, validate(greater = 0.0, less_or_equal = 9.5))]
+        pub struct Weight(f64);
+
+        assert_eq!(
+            Weight::sql_check("weight"),
+            "weight > 0.0 AND weight <= 9.5"
+        );
+    }
+
+    #[test]
+    fn test_sql_check_with_presets() {
+        #[nutype(sql_check, validate(finite))]
+        pub struct Temperature(f32);
+
+        assert_eq!(
+            Temperature::sql_check("t"),
+            "t BETWEEN -3.4028235e38 AND 3.4028235e38"
+        );
+
+        #[nutype(sql_check, validate(latitude))]
+        pub struct Latitude(f64);
+
+        assert_eq!(Latitude::sql_check("lat"), "lat BETWEEN -90.0 AND 90.0");
+    }
+}
+
+#[cfg(test)]
+mod zod_schema {
+    use super::*;
+
+    #[test]
+    fn test_zod_schema() {
+        #[nutype(zod, validate(greater = 0.0, less_or_equal = 9.5))]
+        pub struct Weight(f64);
+
+        assert_eq!(Weight::zod_schema(), "z.number().gt(0.0).lte(9.5)");
+
+        #[nutype(zod)]
+        pub struct Any(f32);
+
+        assert_eq!(Any::zod_schema(), "z.number()");
+    }
+
+    #[test]
+    fn test_zod_schema_with_presets() {
+        #[nutype(zod, validate(finite, latitude))]
+        pub struct Latitude(f64);
+
+        assert_eq!(
+            Latitude::zod_schema(),
+            "z.number().finite().gte(-90.0).lte(90.0)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod checked_arithmetic {
+    use super::*;
+
+    #[nutype(
+        checked_arithmetic,
+        validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Probability(f64);
+
+    #[test]
+    fn test_checked_operations() {
+        let half = Probability::new(0.5).unwrap();
+        let quarter = Probability::new(0.25).unwrap();
+        assert_eq!(half.checked_add(quarter).unwrap().into_inner(), 0.75);
+        assert_eq!(half.checked_sub(quarter).unwrap().into_inner(), 0.25);
+        assert_eq!(half.checked_mul(quarter).unwrap().into_inner(), 0.125);
+    }
+
+    #[test]
+    fn test_invalid_result() {
+        let half = Probability::new(0.5).unwrap();
+        let quarter = Probability::new(0.25).unwrap();
+        assert_eq!(
+            quarter.checked_sub(half),
+            Err(ProbabilityArithmeticError::Validate(
+                ProbabilityError::GreaterOrEqualViolated
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(test)]
+mod constructor {
+    use super::*;
+
+    #[nutype(
+        constructor = from_raw,
+        validate(finite, greater_or_equal = 0.0),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Distance(f64);
+
+    #[test]
+    fn test_custom_constructor_name() {
+        assert_eq!(Distance::from_raw(2.5).unwrap().into_inner(), 2.5);
+        assert_eq!(
+            Distance::from_raw(-1.0),
+            Err(DistanceError::GreaterOrEqualViolated)
+        );
+        assert_eq!(Distance::parse("2.5").unwrap().into_inner(), 2.5);
+    }
+}
+
+mod new_slice {
+    use super::*;
+
+    #[nutype(
+        new_slice,
+        validate(finite, greater_or_equal = -1.0, less_or_equal = 1.0),
+        derive(Debug, Clone, Copy)
+    )]
+    pub struct Amplitude(f32);
+
+    #[test]
+    fn test_new_slice() {
+        let buffer = [0.0, -1.0, 0.5, 1.0];
+        let amplitudes = Amplitude::new_slice(&buffer).unwrap();
+        assert_eq!(amplitudes[2].into_inner(), 0.5);
+
+        let err = Amplitude::new_slice(&[0.0, f32::NAN]).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.error, AmplitudeError::FiniteViolated);
+    }
+}
+
+#[cfg(test)]
+mod const_new {
+    use super::*;
+
+    #[nutype(
+        sanitize(clamp(min = 0.0, max = 100.0)),
+        validate(greater = 0.0),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Volume(f32);
+
+    // Reporting the validation error with `tracing` makes the constructor non-const.
+    #[cfg(not(feature = "tracing"))]
+    #[test]
+    fn test_new_in_const_context() {
+        const FULL: Volume = match Volume::new(150.0) {
+            Ok(volume) => volume,
+            Err(_) => panic!("invalid volume"),
+        };
+
+        assert_eq!(FULL.into_inner(), 100.0);
+    }
+
+    #[test]
+    fn test_validate_in_const_context() {
+        const { assert!(!Volume::is_valid(&0.0)) };
+        assert_eq!(const { Volume::sanitize(150.0) }, 100.0);
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "new_unchecked")]
 mod new_unchecked {
@@ -90,6 +90,288 @@ mod validators {
         assert_eq!(Age::new(99).unwrap().into_inner(), 99);
     }
 
+    #[test]
+    fn test_fits_bits_unsigned() {
+        #[nutype(validate(fits_bits = 12), derive(Debug))]
+        struct Field(u16);
+
+        assert_eq!(Field::new(0).unwrap().into_inner(), 0);
+        assert_eq!(Field::new(4095).unwrap().into_inner(), 4095);
+        assert_eq!(Field::new(4096).unwrap_err(), FieldError::FitsBitsViolated);
+    }
+
+    #[test]
+    fn test_fits_bits_signed() {
+        #[nutype(validate(fits_bits = 4), derive(Debug))]
+        struct Nibble(i8);
+
+        assert_eq!(Nibble::new(-8).unwrap().into_inner(), -8);
+        assert_eq!(Nibble::new(7).unwrap().into_inner(), 7);
+        assert_eq!(Nibble::new(8).unwrap_err(), NibbleError::FitsBitsViolated);
+        assert_eq!(Nibble::new(-9).unwrap_err(), NibbleError::FitsBitsViolated);
+    }
+
+    #[test]
+    fn test_fits_bits_full_width() {
+        #[nutype(validate(fits_bits = 64), derive(Debug))]
+        struct Word(i64);
+
+        assert_eq!(Word::new(i64::MIN).unwrap().into_inner(), i64::MIN);
+        assert_eq!(Word::new(i64::MAX).unwrap().into_inner(), i64::MAX);
+    }
+
+    #[test]
+    fn test_aligned_to() {
+        #[nutype(validate(aligned_to = 4096), derive(Debug))]
+        struct PageOffset(u64);
+
+        assert_eq!(PageOffset::new(0).unwrap().into_inner(), 0);
+        assert_eq!(PageOffset::new(8192).unwrap().into_inner(), 8192);
+        assert_eq!(
+            PageOffset::new(4095).unwrap_err(),
+            PageOffsetError::AlignedToViolated
+        );
+        assert_eq!(
+            PageOffset::new(4097).unwrap_err(),
+            PageOffsetError::AlignedToViolated
+        );
+    }
+
+    #[test]
+    fn test_aligned_to_signed() {
+        #[nutype(validate(aligned_to = 4), derive(Debug))]
+        struct Offset(i32);
+
+        assert_eq!(Offset::new(-8).unwrap().into_inner(), -8);
+        assert_eq!(Offset::new(-6).unwrap_err(), OffsetError::AlignedToViolated);
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        #[nutype(validate(multiple_of = 5), derive(Debug))]
+        struct Cents(u64);
+
+        assert_eq!(Cents::new(0).unwrap().into_inner(), 0);
+        assert_eq!(Cents::new(25).unwrap().into_inner(), 25);
+        assert_eq!(Cents::new(7).unwrap_err(), CentsError::MultipleOfViolated);
+
+        #[nutype(validate(divisible_by = 100), derive(Debug))]
+        struct LotSize(i32);
+
+        assert_eq!(LotSize::new(-300).unwrap().into_inner(), -300);
+        assert_eq!(
+            LotSize::new(150).unwrap_err(),
+            LotSizeError::MultipleOfViolated
+        );
+    }
+
+    #[test]
+    fn test_even() {
+        #[nutype(validate(even), derive(Debug))]
+        struct PairCount(i32);
+
+        assert_eq!(PairCount::new(0).unwrap().into_inner(), 0);
+        assert_eq!(PairCount::new(-4).unwrap().into_inner(), -4);
+        assert_eq!(PairCount::new(3).unwrap_err(), PairCountError::EvenViolated);
+        assert_eq!(
+            PairCount::new(-3).unwrap_err(),
+            PairCountError::EvenViolated
+        );
+    }
+
+    #[test]
+    fn test_odd() {
+        #[nutype(validate(odd), derive(Debug))]
+        struct OddIndex(i64);
+
+        assert_eq!(OddIndex::new(1).unwrap().into_inner(), 1);
+        assert_eq!(OddIndex::new(-3).unwrap().into_inner(), -3);
+        assert_eq!(OddIndex::new(0).unwrap_err(), OddIndexError::OddViolated);
+        assert_eq!(
+            OddIndex::new(i64::MIN).unwrap_err(),
+            OddIndexError::OddViolated
+        );
+    }
+
+    #[test]
+    fn test_power_of_two() {
+        #[nutype(validate(power_of_two), derive(Debug))]
+        struct BufferSize(usize);
+
+        assert_eq!(BufferSize::new(1).unwrap().into_inner(), 1);
+        assert_eq!(BufferSize::new(4096).unwrap().into_inner(), 4096);
+        assert_eq!(
+            BufferSize::new(0).unwrap_err(),
+            BufferSizeError::PowerOfTwoViolated
+        );
+        assert_eq!(
+            BufferSize::new(4095).unwrap_err(),
+            BufferSizeError::PowerOfTwoViolated
+        );
+        assert_eq!(
+            BufferSize::new(4095).unwrap_err().to_string(),
+            "BufferSize is not a power of two."
+        );
+    }
+
+    #[test]
+    fn test_power_of_two_log2() {
+        #[nutype(validate(power_of_two), derive(Debug))]
+        struct Alignment(u64);
+
+        assert_eq!(Alignment::new(1).unwrap().log2(), 0);
+        assert_eq!(Alignment::new(4096).unwrap().log2(), 12);
+        assert_eq!(Alignment::new(1 << 63).unwrap().log2(), 63);
+    }
+
+    #[test]
+    fn test_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug))]
+        struct Quantity(i32);
+
+        assert_eq!(Quantity::new(1).unwrap().into_inner(), 1);
+        assert_eq!(Quantity::new(-1).unwrap().into_inner(), -1);
+        assert_eq!(
+            Quantity::new(0).unwrap_err(),
+            QuantityError::NonzeroViolated
+        );
+        assert_eq!(
+            Quantity::new(0).unwrap_err().to_string(),
+            "Quantity must not be zero."
+        );
+    }
+
+    #[test]
+    fn test_one_of() {
+        #[nutype(validate(one_of = [1, 2, 5, 10]), derive(Debug))]
+        struct Denomination(u16);
+
+        assert_eq!(Denomination::new(5).unwrap().into_inner(), 5);
+        assert_eq!(
+            Denomination::new(3).unwrap_err(),
+            DenominationError::OneOfViolated
+        );
+        assert_eq!(
+            Denomination::new(3).unwrap_err().to_string(),
+            "Denomination is not one of the allowed values: 1, 2, 5, 10."
+        );
+    }
+
+    #[test]
+    fn test_one_of_with_negative_values_and_bounds() {
+        #[nutype(validate(one_of = [-1, 0, 1, 2], less = 2), derive(Debug))]
+        struct Sign(i8);
+
+        assert_eq!(Sign::new(-1).unwrap().into_inner(), -1);
+        assert_eq!(Sign::new(-2).unwrap_err(), SignError::OneOfViolated);
+        assert_eq!(Sign::new(2).unwrap_err(), SignError::LessViolated);
+    }
+
+    #[test]
+    fn test_not_in() {
+        #[nutype(
+            validate(not_in = [0, 255], greater_or_equal = 0),
+            derive(Debug)
+        )]
+        struct Channel(i16);
+
+        assert_eq!(Channel::new(1).unwrap().into_inner(), 1);
+        assert_eq!(Channel::new(256).unwrap().into_inner(), 256);
+        assert_eq!(Channel::new(255).unwrap_err(), ChannelError::NotInViolated);
+        assert_eq!(
+            Channel::new(-1).unwrap_err(),
+            ChannelError::GreaterOrEqualViolated
+        );
+        assert_eq!(
+            Channel::new(0).unwrap_err().to_string(),
+            "Channel is one of the excluded values: 0, 255."
+        );
+    }
+
+    #[test]
+    fn test_any() {
+        #[nutype(validate(any(aligned_to = 3, aligned_to = 5)), derive(Debug))]
+        struct FizzOrBuzz(u32);
+
+        assert_eq!(FizzOrBuzz::new(9).unwrap().into_inner(), 9);
+        assert_eq!(FizzOrBuzz::new(10).unwrap().into_inner(), 10);
+        assert_eq!(
+            FizzOrBuzz::new(7).unwrap_err(),
+            FizzOrBuzzError::AnyViolated
+        );
+        assert_eq!(
+            FizzOrBuzz::new(7).unwrap_err().to_string(),
+            "FizzOrBuzz does not satisfy `any(aligned_to = 3, aligned_to = 5)`."
+        );
+    }
+
+    #[test]
+    fn test_any_multiple_of() {
+        #[nutype(validate(any(multiple_of = 3, multiple_of = 5)), derive(Debug))]
+        struct FizzOrBuzz(u32);
+
+        assert!(FizzOrBuzz::new(9).is_ok());
+        assert!(FizzOrBuzz::new(10).is_ok());
+        assert_eq!(
+            FizzOrBuzz::new(7).unwrap_err(),
+            FizzOrBuzzError::AnyViolated
+        );
+    }
+
+    #[test]
+    fn test_not() {
+        #[nutype(validate(not(one_of = [13, 666]), greater = 0), derive(Debug))]
+        struct Floor(i32);
+
+        assert_eq!(Floor::new(14).unwrap().into_inner(), 14);
+        assert_eq!(Floor::new(13).unwrap_err(), FloorError::NotViolated);
+        assert_eq!(Floor::new(0).unwrap_err(), FloorError::GreaterViolated);
+        assert_eq!(
+            Floor::new(666).unwrap_err().to_string(),
+            "Floor must not satisfy `one_of = [13, 666]`."
+        );
+    }
+
+    #[test]
+    fn test_all_names_the_failed_validator() {
+        #[nutype(validate(all(even, less = 100)), derive(Debug))]
+        struct Slot(u8);
+
+        assert_eq!(Slot::new(42).unwrap().into_inner(), 42);
+        assert_eq!(Slot::new(41).unwrap_err(), SlotError::EvenViolated);
+        assert_eq!(Slot::new(102).unwrap_err(), SlotError::LessViolated);
+    }
+
+    #[test]
+    fn test_nested_combinators() {
+        #[nutype(
+            validate(any(all(greater_or_equal = 10, less = 20), not(odd))),
+            derive(Debug)
+        )]
+        struct Mixed(i64);
+
+        assert!(Mixed::new(11).is_ok());
+        assert!(Mixed::new(-4).is_ok());
+        assert_eq!(Mixed::new(21).unwrap_err(), MixedError::AnyViolated);
+    }
+
+    #[test]
+    fn test_percentage() {
+        #[nutype(validate(percentage), derive(Debug))]
+        struct Discount(i8);
+
+        assert_eq!(
+            Discount::new(-1).unwrap_err(),
+            DiscountError::PercentageViolated
+        );
+        assert_eq!(
+            Discount::new(101).unwrap_err(),
+            DiscountError::PercentageViolated
+        );
+        assert_eq!(Discount::new(0).unwrap().into_inner(), 0);
+        assert_eq!(Discount::new(100).unwrap().into_inner(), 100);
+    }
+
     #[test]
     fn test_greater_or_equal_and_less_or_equal() {
         #[nutype(validate(greater_or_equal = 18, less_or_equal = 99), derive(Debug))]
@@ -225,6 +507,68 @@ mod validators {
                 "Age is too small. The value must be greater or equal to 18."
             );
         }
+
+        #[test]
+        fn test_fits_bits_error_display() {
+            #[nutype(validate(fits_bits = 12), derive(Debug))]
+            struct Field(u32);
+
+            assert_eq!(
+                Field::new(5000).unwrap_err().to_string(),
+                "Field does not fit in 12 bits."
+            );
+        }
+
+        #[test]
+        fn test_aligned_to_error_display() {
+            #[nutype(validate(aligned_to = 4096), derive(Debug))]
+            struct PageOffset(u64);
+
+            assert_eq!(
+                PageOffset::new(100).unwrap_err().to_string(),
+                "PageOffset is not aligned to 4096."
+            );
+        }
+
+        #[test]
+        fn test_multiple_of_error_display() {
+            #[nutype(validate(multiple_of = 5), derive(Debug))]
+            struct Cents(u64);
+
+            assert_eq!(
+                Cents::new(7).unwrap_err().to_string(),
+                "Cents is not a multiple of 5."
+            );
+        }
+
+        #[test]
+        fn test_even_and_odd_error_display() {
+            #[nutype(validate(even), derive(Debug))]
+            struct PairCount(u32);
+
+            #[nutype(validate(odd), derive(Debug))]
+            struct OddIndex(u32);
+
+            assert_eq!(
+                PairCount::new(3).unwrap_err().to_string(),
+                "PairCount is not even."
+            );
+            assert_eq!(
+                OddIndex::new(2).unwrap_err().to_string(),
+                "OddIndex is not odd."
+            );
+        }
+
+        #[test]
+        fn test_percentage_error_display() {
+            #[nutype(validate(percentage), derive(Debug))]
+            struct Progress(u8);
+
+            assert_eq!(
+                Progress::new(101).unwrap_err().to_string(),
+                "Progress is not a valid percentage. The value must be between 0 and 100."
+            );
+        }
     }
 }
 
@@ -483,6 +827,49 @@ mod visibility {
     }
 }
 
+#[cfg(test)]
+mod error_placement {
+    mod encapsulated {
+        use nutype::nutype;
+
+        #[nutype(
+            validate(greater_or_equal = 18),
+            error(module = age),
+            derive(Debug, PartialEq, TryFrom)
+        )]
+        pub struct Age(u8);
+
+        #[nutype(
+            validate(less_or_equal = 100),
+            error(vis = pub(crate), module = score),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Score(u8);
+
+        #[nutype(validate(greater = 0), error(vis = pub(crate)), derive(Debug, PartialEq))]
+        pub(crate) struct Quantity(u32);
+    }
+
+    use encapsulated::{age, score, Age, Quantity, QuantityError, Score};
+
+    #[test]
+    fn test_error_in_module() {
+        let err: age::Error = Age::new(17).unwrap_err();
+        assert_eq!(err, age::Error::GreaterOrEqualViolated);
+        assert_eq!(Age::try_from(16), Err(age::Error::GreaterOrEqualViolated));
+    }
+
+    #[test]
+    fn test_error_in_module_with_visibility() {
+        assert_eq!(Score::new(101), Err(score::Error::LessOrEqualViolated));
+    }
+
+    #[test]
+    fn test_error_with_visibility() {
+        assert_eq!(Quantity::new(0), Err(QuantityError::GreaterViolated));
+    }
+}
+
 #[cfg(test)]
 mod traits {
     use super::*;
@@ -579,6 +966,41 @@ mod traits {
         assert_eq!(error, AmountError::LessOrEqualViolated);
     }
 
+    #[test]
+    fn test_trait_try_from_str() {
+        #[nutype(validate(less_or_equal = 1000), derive(Debug, TryFrom, TryFrom<&str>))]
+        pub struct Amount(i64);
+
+        let amount = Amount::try_from("1000").unwrap();
+        assert_eq!(amount.into_inner(), 1000);
+        let amount = Amount::try_from(999).unwrap();
+        assert_eq!(amount.into_inner(), 999);
+
+        let error = Amount::try_from("ten").unwrap_err();
+        assert!(matches!(error, AmountParseError::Parse(_)));
+
+        let error = Amount::try_from("1001").unwrap_err();
+        assert!(matches!(
+            error,
+            AmountParseError::Validate(AmountError::LessOrEqualViolated)
+        ));
+    }
+
+    #[test]
+    fn test_trait_try_from_str_without_validation() {
+        #[nutype(derive(Debug, TryFrom<&str>))]
+        pub struct Amount(u16);
+
+        let amount: Amount = "42".try_into().unwrap();
+        assert_eq!(amount.into_inner(), 42);
+
+        let error = Amount::try_from("-1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse Amount: invalid digit found in string"
+        );
+    }
+
     #[test]
     fn test_trait_from_str_without_validation() {
         #[nutype(derive(Debug, FromStr))]
@@ -617,6 +1039,30 @@ mod traits {
         );
     }
 
+    #[test]
+    fn test_trait_from_str_error_source() {
+        use std::error::Error;
+
+        #[nutype(validate(greater_or_equal = 18), derive(Debug, FromStr))]
+        pub struct Age(u8);
+
+        let err = "eighteen".parse::<Age>().unwrap_err();
+        assert!(matches!(err, AgeParseError::Parse(_)));
+        let source = err.source().unwrap();
+        assert!(source.is::<std::num::ParseIntError>());
+
+        let err = "17".parse::<Age>().unwrap_err();
+        assert!(matches!(
+            err,
+            AgeParseError::Validate(AgeError::GreaterOrEqualViolated)
+        ));
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<AgeError>(),
+            Some(&AgeError::GreaterOrEqualViolated)
+        );
+    }
+
     #[test]
     fn test_trait_display() {
         #[nutype(derive(Display))]
@@ -626,50 +1072,442 @@ mod traits {
         assert_eq!(age.to_string(), "35");
     }
 
-    #[cfg(feature = "serde")]
-    mod serialization {
-        use super::*;
+    #[test]
+    fn test_trait_display_with_formatter_flags() {
+        #[nutype(derive(Display))]
+        pub struct Age(i64);
 
-        mod json_format {
-            use super::*;
+        let age = Age::new(35);
+        assert_eq!(format!("{age:>5}"), "   35");
+        assert_eq!(format!("{age:<5}|"), "35   |");
+        assert_eq!(format!("{age:*^6}"), "**35**");
+        assert_eq!(format!("{age:04}"), "0035");
+        assert_eq!(format!("{age:+}"), "+35");
+    }
 
-            #[cfg(feature = "serde")]
-            #[test]
-            fn test_trait_serialize() {
-                #[nutype(derive(Serialize))]
-                pub struct Offset(i64);
+    #[test]
+    fn test_trait_display_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Display))]
+        pub struct Age(i64);
 
-                let offset = Offset::new(-280);
-                let offset_json = serde_json::to_string(&offset).unwrap();
-                assert_eq!(offset_json, "-280");
-            }
+        let age = Age::new(35).unwrap();
+        assert_eq!(age.to_string(), "35");
+        assert_eq!(format!("{age:04}"), "0035");
+    }
 
-            #[cfg(feature = "serde")]
-            #[test]
-            fn test_trait_deserialize_without_validation() {
-                #[nutype(derive(Deserialize))]
-                pub struct Offset(i64);
+    #[test]
+    fn test_trait_lower_hex() {
+        #[nutype(derive(LowerHex))]
+        pub struct Color(u32);
 
-                {
-                    let res: Result<Offset, _> = serde_json::from_str("three");
-                    assert!(res.is_err());
-                }
+        let color = Color::new(0xff8000);
+        assert_eq!(format!("{color:x}"), "ff8000");
+        assert_eq!(format!("{color:#010x}"), "0x00ff8000");
+    }
 
-                {
-                    let offset: Offset = serde_json::from_str("-259").unwrap();
-                    assert_eq!(offset.into_inner(), -259);
-                }
-            }
+    #[test]
+    fn test_trait_upper_hex() {
+        #[nutype(derive(UpperHex))]
+        pub struct Color(u32);
 
-            #[cfg(feature = "serde")]
-            #[test]
-            fn test_trait_deserialize_with_validation() {
-                #[nutype(validate(greater_or_equal = 13), derive(Deserialize))]
-                pub struct Offset(i64);
+        let color = Color::new(0xff8000);
+        assert_eq!(format!("{color:X}"), "FF8000");
+        assert_eq!(format!("{color:#X}"), "0xFF8000");
+    }
 
-                {
-                    let res: Result<Offset, _> = serde_json::from_str("three");
-                    assert!(res.is_err());
+    #[test]
+    fn test_trait_binary() {
+        #[nutype(validate(less_or_equal = 15), derive(Binary))]
+        pub struct Flags(u8);
+
+        let flags = Flags::new(5).unwrap();
+        assert_eq!(format!("{flags:b}"), "101");
+        assert_eq!(format!("{flags:#06b}"), "0b0101");
+    }
+
+    #[test]
+    fn test_trait_octal() {
+        #[nutype(derive(Octal))]
+        pub struct Mode(i32);
+
+        let mode = Mode::new(0o755);
+        assert_eq!(format!("{mode:o}"), "755");
+        assert_eq!(format!("{mode:#o}"), "0o755");
+    }
+
+    #[test]
+    fn test_trait_sum_and_product_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Sum, Product))]
+        pub struct Count(u32);
+
+        let counts = [Count::new(2), Count::new(3), Count::new(4)];
+        assert_eq!(counts.iter().sum::<Count>(), Count::new(9));
+        assert_eq!(counts.into_iter().product::<Count>(), Count::new(24));
+        assert_eq!(core::iter::empty::<Count>().sum::<Count>(), Count::new(0));
+    }
+
+    #[test]
+    fn test_trait_sum_and_product_with_validation() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            derive(Debug, Clone, Copy, PartialEq, Sum, Product)
+        )]
+        pub struct Percent(u16);
+
+        let parts = [Percent::new(20).unwrap(), Percent::new(30).unwrap()];
+        assert_eq!(Percent::try_sum(parts).unwrap().into_inner(), 50);
+        assert_eq!(
+            Percent::try_product(parts),
+            Err(PercentError::LessOrEqualViolated)
+        );
+
+        let parts = [Percent::new(60).unwrap(), Percent::new(50).unwrap()];
+        assert_eq!(
+            Percent::try_sum(parts),
+            Err(PercentError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_trait_sum_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug, Clone, Copy, Sum))]
+        pub struct Delta(i32);
+
+        let deltas = [Delta::new(5).unwrap(), Delta::new(-2).unwrap()];
+        assert_eq!(Delta::try_sum(deltas).unwrap().into_inner(), 3);
+
+        let deltas = [Delta::new(5).unwrap(), Delta::new(-5).unwrap()];
+        assert_eq!(
+            Delta::try_sum(deltas).unwrap_err(),
+            DeltaError::NonzeroViolated
+        );
+    }
+
+    #[test]
+    fn test_trait_arithmetic_without_validation() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul))]
+        pub struct Count(u32);
+
+        assert_eq!(Count::new(2) + Count::new(3), Count::new(5));
+        assert_eq!(Count::new(5) - Count::new(3), Count::new(2));
+        assert_eq!(Count::new(2) * Count::new(3), Count::new(6));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_saturate_policy() {
+        #[nutype(
+            validate(greater = 0, less_or_equal = 100),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul)
+        )]
+        pub struct Percent(u8);
+
+        let p = |value| Percent::new(value).unwrap();
+        assert_eq!(p(20) + p(30), p(50));
+        assert_eq!(p(60) + p(50), p(100));
+        assert_eq!(p(30) - p(20), p(10));
+        assert_eq!(p(20) - p(30), p(1));
+        assert_eq!(p(20) * p(30), p(100));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_saturate_policy_without_bounds() {
+        #[nutype(
+            validate(less_or_equal = i8::MAX),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Add, Sub)
+        )]
+        pub struct Level(i8);
+
+        let l = |value| Level::new(value).unwrap();
+        assert_eq!(l(100) + l(100), l(127));
+        assert_eq!(l(-100) - l(100), l(-128));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_wrapping_policy() {
+        #[nutype(
+            validate(less_or_equal = 23),
+            arithmetic = wrapping,
+            derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Add<Inner>)
+        )]
+        pub struct Hour(u8);
+
+        let h = |value| Hour::new(value).unwrap();
+        assert_eq!(h(10) + h(5), h(15));
+        assert_eq!(h(22) + h(5), h(3));
+        assert_eq!(h(2) - h(5), h(21));
+        assert_eq!(h(7) * h(7), h(1));
+        assert_eq!(h(22) + 255, h(13));
+
+        #[nutype(
+            validate(greater = 0, less = 13),
+            arithmetic = wrapping,
+            derive(Debug, Clone, Copy, PartialEq, Add<Inner>, Sub<Inner>)
+        )]
+        pub struct Month(u32);
+
+        let m = |value| Month::new(value).unwrap();
+        assert_eq!(m(12) + 1, m(1));
+        assert_eq!(m(1) - 1, m(12));
+        assert_eq!(m(3) + 24, m(3));
+
+        #[nutype(
+            validate(greater_or_equal = -180, less = 180),
+            arithmetic = wrapping,
+            derive(Debug, Clone, Copy, PartialEq, Add, Neg)
+        )]
+        pub struct Angle(i16);
+
+        let a = |value| Angle::new(value).unwrap();
+        assert_eq!(a(170) + a(20), a(-170));
+        assert_eq!(-a(90), a(-90));
+        assert_eq!(-a(-180), a(-180));
+
+        #[nutype(
+            validate(greater_or_equal = 1),
+            arithmetic = wrapping,
+            derive(Debug, Clone, Copy, PartialEq, Mul)
+        )]
+        pub struct Large(u64);
+
+        let l = |value| Large::new(value).unwrap();
+        assert_eq!(l(u64::MAX - 1) * l(u64::MAX - 1), l(1));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_wrapping_policy_without_validation() {
+        #[nutype(
+            arithmetic = wrapping,
+            derive(Debug, Clone, Copy, PartialEq, Add, AddAssign)
+        )]
+        pub struct Counter(u8);
+
+        assert_eq!(Counter::new(250) + Counter::new(10), Counter::new(4));
+        let mut counter = Counter::new(255);
+        counter += Counter::new(1);
+        assert_eq!(counter, Counter::new(0));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_panic_policy() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            arithmetic = panic,
+            derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul)
+        )]
+        pub struct Percent(u8);
+
+        let p = |value| Percent::new(value).unwrap();
+        assert_eq!(p(20) + p(30), p(50));
+        assert_eq!(p(20) * p(5), p(100));
+
+        let result = std::panic::catch_unwind(|| p(60) + p(50));
+        assert!(result.is_err());
+
+        // Overflow panics as well, even in release builds.
+        let result = std::panic::catch_unwind(|| p(20) - p(30));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_nonzero() {
+        #[nutype(
+            validate(nonzero),
+            arithmetic = panic,
+            derive(Debug, Clone, Copy, PartialEq, Add)
+        )]
+        pub struct Delta(i32);
+
+        let d = |value| Delta::new(value).unwrap();
+        assert_eq!(d(5) + d(-2), d(3));
+
+        let result = std::panic::catch_unwind(|| d(5) + d(-5));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_inner() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Add<Inner>, Sub<Inner>, Mul<Inner>))]
+        pub struct Quantity(u32);
+
+        assert_eq!(Quantity::new(2) + 3, Quantity::new(5));
+        assert_eq!(Quantity::new(5) - 3, Quantity::new(2));
+        assert_eq!(Quantity::new(2) * 3, Quantity::new(6));
+
+        #[nutype(
+            validate(less_or_equal = 150),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Add, Add<Inner>, Sub<Inner>)
+        )]
+        pub struct Age(u8);
+
+        let age = Age::new(30).unwrap();
+        assert_eq!(age + 1, Age::new(31).unwrap());
+        assert_eq!(age + age, Age::new(60).unwrap());
+        assert_eq!(age + 200, Age::new(150).unwrap());
+        assert_eq!(age - 40, Age::new(0).unwrap());
+
+        #[nutype(
+            validate(nonzero),
+            arithmetic = panic,
+            derive(Debug, Clone, Copy, PartialEq, Mul<Inner>)
+        )]
+        pub struct Factor(i32);
+
+        let factor = Factor::new(3).unwrap();
+        assert_eq!(factor * -2, Factor::new(-6).unwrap());
+        let result = std::panic::catch_unwind(|| factor * std::hint::black_box(0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trait_neg() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Neg))]
+        pub struct Balance(i64);
+
+        assert_eq!(-Balance::new(100), Balance::new(-100));
+        assert_eq!(-Balance::new(-100), Balance::new(100));
+    }
+
+    #[test]
+    fn test_trait_neg_with_validation() {
+        #[nutype(
+            validate(greater_or_equal = -50, less_or_equal = 100),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, Neg)
+        )]
+        pub struct Saturated(i32);
+
+        let s = |value| Saturated::new(value).unwrap();
+        assert_eq!(-s(30), s(-30));
+        assert_eq!(-s(80), s(-50));
+        assert_eq!(-s(-50), s(50));
+
+        #[nutype(
+            validate(greater_or_equal = -50, less_or_equal = 100),
+            arithmetic = panic,
+            derive(Debug, Clone, Copy, PartialEq, Neg)
+        )]
+        pub struct Strict(i32);
+
+        let s = |value| Strict::new(value).unwrap();
+        assert_eq!(-s(30), s(-30));
+        let result = std::panic::catch_unwind(|| -s(80));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trait_arithmetic_assign() {
+        #[nutype(
+            sanitize(with = |volume| volume.min(100)),
+            arithmetic = saturate,
+            derive(Debug, Clone, Copy, PartialEq, AddAssign, SubAssign, MulAssign)
+        )]
+        pub struct Volume(u8);
+
+        let mut volume = Volume::new(40);
+        volume += Volume::new(30);
+        assert_eq!(volume, Volume::new(70));
+        volume += Volume::new(250);
+        assert_eq!(volume, Volume::new(100));
+        volume -= Volume::new(130);
+        assert_eq!(volume, Volume::new(0));
+        volume = Volume::new(20);
+        volume *= Volume::new(20);
+        assert_eq!(volume, Volume::new(100));
+    }
+
+    #[test]
+    fn test_trait_partial_eq_inner() {
+        #[nutype(validate(less_or_equal = 150), derive(Debug, PartialEq<Inner>))]
+        pub struct Age(u8);
+
+        let age = Age::new(18).unwrap();
+        assert!(age == 18);
+        assert!(18 == age);
+        assert!(age != 21);
+        assert!(21 != age);
+    }
+
+    #[test]
+    fn test_trait_partial_eq_inner_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug, PartialEq, PartialEq<Inner>))]
+        pub struct Quantity(u32);
+
+        let quantity = Quantity::new(3).unwrap();
+        assert_eq!(quantity, 3);
+        assert_eq!(3, quantity);
+        assert_eq!(quantity, Quantity::new(3).unwrap());
+    }
+
+    #[test]
+    fn test_trait_partial_ord_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>, PartialOrd<Inner>))]
+        pub struct Amount(u64);
+
+        let amount = Amount::new(1500);
+        assert!(amount > 1000);
+        assert!(amount <= 1500);
+        assert!(1000 < amount);
+        assert!(2000 >= amount);
+    }
+
+    #[test]
+    fn test_trait_partial_ord_inner_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug, PartialEq<Inner>, PartialOrd<Inner>))]
+        pub struct Delta(i32);
+
+        let delta = Delta::new(-3).unwrap();
+        assert!(delta < 0);
+        assert!(0 > delta);
+    }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use super::*;
+
+        mod json_format {
+            use super::*;
+            use std::collections::{BTreeMap, HashMap};
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_trait_serialize() {
+                #[nutype(derive(Serialize))]
+                pub struct Offset(i64);
+
+                let offset = Offset::new(-280);
+                let offset_json = serde_json::to_string(&offset).unwrap();
+                assert_eq!(offset_json, "-280");
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_trait_deserialize_without_validation() {
+                #[nutype(derive(Deserialize))]
+                pub struct Offset(i64);
+
+                {
+                    let res: Result<Offset, _> = serde_json::from_str("three");
+                    assert!(res.is_err());
+                }
+
+                {
+                    let offset: Offset = serde_json::from_str("-259").unwrap();
+                    assert_eq!(offset.into_inner(), -259);
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_trait_deserialize_with_validation() {
+                #[nutype(validate(greater_or_equal = 13), derive(Deserialize))]
+                pub struct Offset(i64);
+
+                {
+                    let res: Result<Offset, _> = serde_json::from_str("three");
+                    assert!(res.is_err());
                 }
 
                 {
@@ -682,6 +1520,193 @@ mod traits {
                     assert_eq!(offset.into_inner(), 13);
                 }
             }
+
+            #[nutype(
+                validate(greater = 0),
+                derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize)
+            )]
+            pub struct UserId(u64);
+
+            #[test]
+            fn test_map_keys() {
+                let mut names: BTreeMap<u64, &str> = BTreeMap::new();
+                names.insert(7, "Alice");
+                let mut users: HashMap<UserId, &str> = HashMap::new();
+                users.insert(UserId::new(7).unwrap(), "Alice");
+
+                let json = serde_json::to_string(&users).unwrap();
+                assert_eq!(json, r#"{"7":"Alice"}"#);
+                assert_eq!(json, serde_json::to_string(&names).unwrap());
+
+                let deserialized: HashMap<UserId, String> = serde_json::from_str(&json).unwrap();
+                assert_eq!(deserialized[&UserId::new(7).unwrap()], "Alice");
+
+                let err =
+                    serde_json::from_str::<HashMap<UserId, String>>(r#"{"0":"Bob"}"#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "UserId is too small. The value must be greater than 0. Expected valid UserId at line 1 column 4"
+                );
+            }
+
+            #[test]
+            fn test_flattened_map_keys() {
+                // Flattened maps buffer the keys as strings, so `serde(as_string)` is needed
+                #[nutype(
+                    validate(greater = 0),
+                    serde(as_string),
+                    derive(Debug, PartialEq, Eq, Hash, Deserialize)
+                )]
+                pub struct GroupId(u64);
+
+                #[derive(serde::Deserialize)]
+                struct Groups {
+                    #[serde(flatten)]
+                    names: HashMap<GroupId, String>,
+                }
+
+                let groups: Groups =
+                    serde_json::from_str(r#"{"7":"Admins","8":"Guests"}"#).unwrap();
+                assert_eq!(groups.names.len(), 2);
+                assert_eq!(groups.names[&GroupId::new(8).unwrap()], "Guests");
+
+                let res = serde_json::from_str::<Groups>(r#"{"seven":"Admins"}"#);
+                assert!(res.is_err());
+            }
+
+            #[test]
+            fn test_string_is_rejected_as_value() {
+                #[nutype(derive(Debug, PartialEq, Deserialize))]
+                pub struct Level(u8);
+
+                let level: Level = serde_json::from_str("12").unwrap();
+                assert_eq!(level, Level::new(12));
+
+                let err = serde_json::from_str::<Level>(r#""42""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "invalid type: string \"42\", expected u8 at line 1 column 4"
+                );
+
+                let err = serde_json::from_str::<UserId>(r#""42""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "invalid type: string \"42\", expected u64 at line 1 column 4"
+                );
+            }
+        }
+
+        mod serde_with_adapters {
+            use super::*;
+            use serde_with::{serde_as, DisplayFromStr};
+            use std::collections::BTreeMap;
+
+            #[nutype(
+                validate(greater = 0),
+                derive(Debug, Display, FromStr, PartialEq, Eq, PartialOrd, Ord)
+            )]
+            pub struct Port(u16);
+
+            #[serde_as]
+            #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+            struct Config {
+                #[serde_as(as = "DisplayFromStr")]
+                port: Port,
+                #[serde_as(as = "Vec<DisplayFromStr>")]
+                fallback_ports: Vec<Port>,
+                #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+                services: BTreeMap<Port, String>,
+            }
+
+            #[test]
+            fn test_display_from_str() {
+                let config = Config {
+                    port: Port::new(80).unwrap(),
+                    fallback_ports: vec![Port::new(8080).unwrap()],
+                    services: BTreeMap::from([(Port::new(22).unwrap(), "ssh".to_string())]),
+                };
+
+                let json = serde_json::to_string(&config).unwrap();
+                assert_eq!(
+                    json,
+                    r#"{"port":"80","fallback_ports":["8080"],"services":{"22":"ssh"}}"#
+                );
+                assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+            }
+
+            #[test]
+            fn test_display_from_str_with_invalid_value() {
+                let err = serde_json::from_str::<Config>(
+                    r#"{"port":"0","fallback_ports":[],"services":{}}"#,
+                )
+                .unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Failed to parse Port: Port is too small. The value must be greater than 0. at line 1 column 11"
+                );
+            }
+        }
+
+        mod as_string {
+            use super::*;
+
+            #[nutype(
+                validate(greater = 0),
+                serde(as_string),
+                derive(Debug, PartialEq, Serialize, Deserialize)
+            )]
+            pub struct AccountId(u64);
+
+            #[test]
+            fn test_serialize_as_string() {
+                let id = AccountId::new(9007199254740993).unwrap();
+                let json = serde_json::to_string(&id).unwrap();
+                assert_eq!(json, r#""9007199254740993""#);
+            }
+
+            #[test]
+            fn test_deserialize_from_string() {
+                let id: AccountId = serde_json::from_str(r#""42""#).unwrap();
+                assert_eq!(id, AccountId::new(42).unwrap());
+            }
+
+            #[test]
+            fn test_deserialize_rejects_invalid_values() {
+                let err = serde_json::from_str::<AccountId>(r#""0""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "AccountId is too small. The value must be greater than 0. Expected valid AccountId"
+                );
+
+                let err = serde_json::from_str::<AccountId>(r#""forty two""#).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "invalid digit found in string Expected a string representation of u64"
+                );
+
+                // The wire form is always a string
+                assert!(serde_json::from_str::<AccountId>("42").is_err());
+            }
+
+            #[test]
+            fn test_ron_roundtrip() {
+                let id = AccountId::new(77).unwrap();
+
+                let serialized = ron::to_string(&id).unwrap();
+                let deserialized: AccountId = ron::from_str(&serialized).unwrap();
+
+                assert_eq!(deserialized, id);
+            }
+
+            #[test]
+            fn test_rmp_roundtrip() {
+                let id = AccountId::new(77).unwrap();
+
+                let bytes = rmp_serde::to_vec(&id).unwrap();
+                let deserialized: AccountId = rmp_serde::from_slice(&bytes).unwrap();
+
+                assert_eq!(deserialized, id);
+            }
         }
 
         mod ron_format {
@@ -742,42 +1767,1586 @@ mod traits {
         #[test]
         #[should_panic(expected = "Default value for type `Number` is invalid")]
         fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(less_or_equal = 20), default = 21, derive(Default))]
+            // A number literal would be rejected at compile time, but an expression is checked
+            // only at runtime.
+            #[nutype(validate(less_or_equal = 20), default = 20 + 1, derive(Default))]
             pub struct Number(i16);
 
             Number::default();
         }
-    }
-}
 
-#[cfg(test)]
-#[cfg(feature = "new_unchecked")]
-mod new_unchecked {
-    use super::*;
+        #[test]
+        fn test_default_out_of_bounds_with_sanitizer() {
+            // The default value is sanitized, so it's not rejected at compile time.
+            #[nutype(
+                sanitize(with = |n| n.min(20)),
+                validate(less_or_equal = 20),
+                default = 100,
+                derive(Default)
+            )]
+            pub struct Number(i16);
 
-    #[test]
-    fn test_new_unchecked() {
-        #[nutype(new_unchecked, validate(greater_or_equal = 50))]
-        pub struct Dist(u32);
+            assert_eq!(Number::default().into_inner(), 20);
+        }
 
-        let dist = unsafe { Dist::new_unchecked(3) };
-        assert_eq!(dist.into_inner(), 3);
+        #[test]
+        fn test_new_or_default() {
+            #[nutype(validate(greater_or_equal = 1024), default = 8080)]
+            pub struct Port(u16);
+
+            assert_eq!(Port::new_or_default(3000).into_inner(), 3000);
+            assert_eq!(Port::new_or_default(80).into_inner(), 8080);
+        }
     }
 }
 
 #[cfg(test)]
-#[cfg(feature = "schemars08")]
-mod derive_schemars_json_schema {
+mod parse {
     use super::*;
-    use schemars::{schema_for, JsonSchema};
 
     #[test]
-    fn test_json_schema_derive() {
-        #[nutype(derive(JsonSchema))]
-        pub struct CustomerId(i128);
+    fn test_parse_without_validation() {
+        #[nutype(sanitize(with = |n| n.min(100)), derive(Debug, PartialEq))]
+        pub struct Percent(u8);
+
+        assert_eq!(Percent::parse("42").unwrap(), Percent::new(42));
+        assert_eq!(Percent::parse("200").unwrap(), Percent::new(100));
+
+        let err: PercentParseError = Percent::parse("-1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Percent: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_validation() {
+        #[nutype(validate(greater_or_equal = 1024), derive(Debug))]
+        pub struct Port(u16);
+
+        assert_eq!(Port::parse("8080").unwrap().into_inner(), 8080);
+
+        let err = Port::parse("80").unwrap_err();
+        assert!(matches!(
+            err,
+            PortParseError::Validate(PortError::GreaterOrEqualViolated)
+        ));
+
+        let err = Port::parse("http").unwrap_err();
+        assert!(matches!(err, PortParseError::Parse(_)));
+    }
+
+    #[test]
+    fn test_parse_radix() {
+        #[nutype(validate(less_or_equal = 511), derive(Debug))]
+        pub struct Permissions(u16);
+
+        assert_eq!(
+            Permissions::parse_radix("755", 8).unwrap().into_inner(),
+            493
+        );
+        assert_eq!(
+            Permissions::parse_radix("1ff", 16).unwrap().into_inner(),
+            511
+        );
+
+        let err = Permissions::parse_radix("1000", 8).unwrap_err();
+        assert!(matches!(
+            err,
+            PermissionsParseError::Validate(PermissionsError::LessOrEqualViolated)
+        ));
+
+        let err = Permissions::parse_radix("789", 8).unwrap_err();
+        assert!(matches!(err, PermissionsParseError::Parse(_)));
+    }
+
+    #[test]
+    fn test_parse_radix_without_validation() {
+        #[nutype(derive(Debug, PartialEq))]
+        pub struct Mask(i32);
+
+        assert_eq!(Mask::parse_radix("-1010", 2).unwrap(), Mask::new(-10));
+    }
+}
+
+#[cfg(test)]
+mod const_generics {
+    use super::*;
+
+    #[nutype(
+        validate(greater_or_equal = MIN, less_or_equal = MAX),
+        derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, FromStr, TryFrom, Display, Into)
+    )]
+    pub struct Bounded<const MIN: i64, const MAX: i64>(i64);
+
+    type Dice = Bounded<1, 6>;
+    type Percentage = Bounded<0, 100>;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Dice::new(0), Err(BoundedError::GreaterOrEqualViolated));
+        assert_eq!(Dice::new(7), Err(BoundedError::LessOrEqualViolated));
+        assert_eq!(Dice::new(6).unwrap().into_inner(), 6);
+
+        assert_eq!(Percentage::new(101), Err(BoundedError::LessOrEqualViolated));
+        assert_eq!(Percentage::new(0).unwrap().into_inner(), 0);
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            Dice::new(7).unwrap_err().to_string(),
+            "Bounded is too big. The value must be less or equal to MAX."
+        );
+    }
+
+    #[test]
+    fn test_traits() {
+        let dice: Dice = "4".parse().unwrap();
+        assert_eq!(dice.to_string(), "4");
+        assert_eq!(Dice::try_from(5).unwrap(), Dice::new(5).unwrap());
+        assert!(Dice::new(2).unwrap() < Dice::new(3).unwrap());
+
+        let value: i64 = dice.into();
+        assert_eq!(value, 4);
+    }
+
+    #[test]
+    fn test_const_generic_with_regular_bound() {
+        #[nutype(
+            validate(greater = 0, aligned_to = 8, less_or_equal = LIMIT),
+            derive(Debug, PartialEq)
+        )]
+        pub struct BufferSize<const LIMIT: u32>(u32);
+
+        assert_eq!(
+            BufferSize::<64>::new(72),
+            Err(BufferSizeError::LessOrEqualViolated)
+        );
+        assert_eq!(
+            BufferSize::<64>::new(0),
+            Err(BufferSizeError::GreaterViolated)
+        );
+        assert_eq!(BufferSize::<64>::new(64).unwrap().into_inner(), 64);
+        assert_eq!(
+            BufferSize::<64>::new(0).unwrap_err().to_string(),
+            "BufferSize is too small. The value must be greater than 0."
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        #[nutype(
+            validate(less_or_equal = MAX),
+            derive(Debug, PartialEq, Serialize, Deserialize)
+        )]
+        pub struct Limited<const MAX: u8>(u8);
+
+        let json = serde_json::to_string(&Limited::<10>::new(7).unwrap()).unwrap();
+        assert_eq!(json, "7");
+        let limited: Limited<10> = serde_json::from_str(&json).unwrap();
+        assert_eq!(limited.into_inner(), 7);
+        assert!(serde_json::from_str::<Limited<5>>(&json).is_err());
+    }
+}
+
+#[cfg(test)]
+mod phantom_type_params {
+    use super::*;
+    use std::collections::HashSet;
+    use std::marker::PhantomData;
+
+    #[nutype(
+        validate(greater = 0),
+        derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            PartialEq<Inner>,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            Display,
+            TryFrom,
+            Into
+        )
+    )]
+    pub struct Id<T>(u64, PhantomData<T>);
+
+    // Tags do not need to implement any traits.
+    struct User;
+    struct Order;
+
+    type UserId = Id<User>;
+    type OrderId = Id<Order>;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(UserId::new(0), Err(IdError::GreaterViolated));
+        assert_eq!(UserId::new(7).unwrap().into_inner(), 7);
+        assert_eq!(OrderId::new(7).unwrap().into_inner(), 7);
+    }
+
+    #[test]
+    fn test_traits() {
+        let user_id = UserId::new(3).unwrap();
+        let copied = user_id;
+        assert_eq!(user_id, copied);
+        assert_eq!(user_id, 3);
+        assert_eq!(format!("{user_id:?}"), "Id(3)");
+        assert_eq!(user_id.to_string(), "3");
+        assert!(UserId::new(2).unwrap() < user_id);
+        assert_eq!(OrderId::try_from(5).unwrap().into_inner(), 5);
+
+        let ids: HashSet<UserId> = [user_id, copied].into_iter().collect();
+        assert_eq!(ids.len(), 1);
+
+        let value: u64 = user_id.into();
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn test_with_const_generics() {
+        #[nutype(validate(less_or_equal = MAX), derive(Debug, PartialEq))]
+        pub struct Amount<Currency, const MAX: u32>(u32, PhantomData<Currency>);
+
+        struct Eur;
+
+        assert_eq!(
+            Amount::<Eur, 100>::new(101),
+            Err(AmountError::LessOrEqualViolated)
+        );
+        assert_eq!(Amount::<Eur, 100>::new(100).unwrap().into_inner(), 100);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        #[nutype(
+            validate(greater = 0),
+            derive(Debug, PartialEq, Serialize, Deserialize)
+        )]
+        pub struct Id<T>(u64, PhantomData<T>);
+
+        struct User;
+
+        let id = Id::<User>::new(5).unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "5");
+        assert_eq!(serde_json::from_str::<Id<User>>(&json).unwrap(), id);
+        assert!(serde_json::from_str::<Id<User>>("0").is_err());
+    }
+}
+
+#[cfg(test)]
+mod convertible_with {
+    use super::*;
+
+    #[nutype(
+        validate(greater_or_equal = 0, less_or_equal = 100),
+        derive(Debug, PartialEq, TryFrom)
+    )]
+    pub struct Percentage(u8);
+
+    #[nutype(
+        validate(greater_or_equal = 1, less_or_equal = 10),
+        derive(Debug, PartialEq, TryFrom),
+        convertible_with = Percentage
+    )]
+    pub struct Score(u8);
+
+    #[test]
+    fn test_try_from_other() {
+        let percentage = Percentage::new(7).unwrap();
+        assert_eq!(Score::try_from(percentage).unwrap(), Score::new(7).unwrap());
+
+        let percentage = Percentage::new(50).unwrap();
+        assert_eq!(
+            Score::try_from(percentage),
+            Err(ScoreError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_try_into_other() {
+        let score = Score::new(10).unwrap();
+        let percentage: Percentage = score.try_into().unwrap();
+        assert_eq!(percentage, Percentage::new(10).unwrap());
+    }
+
+    #[nutype(derive(Debug, PartialEq, From))]
+    pub struct RawScore(u8);
+
+    #[nutype(
+        validate(less_or_equal = 10),
+        derive(Debug, PartialEq),
+        convertible_with = RawScore
+    )]
+    pub struct CheckedScore(u8);
+
+    #[test]
+    fn test_into_other_without_validation() {
+        assert_eq!(
+            CheckedScore::try_from(RawScore::new(11)),
+            Err(CheckedScoreError::LessOrEqualViolated)
+        );
+        assert_eq!(
+            RawScore::try_from(CheckedScore::new(5).unwrap()),
+            Ok(RawScore::new(5))
+        );
+    }
+
+    #[nutype(derive(Debug, PartialEq, TryFrom))]
+    pub struct Small(i32);
+
+    #[nutype(validate(greater = 0), derive(Debug, PartialEq, TryFrom))]
+    pub struct Positive(i32);
+
+    #[nutype(
+        derive(Debug, PartialEq),
+        convertible_with = Small,
+        convertible_with = Positive
+    )]
+    pub struct Number(i32);
+
+    #[test]
+    fn test_multiple_types() {
+        assert_eq!(Number::try_from(Small::new(-3)), Ok(Number::new(-3)));
+        assert_eq!(
+            Positive::try_from(Number::new(-3)),
+            Err(PositiveError::GreaterViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod associated_functions {
+    use super::*;
+
+    #[nutype(
+        sanitize(with = |n| n.clamp(0, 200)),
+        validate(greater_or_equal = 18),
+        derive(Debug)
+    )]
+    pub struct Age(u8);
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(Age::sanitize(255), 200);
+        assert_eq!(Age::sanitize(30), 30);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Age::validate(&18), Ok(()));
+        assert_eq!(Age::validate(&17), Err(AgeError::GreaterOrEqualViolated));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Age::is_valid(&30));
+        assert!(!Age::is_valid(&10));
+    }
+
+    #[test]
+    fn test_as_inner() {
+        let age = Age::new(30).unwrap();
+        assert_eq!(age.as_inner(), &30);
+    }
+
+    #[test]
+    fn test_new_all() {
+        let ages = Age::new_all([18, 30, 255]).unwrap();
+        let values: Vec<u8> = ages.into_iter().map(Age::into_inner).collect();
+        assert_eq!(values, vec![18, 30, 200]);
+
+        assert_eq!(
+            Age::new_all(vec![20, 30, 17, 10]).unwrap_err(),
+            (2, AgeError::GreaterOrEqualViolated)
+        );
+        assert!(Age::new_all([]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_as_inner_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug))]
+        pub struct Step(i64);
+
+        let step = Step::new(-7).unwrap();
+        assert_eq!(*step.as_inner(), -7);
+        assert_eq!(step.into_inner(), -7);
+    }
+
+    #[test]
+    fn test_with_const_generics() {
+        #[nutype(validate(less_or_equal = MAX), derive(Debug))]
+        pub struct Limited<const MAX: u32>(u32);
+
+        assert!(Limited::<10>::is_valid(&10));
+        assert!(!Limited::<10>::is_valid(&11));
+    }
+
+    #[test]
+    fn test_try_map() {
+        let age = Age::new(30).unwrap();
+        assert_eq!(age.try_map(|n| n + 1).unwrap().into_inner(), 31);
+        assert_eq!(
+            Age::new(30).unwrap().try_map(|n| n - 20).unwrap_err(),
+            AgeError::GreaterOrEqualViolated
+        );
+        assert_eq!(
+            Age::new(30)
+                .unwrap()
+                .try_map(|n| n * 8)
+                .unwrap()
+                .into_inner(),
+            200
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        #[nutype(sanitize(with = |n| n.max(0)), derive(Debug))]
+        pub struct Balance(i64);
+
+        assert_eq!(Balance::new(10).map(|n| n * 2).into_inner(), 20);
+        assert_eq!(Balance::new(10).map(|n| n - 50).into_inner(), 0);
+    }
+}
+
+#[cfg(test)]
+mod all_values {
+    use super::*;
+
+    #[test]
+    fn test_all_within_bounds() {
+        #[nutype(
+            validate(greater_or_equal = 1, less_or_equal = 6),
+            derive(Debug, PartialEq)
+        )]
+        pub struct DieFace(u8);
+
+        let faces: Vec<u8> = DieFace::all().map(DieFace::into_inner).collect();
+        assert_eq!(faces, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(DieFace::count(), 6);
+    }
+
+    #[test]
+    fn test_all_with_exclusive_bounds() {
+        #[nutype(validate(greater = -3, less = 3), derive(Debug))]
+        pub struct Offset(i8);
+
+        let offsets: Vec<i8> = Offset::all().map(Offset::into_inner).collect();
+        assert_eq!(offsets, vec![-2, -1, 0, 1, 2]);
+        assert_eq!(Offset::count(), 5);
+    }
+
+    #[test]
+    fn test_count_of_wide_range() {
+        #[nutype(validate(greater_or_equal = -1_000_000_000_000, less = 1_000_000_000_000))]
+        pub struct Cents(i64);
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(Cents::count(), 2_000_000_000_000);
+
+        #[nutype(validate(greater_or_equal = i64::MIN, less_or_equal = i64::MAX))]
+        pub struct Whole(i64);
+
+        // 2^64 values don't fit in `usize` even on 64-bit targets
+        assert!(std::panic::catch_unwind(Whole::count).is_err());
+    }
+
+    #[test]
+    fn test_all_skips_invalid_values() {
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 10, even),
+            derive(Debug)
+        )]
+        pub struct Even(u32);
+
+        let values: Vec<u32> = Even::all().map(Even::into_inner).collect();
+        assert_eq!(values, vec![0, 2, 4, 6, 8, 10]);
+        assert_eq!(Even::count(), 6);
+    }
+
+    #[test]
+    fn test_all_with_nonzero() {
+        #[nutype(
+            validate(nonzero, greater_or_equal = -2, less_or_equal = 2),
+            derive(Debug)
+        )]
+        pub struct Step(i16);
+
+        let steps: Vec<i16> = Step::all().map(Step::into_inner).collect();
+        assert_eq!(steps, vec![-2, -1, 1, 2]);
+        assert_eq!(Step::count(), 4);
+    }
+
+    #[test]
+    fn test_all_with_const_generics() {
+        #[nutype(validate(greater_or_equal = 1, less_or_equal = MAX), derive(Debug))]
+        pub struct Limited<const MAX: u8>(u8);
+
+        assert_eq!(Limited::<3>::count(), 3);
+        assert_eq!(Limited::<3>::all().last().unwrap().into_inner(), 3);
+    }
+
+    #[test]
+    fn test_range() {
+        #[nutype(validate(greater_or_equal = 18), derive(Debug, Clone, Copy, PartialEq))]
+        pub struct Age(u8);
+
+        let from = Age::new(20).unwrap();
+        let to = Age::new(23).unwrap();
+        let ages: Vec<u8> = Age::range(from, to).map(Age::into_inner).collect();
+        assert_eq!(ages, vec![20, 21, 22, 23]);
+
+        assert_eq!(Age::range(to, from).count(), 0);
+        assert_eq!(Age::range(from, from).collect::<Vec<_>>(), vec![from]);
+    }
+
+    #[test]
+    fn test_range_skips_invalid_values() {
+        #[nutype(validate(odd), derive(Debug))]
+        pub struct Odd(i32);
+
+        let from = Odd::new(-3).unwrap();
+        let to = Odd::new(3).unwrap();
+        let values: Vec<i32> = Odd::range(from, to).map(Odd::into_inner).collect();
+        assert_eq!(values, vec![-3, -1, 1, 3]);
+    }
+
+    #[test]
+    fn test_range_with_nonzero() {
+        #[nutype(validate(nonzero), derive(Debug))]
+        pub struct Step(i8);
+
+        let from = Step::new(-2).unwrap();
+        let to = Step::new(2).unwrap();
+        let steps: Vec<i8> = Step::range(from, to).map(Step::into_inner).collect();
+        assert_eq!(steps, vec![-2, -1, 1, 2]);
+    }
+
+    #[test]
+    fn test_all_with_sanitizer() {
+        #[nutype(
+            sanitize(with = |n| n.clamp(0, 5)),
+            validate(greater_or_equal = 0, less_or_equal = 5),
+            derive(Debug)
+        )]
+        pub struct Level(u8);
+
+        assert_eq!(Level::count(), 6);
+    }
+}
+
+#[cfg(test)]
+mod saturating {
+    use super::*;
+
+    #[test]
+    fn test_saturating_add_and_sub() {
+        #[nutype(validate(less_or_equal = 100), derive(Debug, Clone, Copy, PartialEq))]
+        pub struct Health(u32);
+
+        let health = Health::new(80).unwrap();
+        let heal = Health::new(30).unwrap();
+        assert_eq!(health.saturating_add(heal).into_inner(), 100);
+        assert_eq!(heal.saturating_add(heal).into_inner(), 60);
+        assert_eq!(heal.saturating_sub(health).into_inner(), 0);
+        assert_eq!(health.saturating_sub(heal).into_inner(), 50);
+    }
+
+    #[test]
+    fn test_saturating_with_exclusive_bounds() {
+        #[nutype(validate(greater = -10, less = 10), derive(Debug, Clone, Copy))]
+        pub struct Offset(i8);
+
+        let offset = Offset::new(7).unwrap();
+        let negative = Offset::new(-7).unwrap();
+        assert_eq!(offset.saturating_add(offset).into_inner(), 9);
+        assert_eq!(negative.saturating_add(negative).into_inner(), -9);
+        assert_eq!(negative.saturating_sub(offset).into_inner(), -9);
+        assert_eq!(offset.saturating_sub(negative).into_inner(), 9);
+    }
+
+    #[test]
+    fn test_saturating_at_inner_type_bounds() {
+        #[nutype(validate(greater_or_equal = 10), derive(Debug, Clone, Copy))]
+        pub struct Big(u8);
+
+        let big = Big::new(200).unwrap();
+        assert_eq!(big.saturating_add(big).into_inner(), 255);
+        assert_eq!(Big::new(20).unwrap().saturating_sub(big).into_inner(), 10);
+    }
+}
+
+#[cfg(test)]
+mod new_or_clamp {
+    use super::*;
+
+    #[test]
+    fn test_new_or_clamp() {
+        #[nutype(
+            validate(greater_or_equal = 1, less = 11),
+            derive(Debug, Clone, Copy, PartialEq)
+        )]
+        pub struct Volume(i32);
+
+        assert_eq!(Volume::new_or_clamp(5), Volume::new(5).unwrap());
+        assert_eq!(Volume::new_or_clamp(-3).into_inner(), 1);
+        assert_eq!(Volume::new_or_clamp(42).into_inner(), 10);
+    }
+
+    #[test]
+    fn test_new_or_clamp_with_sanitizer() {
+        #[nutype(
+            sanitize(with = |n| n * 10),
+            validate(less_or_equal = 100),
+            derive(Debug)
+        )]
+        pub struct Percent(u16);
+
+        assert_eq!(Percent::new_or_clamp(7).into_inner(), 70);
+        assert_eq!(Percent::new_or_clamp(20).into_inner(), 100);
+    }
+}
+
+#[cfg(test)]
+mod from_float {
+    use super::*;
+
+    #[nutype(from_float = floor, derive(Debug, PartialEq))]
+    pub struct Floor(i32);
+
+    #[nutype(from_float = ceil, derive(Debug, PartialEq))]
+    pub struct Ceil(i32);
+
+    #[nutype(from_float = round, validate(less_or_equal = 100), derive(Debug))]
+    pub struct Percent(u8);
+
+    #[nutype(from_float = exact, derive(Debug))]
+    pub struct Cents(i64);
+
+    #[test]
+    fn test_floor() {
+        assert_eq!(Floor::try_from(2.7).unwrap().into_inner(), 2);
+        assert_eq!(Floor::try_from(-2.1).unwrap().into_inner(), -3);
+        assert_eq!(Floor::try_from(-3.0).unwrap().into_inner(), -3);
+    }
+
+    #[test]
+    fn test_ceil() {
+        assert_eq!(Ceil::try_from(2.1).unwrap().into_inner(), 3);
+        assert_eq!(Ceil::try_from(-2.7).unwrap().into_inner(), -2);
+        assert_eq!(Ceil::try_from(5.0).unwrap().into_inner(), 5);
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(Percent::try_from(42.5).unwrap().into_inner(), 43);
+        assert_eq!(Percent::try_from(42.49).unwrap().into_inner(), 42);
+        assert_eq!(Percent::try_from(-0.4).unwrap().into_inner(), 0);
+        assert_eq!(
+            Percent::try_from(100.7).unwrap_err(),
+            PercentFromFloatError::Validate(PercentError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_exact() {
+        assert_eq!(Cents::try_from(-1250.0).unwrap().into_inner(), -1250);
+        assert_eq!(
+            Cents::try_from(12.5).unwrap_err(),
+            CentsFromFloatError::Fractional
+        );
+    }
+
+    #[test]
+    fn test_not_finite() {
+        assert_eq!(
+            Floor::try_from(f64::NAN).unwrap_err(),
+            FloorFromFloatError::NotFinite
+        );
+        assert_eq!(
+            Ceil::try_from(f64::NEG_INFINITY).unwrap_err(),
+            CeilFromFloatError::NotFinite
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(
+            Percent::try_from(255.5).unwrap_err(),
+            PercentFromFloatError::OutOfRange
+        );
+        assert_eq!(
+            Percent::try_from(-0.5).unwrap_err(),
+            PercentFromFloatError::OutOfRange
+        );
+        assert_eq!(
+            Cents::try_from(9223372036854775808.0).unwrap_err(),
+            CentsFromFloatError::OutOfRange
+        );
+        assert_eq!(
+            Cents::try_from(-9223372036854775808.0)
+                .unwrap()
+                .into_inner(),
+            i64::MIN
+        );
+        assert_eq!(
+            Floor::try_from(2147483647.5).unwrap().into_inner(),
+            i32::MAX
+        );
+        assert_eq!(
+            Ceil::try_from(2147483647.5).unwrap_err(),
+            CeilFromFloatError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            CentsFromFloatError::Fractional.to_string(),
+            "Failed to convert Cents from float: the value has a fractional part"
+        );
+        assert_eq!(
+            PercentFromFloatError::OutOfRange.to_string(),
+            "Failed to convert Percent from float: the value is out of range of u8"
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "num_traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    #[nutype(
+        validate(greater_or_equal = 1, less_or_equal = 65535),
+        derive(Debug, PartialEq, ToPrimitive, FromPrimitive)
+    )]
+    pub struct Port(u32);
+
+    #[nutype(derive(Debug, PartialEq, FromPrimitive))]
+    pub struct Offset(i8);
+
+    #[test]
+    fn test_to_primitive() {
+        let port = Port::new(8080).unwrap();
+        assert_eq!(port.to_u16(), Some(8080));
+        assert_eq!(port.to_i64(), Some(8080));
+        assert_eq!(port.to_u8(), None);
+        assert_eq!(port.to_f64(), Some(8080.0));
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(Port::from_i64(443), Some(Port::new(443).unwrap()));
+        assert_eq!(Port::from_f64(22.0), Some(Port::new(22).unwrap()));
+
+        // Violates validation
+        assert_eq!(Port::from_u64(0), None);
+        assert_eq!(Port::from_u64(70000), None);
+
+        // Does not fit into the inner type
+        assert_eq!(Port::from_i32(-1), None);
+    }
+
+    #[test]
+    fn test_to_primitive_with_nonzero() {
+        #[nutype(validate(nonzero), derive(ToPrimitive))]
+        pub struct Step(i16);
+
+        assert_eq!(Step::new(-300).unwrap().to_i64(), Some(-300));
+        assert_eq!(Step::new(-300).unwrap().to_u8(), None);
+    }
+
+    #[test]
+    fn test_from_primitive_without_validation() {
+        assert_eq!(Offset::from_i64(-100), Some(Offset::new(-100)));
+        assert_eq!(Offset::from_i64(200), None);
+    }
+
+    #[test]
+    fn test_generic_usage() {
+        fn sum<T: ToPrimitive>(values: &[T]) -> u64 {
+            values.iter().filter_map(ToPrimitive::to_u64).sum()
+        }
+
+        let ports = [Port::new(80).unwrap(), Port::new(443).unwrap()];
+        assert_eq!(sum(&ports), 523);
+    }
+
+    mod bounded_zero_one {
+        use super::*;
+        use core::ops::{Add, Mul};
+        use num_traits::{Bounded, One, Zero};
+
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 100),
+            derive(Debug, Clone, Copy, PartialEq, Bounded, Zero, One)
+        )]
+        pub struct Percent(u8);
+
+        impl Add for Percent {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::new(self.into_inner() + other.into_inner()).unwrap()
+            }
+        }
+
+        impl Mul for Percent {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self::new(self.into_inner() * other.into_inner()).unwrap()
+            }
+        }
+
+        #[test]
+        fn test_bounded() {
+            assert_eq!(Percent::min_value().into_inner(), 0);
+            assert_eq!(Percent::max_value().into_inner(), 100);
+        }
+
+        #[test]
+        fn test_bounded_with_exclusive_bounds() {
+            #[nutype(validate(greater = -10, less = 10), derive(Bounded))]
+            pub struct Offset(i32);
+
+            assert_eq!(Offset::min_value().into_inner(), -9);
+            assert_eq!(Offset::max_value().into_inner(), 9);
+        }
+
+        #[test]
+        fn test_bounded_with_one_bound() {
+            #[nutype(validate(greater_or_equal = 18), derive(Bounded))]
+            pub struct Age(u8);
+
+            assert_eq!(Age::min_value().into_inner(), 18);
+            assert_eq!(Age::max_value().into_inner(), 255);
+        }
+
+        #[test]
+        fn test_bounded_without_validation() {
+            #[nutype(derive(Bounded))]
+            pub struct Level(i8);
+
+            assert_eq!(Level::min_value().into_inner(), -128);
+            assert_eq!(Level::max_value().into_inner(), 127);
+        }
+
+        #[test]
+        fn test_zero_and_one() {
+            assert_eq!(Percent::zero().into_inner(), 0);
+            assert!(Percent::zero().is_zero());
+            assert!(!Percent::one().is_zero());
+            assert_eq!(Percent::one().into_inner(), 1);
+        }
+
+        #[test]
+        #[should_panic(expected = "Zero value for type `Age` is invalid.")]
+        fn test_zero_out_of_range() {
+            #[nutype(validate(greater_or_equal = 18), derive(Debug, Clone, Copy, Zero))]
+            pub struct Age(u8);
+
+            impl Add for Age {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    Self::new(self.into_inner() + other.into_inner()).unwrap()
+                }
+            }
+
+            Age::zero();
+        }
+
+        #[test]
+        fn test_one_with_nonzero() {
+            #[nutype(validate(nonzero), derive(Debug, Clone, Copy, One))]
+            pub struct Factor(i64);
+
+            impl Mul for Factor {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    Self::new(self.into_inner() * other.into_inner()).unwrap()
+                }
+            }
+
+            assert_eq!(Factor::one().into_inner(), 1);
+        }
+
+        #[test]
+        fn test_generic_usage() {
+            fn total<T: Zero + Copy>(values: &[T]) -> T {
+                values.iter().fold(T::zero(), |acc, &value| acc + value)
+            }
+
+            let values = [Percent::new(20).unwrap(), Percent::new(30).unwrap()];
+            assert_eq!(total(&values).into_inner(), 50);
+            assert_eq!(total::<Percent>(&[]), Percent::zero());
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "kani")]
+mod kani_proofs {
+    use super::*;
+
+    // The proof harness is verified with `cargo kani --features kani`,
+    // regular builds must not be affected by it.
+    #[nutype(
+        sanitize(with = |n: i32| n.saturating_abs()),
+        validate(less_or_equal = 1000),
+        derive(Debug)
+    )]
+    pub struct Distance(i32);
+
+    #[test]
+    fn test_type_with_proof_harness() {
+        assert_eq!(Distance::new(-5).unwrap().into_inner(), 5);
+        assert!(Distance::new(i32::MIN).is_err());
+    }
+}
+
+#[cfg(test)]
+mod checked_arithmetic {
+    use super::*;
+
+    #[nutype(
+        checked_arithmetic,
+        validate(less_or_equal = 100),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Percent(u8);
+
+    #[test]
+    fn test_checked_add() {
+        let a = Percent::new(60).unwrap();
+        let b = Percent::new(30).unwrap();
+        assert_eq!(a.checked_add(b).unwrap().into_inner(), 90);
+        assert_eq!(
+            a.checked_add(a),
+            Err(PercentArithmeticError::Validate(
+                PercentError::LessOrEqualViolated
+            ))
+        );
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let a = Percent::new(60).unwrap();
+        let b = Percent::new(30).unwrap();
+        assert_eq!(a.checked_sub(b).unwrap().into_inner(), 30);
+        assert_eq!(b.checked_sub(a), Err(PercentArithmeticError::Overflow));
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let a = Percent::new(5).unwrap();
+        let b = Percent::new(20).unwrap();
+        assert_eq!(a.checked_mul(b).unwrap().into_inner(), 100);
+        assert_eq!(b.checked_mul(b), Err(PercentArithmeticError::Overflow));
+    }
+
+    #[test]
+    fn test_result_is_sanitized() {
+        #[nutype(
+            checked_arithmetic,
+            sanitize(with = |n| n.clamp(-10, 10)),
+            derive(Debug, Clone, Copy)
+        )]
+        pub struct Offset(i32);
+
+        let offset = Offset::new(8);
+        assert_eq!(offset.checked_add(offset).unwrap().into_inner(), 10);
+        assert_eq!(offset.checked_sub(offset).unwrap().into_inner(), 0);
+    }
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(checked_arithmetic, derive(Debug, Clone, Copy))]
+        pub struct Count(u8);
+
+        let count = Count::new(200);
+        assert_eq!(
+            count.checked_add(count).unwrap_err(),
+            CountArithmeticError::Overflow
+        );
+    }
+
+    #[test]
+    fn test_with_nonzero() {
+        #[nutype(checked_arithmetic, validate(nonzero), derive(Debug, Clone, Copy))]
+        pub struct Delta(i64);
+
+        let delta = Delta::new(3).unwrap();
+        assert_eq!(delta.checked_mul(delta).unwrap().into_inner(), 9);
+        assert_eq!(
+            delta.checked_sub(delta).unwrap_err(),
+            DeltaArithmeticError::Validate(DeltaError::NonzeroViolated)
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            PercentArithmeticError::Overflow.to_string(),
+            "Arithmetic operation on Percent overflowed"
+        );
+        assert_eq!(
+            PercentArithmeticError::Validate(PercentError::LessOrEqualViolated).to_string(),
+            "Arithmetic operation on Percent produced an invalid value: Percent is too big. The value must be less or equal to 100."
+        );
+    }
+}
+
+#[cfg(test)]
+mod new_slice {
+    use super::*;
+
+    #[nutype(new_slice, validate(less_or_equal = 4095), derive(Debug, PartialEq))]
+    pub struct Sample(u16);
+
+    #[test]
+    fn test_new_slice() {
+        let raw_samples: Vec<u16> = vec![0, 17, 4095];
+        let samples: &[Sample] = Sample::new_slice(&raw_samples).unwrap();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[1], Sample::new(17).unwrap());
+
+        // No copying takes place
+        assert_eq!(samples.as_ptr() as *const u16, raw_samples.as_ptr());
+    }
+
+    #[test]
+    fn test_new_slice_with_invalid_element() {
+        let err = Sample::new_slice(&[1, 2, 4096, 5000]).unwrap_err();
+        assert_eq!(
+            err,
+            SampleSliceError {
+                index: 2,
+                error: SampleError::LessOrEqualViolated,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Element at index 2 is invalid: Sample is too big. The value must be less or equal to 4095."
+        );
+    }
+
+    #[test]
+    fn test_new_slice_without_validation() {
+        #[nutype(new_slice, derive(Debug, Clone, Copy))]
+        pub struct Offset(i64);
+
+        let offsets: &[Offset] = Offset::new_slice(&[-1, 0, 1]);
+        assert_eq!(offsets[0].into_inner(), -1);
+    }
+
+    #[test]
+    fn test_new_slice_with_const_generics() {
+        #[nutype(
+            new_slice,
+            validate(less_or_equal = MAX),
+            derive(Debug)
+        )]
+        pub struct Level<const MAX: u8>(u8);
+
+        assert!(Level::<10>::new_slice(&[1, 10]).is_ok());
+        assert_eq!(Level::<10>::new_slice(&[1, 11]).unwrap_err().index, 1);
+    }
+}
+
+#[cfg(test)]
+mod constructor {
+    use super::*;
+
+    #[nutype(
+        constructor = from_raw,
+        validate(less_or_equal = 100),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Percentage(u8);
+
+    #[test]
+    fn test_custom_constructor_name() {
+        assert_eq!(Percentage::from_raw(42).unwrap().into_inner(), 42);
+        assert_eq!(
+            Percentage::from_raw(101),
+            Err(PercentageError::LessOrEqualViolated)
+        );
+        // `::parse()` still parses a string
+        assert_eq!(Percentage::parse("42").unwrap().into_inner(), 42);
+    }
+}
+
+#[cfg(test)]
+mod sql_check {
+    use super::*;
+
+    #[test]
+    fn test_sql_check_with_bounds() {
+        #[nutype(sql_check, validate(greater_or_equal = 18, less = 150))]
+        pub struct Age(u8);
+
+        assert_eq!(Age::sql_check("age"), "age >= 18 AND age < 150");
+    }
+
+    #[test]
+    fn test_sql_check_with_other_validators() {
+        #[nutype(sql_check, validate(fits_bits = 4, aligned_to = 2))]
+        pub struct Nibble(i8);
+
+        assert_eq!(
+            Nibble::sql_check("nibble"),
+            "nibble BETWEEN -8 AND 7 AND MOD(nibble, 2) = 0"
+        );
+
+        #[nutype(sql_check, validate(percentage))]
+        pub struct Progress(u32);
+
+        assert_eq!(
+            Progress::sql_check("progress"),
+            "progress BETWEEN 0 AND 100"
+        );
+
+        #[nutype(sql_check, validate(even))]
+        pub struct PairCount(u32);
+
+        #[nutype(sql_check, validate(odd))]
+        pub struct OddIndex(u32);
+
+        assert_eq!(PairCount::sql_check("n"), "MOD(n, 2) = 0");
+        assert_eq!(OddIndex::sql_check("n"), "MOD(n, 2) <> 0");
+
+        #[nutype(sql_check, validate(one_of = [1, 2, -5]))]
+        pub struct Denomination(i32);
+
+        assert_eq!(Denomination::sql_check("n"), "n IN (1, 2, -5)");
+
+        #[nutype(sql_check, validate(not_in = [0, 255]))]
+        pub struct Channel(u8);
+
+        assert_eq!(Channel::sql_check("n"), "n NOT IN (0, 255)");
+
+        #[nutype(sql_check, validate(any(aligned_to = 3, not(greater = 5))))]
+        pub struct Fizz(u8);
+
+        assert_eq!(Fizz::sql_check("n"), "(MOD(n, 3) = 0 OR NOT (n > 5))");
+    }
+
+    #[test]
+    fn test_sql_check_with_const_generics() {
+        #[nutype(sql_check, validate(less_or_equal = MAX))]
+        pub struct Limited<const MAX: i64>(i64);
+
+        assert_eq!(Limited::<10>::sql_check("n"), "n <= 10");
+    }
+}
+
+#[cfg(test)]
+mod zod_schema {
+    use super::*;
+
+    #[test]
+    fn test_zod_schema_with_bounds() {
+        #[nutype(zod, validate(greater_or_equal = 18, less = 150))]
+        pub struct Age(u8);
+
+        assert_eq!(Age::zod_schema(), "z.number().int().gte(18).lt(150)");
+    }
+
+    #[test]
+    fn test_zod_schema_adds_range_of_inner_type() {
+        #[nutype(zod)]
+        pub struct Count(u16);
+
+        assert_eq!(Count::zod_schema(), "z.number().int().gte(0).lte(65535)");
+
+        #[nutype(zod, validate(greater = -10))]
+        pub struct Offset(i8);
+
+        assert_eq!(Offset::zod_schema(), "z.number().int().lte(127).gt(-10)");
+    }
+
+    #[test]
+    fn test_zod_schema_with_other_validators() {
+        #[nutype(zod, validate(fits_bits = 4, aligned_to = 2))]
+        pub struct Nibble(i8);
+
+        assert_eq!(
+            Nibble::zod_schema(),
+            "z.number().int().gte(-8).lte(7).multipleOf(2)"
+        );
+
+        #[nutype(zod, validate(percentage))]
+        pub struct Progress(u32);
+
+        assert_eq!(Progress::zod_schema(), "z.number().int().gte(0).lte(100)");
+
+        #[nutype(zod, validate(even))]
+        pub struct PairCount(u8);
+
+        assert_eq!(
+            PairCount::zod_schema(),
+            "z.number().int().gte(0).lte(255).multipleOf(2)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod consts {
+    use super::*;
+
+    const LEGAL_AGE: u8 = 18;
+
+    #[nutype(
+        validate(less_or_equal = 150),
+        consts(ZERO = 0, RETIREMENT = 65, ADULT = LEGAL_AGE),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Age(u8);
+
+    #[nutype(consts(MIN = -273, FREEZING = 0), derive(Debug, PartialEq))]
+    pub struct Celsius(i16);
+
+    #[nutype(
+        validate(greater_or_equal = MIN),
+        consts(LOWEST = MIN),
+        derive(Debug)
+    )]
+    pub struct Bounded<const MIN: i32>(i32);
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(Age::ZERO.into_inner(), 0);
+        assert_eq!(Age::RETIREMENT, Age::new(65).unwrap());
+        assert_eq!(Age::ADULT.into_inner(), LEGAL_AGE);
+    }
+
+    #[test]
+    fn test_consts_without_validation() {
+        assert_eq!(Celsius::MIN.into_inner(), -273);
+        assert_eq!(Celsius::FREEZING, Celsius::new(0));
+    }
+
+    #[test]
+    fn test_consts_in_const_context() {
+        const AGES: [Age; 2] = [Age::ZERO, Age::RETIREMENT];
+        assert_eq!(AGES[1].into_inner(), 65);
+    }
+
+    #[test]
+    fn test_consts_with_const_generics() {
+        assert_eq!(Bounded::<-5>::LOWEST.into_inner(), -5);
+    }
+
+    #[test]
+    fn test_min_and_max_from_bounds() {
+        #[nutype(
+            validate(greater_or_equal = 18, less_or_equal = 99),
+            derive(Debug, PartialEq)
+        )]
+        pub struct AdultAge(u8);
+
+        #[nutype(validate(greater = -10, less = 10), derive(Debug))]
+        pub struct Offset(i32);
+
+        assert_eq!(AdultAge::MIN, AdultAge::new(18).unwrap());
+        assert_eq!(AdultAge::MAX.into_inner(), 99);
+        assert_eq!(Offset::MIN.into_inner(), -9);
+        assert_eq!(Offset::MAX.into_inner(), 9);
+    }
+
+    #[test]
+    fn test_min_and_max_from_bounds_with_const_generics() {
+        assert_eq!(Bounded::<-5>::MIN.into_inner(), -5);
+    }
+
+    #[test]
+    fn test_min_does_not_override_custom_const() {
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 100),
+            consts(MIN = 10),
+            derive(Debug)
+        )]
+        pub struct Score(u8);
+
+        assert_eq!(Score::MIN.into_inner(), 10);
+        assert_eq!(Score::MAX.into_inner(), 100);
+    }
+}
+
+#[cfg(test)]
+mod const_new {
+    use super::*;
+
+    #[nutype(
+        validate(greater_or_equal = 18, less_or_equal = 150),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Age(u8);
+
+    #[nutype(validate(nonzero), derive(Debug, Clone, Copy))]
+    pub struct Quantity(u32);
+
+    #[nutype(derive(Debug, Clone, Copy, PartialEq))]
+    pub struct Offset(i64);
+
+    #[test]
+    fn test_new_without_validation_in_const_context() {
+        const ZERO: Offset = Offset::new(0);
+
+        assert_eq!(ZERO, Offset::new(0));
+        const { assert!(!Age::is_valid(&10)) };
+    }
+
+    // Reporting the validation error with `tracing` makes the constructors non-const.
+    #[cfg(not(feature = "tracing"))]
+    #[test]
+    fn test_new_in_const_context() {
+        const ADULT: Age = match Age::new(18) {
+            Ok(age) => age,
+            Err(_) => panic!("invalid age"),
+        };
+
+        static ONE: Quantity = match Quantity::new(1) {
+            Ok(quantity) => quantity,
+            Err(_) => panic!("invalid quantity"),
+        };
+
+        assert_eq!(ADULT.into_inner(), 18);
+        assert_eq!(ONE.into_inner(), 1);
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[test]
+    fn test_new_with_const_generics() {
+        const DICE: Bounded<1, 6> = match Bounded::<1, 6>::new(6) {
+            Ok(dice) => dice,
+            Err(_) => panic!("invalid dice"),
+        };
+
+        assert_eq!(DICE.into_inner(), 6);
+    }
+
+    #[test]
+    fn test_const_new() {
+        const RETIREMENT: Age = Age::const_new::<65>();
+
+        assert_eq!(RETIREMENT.into_inner(), 65);
+        assert_eq!(Quantity::const_new::<3>().into_inner(), 3);
+        assert_eq!(Bounded::<1, 6>::const_new::<2>().into_inner(), 2);
+    }
+
+    #[nutype(
+        validate(greater_or_equal = MIN, less_or_equal = MAX),
+        derive(Debug, Clone, Copy)
+    )]
+    pub struct Bounded<const MIN: i64, const MAX: i64>(i64);
+
+    thread_local! {
+        static FAILURES: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    fn record_failure<E: std::fmt::Display>(_type_name: &'static str, error: &E) {
+        FAILURES.with(|failures| failures.borrow_mut().push(error.to_string()));
+    }
+
+    #[nutype(
+        validate(less_or_equal = 150),
+        on_error = record_failure,
+        derive(Debug, Clone, Copy)
+    )]
+    pub struct Years(u8);
+
+    #[test]
+    fn test_on_error_with_bounds() {
+        // `::new()` is not `const` because of the callback, but `::const_new()` still is
+        const RETIREMENT: Years = Years::const_new::<65>();
+        assert_eq!(RETIREMENT.into_inner(), 65);
+        const { assert!(Years::is_valid(&150)) };
+
+        assert!(Years::new(20).is_ok());
+        assert!(Years::new(200).is_err());
+        assert_eq!(
+            FAILURES.with(|failures| failures.take()),
+            vec!["Years is too big. The value must be less or equal to 150.".to_string()]
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tracing")]
+mod tracing_events {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    #[derive(Default, Clone)]
+    struct RecordingSubscriber {
+        violations: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct ViolationVisitor<'a>(&'a mut Vec<String>);
+
+    impl Visit for ViolationVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "violation" {
+                self.0.push(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut ViolationVisitor(&mut self.violations.lock().unwrap()));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    // Validated only by the bounds, so without `tracing` the constructor would be `const`
+    #[nutype(validate(less_or_equal = 150), derive(Debug))]
+    pub struct Age(u8);
+
+    #[test]
+    fn test_bounds_only_type_emits_debug_event() {
+        let subscriber = RecordingSubscriber::default();
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            assert!(Age::new(30).is_ok());
+            assert!(Age::new(200).is_err());
+            // Checked at compile time, so there is nothing to report
+            assert_eq!(Age::const_new::<65>().into_inner(), 65);
+        });
+
+        assert_eq!(
+            *subscriber.violations.lock().unwrap(),
+            vec!["LessOrEqualViolated".to_string()]
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(new_unchecked, validate(greater_or_equal = 50))]
+        pub struct Dist(u32);
+
+        let dist = unsafe { Dist::new_unchecked(3) };
+        assert_eq!(dist.into_inner(), 3);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "schemars08")]
+mod derive_schemars_json_schema {
+    use super::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn test_json_schema_derive() {
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerId(i128);
 
         assert_eq!(CustomerId::schema_name(), "CustomerId");
         // Make sure it compiles
         let _schema = schema_for!(CustomerId);
     }
 }
+
+#[cfg(test)]
+mod nonzero {
+    use super::*;
+    use core::borrow::Borrow;
+    use core::mem::size_of;
+
+    #[nutype(
+        validate(nonzero, less_or_equal = 1000),
+        consts(ONE = 1),
+        derive(
+            Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, FromStr, AsRef, Deref,
+            Borrow, Into, TryFrom, Display, LowerHex
+        )
+    )]
+    pub struct UserId(u64);
+
+    #[test]
+    fn test_option_has_the_same_size() {
+        assert_eq!(size_of::<UserId>(), size_of::<u64>());
+        assert_eq!(size_of::<Option<UserId>>(), size_of::<u64>());
+    }
+
+    #[test]
+    fn test_works_on_the_primitive() {
+        let id = UserId::new(42).unwrap();
+        assert_eq!(id.into_inner(), 42u64);
+        assert_eq!(UserId::new(0).unwrap_err(), UserIdError::NonzeroViolated);
+        assert_eq!(UserId::ONE.into_inner(), 1);
+    }
+
+    #[test]
+    fn test_traits() {
+        let id = UserId::try_from(42).unwrap();
+        assert_eq!(*id, 42);
+        assert_eq!(id.as_ref(), &42);
+        assert_eq!(Borrow::<u64>::borrow(&id), &42);
+        assert_eq!(u64::from(id), 42);
+        assert_eq!(id.to_string(), "42");
+        assert_eq!(format!("{id:x}"), "2a");
+        assert_eq!("42".parse::<UserId>().unwrap(), id);
+        assert!(UserId::ONE < id);
+    }
+
+    #[test]
+    fn test_new_slice() {
+        #[nutype(new_slice, validate(nonzero), derive(Debug, Clone, Copy))]
+        pub struct Divisor(i16);
+
+        let divisors = Divisor::new_slice(&[3, -7]).unwrap();
+        assert_eq!(divisors[1].into_inner(), -7);
+        assert_eq!(Divisor::new_slice(&[3, 0]).unwrap_err().index, 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[nutype(validate(nonzero), derive(Debug, Serialize, Deserialize))]
+        pub struct Weight(u8);
+
+        let weight = Weight::new(3).unwrap();
+        assert_eq!(serde_json::to_string(&weight).unwrap(), "3");
+        let weight: Weight = serde_json::from_str("7").unwrap();
+        assert_eq!(weight.into_inner(), 7);
+        assert!(serde_json::from_str::<Weight>("0").is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use nutype::nutype;
+use std::ops::{Range, RangeInclusive};
+
+#[test]
+fn test_range_without_validation() {
+    #[nutype(derive(Debug, Clone, PartialEq, Eq, Hash, AsRef, Deref, Into, From, Borrow))]
+    pub struct TimeWindow(Range<u64>);
+
+    let window = TimeWindow::new(10..20);
+    assert_eq!(window.clone().into_inner(), 10..20);
+    assert_eq!(window.as_ref(), &(10..20));
+    assert_eq!(window.start, 10);
+
+    let window: TimeWindow = (5..7).into();
+    let range: Range<u64> = window.into();
+    assert_eq!(range, 5..7);
+}
+
+#[test]
+fn test_range_with_full_path() {
+    #[nutype(validate(non_empty), derive(Debug))]
+    pub struct Span(std::ops::Range<usize>);
+
+    assert_eq!(Span::new(3..3).unwrap_err(), SpanError::NonEmptyViolated);
+    assert_eq!(Span::new(3..4).unwrap().into_inner(), 3..4);
+}
+
+mod sanitizers {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_with() {
+        #[nutype(
+            sanitize(with = |r| if r.start > r.end { r.end..r.start } else { r }),
+            derive(Debug)
+        )]
+        pub struct Interval(Range<i32>);
+
+        assert_eq!(Interval::new(10..-5).into_inner(), -5..10);
+        assert_eq!(Interval::new(1..2).into_inner(), 1..2);
+    }
+}
+
+mod validators {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_non_empty() {
+        #[nutype(validate(non_empty), derive(Debug))]
+        pub struct TimeWindow(Range<u64>);
+
+        assert_eq!(
+            TimeWindow::new(10..10).unwrap_err(),
+            TimeWindowError::NonEmptyViolated
+        );
+        assert_eq!(
+            TimeWindow::new(20..10).unwrap_err(),
+            TimeWindowError::NonEmptyViolated
+        );
+        assert_eq!(TimeWindow::new(10..11).unwrap().into_inner(), 10..11);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_non_empty_inclusive() {
+        #[nutype(validate(non_empty), derive(Debug))]
+        pub struct Pages(RangeInclusive<u32>);
+
+        assert_eq!(Pages::new(3..=3).unwrap().into_inner(), 3..=3);
+        assert_eq!(Pages::new(4..=3).unwrap_err(), PagesError::NonEmptyViolated);
+    }
+
+    #[test]
+    fn test_bounds() {
+        #[nutype(
+            validate(start_greater_or_equal = 0, end_less_or_equal = 86_400),
+            derive(Debug)
+        )]
+        pub struct SecondsOfDay(Range<i64>);
+
+        assert_eq!(
+            SecondsOfDay::new(-1..100).unwrap_err(),
+            SecondsOfDayError::StartGreaterOrEqualViolated
+        );
+        assert_eq!(
+            SecondsOfDay::new(0..86_401).unwrap_err(),
+            SecondsOfDayError::EndLessOrEqualViolated
+        );
+        assert_eq!(
+            SecondsOfDay::new(0..86_400).unwrap().into_inner(),
+            0..86_400
+        );
+    }
+
+    #[test]
+    fn test_bounds_inclusive() {
+        #[nutype(
+            validate(start_greater_or_equal = 1.0, end_less_or_equal = 5.0),
+            derive(Debug)
+        )]
+        pub struct Rating(RangeInclusive<f64>);
+
+        assert_eq!(
+            Rating::new(0.5..=3.0).unwrap_err(),
+            RatingError::StartGreaterOrEqualViolated
+        );
+        assert_eq!(
+            Rating::new(1.0..=5.5).unwrap_err(),
+            RatingError::EndLessOrEqualViolated
+        );
+        assert_eq!(Rating::new(1.0..=5.0).unwrap().into_inner(), 1.0..=5.0);
+    }
+
+    #[test]
+    fn test_predicate() {
+        #[nutype(validate(predicate = |r| r.end - r.start <= 60), derive(Debug))]
+        pub struct ShortWindow(Range<u64>);
+
+        assert_eq!(
+            ShortWindow::new(0..61).unwrap_err(),
+            ShortWindowError::PredicateViolated
+        );
+        assert_eq!(ShortWindow::new(0..60).unwrap().into_inner(), 0..60);
+    }
+
+    #[test]
+    fn test_error_display() {
+        #[nutype(
+            validate(non_empty, start_greater_or_equal = 0, end_less_or_equal = 100),
+            derive(Debug)
+        )]
+        pub struct Percentile(Range<i32>);
+
+        assert_eq!(
+            Percentile::new(5..5).unwrap_err().to_string(),
+            "Percentile is empty."
+        );
+        assert_eq!(
+            Percentile::new(-1..5).unwrap_err().to_string(),
+            "Percentile starts too early. The start must be greater or equal to 0."
+        );
+        assert_eq!(
+            Percentile::new(0..101).unwrap_err().to_string(),
+            "Percentile ends too late. The end must be less or equal to 100."
+        );
+        assert_eq!(Percentile::new(0..100).unwrap().into_inner(), 0..100);
+    }
+}
+
+mod traits {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        #[nutype(validate(non_empty), derive(Debug, TryFrom))]
+        pub struct TimeWindow(Range<u64>);
+
+        assert_eq!(
+            TimeWindow::try_from(1..1).unwrap_err(),
+            TimeWindowError::NonEmptyViolated
+        );
+        assert_eq!(TimeWindow::try_from(1..2).unwrap().into_inner(), 1..2);
+    }
+
+    #[test]
+    fn test_default() {
+        #[nutype(validate(non_empty), derive(Debug, Default), default = 0..60)]
+        pub struct TimeWindow(Range<u64>);
+
+        assert_eq!(TimeWindow::default().into_inner(), 0..60);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        #[nutype(validate(non_empty), derive(Debug, PartialEq, Serialize, Deserialize))]
+        pub struct TimeWindow(Range<u64>);
+
+        let window = TimeWindow::new(3..7).unwrap();
+        let json = serde_json::to_string(&window).unwrap();
+        assert_eq!(json, "{\"start\":3,\"end\":7}");
+
+        let deserialized: TimeWindow = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, window);
+
+        let err = serde_json::from_str::<TimeWindow>("{\"start\":7,\"end\":7}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "TimeWindow is empty. Expected valid TimeWindow"
+        );
+    }
+}
+
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_new_unchecked() {
+        #[nutype(new_unchecked, validate(non_empty), derive(Debug))]
+        pub struct TimeWindow(Range<u64>);
+
+        let window = unsafe { TimeWindow::new_unchecked(20..10) };
+        assert_eq!(window.into_inner(), 20..10);
+    }
+}
@@ -4,6 +4,8 @@ use nutype::nutype;
 mod sanitizers {
     use super::*;
 
+    const CODE_LEN: usize = 3;
+
     #[test]
     fn test_trim() {
         #[nutype(sanitize(trim))]
@@ -33,6 +35,71 @@ mod sanitizers {
         assert_eq!(Name::new("Hello THERE").into_inner(), "HELLO THERE");
     }
 
+    #[test]
+    fn test_normalize_newlines() {
+        #[nutype(sanitize(normalize_newlines))]
+        pub struct Text(String);
+
+        assert_eq!(Text::new("").into_inner(), "");
+        assert_eq!(Text::new("one\ntwo").into_inner(), "one\ntwo");
+        assert_eq!(Text::new("one\r\ntwo\r\n").into_inner(), "one\ntwo\n");
+        assert_eq!(Text::new("one\rtwo\r").into_inner(), "one\ntwo\n");
+        assert_eq!(Text::new("a\r\r\nb\n\rc").into_inner(), "a\n\nb\n\nc");
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        #[nutype(sanitize(collapse_whitespace))]
+        pub struct Title(String);
+
+        assert_eq!(Title::new("").into_inner(), "");
+        assert_eq!(Title::new("one two").into_inner(), "one two");
+        assert_eq!(Title::new("one \t\n  two").into_inner(), "one two");
+        assert_eq!(Title::new("  one  ").into_inner(), " one ");
+
+        #[nutype(sanitize(trim, collapse_whitespace))]
+        pub struct DisplayName(String);
+
+        assert_eq!(
+            DisplayName::new("  John \u{a0}  Doe ").into_inner(),
+            "John Doe"
+        );
+    }
+
+    #[test]
+    fn test_remove_control_chars() {
+        #[nutype(sanitize(remove_control_chars))]
+        pub struct Title(String);
+
+        assert_eq!(Title::new("").into_inner(), "");
+        assert_eq!(
+            Title::new("Hello\u{0}\u{7} \u{1b}world").into_inner(),
+            "Hello world"
+        );
+        assert_eq!(Title::new("one\ttwo\n").into_inner(), "onetwo");
+    }
+
+    #[test]
+    fn test_truncate() {
+        #[nutype(sanitize(trim, truncate = 5))]
+        pub struct DisplayName(String);
+
+        assert_eq!(DisplayName::new("").into_inner(), "");
+        assert_eq!(DisplayName::new("Bob").into_inner(), "Bob");
+        assert_eq!(DisplayName::new("Alice").into_inner(), "Alice");
+        assert_eq!(DisplayName::new("  Alexander ").into_inner(), "Alexa");
+        // Counts chars, not bytes
+        assert_eq!(DisplayName::new("Żółćęą").into_inner(), "Żółćę");
+    }
+
+    #[test]
+    fn test_truncate_with_const() {
+        #[nutype(sanitize(truncate = CODE_LEN), validate(len_char_max = CODE_LEN))]
+        pub struct Code(String);
+
+        assert_eq!(Code::new("ABCD").unwrap().into_inner(), "ABC");
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -117,6 +184,47 @@ mod validators {
         assert_eq!(Name::new("Антон"), Err(NameError::LenCharMinViolated));
     }
 
+    #[test]
+    fn test_len_byte_min_and_max() {
+        #[nutype(
+            validate(len_byte_min = 4, len_byte_max = 10),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Name(String);
+
+        assert_eq!(Name::new("Bob"), Err(NameError::LenByteMinViolated));
+        assert_eq!(Name::new("Anton").unwrap().into_inner(), "Anton");
+
+        // Ukrainian, Cyrillic. Every char is 2 bytes.
+        assert_eq!(Name::new("Антон").unwrap().into_inner(), "Антон");
+        assert_eq!(Name::new("Сергій"), Err(NameError::LenByteMaxViolated));
+        assert_eq!(
+            Name::new("Сергій").unwrap_err().to_string(),
+            "Name is too long. The value length must be less than 10 byte(s)."
+        );
+    }
+
+    #[cfg(feature = "unicode_segmentation")]
+    #[test]
+    fn test_len_grapheme_min_and_max() {
+        #[nutype(
+            validate(len_grapheme_min = 2, len_grapheme_max = 3),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Reaction(String);
+
+        // Thumbs up with skin tone modifier is 2 chars, but 1 grapheme
+        assert_eq!(
+            Reaction::new("👍🏽"),
+            Err(ReactionError::LenGraphemeMinViolated)
+        );
+        assert_eq!(Reaction::new("👍🏽👍🏽👍🏽").unwrap().into_inner(), "👍🏽👍🏽👍🏽");
+        assert_eq!(
+            Reaction::new("👍🏽👍🏽👍🏽👍🏽"),
+            Err(ReactionError::LenGraphemeMaxViolated)
+        );
+    }
+
     #[test]
     fn test_not_empty() {
         #[nutype(validate(not_empty), derive(Debug, PartialEq))]
@@ -137,6 +245,216 @@ mod validators {
         assert_eq!(Name::new("Julia").unwrap().into_inner(), "Julia");
     }
 
+    #[test]
+    fn test_numeric() {
+        #[nutype(validate(numeric), derive(Debug, PartialEq))]
+        pub struct Amount(String);
+
+        assert_eq!(Amount::new("").unwrap_err(), AmountError::NumericViolated);
+        assert_eq!(
+            Amount::new("12a").unwrap_err(),
+            AmountError::NumericViolated
+        );
+        assert_eq!(
+            Amount::new("NaN").unwrap_err(),
+            AmountError::NumericViolated
+        );
+        assert_eq!(
+            Amount::new("inf").unwrap_err(),
+            AmountError::NumericViolated
+        );
+        for invalid in ["1e3", "+5", "-", "1.", ".5", "1.2.3", " 1", "infinity"] {
+            assert_eq!(
+                Amount::new(invalid).unwrap_err(),
+                AmountError::NumericViolated
+            );
+        }
+        assert_eq!(Amount::new("-12.50").unwrap().into_inner(), "-12.50");
+        assert_eq!(Amount::new("007").unwrap().into_inner(), "007");
+    }
+
+    #[test]
+    fn test_numeric_with_range() {
+        #[nutype(validate(numeric(min = 0, max = 9999)), derive(Debug, PartialEq))]
+        pub struct Code(String);
+
+        // The representation is kept as it is, e.g. leading zeros are not removed.
+        assert_eq!(Code::new("0042").unwrap().into_inner(), "0042");
+        assert_eq!(Code::new("9999").unwrap().into_inner(), "9999");
+        assert_eq!(Code::new("0").unwrap().into_inner(), "0");
+        assert_eq!(Code::new("10000").unwrap_err(), CodeError::NumericViolated);
+        assert_eq!(Code::new("-1").unwrap_err(), CodeError::NumericViolated);
+        assert_eq!(Code::new("forty").unwrap_err(), CodeError::NumericViolated);
+    }
+
+    #[test]
+    fn test_numeric_with_range_is_exact() {
+        #[nutype(
+            validate(numeric(min = -0.1, max = 9007199254740992)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Id(String);
+
+        // Beyond the precision of f64
+        assert_eq!(
+            Id::new("9007199254740992").unwrap().into_inner(),
+            "9007199254740992"
+        );
+        assert_eq!(
+            Id::new("9007199254740993").unwrap_err(),
+            IdError::NumericViolated
+        );
+        assert_eq!(
+            Id::new("9007199254740992.000000000001").unwrap_err(),
+            IdError::NumericViolated
+        );
+        assert_eq!(
+            Id::new("123456789012345678901234567890").unwrap_err(),
+            IdError::NumericViolated
+        );
+
+        assert_eq!(Id::new("-0.1").unwrap().into_inner(), "-0.1");
+        assert_eq!(Id::new("-0.10").unwrap().into_inner(), "-0.10");
+        assert_eq!(Id::new("-0").unwrap().into_inner(), "-0");
+        assert_eq!(Id::new("-0.11").unwrap_err(), IdError::NumericViolated);
+        assert_eq!(Id::new("-1").unwrap_err(), IdError::NumericViolated);
+    }
+
+    #[test]
+    fn test_numeric_error_display() {
+        #[nutype(validate(numeric(min = -1.5, max = 10)), derive(Debug))]
+        pub struct Level(String);
+
+        assert_eq!(
+            Level::new("11").unwrap_err().to_string(),
+            "Level is not a number between -1.5 and 10."
+        );
+    }
+
+    #[test]
+    fn test_email() {
+        #[nutype(sanitize(trim), validate(email), derive(Debug, PartialEq))]
+        pub struct Email(String);
+
+        for valid in [
+            "jane@example.com",
+            " jane.doe+news@mail.example.co.uk ",
+            "o'brien@example.ie",
+            "user@xn--80ak6aa92e.com",
+            "user@пример.укр",
+        ] {
+            assert!(Email::new(valid).is_ok(), "{valid} must be valid");
+        }
+        for invalid in [
+            "",
+            "jane",
+            "jane@",
+            "@example.com",
+            "jane@localhost",
+            "jane@@example.com",
+            "jane..doe@example.com",
+            ".jane@example.com",
+            "jane doe@example.com",
+            "jane@-example.com",
+            "jane@example..com",
+        ] {
+            assert_eq!(
+                Email::new(invalid),
+                Err(EmailError::EmailViolated),
+                "{invalid} must be invalid"
+            );
+        }
+        assert_eq!(
+            Email::new("jane").unwrap_err().to_string(),
+            "Email is not a valid email address."
+        );
+    }
+
+    #[test]
+    fn test_starts_with_ends_with_contains() {
+        #[nutype(
+            validate(starts_with = "sk_", ends_with = "_live", contains = "-"),
+            derive(Debug, PartialEq)
+        )]
+        pub struct ApiKey(String);
+
+        assert_eq!(
+            ApiKey::new("sk_1a2b-3c4d_live").unwrap().into_inner(),
+            "sk_1a2b-3c4d_live"
+        );
+        assert_eq!(
+            ApiKey::new("pk_1a2b-3c4d_live"),
+            Err(ApiKeyError::StartsWithViolated)
+        );
+        assert_eq!(
+            ApiKey::new("sk_1a2b-3c4d_test"),
+            Err(ApiKeyError::EndsWithViolated)
+        );
+        assert_eq!(
+            ApiKey::new("sk_1a2b3c4d_live"),
+            Err(ApiKeyError::ContainsViolated)
+        );
+        assert_eq!(
+            ApiKey::new("pk").unwrap_err().to_string(),
+            r#"ApiKey must start with "sk_"."#
+        );
+    }
+
+    #[test]
+    fn test_ascii() {
+        #[nutype(validate(ascii), derive(Debug, PartialEq))]
+        pub struct Slug(String);
+
+        assert_eq!(
+            Slug::new("hello-world").unwrap().into_inner(),
+            "hello-world"
+        );
+        assert_eq!(Slug::new("").unwrap().into_inner(), "");
+        assert_eq!(Slug::new("grüße").unwrap_err(), SlugError::AsciiViolated);
+        assert_eq!(
+            Slug::new("grüße").unwrap_err().to_string(),
+            "Slug contains non-ASCII characters."
+        );
+    }
+
+    #[test]
+    fn test_alphanumeric() {
+        #[nutype(validate(not_empty, alphanumeric), derive(Debug, PartialEq))]
+        pub struct Login(String);
+
+        assert_eq!(Login::new("Anna1984").unwrap().into_inner(), "Anna1984");
+        assert_eq!(Login::new("Łucja7").unwrap().into_inner(), "Łucja7");
+        assert_eq!(Login::new("").unwrap_err(), LoginError::NotEmptyViolated);
+        assert_eq!(
+            Login::new("anna_1984").unwrap_err(),
+            LoginError::AlphanumericViolated
+        );
+        assert_eq!(
+            Login::new("anna 1984").unwrap_err(),
+            LoginError::AlphanumericViolated
+        );
+    }
+
+    #[test]
+    fn test_alphabetic() {
+        #[nutype(
+            sanitize(uppercase),
+            validate(ascii, alphabetic),
+            derive(Debug, PartialEq)
+        )]
+        pub struct CountryCode(String);
+
+        assert_eq!(CountryCode::new("de").unwrap().into_inner(), "DE");
+        assert_eq!(
+            CountryCode::new("D3").unwrap_err(),
+            CountryCodeError::AlphabeticViolated
+        );
+        assert_eq!(
+            CountryCode::new("ÄÖ").unwrap_err(),
+            CountryCodeError::AsciiViolated
+        );
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -253,6 +571,36 @@ mod validators {
             );
         }
     }
+
+    mod when_boundaries_defined_as_const_generics {
+        use super::*;
+
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty, len_char_max = MAX_LEN),
+            derive(Debug, PartialEq, AsRef, Deref, TryFrom)
+        )]
+        struct BoundedString<const MAX_LEN: usize>(String);
+
+        #[test]
+        fn test_boundaries_defined_as_const_generics() {
+            type Code = BoundedString<3>;
+
+            assert_eq!(Code::new(" ab ").unwrap().as_ref(), "ab");
+            assert_eq!(
+                Code::new("abcd").unwrap_err(),
+                BoundedStringError::LenCharMaxViolated
+            );
+            assert_eq!(
+                Code::new("abcd").unwrap_err().to_string(),
+                "BoundedString is too long. The value length must be less than MAX_LEN character(s)."
+            );
+            assert_eq!(
+                BoundedString::<5>::try_from("abcd").unwrap().into_inner(),
+                "abcd"
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -372,6 +720,7 @@ mod derives {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_trait_deref() {
         #[nutype(derive(Deref))]
         pub struct Name(String);
@@ -383,6 +732,21 @@ mod derives {
         assert_eq!(name.is_empty(), false);
     }
 
+    #[test]
+    fn test_trait_deref_to_str_argument() {
+        #[nutype(validate(not_empty), derive(Deref))]
+        pub struct Name(String);
+
+        fn greet(name: &str) -> String {
+            format!("Hello, {name}!")
+        }
+
+        let name = Name::new("Anna").unwrap();
+
+        // `&Name` is coerced to `&str` through `&String`
+        assert_eq!(greet(&name), "Hello, Anna!");
+    }
+
     #[test]
     fn test_trait_borrow_str() {
         use std::borrow::Borrow;
@@ -407,6 +771,41 @@ mod derives {
         assert_eq!(name_borrowed, "Anna");
     }
 
+    #[test]
+    fn test_trait_partial_eq_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>))]
+        pub struct Name(String);
+
+        let name = Name::new("Anna");
+        assert_eq!(name, "Anna".to_string());
+        assert_eq!("Anna".to_string(), name);
+        assert_ne!(name, "Bob".to_string());
+    }
+
+    #[test]
+    fn test_trait_partial_ord_inner() {
+        #[nutype(derive(Debug, PartialEq<Inner>, PartialOrd<Inner>))]
+        pub struct Name(String);
+
+        let name = Name::new("Bob");
+        assert!(name > "Anna".to_string());
+        assert!("Carl".to_string() > name);
+    }
+
+    #[test]
+    fn test_trait_borrow_str_for_hash_map_lookup() {
+        use std::collections::HashMap;
+
+        #[nutype(derive(Hash, PartialEq, Eq, Borrow))]
+        pub struct Tag(String);
+
+        let mut counts: HashMap<Tag, u32> = HashMap::new();
+        counts.insert(Tag::new("rust"), 3);
+
+        assert_eq!(counts.get("rust"), Some(&3));
+        assert_eq!(counts.get("go"), None);
+    }
+
     #[test]
     fn test_trait_try_from_str() {
         #[nutype(validate(not_empty), derive(Debug, TryFrom))]
@@ -419,6 +818,18 @@ mod derives {
         assert_eq!(error, NameError::NotEmptyViolated);
     }
 
+    #[test]
+    fn test_trait_try_from_str_explicitly() {
+        #[nutype(validate(not_empty), derive(Debug, TryFrom<&str>))]
+        pub struct Name(String);
+
+        let name = Name::try_from("Anna").unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+
+        let error = Name::try_from("").unwrap_err();
+        assert_eq!(error, NameError::NotEmptyViolated);
+    }
+
     #[test]
     fn test_trait_try_from_string() {
         #[nutype(validate(not_empty), derive(Debug, TryFrom))]
@@ -440,6 +851,18 @@ mod derives {
         assert_eq!(name.to_string(), "Serhii");
     }
 
+    #[test]
+    fn test_trait_display_with_formatter_flags() {
+        #[nutype(derive(Display))]
+        pub struct Name(String);
+
+        let name = Name::new("Serhii");
+        assert_eq!(format!("{name:>8}"), "  Serhii");
+        assert_eq!(format!("{name:-<8}"), "Serhii--");
+        assert_eq!(format!("{name:.3}"), "Ser");
+        assert_eq!(format!("[{name:^10.4}]"), "[   Serh   ]");
+    }
+
     #[cfg(test)]
     mod trait_default {
         use super::*;
@@ -518,6 +941,44 @@ mod derives {
             }
         }
 
+        mod serde_with_adapters {
+            use super::*;
+            use serde_with::{serde_as, DisplayFromStr};
+            use std::collections::HashMap;
+
+            #[nutype(
+                sanitize(trim, lowercase),
+                validate(not_empty),
+                derive(Debug, Display, FromStr, PartialEq, Eq, Hash)
+            )]
+            pub struct Tag(String);
+
+            #[serde_as]
+            #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+            struct Article {
+                #[serde_as(as = "HashMap<DisplayFromStr, _>")]
+                tag_counts: HashMap<Tag, u32>,
+                #[serde_as(as = "Option<DisplayFromStr>")]
+                category: Option<Tag>,
+            }
+
+            #[test]
+            fn test_display_from_str() {
+                let article: Article =
+                    serde_json::from_str(r#"{"tag_counts":{" Rust ":2},"category":"News"}"#)
+                        .unwrap();
+                assert_eq!(article.tag_counts[&Tag::new("rust").unwrap()], 2);
+                assert_eq!(article.category, Some(Tag::new("news").unwrap()));
+
+                let json = serde_json::to_string(&article).unwrap();
+                assert_eq!(json, r#"{"tag_counts":{"rust":2},"category":"news"}"#);
+
+                let res =
+                    serde_json::from_str::<Article>(r#"{"tag_counts":{"  ":1},"category":null}"#);
+                assert!(res.is_err());
+            }
+        }
+
         mod ron_format {
             use super::*;
 
@@ -554,6 +1015,917 @@ mod derives {
     }
 }
 
+#[cfg(test)]
+mod associated_functions {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(not_empty, len_char_max = 5),
+        derive(Debug)
+    )]
+    pub struct Nickname(String);
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(Nickname::sanitize("  ALICE "), "alice");
+        assert_eq!(Nickname::sanitize(String::from("Bob")), "bob");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Nickname::validate("alice"), Ok(()));
+        assert_eq!(Nickname::validate(""), Err(NicknameError::NotEmptyViolated));
+        assert_eq!(
+            Nickname::validate("alice_in_wonderland"),
+            Err(NicknameError::LenCharMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Nickname::is_valid("bob"));
+        assert!(!Nickname::is_valid("bobby_tables"));
+
+        // The value is not sanitized.
+        assert!(!Nickname::is_valid("   bob   "));
+        assert!(Nickname::is_valid(&Nickname::sanitize("   bob   ")));
+    }
+
+    #[test]
+    fn test_sanitize_without_validation() {
+        #[nutype(sanitize(trim))]
+        pub struct Title(String);
+
+        assert_eq!(Title::sanitize(" Dune "), "Dune");
+    }
+
+    #[test]
+    fn test_new_all() {
+        let nicknames = Nickname::new_all(["Alice", " bob "]).unwrap();
+        assert_eq!(nicknames[0].as_inner(), "alice");
+        assert_eq!(nicknames[1].as_inner(), "bob");
+
+        let column = vec![String::from("eve"), String::from("mallory")];
+        assert_eq!(
+            Nickname::new_all(column).unwrap_err(),
+            (1, NicknameError::LenCharMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_as_inner() {
+        let nickname = Nickname::new(" Bob ").unwrap();
+        let inner: &String = nickname.as_inner();
+        assert_eq!(inner, "bob");
+    }
+}
+
+#[cfg(test)]
+mod new_or_return {
+    use super::*;
+
+    #[nutype(sanitize(trim), validate(len_char_max = 5), derive(Debug, PartialEq))]
+    pub struct Code(String);
+
+    #[test]
+    fn test_gives_value_back_on_error() {
+        let raw = String::from(" abcdef ");
+        let (value, err) = Code::new_or_return(raw).unwrap_err();
+        assert_eq!(value, "abcdef");
+        assert_eq!(err, CodeError::LenCharMaxViolated);
+    }
+
+    #[test]
+    fn test_valid_value() {
+        let code = Code::new_or_return(" abc ").unwrap();
+        assert_eq!(code, Code::new("abc").unwrap());
+    }
+}
+
+#[cfg(test)]
+mod borrowed {
+    use super::*;
+    use std::borrow::Borrow;
+    use std::collections::HashMap;
+
+    #[nutype(
+        validate(not_empty, len_char_max = 8),
+        derive(Debug, Clone, PartialEq, Eq, Hash),
+        borrowed = UsernameRef
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_from_str() {
+        let username: &UsernameRef = UsernameRef::from_str("alice").unwrap();
+        assert_eq!(username.as_str(), "alice");
+        assert_eq!(username.to_string(), "alice");
+        assert_eq!(
+            UsernameRef::from_str(""),
+            Err(UsernameError::NotEmptyViolated)
+        );
+        assert_eq!(
+            UsernameRef::from_str("bobby_tables"),
+            Err(UsernameError::LenCharMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_borrow_and_to_owned() {
+        let owned = Username::new("alice").unwrap();
+        let borrowed: &UsernameRef = owned.borrow();
+        assert_eq!(borrowed.as_ref(), "alice");
+        assert_eq!(borrowed.to_owned(), owned);
+    }
+
+    #[test]
+    fn test_lookup_by_borrowed_key() {
+        let mut scores: HashMap<Username, u32> = HashMap::new();
+        scores.insert(Username::new("alice").unwrap(), 10);
+
+        let key = UsernameRef::from_str("alice").unwrap();
+        assert_eq!(scores.get(key), Some(&10));
+    }
+
+    #[nutype(derive(Debug, PartialEq), borrowed = NoteRef)]
+    pub struct Note(String);
+
+    #[test]
+    fn test_without_validation() {
+        let note: &NoteRef = NoteRef::from_str("anything");
+        assert_eq!(note.to_owned(), Note::new("anything"));
+    }
+}
+
+#[cfg(test)]
+mod on_error {
+    use super::*;
+    use std::cell::RefCell;
+    use std::fmt::Display;
+
+    thread_local! {
+        static FAILURES: RefCell<Vec<(&'static str, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record_failure<E: Display>(type_name: &'static str, error: &E) {
+        FAILURES.with(|failures| failures.borrow_mut().push((type_name, error.to_string())));
+    }
+
+    fn take_failures() -> Vec<(&'static str, String)> {
+        FAILURES.with(|failures| failures.take())
+    }
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 8),
+        on_error = record_failure,
+        derive(Debug, TryFrom)
+    )]
+    pub struct Nickname(String);
+
+    #[test]
+    fn test_failed_construction_is_reported() {
+        take_failures();
+
+        assert!(Nickname::new("   ").is_err());
+        assert!(Nickname::try_from("way too long nickname").is_err());
+        assert!(Nickname::new_or_return("").is_err());
+
+        assert_eq!(
+            take_failures(),
+            vec![
+                ("Nickname", "Nickname is empty.".to_string()),
+                (
+                    "Nickname",
+                    "Nickname is too long. The value length must be less than 8 character(s)."
+                        .to_string()
+                ),
+                ("Nickname", "Nickname is empty.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_success_and_checks_are_not_reported() {
+        take_failures();
+
+        assert!(Nickname::new("neo").is_ok());
+        assert!(!Nickname::is_valid(""));
+        assert!(Nickname::validate("").is_err());
+
+        assert_eq!(take_failures(), vec![]);
+    }
+
+    #[test]
+    fn test_closure() {
+        #[nutype(
+            validate(not_empty),
+            on_error = |type_name, err: &TagError| record_failure(type_name, err),
+        )]
+        pub struct Tag(String);
+
+        take_failures();
+        assert!(Tag::new("").is_err());
+        assert_eq!(take_failures(), vec![("Tag", "Tag is empty.".to_string())]);
+    }
+}
+
+#[cfg(test)]
+mod no_new {
+    use super::*;
+    use std::str::FromStr;
+
+    #[nutype(
+        no_new,
+        sanitize(trim),
+        validate(not_empty),
+        derive(Debug, PartialEq, TryFrom, FromStr, AsRef)
+    )]
+    pub struct Username(String);
+
+    #[nutype(no_new, sanitize(lowercase), derive(Debug, From, AsRef))]
+    pub struct Slug(String);
+
+    #[test]
+    fn test_construct_with_traits() {
+        let username = Username::try_from("  ada ").unwrap();
+        assert_eq!(username.as_ref(), "ada");
+        assert_eq!(Username::from_str("ada").unwrap(), username);
+        assert_eq!(
+            Username::try_from("  "),
+            Err(UsernameError::NotEmptyViolated)
+        );
+    }
+
+    #[test]
+    fn test_construct_without_validation() {
+        let slug = Slug::from("Hello-World");
+        assert_eq!(slug.as_ref(), "hello-world");
+    }
+}
+
+#[cfg(test)]
+mod try_new {
+    use super::*;
+
+    #[nutype(
+        try_new,
+        sanitize(trim),
+        validate(not_empty),
+        derive(Debug, PartialEq, TryFrom, AsRef)
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_try_new() {
+        let username = Username::try_new("  ada ").unwrap();
+        assert_eq!(username.as_ref(), "ada");
+        assert_eq!(
+            Username::try_new("  "),
+            Err(UsernameError::NotEmptyViolated)
+        );
+        assert_eq!(Username::try_from("ada").unwrap(), username);
+    }
+}
+
+#[cfg(test)]
+mod constructor {
+    use super::*;
+
+    #[nutype(
+        constructor = parse,
+        validate(len_char_max = 8),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Code(String);
+
+    #[nutype(constructor = from_raw, sanitize(uppercase), derive(Debug, AsRef))]
+    pub struct Ticker(String);
+
+    #[test]
+    fn test_custom_constructor_name() {
+        assert_eq!(Code::parse("abc").unwrap().as_ref(), "abc");
+        assert_eq!(
+            Code::parse("too long code"),
+            Err(CodeError::LenCharMaxViolated)
+        );
+        assert_eq!(Ticker::from_raw("aapl").as_ref(), "AAPL");
+    }
+}
+
+#[cfg(test)]
+mod new_or_default {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 16),
+        default = "localhost",
+        derive(Debug)
+    )]
+    pub struct Host(String);
+
+    #[test]
+    fn test_new_or_default() {
+        assert_eq!(
+            Host::new_or_default(" example.com ").into_inner(),
+            "example.com"
+        );
+        assert_eq!(Host::new_or_default("   ").into_inner(), "localhost");
+        assert_eq!(
+            Host::new_or_default("very-long-host-name.example.com").into_inner(),
+            "localhost"
+        );
+    }
+}
+
+#[cfg(test)]
+mod map {
+    use super::*;
+
+    #[test]
+    fn test_map_and_try_map() {
+        #[nutype(sanitize(trim, lowercase), derive(Debug, AsRef))]
+        pub struct Slug(String);
+
+        #[nutype(validate(len_char_max = 5), derive(Debug, PartialEq, AsRef))]
+        pub struct Code(String);
+
+        let slug = Slug::new("hello").map(|s| format!("  {s}-World "));
+        assert_eq!(slug.as_ref(), "hello-world");
+
+        let code = Code::new("ab").unwrap();
+        let code = code.try_map(|s| s.repeat(2)).unwrap();
+        assert_eq!(code.as_ref(), "abab");
+        assert_eq!(
+            code.try_map(|s| s.repeat(2)),
+            Err(CodeError::LenCharMaxViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod mut_guard {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 10),
+        mut_guard = restore,
+        derive(Debug, AsRef)
+    )]
+    pub struct Title(String);
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty),
+        mut_guard = panic,
+        derive(Debug, AsRef)
+    )]
+    pub struct Label(String);
+
+    #[test]
+    fn test_mutation_is_sanitized() {
+        let mut title = Title::new("Dune").unwrap();
+        {
+            let mut guard = title.as_mut_guard();
+            guard.push_str(" II  ");
+            assert_eq!(guard.as_str(), "Dune II  ");
+        }
+        assert_eq!(title.as_ref(), "Dune II");
+    }
+
+    #[test]
+    fn test_invalid_mutation_is_restored() {
+        let mut title = Title::new("Dune").unwrap();
+        title.as_mut_guard().push_str(": Part Two");
+        assert_eq!(title.as_ref(), "Dune");
+
+        title.as_mut_guard().clear();
+        assert_eq!(title.as_ref(), "Dune");
+    }
+
+    #[test]
+    fn test_valid_mutation_with_panic_policy() {
+        let mut label = Label::new("new").unwrap();
+        *label.as_mut_guard() = " done ".to_string();
+        assert_eq!(label.as_ref(), "done");
+    }
+
+    #[test]
+    #[should_panic(expected = "Label does not pass the validation after mutation: Label is empty.")]
+    fn test_invalid_mutation_with_panic_policy() {
+        let mut label = Label::new("new").unwrap();
+        label.as_mut_guard().clear();
+    }
+
+    #[test]
+    fn test_invalid_mutation_with_panic_policy_restores_value() {
+        let mut label = Label::new("new").unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            label.as_mut_guard().clear();
+        }));
+        assert!(result.is_err());
+        assert_eq!(label.as_ref(), "new");
+    }
+
+    #[test]
+    fn test_guard_dropped_while_panicking() {
+        let mut label = Label::new("new").unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut guard = label.as_mut_guard();
+            guard.clear();
+            panic!("Something went wrong");
+        }));
+        assert!(result.is_err());
+        assert_eq!(label.as_ref(), "new");
+    }
+
+    #[test]
+    fn test_modify() {
+        let mut title = Title::new("Dune").unwrap();
+        assert_eq!(title.modify(|s| s.push_str(" II  ")), Ok(()));
+        assert_eq!(title.as_ref(), "Dune II");
+
+        assert_eq!(
+            title.modify(|s| s.push_str(": Part Two")),
+            Err(TitleError::LenCharMaxViolated)
+        );
+        assert_eq!(title.as_ref(), "Dune II");
+    }
+
+    #[test]
+    fn test_modify_with_panic_policy() {
+        let mut label = Label::new("new").unwrap();
+        assert_eq!(label.modify(|s| s.push_str(" one ")), Ok(()));
+        assert_eq!(label.as_ref(), "new one");
+
+        // The error is returned instead of panicking
+        assert_eq!(
+            label.modify(String::clear),
+            Err(LabelError::NotEmptyViolated)
+        );
+        assert_eq!(label.as_ref(), "new one");
+    }
+
+    #[test]
+    fn test_modify_restores_value_on_panic() {
+        let mut title = Title::new("Dune").unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = title.modify(|s| {
+                s.clear();
+                panic!("Something went wrong");
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(title.as_ref(), "Dune");
+    }
+
+    #[test]
+    fn test_modify_without_validation() {
+        #[nutype(sanitize(lowercase), mut_guard = restore, derive(Debug, AsRef))]
+        pub struct Slug(String);
+
+        let mut slug = Slug::new("hello");
+        slug.modify(|s| s.push_str("-World"));
+        assert_eq!(slug.as_ref(), "hello-world");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tracing")]
+mod tracing_events {
+    use super::*;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    type Fields = Vec<(&'static str, String)>;
+
+    #[derive(Default, Clone)]
+    struct RecordingSubscriber {
+        events: Arc<Mutex<Vec<(Level, Fields)>>>,
+    }
+
+    struct FieldsVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldsVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push((field.name(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Vec::new();
+            event.record(&mut FieldsVisitor(&mut fields));
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 8),
+        derive(Debug, TryFrom)
+    )]
+    pub struct Login(String);
+
+    #[test]
+    fn test_failed_construction_emits_debug_event() {
+        let subscriber = RecordingSubscriber::default();
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            assert!(Login::new("neo").is_ok());
+            assert!(!Login::is_valid(""));
+            assert!(Login::new("  ").is_err());
+            assert!(Login::try_from("way too long login").is_err());
+        });
+
+        let events = subscriber.events.lock().unwrap();
+        assert_eq!(
+            *events,
+            vec![
+                (
+                    Level::DEBUG,
+                    vec![
+                        ("message", "Validation failed".to_string()),
+                        ("type_name", "Login".to_string()),
+                        ("violation", "NotEmptyViolated".to_string()),
+                    ]
+                ),
+                (
+                    Level::DEBUG,
+                    vec![
+                        ("message", "Validation failed".to_string()),
+                        ("type_name", "Login".to_string()),
+                        ("violation", "LenCharMaxViolated".to_string()),
+                    ]
+                ),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod debug_redacted {
+    use super::*;
+
+    #[nutype(
+        debug = redacted,
+        validate(not_empty),
+        derive(Debug, Clone, PartialEq)
+    )]
+    pub struct ApiKey(String);
+
+    #[test]
+    fn test_debug_hides_inner_value() {
+        let key = ApiKey::new("secret").unwrap();
+        assert_eq!(format!("{key:?}"), "ApiKey(***)");
+        assert_eq!(format!("{:?}", Some(key.clone())), "Some(ApiKey(***))");
+        assert_eq!(format!("{key:#?}"), "ApiKey(\n    ***,\n)");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "subtle")]
+mod sensitive {
+    use super::*;
+
+    #[nutype(
+        sensitive,
+        validate(len_char_min = 8),
+        derive(Debug, Clone, PartialEq, Eq, Hash)
+    )]
+    pub struct ApiToken(String);
+
+    #[test]
+    fn test_constant_time_eq() {
+        let token = ApiToken::new("secret-token").unwrap();
+        assert_eq!(token, ApiToken::new("secret-token").unwrap());
+        assert_ne!(token, ApiToken::new("secret-tokem").unwrap());
+        assert_ne!(token, ApiToken::new("secret-token-2").unwrap());
+    }
+}
+
+#[cfg(test)]
+mod sql_check {
+    use super::*;
+
+    #[test]
+    fn test_sql_check() {
+        #[nutype(sql_check, sanitize(trim), validate(not_empty, len_char_max = 64))]
+        pub struct Username(String);
+
+        assert_eq!(
+            Username::sql_check("username"),
+            "CHAR_LENGTH(username) > 0 AND CHAR_LENGTH(username) <= 64"
+        );
+    }
+}
+
+#[cfg(test)]
+mod zod_schema {
+    use super::*;
+
+    #[test]
+    fn test_zod_schema() {
+        #[nutype(zod, sanitize(trim, lowercase), validate(not_empty, len_char_max = 64))]
+        pub struct Username(String);
+
+        assert_eq!(
+            Username::zod_schema(),
+            "z.string().trim().toLowerCase().min(1).max(64)"
+        );
+    }
+
+    #[test]
+    fn test_zod_schema_with_substrings() {
+        #[nutype(
+            zod,
+            validate(starts_with = "sk_", contains = "-", ends_with = "_live")
+        )]
+        pub struct ApiKey(String);
+
+        assert_eq!(
+            ApiKey::zod_schema(),
+            r#"z.string().startsWith("sk_").includes("-").endsWith("_live")"#
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_zod_schema_with_regex() {
+        #[nutype(zod, validate(regex = "^[a-z]+/[0-9]+$"))]
+        pub struct Path(String);
+
+        assert_eq!(Path::zod_schema(), r"z.string().regex(/^[a-z]+\/[0-9]+$/)");
+    }
+}
+
+#[cfg(feature = "url")]
+mod url_validator {
+    use super::*;
+
+    #[test]
+    fn test_url() {
+        #[nutype(validate(url), derive(Debug, PartialEq))]
+        pub struct Link(String);
+
+        assert_eq!(
+            Link::new("https://example.com/path?q=1")
+                .unwrap()
+                .into_inner(),
+            "https://example.com/path?q=1"
+        );
+        assert!(Link::new("mailto:jane@example.com").is_ok());
+        assert_eq!(Link::new("example.com"), Err(LinkError::UrlViolated));
+        assert_eq!(Link::new("https://"), Err(LinkError::UrlViolated));
+        assert_eq!(
+            Link::new("").unwrap_err().to_string(),
+            "Link is not a valid URL."
+        );
+    }
+
+    #[test]
+    fn test_url_with_schemes() {
+        #[nutype(validate(url(schemes = ["https", "HTTP"])), derive(Debug, PartialEq))]
+        pub struct WebsiteUrl(String);
+
+        assert!(WebsiteUrl::new("https://example.com").is_ok());
+        assert!(WebsiteUrl::new("HTTP://example.com").is_ok());
+        assert_eq!(
+            WebsiteUrl::new("ftp://example.com"),
+            Err(WebsiteUrlError::UrlViolated)
+        );
+        assert_eq!(
+            WebsiteUrl::new("ftp://example.com")
+                .unwrap_err()
+                .to_string(),
+            "WebsiteUrl is not a valid URL with one of the schemes: https, http."
+        );
+    }
+}
+
+#[cfg(test)]
+mod str_containers {
+    use super::*;
+    use std::borrow::{Borrow, Cow};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_box_str() {
+        #[nutype(
+            sanitize(trim, lowercase),
+            validate(not_empty, len_char_max = 10),
+            derive(
+                Debug, Clone, PartialEq, Eq, Hash, AsRef, Deref, FromStr, TryFrom, Borrow, Into,
+                Display
+            )
+        )]
+        pub struct Tag(Box<str>);
+
+        let tag = Tag::new("  RUST ").unwrap();
+        assert_eq!(tag.as_ref(), "rust");
+        assert_eq!(tag.len(), 4);
+        assert_eq!(tag.to_string(), "rust");
+        assert_eq!("Rust".parse::<Tag>().unwrap(), tag);
+        assert_eq!(Tag::try_from("rust").unwrap(), tag);
+        assert_eq!(Tag::new("   "), Err(TagError::NotEmptyViolated));
+
+        let mut tags = HashMap::new();
+        tags.insert(tag.clone(), 1);
+        assert_eq!(tags.get("rust"), Some(&1));
+
+        let inner: Box<str> = tag.into();
+        assert_eq!(&*inner, "rust");
+    }
+
+    #[test]
+    fn test_arc_str() {
+        #[nutype(derive(Debug, Clone, PartialEq, From, Deref, Borrow))]
+        pub struct Label(Arc<str>);
+
+        let label = Label::new("shared");
+        let clone = label.clone();
+        assert_eq!(label, clone);
+        assert_eq!(Label::from(String::from("shared")), label);
+
+        let borrowed: &str = label.borrow();
+        assert_eq!(borrowed, "shared");
+        assert!(label.starts_with("sh"));
+
+        let inner: Arc<str> = label.into_inner();
+        assert_eq!(&*inner, "shared");
+    }
+
+    #[test]
+    fn test_cow_str() {
+        #[nutype(sanitize(trim), derive(Debug, PartialEq, From, FromStr, AsRef))]
+        pub struct Title(Cow<'static, str>);
+
+        assert_eq!(Title::new("Dune").into_inner(), Cow::Borrowed("Dune"));
+        assert_eq!(Title::from(" Dune ").as_ref(), "Dune");
+        assert_eq!("Dune".parse::<Title>().unwrap(), Title::new("Dune"));
+    }
+
+    #[test]
+    fn test_cow_str_with_validation() {
+        #[nutype(validate(not_empty), derive(Debug, PartialEq, FromStr, TryFrom))]
+        pub struct Title(Cow<'static, str>);
+
+        assert_eq!(
+            Title::try_from("Dune").unwrap(),
+            Title::new("Dune").unwrap()
+        );
+        assert_eq!(Title::try_from(""), Err(TitleError::NotEmptyViolated));
+        assert_eq!("".parse::<Title>(), Err(TitleError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_fully_qualified_path() {
+        #[nutype(derive(Debug, AsRef))]
+        pub struct Name(::std::boxed::Box<str>);
+
+        assert_eq!(Name::new("Ann").as_ref(), "Ann");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[nutype(validate(not_empty), derive(Debug, PartialEq, Serialize, Deserialize))]
+        pub struct Label(Arc<str>);
+
+        let label = Label::new("shared").unwrap();
+        let json = serde_json::to_string(&label).unwrap();
+        assert_eq!(json, "\"shared\"");
+        assert_eq!(serde_json::from_str::<Label>(&json).unwrap(), label);
+        assert!(serde_json::from_str::<Label>("\"\"").is_err());
+    }
+}
+
+#[cfg(feature = "unicode_normalization")]
+mod unicode_normalization {
+    use super::*;
+
+    #[test]
+    fn test_nfc() {
+        #[nutype(sanitize(nfc), derive(Debug, PartialEq))]
+        pub struct Name(String);
+
+        // "e" followed by combining acute accent is composed into "é"
+        let decomposed = Name::new("Jose\u{301}");
+        let composed = Name::new("Jos\u{e9}");
+        assert_eq!(decomposed, composed);
+        assert_eq!(decomposed.into_inner(), "José");
+
+        // Compatibility characters are kept
+        assert_eq!(Name::new("ﬁle").into_inner(), "ﬁle");
+    }
+
+    #[test]
+    fn test_nfkc() {
+        #[nutype(
+            sanitize(nfkc, lowercase),
+            validate(alphanumeric),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Handle(String);
+
+        assert_eq!(Handle::new("ﬁle").unwrap().into_inner(), "file");
+        assert_eq!(Handle::new("Ｂｏｂ２").unwrap().into_inner(), "bob2");
+    }
+}
+
+mod len_unit {
+    use super::*;
+
+    #[test]
+    fn test_len_unit_bytes() {
+        #[nutype(
+            len_unit = bytes,
+            sanitize(truncate = 5),
+            validate(len_char_min = 2, len_char_max = 5),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Tag(String);
+
+        assert_eq!(Tag::new("a"), Err(TagError::LenCharMinViolated));
+        assert_eq!(Tag::new("ab").unwrap().into_inner(), "ab");
+        // "ż" takes 2 bytes, so it counts as 2
+        assert_eq!(Tag::new("ż").unwrap().into_inner(), "ż");
+        assert_eq!(Tag::new("abcdefg").unwrap().into_inner(), "abcde");
+        // The cut happens on the closest char boundary: "ż" can not be split in half
+        assert_eq!(Tag::new("abcdżź").unwrap().into_inner(), "abcd");
+        assert_eq!(Tag::new("żółć").unwrap().into_inner(), "żó");
+        assert_eq!(
+            TagError::LenCharMinViolated.to_string(),
+            "Tag is too short. The value length must be more than 2 byte(s)."
+        );
+    }
+
+    #[test]
+    fn test_len_unit_chars() {
+        #[nutype(
+            len_unit = chars,
+            sanitize(truncate = 3),
+            validate(len_char_min = 2),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Tag(String);
+
+        assert_eq!(Tag::new("ż"), Err(TagError::LenCharMinViolated));
+        assert_eq!(Tag::new("żółć").unwrap().into_inner(), "żół");
+    }
+
+    #[cfg(feature = "unicode_segmentation")]
+    #[test]
+    fn test_len_unit_graphemes() {
+        #[nutype(
+            len_unit = graphemes,
+            sanitize(truncate = 2),
+            validate(len_char_min = 2),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Reaction(String);
+
+        // Thumbs up with skin tone modifier is 2 chars, but 1 grapheme
+        assert_eq!(Reaction::new("👍🏽"), Err(ReactionError::LenCharMinViolated));
+        assert_eq!(Reaction::new("👍🏽👍🏽").unwrap().into_inner(), "👍🏽👍🏽");
+        // The cut happens on the grapheme boundary, the modifier stays with its emoji
+        assert_eq!(Reaction::new("👍🏽👍🏽👍🏽").unwrap().into_inner(), "👍🏽👍🏽");
+        assert_eq!(
+            ReactionError::LenCharMinViolated.to_string(),
+            "Reaction is too short. The value length must be more than 2 grapheme(s)."
+        );
+    }
+}
+
 #[cfg(feature = "new_unchecked")]
 mod new_unchecked {
     use super::*;
@@ -0,0 +1,232 @@
+use nutype::nutype;
+
+#[test]
+fn test_tuple_without_validation() {
+    #[nutype(derive(Debug, Clone, Copy, PartialEq, PartialOrd, AsRef, Deref, Into, From))]
+    pub struct Point((i32, i32));
+
+    let point = Point::new((3, 4));
+    assert_eq!(point.into_inner(), (3, 4));
+    assert_eq!(point.as_ref(), &(3, 4));
+    assert_eq!(point.0, 3);
+
+    let point: Point = (1, 2).into();
+    let inner: (i32, i32) = point.into();
+    assert_eq!(inner, (1, 2));
+}
+
+mod sanitizers {
+    use super::*;
+
+    #[test]
+    fn test_component_with() {
+        #[nutype(
+            sanitize(0 => with = |lat| lat.clamp(-90.0, 90.0); 1 => with = |lng| lng.clamp(-180.0, 180.0)),
+            derive(Debug)
+        )]
+        pub struct Coordinate((f64, f64));
+
+        assert_eq!(Coordinate::new((95.0, -200.0)).into_inner(), (90.0, -180.0));
+        assert_eq!(Coordinate::new((50.5, 30.5)).into_inner(), (50.5, 30.5));
+    }
+
+    #[test]
+    fn test_whole_tuple_with() {
+        #[nutype(
+            sanitize(with = |(a, b)| if a > b { (b, a) } else { (a, b) }, 0 => with = |a| a.max(0)),
+            derive(Debug)
+        )]
+        pub struct Interval((i32, i32));
+
+        assert_eq!(Interval::new((10, -5)).into_inner(), (0, 10));
+        assert_eq!(Interval::new((1, 2)).into_inner(), (1, 2));
+    }
+}
+
+mod validators {
+    use super::*;
+
+    #[test]
+    fn test_component_bounds() {
+        #[nutype(
+            validate(
+                0 => greater_or_equal = -90.0, less_or_equal = 90.0;
+                1 => greater_or_equal = -180.0, less_or_equal = 180.0
+            ),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Coordinate((f64, f64));
+
+        assert_eq!(
+            Coordinate::new((-90.5, 0.0)),
+            Err(CoordinateError::Component0GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            Coordinate::new((90.5, 0.0)),
+            Err(CoordinateError::Component0LessOrEqualViolated)
+        );
+        assert_eq!(
+            Coordinate::new((0.0, -180.5)),
+            Err(CoordinateError::Component1GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            Coordinate::new((0.0, 180.5)),
+            Err(CoordinateError::Component1LessOrEqualViolated)
+        );
+        assert_eq!(
+            Coordinate::new((50.45, 30.52)).unwrap().into_inner(),
+            (50.45, 30.52)
+        );
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        #[nutype(validate(0 => greater = 0, 1 => less = 10), derive(Debug))]
+        pub struct Pair((u8, u8));
+
+        assert_eq!(
+            Pair::new((0, 5)).unwrap_err(),
+            PairError::Component0GreaterViolated
+        );
+        assert_eq!(
+            Pair::new((1, 10)).unwrap_err(),
+            PairError::Component1LessViolated
+        );
+        assert_eq!(Pair::new((1, 9)).unwrap().into_inner(), (1, 9));
+    }
+
+    #[test]
+    fn test_finite() {
+        #[nutype(validate(0 => finite; 1 => finite), derive(Debug))]
+        pub struct Vector((f32, f32));
+
+        assert_eq!(
+            Vector::new((f32::NAN, 1.0)).unwrap_err(),
+            VectorError::Component0FiniteViolated
+        );
+        assert_eq!(
+            Vector::new((1.0, f32::INFINITY)).unwrap_err(),
+            VectorError::Component1FiniteViolated
+        );
+        assert_eq!(Vector::new((1.0, 2.0)).unwrap().into_inner(), (1.0, 2.0));
+    }
+
+    #[test]
+    fn test_predicate() {
+        #[nutype(
+            validate(predicate = |&(min, max)| min <= max, 1 => predicate = |max| max % 2 == 0),
+            derive(Debug)
+        )]
+        pub struct EvenBounds((i32, i32));
+
+        assert_eq!(
+            EvenBounds::new((5, 4)).unwrap_err(),
+            EvenBoundsError::PredicateViolated
+        );
+        assert_eq!(
+            EvenBounds::new((1, 3)).unwrap_err(),
+            EvenBoundsError::Component1PredicateViolated
+        );
+        assert_eq!(EvenBounds::new((1, 4)).unwrap().into_inner(), (1, 4));
+    }
+
+    #[test]
+    fn test_three_components() {
+        #[nutype(validate(2 => less_or_equal = 255), derive(Debug))]
+        pub struct Rgb((u16, u16, u16));
+
+        assert_eq!(
+            Rgb::new((0, 0, 256)).unwrap_err(),
+            RgbError::Component2LessOrEqualViolated
+        );
+        assert_eq!(Rgb::new((300, 0, 255)).unwrap().into_inner(), (300, 0, 255));
+    }
+
+    #[test]
+    fn test_error_display() {
+        #[nutype(
+            validate(predicate = |&(a, b)| a != b, 0 => less_or_equal = 90.0; 1 => finite),
+            derive(Debug)
+        )]
+        pub struct Pair((f64, f64));
+
+        assert_eq!(
+            Pair::new((91.0, 0.0)).unwrap_err().to_string(),
+            "Pair.0 is too big. The value must be less or equal to 90.0."
+        );
+        assert_eq!(
+            Pair::new((1.0, f64::NAN)).unwrap_err().to_string(),
+            "Pair.1 is not finite."
+        );
+        assert_eq!(
+            Pair::new((1.0, 1.0)).unwrap_err().to_string(),
+            "Pair failed the predicate test."
+        );
+        assert_eq!(Pair::new((1.0, 2.0)).unwrap().into_inner(), (1.0, 2.0));
+    }
+}
+
+mod traits {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        #[nutype(validate(0 => less = 10), derive(Debug, TryFrom))]
+        pub struct Pair((u8, u8));
+
+        assert_eq!(
+            Pair::try_from((10, 0)).unwrap_err(),
+            PairError::Component0LessViolated
+        );
+        assert_eq!(Pair::try_from((9, 0)).unwrap().into_inner(), (9, 0));
+    }
+
+    #[test]
+    fn test_default() {
+        #[nutype(validate(0 => less = 10), derive(Debug, Default), default = (1, 2))]
+        pub struct Pair((u8, u8));
+
+        assert_eq!(Pair::default().into_inner(), (1, 2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        #[nutype(
+            validate(0 => greater_or_equal = -90.0, less_or_equal = 90.0),
+            derive(Debug, PartialEq, Serialize, Deserialize)
+        )]
+        pub struct Coordinate((f64, f64));
+
+        let coordinate = Coordinate::new((50.5, 30.5)).unwrap();
+        let json = serde_json::to_string(&coordinate).unwrap();
+        assert_eq!(json, "[50.5,30.5]");
+
+        let deserialized: Coordinate = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, coordinate);
+
+        let err = serde_json::from_str::<Coordinate>("[91.0,0.0]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Coordinate.0 is too big. The value must be less or equal to 90.0. Expected valid Coordinate"
+        );
+    }
+}
+
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(
+            new_unchecked,
+            validate(0 => greater_or_equal = -90.0, less_or_equal = 90.0),
+            derive(Debug)
+        )]
+        pub struct Coordinate((f64, f64));
+
+        let coordinate = unsafe { Coordinate::new_unchecked((100.0, 0.0)) };
+        assert_eq!(coordinate.into_inner(), (100.0, 0.0));
+    }
+}
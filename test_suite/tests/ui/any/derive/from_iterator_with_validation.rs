@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), derive(FromIterator))]
+pub struct Ids(Vec<u32>);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_min = 5, len_max = 3))]
+pub struct Tags(Vec<u8>);
+
+fn main () {}
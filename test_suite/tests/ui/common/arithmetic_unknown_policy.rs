@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(arithmetic = wrap, derive(Add))]
+pub struct Count(u32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(arithmetic = saturate, derive(Debug))]
+pub struct Count(u32);
+
+fn main() {}
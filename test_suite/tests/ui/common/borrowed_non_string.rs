@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0), borrowed = AmountRef)]
+pub struct Amount(u32);
+
+fn main() {}
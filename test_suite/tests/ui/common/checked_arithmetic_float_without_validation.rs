@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(checked_arithmetic)]
+pub struct Weight(f64);
+
+fn main() {}
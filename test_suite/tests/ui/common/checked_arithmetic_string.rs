@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(checked_arithmetic, validate(not_empty))]
+pub struct Name(String);
+
+fn main() {}
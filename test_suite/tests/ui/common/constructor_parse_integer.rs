@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(constructor = parse, validate(less_or_equal = 100))]
+pub struct Percentage(u8);
+
+fn main () {}
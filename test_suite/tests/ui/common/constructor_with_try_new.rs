@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(try_new, constructor = parse, validate(not_empty))]
+pub struct Username(String);
+
+fn main () {}
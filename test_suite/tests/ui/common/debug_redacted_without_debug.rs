@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(debug = redacted, derive(Clone))]
+pub struct ApiKey(String);
+
+fn main() {}
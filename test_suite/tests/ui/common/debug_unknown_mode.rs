@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(debug = hidden, derive(Debug))]
+pub struct ApiKey(String);
+
+fn main() {}
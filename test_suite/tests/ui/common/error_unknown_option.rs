@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), error(name = NameError))]
+pub struct Name(String);
+
+fn main () {}
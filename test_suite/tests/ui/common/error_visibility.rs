@@ -0,0 +1,10 @@
+mod encapsulated {
+    use nutype::nutype;
+
+    #[nutype(validate(greater = 0), error(vis = pub(self), module = quantity))]
+    pub struct Quantity(u32);
+}
+
+fn main () {
+    let _ = encapsulated::Quantity::new(0).map_err(|_: encapsulated::quantity::Error| ());
+}
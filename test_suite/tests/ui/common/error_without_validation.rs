@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim), error(module = name))]
+pub struct Name(String);
+
+fn main () {}
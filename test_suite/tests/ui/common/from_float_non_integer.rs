@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(from_float = round)]
+pub struct Distance(f64);
+
+fn main () {}
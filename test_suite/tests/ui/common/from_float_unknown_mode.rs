@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(from_float = truncate)]
+pub struct Meters(u32);
+
+fn main () {}
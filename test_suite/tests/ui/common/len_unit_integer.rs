@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0), len_unit = bytes)]
+pub struct Amount(u32);
+
+fn main () {}
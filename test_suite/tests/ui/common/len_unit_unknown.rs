@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_char_max = 20), len_unit = words)]
+pub struct Title(String);
+
+fn main () {}
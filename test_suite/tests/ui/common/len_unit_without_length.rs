@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), len_unit = bytes)]
+pub struct Title(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0), mut_guard = restore)]
+pub struct Amount(u32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), mut_guard = ignore)]
+pub struct Title(String);
+
+fn main () {}
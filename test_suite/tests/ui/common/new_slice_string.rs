@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(new_slice, validate(not_empty))]
+pub struct Name(String);
+
+fn main() {}
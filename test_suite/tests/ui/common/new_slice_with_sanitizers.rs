@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(new_slice, sanitize(with = |n: i32| n.abs()), validate(less = 100))]
+pub struct Distance(i32);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+#[nutype(no_new, validate(not_empty), derive(TryFrom))]
+pub struct Username(String);
+
+fn main () {
+    let _ = Username::new("ada");
+}
@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+fn report<E>(_type_name: &str, _err: &E) {}
+
+#[nutype(sanitize(trim), on_error = report)]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(PartialEq<u8>))]
+pub struct Age(u8);
+
+fn main() {}
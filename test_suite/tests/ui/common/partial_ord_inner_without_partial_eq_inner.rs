@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(PartialEq, PartialOrd<Inner>))]
+pub struct Amount(u64);
+
+fn main() {}
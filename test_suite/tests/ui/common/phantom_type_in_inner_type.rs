@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug))]
+pub struct Wrapper<T>(Vec<T>, std::marker::PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sensitive, derive(PartialEq))]
+pub struct ApiToken(String);
+
+fn main() {}
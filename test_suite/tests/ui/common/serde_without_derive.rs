@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(serde(as_string), derive(Debug))]
+pub struct AccountId(u64);
+
+fn main() {}
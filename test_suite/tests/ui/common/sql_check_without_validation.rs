@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sql_check, sanitize(trim))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(TryFrom<&String>))]
+pub struct Amount(u32);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+#[nutype(try_new, validate(not_empty))]
+pub struct Username(String);
+
+fn main () {
+    let _ = Username::new("ada");
+}
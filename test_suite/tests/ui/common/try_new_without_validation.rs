@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(try_new, sanitize(trim))]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(zod, validate(predicate = |n: &i32| n % 3 == 0))]
+pub struct MultipleOfThree(i32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(zod)]
+pub struct Point((i32, i32));
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater = 0.0),
+    default = -1.5,
+    derive(Default),
+)]
+pub struct Weight(f64);
+
+fn main () {}
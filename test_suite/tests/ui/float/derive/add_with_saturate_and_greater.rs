@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0.0), arithmetic = saturate, derive(Add))]
+pub struct Positive(f64);
+
+fn main() {}
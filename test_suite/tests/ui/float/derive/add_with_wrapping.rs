@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(arithmetic = wrapping, derive(Add))]
+pub struct Angle(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, LowerHex))]
+struct Weight(f64);
+
+fn main() {}
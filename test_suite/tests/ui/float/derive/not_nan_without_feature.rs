@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(finite), derive(NotNan))]
+pub struct Weight(f64);
+
+fn main() {}
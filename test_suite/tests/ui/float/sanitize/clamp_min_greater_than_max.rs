@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(clamp(min = 100.0, max = 0.0)))]
+pub struct Percent(f64);
+
+fn main() {}
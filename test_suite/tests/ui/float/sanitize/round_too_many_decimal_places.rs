@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(round = 16))]
+pub struct Price(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(latitude, longitude))]
+pub struct Coordinate(f64);
+
+fn main() {}
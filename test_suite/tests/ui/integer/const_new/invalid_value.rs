@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 150), derive(Debug))]
+pub struct Age(u8);
+
+const AGE: Age = Age::const_new::<200>();
+
+fn main() {
+    println!("{AGE:?}");
+}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater_or_equal = 18, less_or_equal = 150),
+    consts(ADULT = 18, NEWBORN = 0),
+)]
+pub struct Age(u8);
+
+fn main () {}
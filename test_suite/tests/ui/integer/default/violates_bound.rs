@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater_or_equal = 18, less_or_equal = 150),
+    default = 17,
+    derive(Default),
+)]
+pub struct Age(u8);
+
+fn main () {}
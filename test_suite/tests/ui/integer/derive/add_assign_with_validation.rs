@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 100), derive(AddAssign))]
+pub struct Percent(u8);
+
+fn main() {}
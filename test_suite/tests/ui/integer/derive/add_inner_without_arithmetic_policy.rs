@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 150), derive(Add<Inner>))]
+pub struct Age(u8);
+
+fn main() {}
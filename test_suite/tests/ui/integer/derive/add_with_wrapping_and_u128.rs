@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 1000), arithmetic = wrapping, derive(Add))]
+pub struct Amount(u128);
+
+fn main() {}
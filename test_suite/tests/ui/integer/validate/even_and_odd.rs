@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(even, odd))]
+pub struct Parity(u8);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(fits_bits = 9))]
+struct Flags(u8);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of = 0))]
+pub struct Cents(u64);
+
+fn main () {}
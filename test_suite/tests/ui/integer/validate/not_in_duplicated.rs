@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_in = [0, 255, 0]))]
+pub struct Channel(u8);
+
+fn main () {}
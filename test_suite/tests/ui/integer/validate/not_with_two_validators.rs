@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not(even, greater = 5)))]
+pub struct Amount(u32);
+
+fn main () {}
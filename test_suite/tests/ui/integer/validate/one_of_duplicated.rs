@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(one_of = [1, 2, 5, 2]))]
+pub struct Denomination(u32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(one_of = []))]
+pub struct Denomination(u32);
+
+fn main () {}
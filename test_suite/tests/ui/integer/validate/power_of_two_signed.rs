@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(power_of_two))]
+pub struct Size(i32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(finite))]
+pub struct TimeWindow(std::ops::Range<u64>);
+
+fn main() {}
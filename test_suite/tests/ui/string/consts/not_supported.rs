@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(consts(ADMIN = String::new()))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim), validate(not_empty), borrowed = NameRef)]
+pub struct Name(String);
+
+fn main() {}
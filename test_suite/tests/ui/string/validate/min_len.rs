@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(min_len = 3))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(url))]
+pub struct Link(String);
+
+fn main () {}
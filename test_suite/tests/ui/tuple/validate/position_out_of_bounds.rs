@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(2 => less = 10))]
+pub struct Pair((u8, u8));
+
+fn main() {}